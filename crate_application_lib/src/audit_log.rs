@@ -0,0 +1,652 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Append-only audit log of the runs
+//!
+//! Each record is a line of json containing the hash of the previous record, so that the records
+//! build a chain starting at [GENESIS_HASH]. The hash of a record is the SHA-256 (base16 encoded)
+//! of the json of the record without its hash. Editing, removing or inserting a record afterwards
+//! breaks the chain, what is detected by [verify_audit_log], except removing the last records:
+//! a log truncated after any record is a valid chain.
+//!
+//! The log is then tamper-evident only with an anchor: someone able to write the file can truncate
+//! it or recalculate the whole chain. The head of the log (see [AuditLog::head]) at the end of
+//! each audited run is written in the [crate::RunInformation] and in the report of the run, which
+//! is covered by the fingerprint and the signature of the report files. The head should also be
+//! written down elsewhere (e.g. in the minutes of the electoral board). A log that does not contain
+//! the anchored head (see [AuditLogHead::is_contained_in]) has been truncated or rewritten.
+//!
+//! The runs are audited by the runner (see [RunAudit] and [crate::Runner::set_audit]).
+//! The log is locked by the [AuditLog], so that two instances cannot write it at the same time.
+//!
+//! The default location of the log is [VerifierConfig::audit_log_file_path].
+//!
+//! [VerifierConfig::audit_log_file_path]: rust_ev_verifier_lib::VerifierConfig::audit_log_file_path
+
+use crate::{ExtractDataSetResults, RunnerEvent};
+use chrono::Local;
+use derive_getters::Getters;
+use rust_ev_system_library::rust_ev_crypto_primitives::prelude::{
+    ByteArray, EncodeTrait,
+    basic_crypto_functions::{BasisCryptoError, sha256, sha256_stream},
+};
+use rust_ev_verifier_lib::{
+    DatasetTypeKind,
+    direct_trust::{DirectTrustError, Keystore},
+    verification::VerificationPeriod,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs::{File, OpenOptions, TryLockError},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+use thiserror::Error;
+
+/// Extension appended to the name of the log for the lock file
+const LOCK_EXTENSION: &str = "lock";
+
+/// Previous hash of the first record of the chain
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+#[derive(Error, Debug)]
+#[error(transparent)]
+/// Error with the audit log
+pub struct AuditLogError(#[from] AuditLogErrorImpl);
+
+#[derive(Error, Debug)]
+enum AuditLogErrorImpl {
+    #[error("IO Error: {msg}")]
+    IOError { msg: String, source: std::io::Error },
+    #[error("Error serializing the record")]
+    Serialize { source: serde_json::Error },
+    #[error("Error parsing the record at line {line}")]
+    Parse {
+        line: usize,
+        source: serde_json::Error,
+    },
+    #[error("Error calculating the hash")]
+    Hash { source: BasisCryptoError },
+    #[error("Error reading the fingerprints of the keystore")]
+    Keystore { source: DirectTrustError },
+    #[error("Chain of the audit log broken at line {line}: {msg}")]
+    ChainBroken { line: usize, msg: String },
+    #[error("The audit log {} is used by another instance", path.display())]
+    Locked { path: PathBuf },
+}
+
+/// Dataset recorded in the audit log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditDataset {
+    /// Kind of the dataset (context or tally)
+    pub kind: String,
+    /// Zip file from which the dataset was extracted
+    pub source_path: PathBuf,
+    /// Fingerprint of the zip file (see [rust_ev_verifier_lib::dataset::DatasetMetadata::fingerprint])
+    pub fingerprint: String,
+}
+
+/// Event recorded in the audit log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum AuditEvent {
+    /// The datasets have been extracted
+    DatasetsExtracted {
+        period: String,
+        location: PathBuf,
+        datasets: Vec<AuditDataset>,
+    },
+    /// Fingerprints of the certificates of the direct trust used for the run
+    KeystoreFingerprints {
+        /// Fingerprint for each certificate authority
+        fingerprints: BTreeMap<String, String>,
+        /// Certificates of the additional keystores
+        additional: Vec<String>,
+    },
+    /// Verifications excluded from the run, with who excluded them and why
+    VerificationsExcluded {
+        period: String,
+        ids: Vec<String>,
+        by: String,
+        reason: String,
+    },
+    /// The run has started
+    RunStarted {
+        period: String,
+        number_of_verifications: usize,
+    },
+    /// Summary of the result of a verification
+    VerificationFinished {
+        id: String,
+        status: String,
+        errors: usize,
+        failures: usize,
+        duration_ms: Option<u64>,
+    },
+    /// The run has finished
+    RunFinished {
+        period: String,
+        election_event_id: Option<String>,
+        duration_ms: Option<u64>,
+    },
+    /// A report file has been generated
+    ReportGenerated { path: PathBuf, sha256: String },
+}
+
+impl AuditEvent {
+    /// Event for the extracted datasets, with the fingerprints of the zip files
+    pub fn datasets_extracted(
+        period: VerificationPeriod,
+        extracted: &ExtractDataSetResults,
+    ) -> Self {
+        let datasets = [DatasetTypeKind::Context, DatasetTypeKind::Tally]
+            .iter()
+            .filter_map(|kind| extracted.dataset_metadata(kind))
+            .map(|md| AuditDataset {
+                kind: md.kind().as_ref().to_string(),
+                source_path: md.source_path().to_path_buf(),
+                fingerprint: md.fingerprint_str(),
+            })
+            .collect();
+        Self::DatasetsExtracted {
+            period: period.as_ref().to_string(),
+            location: extracted.location().to_path_buf(),
+            datasets,
+        }
+    }
+
+    /// Event with the fingerprints of the keystore
+    pub fn keystore_fingerprints(keystore: &Keystore) -> Result<Self, AuditLogError> {
+        let fingerprints = keystore
+            .fingerprints()
+            .map_err(|e| AuditLogErrorImpl::Keystore { source: e })?
+            .into_iter()
+            .map(|(ca, f)| {
+                (
+                    ca.as_ref().to_string(),
+                    f.base16_encode().unwrap_or_default(),
+                )
+            })
+            .collect();
+        let additional = keystore
+            .additional_fingerprints()
+            .iter()
+            .map(|(c, validity)| match validity {
+                Some(v) => format!("{c} valid {v}"),
+                None => c.to_string(),
+            })
+            .collect();
+        Ok(Self::KeystoreFingerprints {
+            fingerprints,
+            additional,
+        })
+    }
+
+    /// Event of the runner to record, if relevant for the audit
+    ///
+    /// The start of the verifications and the progress are not recorded
+    pub fn from_runner_event(period: VerificationPeriod, event: &RunnerEvent) -> Option<Self> {
+        match event {
+            RunnerEvent::RunStarted {
+                number_of_verifications,
+                ..
+            } => Some(Self::RunStarted {
+                period: period.as_ref().to_string(),
+                number_of_verifications: *number_of_verifications,
+            }),
+            RunnerEvent::VerificationFinished {
+                id,
+                status,
+                duration,
+                result,
+            } => Some(Self::VerificationFinished {
+                id: id.clone(),
+                status: status.as_ref().to_string(),
+                errors: result.errors().len(),
+                failures: result.failures().len(),
+                duration_ms: duration.map(|d| d.as_millis() as u64),
+            }),
+            RunnerEvent::RunFinished(runner_information) => Some(Self::RunFinished {
+                period: period.as_ref().to_string(),
+                election_event_id: runner_information.election_event_id.clone(),
+                duration_ms: runner_information.duration.map(|d| d.as_millis() as u64),
+            }),
+            RunnerEvent::VerificationStarted { .. } | RunnerEvent::Progress { .. } => None,
+        }
+    }
+
+    /// Event for a generated report file, with the hash of its content
+    pub fn report_generated(path: &Path) -> Result<Self, AuditLogError> {
+        let f = File::open(path).map_err(|e| AuditLogErrorImpl::IOError {
+            msg: format!("Error opening the report file {}", path.display()),
+            source: e,
+        })?;
+        let hash = sha256_stream(&mut BufReader::new(f))
+            .map_err(|e| AuditLogErrorImpl::Hash { source: e })?;
+        Ok(Self::ReportGenerated {
+            path: path.to_path_buf(),
+            sha256: hash.base16_encode().unwrap_or_default(),
+        })
+    }
+}
+
+/// Record of the audit log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Getters)]
+pub struct AuditRecord {
+    /// Position of the record in the log, starting at 0
+    #[getter(copy)]
+    sequence: u64,
+    /// Date and time of the record (RFC 3339)
+    timestamp: String,
+    event: AuditEvent,
+    /// Hash of the previous record ([GENESIS_HASH] for the first record)
+    previous_hash: String,
+    /// Hash of the record
+    hash: String,
+}
+
+/// Content of the record used to calculate the hash
+#[derive(Serialize)]
+struct HashedContent<'a> {
+    sequence: u64,
+    timestamp: &'a str,
+    event: &'a AuditEvent,
+    previous_hash: &'a str,
+}
+
+impl AuditRecord {
+    fn new(
+        sequence: u64,
+        timestamp: String,
+        event: AuditEvent,
+        previous_hash: String,
+    ) -> Result<Self, AuditLogErrorImpl> {
+        let mut res = Self {
+            sequence,
+            timestamp,
+            event,
+            previous_hash,
+            hash: String::new(),
+        };
+        res.hash = res.calculate_hash()?;
+        Ok(res)
+    }
+
+    fn calculate_hash(&self) -> Result<String, AuditLogErrorImpl> {
+        let content = serde_json::to_vec(&HashedContent {
+            sequence: self.sequence,
+            timestamp: &self.timestamp,
+            event: &self.event,
+            previous_hash: &self.previous_hash,
+        })
+        .map_err(|e| AuditLogErrorImpl::Serialize { source: e })?;
+        sha256(&ByteArray::from_bytes(&content))
+            .map(|h| h.base16_encode().unwrap_or_default())
+            .map_err(|e| AuditLogErrorImpl::Hash { source: e })
+    }
+}
+
+/// Head of the audit log, to anchor the chain (see the [module documentation](self))
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Getters)]
+pub struct AuditLogHead {
+    /// Path of the log
+    path: PathBuf,
+    /// Number of records in the log
+    #[getter(copy)]
+    records: u64,
+    /// Hash of the last record ([GENESIS_HASH] if the log is empty)
+    last_hash: String,
+}
+
+impl AuditLogHead {
+    /// The records of the log contain the head, i.e. the log has not been truncated before the
+    /// head or rewritten
+    ///
+    /// The records must be verified (see [verify_audit_log])
+    pub fn is_contained_in(&self, records: &[AuditRecord]) -> bool {
+        match self.records {
+            0 => self.last_hash == GENESIS_HASH,
+            n => records
+                .get(n as usize - 1)
+                .is_some_and(|r| r.hash == self.last_hash),
+        }
+    }
+}
+
+/// Audit log in a file, to which the records are appended
+///
+/// The file is opened for each record and synchronized after the write, so that a record is not
+/// lost if the application stops. The log is locked with a lock file (the path of the log with
+/// the extension `.lock` appended) as long as the [AuditLog] exists, so that the log cannot be
+/// written by two instances at the same time.
+#[derive(Debug)]
+pub struct AuditLog {
+    path: PathBuf,
+    next_sequence: u64,
+    last_hash: String,
+    _lock: File,
+}
+
+/// Path of the lock file of the log
+fn lock_file_path(path: &Path) -> PathBuf {
+    let mut res = path.as_os_str().to_os_string();
+    res.push(format!(".{LOCK_EXTENSION}"));
+    PathBuf::from(res)
+}
+
+impl AuditLog {
+    /// Open the audit log, which is created if it does not exist
+    ///
+    /// The chain of the existing records is verified, so that no record is appended to a
+    /// broken chain. Return an error if the log is used by another instance
+    pub fn open(path: &Path) -> Result<Self, AuditLogError> {
+        if let Some(dir) = path.parent()
+            && !dir.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(dir).map_err(|e| AuditLogErrorImpl::IOError {
+                msg: format!("Error creating the directory {}", dir.display()),
+                source: e,
+            })?;
+        }
+        let lock_path = lock_file_path(path);
+        let lock = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_path)
+            .map_err(|e| AuditLogErrorImpl::IOError {
+                msg: format!("Error opening the lock file {}", lock_path.display()),
+                source: e,
+            })?;
+        lock.try_lock().map_err(|e| match e {
+            TryLockError::WouldBlock => AuditLogErrorImpl::Locked {
+                path: path.to_path_buf(),
+            },
+            TryLockError::Error(e) => AuditLogErrorImpl::IOError {
+                msg: format!("Error locking the lock file {}", lock_path.display()),
+                source: e,
+            },
+        })?;
+        let records = match path.exists() {
+            true => verify_audit_log(path)?,
+            false => vec![],
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            next_sequence: records.len() as u64,
+            last_hash: records
+                .last()
+                .map(|r| r.hash.clone())
+                .unwrap_or(GENESIS_HASH.to_string()),
+            _lock: lock,
+        })
+    }
+
+    /// Path of the log
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Hash of the last record ([GENESIS_HASH] if the log is empty)
+    pub fn last_hash(&self) -> &str {
+        &self.last_hash
+    }
+
+    /// Head of the log, to anchor the chain
+    pub fn head(&self) -> AuditLogHead {
+        AuditLogHead {
+            path: self.path.clone(),
+            records: self.next_sequence,
+            last_hash: self.last_hash.clone(),
+        }
+    }
+
+    /// Number of records in the log
+    pub fn len(&self) -> u64 {
+        self.next_sequence
+    }
+
+    /// The log contains no record
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Append the event to the log and return the new record
+    pub fn append(&mut self, event: AuditEvent) -> Result<AuditRecord, AuditLogError> {
+        let record = AuditRecord::new(
+            self.next_sequence,
+            Local::now().to_rfc3339(),
+            event,
+            self.last_hash.clone(),
+        )?;
+        let mut line = serde_json::to_string(&record)
+            .map_err(|e| AuditLogErrorImpl::Serialize { source: e })?;
+        line.push('\n');
+        let io_error = |e| AuditLogErrorImpl::IOError {
+            msg: format!("Error writing the audit log {}", self.path.display()),
+            source: e,
+        };
+        let mut f = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+        f.write_all(line.as_bytes()).map_err(io_error)?;
+        f.sync_data().map_err(io_error)?;
+        self.next_sequence += 1;
+        self.last_hash = record.hash.clone();
+        Ok(record)
+    }
+}
+
+/// Audit of the runs of a runner (see [crate::Runner::set_audit])
+///
+/// The runner records the exclusion of the verifications (with who excluded them and why), the
+/// fingerprints of the keystore, the start of the run, the result of each verification and the
+/// end of the run. The log is shared, e.g. by the runners of the setup and the tally
+#[derive(Debug, Clone)]
+pub struct RunAudit {
+    audit_log: Arc<Mutex<AuditLog>>,
+    excluded_by: String,
+    exclusion_reason: String,
+}
+
+impl RunAudit {
+    /// Create the audit with the shared log
+    ///
+    /// The person excluding the verifications and the reason are empty
+    /// (see [RunAudit::with_exclusion_justification])
+    pub fn new(audit_log: Arc<Mutex<AuditLog>>) -> Self {
+        Self {
+            audit_log,
+            excluded_by: String::new(),
+            exclusion_reason: String::new(),
+        }
+    }
+
+    /// Set the person excluding the verifications and the reason of the exclusion
+    pub fn with_exclusion_justification(mut self, excluded_by: &str, reason: &str) -> Self {
+        self.excluded_by = excluded_by.to_string();
+        self.exclusion_reason = reason.to_string();
+        self
+    }
+
+    /// The shared log
+    pub fn audit_log(&self) -> &Arc<Mutex<AuditLog>> {
+        &self.audit_log
+    }
+
+    /// Append the event to the shared log
+    pub fn append(&self, event: AuditEvent) -> Result<AuditRecord, AuditLogError> {
+        self.audit_log.lock().unwrap().append(event)
+    }
+
+    /// Head of the shared log
+    pub fn head(&self) -> AuditLogHead {
+        self.audit_log.lock().unwrap().head()
+    }
+
+    /// Event of the exclusion of the verifications with the justification
+    pub fn exclusion_event(&self, period: VerificationPeriod, ids: &[String]) -> AuditEvent {
+        AuditEvent::VerificationsExcluded {
+            period: period.as_ref().to_string(),
+            ids: ids.to_vec(),
+            by: self.excluded_by.clone(),
+            reason: self.exclusion_reason.clone(),
+        }
+    }
+}
+
+/// Verify the chain of the audit log and return the records
+///
+/// Return an error if a record cannot be read, if a record has been modified, or if records
+/// have been removed, inserted or reordered
+pub fn verify_audit_log(path: &Path) -> Result<Vec<AuditRecord>, AuditLogError> {
+    let f = File::open(path).map_err(|e| AuditLogErrorImpl::IOError {
+        msg: format!("Error opening the audit log {}", path.display()),
+        source: e,
+    })?;
+    let mut res: Vec<AuditRecord> = vec![];
+    for (i, line) in BufReader::new(f).lines().enumerate() {
+        let line_nb = i + 1;
+        let line = line.map_err(|e| AuditLogErrorImpl::IOError {
+            msg: format!("Error reading the line {line_nb} of {}", path.display()),
+            source: e,
+        })?;
+        let record: AuditRecord =
+            serde_json::from_str(&line).map_err(|e| AuditLogErrorImpl::Parse {
+                line: line_nb,
+                source: e,
+            })?;
+        let broken = |msg: String| AuditLogErrorImpl::ChainBroken { line: line_nb, msg };
+        if record.sequence != i as u64 {
+            return Err(broken(format!("sequence {} instead of {i}", record.sequence)).into());
+        }
+        let expected_previous = res.last().map(|r| r.hash.as_str()).unwrap_or(GENESIS_HASH);
+        if record.previous_hash != expected_previous {
+            return Err(broken("previous hash does not match".to_string()).into());
+        }
+        if record.calculate_hash()? != record.hash {
+            return Err(broken("hash of the record does not match".to_string()).into());
+        }
+        res.push(record);
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_log_path(name: &str) -> PathBuf {
+        let now = Local::now().format("%Y%m%d_%H%M%S%f").to_string();
+        PathBuf::from(".")
+            .join("test_temp_dir")
+            .join(format!("test_audit_log_{name}_{now}.jsonl"))
+    }
+
+    fn write_sample(path: &Path) -> AuditLog {
+        let mut log = AuditLog::open(path).unwrap();
+        log.append(AuditEvent::VerificationsExcluded {
+            period: "setup".to_string(),
+            ids: vec!["05.01".to_string()],
+            by: "Jane Doe".to_string(),
+            reason: "Known issue".to_string(),
+        })
+        .unwrap();
+        log.append(AuditEvent::RunStarted {
+            period: "setup".to_string(),
+            number_of_verifications: 2,
+        })
+        .unwrap();
+        log.append(AuditEvent::VerificationFinished {
+            id: "01.01".to_string(),
+            status: "Successful".to_string(),
+            errors: 0,
+            failures: 0,
+            duration_ms: Some(10),
+        })
+        .unwrap();
+        log
+    }
+
+    #[test]
+    fn test_chain() {
+        let path = test_log_path("chain");
+        let log = write_sample(&path);
+        assert_eq!(log.len(), 3);
+        let records = verify_audit_log(&path).unwrap();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].previous_hash(), GENESIS_HASH);
+        assert_eq!(records[1].previous_hash(), records[0].hash());
+        assert_eq!(records[2].hash(), log.last_hash());
+        let head = log.head();
+        assert_eq!(head.records(), 3);
+        assert!(head.is_contained_in(&records));
+        // The log is locked as long as it is open
+        assert!(AuditLog::open(&path).is_err());
+        drop(log);
+        let mut reopened = AuditLog::open(&path).unwrap();
+        assert_eq!(reopened.last_hash(), head.last_hash());
+        let record = reopened
+            .append(AuditEvent::ReportGenerated {
+                path: PathBuf::from("report.txt"),
+                sha256: "00".to_string(),
+            })
+            .unwrap();
+        assert_eq!(record.sequence(), 3);
+        assert_eq!(record.previous_hash(), head.last_hash());
+        let records = verify_audit_log(&path).unwrap();
+        assert_eq!(records.len(), 4);
+        assert!(head.is_contained_in(&records));
+    }
+
+    #[test]
+    fn test_truncated() {
+        let path = test_log_path("truncated");
+        let log = write_sample(&path);
+        let head = log.head();
+        drop(log);
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines = content.lines().collect::<Vec<_>>();
+        std::fs::write(&path, format!("{}\n{}\n", lines[0], lines[1])).unwrap();
+        // The chain is valid, but the head is missing
+        let records = verify_audit_log(&path).unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(!head.is_contained_in(&records));
+    }
+
+    #[test]
+    fn test_tampered() {
+        let path = test_log_path("edited");
+        drop(write_sample(&path));
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(&path, content.replace("Known issue", "Other reason")).unwrap();
+        let err = verify_audit_log(&path).unwrap_err().to_string();
+        assert!(err.contains("line 1"), "{err}");
+        assert!(AuditLog::open(&path).is_err());
+
+        let path = test_log_path("removed");
+        write_sample(&path);
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines = content.lines().collect::<Vec<_>>();
+        std::fs::write(&path, format!("{}\n{}\n", lines[0], lines[2])).unwrap();
+        let err = verify_audit_log(&path).unwrap_err().to_string();
+        assert!(err.contains("line 2"), "{err}");
+    }
+}
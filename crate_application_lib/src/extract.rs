@@ -17,12 +17,15 @@
 use crate::RunnerErrorImpl;
 
 use super::RunnerError;
+use rust_ev_system_library::rust_ev_crypto_primitives::prelude::{ByteArray, DecodeTrait};
 use rust_ev_verifier_lib::{
     dataset::DatasetMetadata, verification::VerificationPeriod, DatasetTypeKind, VerifierConfig,
 };
+use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 use tracing::{info, instrument};
 
@@ -47,7 +50,7 @@ impl ExtractDataSetResults {
         context_zip_file: &Path,
         tally_zip_file: Option<&Path>,
         password: &str,
-        config: &'static VerifierConfig,
+        config: &VerifierConfig,
     ) -> Result<Self, RunnerError> {
         Self::extract_datasets_impl(period, context_zip_file, tally_zip_file, password, config)
             .map_err(RunnerError::from)
@@ -58,7 +61,7 @@ impl ExtractDataSetResults {
         context_zip_file: &Path,
         tally_zip_file: Option<&Path>,
         password: &str,
-        config: &'static VerifierConfig,
+        config: &VerifierConfig,
     ) -> Result<Self, RunnerErrorImpl> {
         let dataset_root_path = config.create_dataset_dir_path();
         let mut hm = HashMap::new();
@@ -111,3 +114,78 @@ impl ExtractDataSetResults {
         })
     }
 }
+
+/// Metadata of an extracted dataset, as archived with the [crate::RunInformation]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DatasetMetadataArchive {
+    kind: String,
+    source_path: PathBuf,
+    decrypted_zip_path: PathBuf,
+    extracted_dir_path: PathBuf,
+    fingerprint: String,
+}
+
+/// [ExtractDataSetResults] as archived with the [crate::RunInformation]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ExtractDataSetResultsArchive {
+    location: PathBuf,
+    datasets: Vec<DatasetMetadataArchive>,
+}
+
+impl From<&ExtractDataSetResults> for ExtractDataSetResultsArchive {
+    fn from(value: &ExtractDataSetResults) -> Self {
+        let mut datasets = value
+            .metadata_hm
+            .values()
+            .map(|md| DatasetMetadataArchive {
+                kind: md.kind().as_ref().to_string(),
+                source_path: md.source_path().to_path_buf(),
+                decrypted_zip_path: md.decrypted_zip_path().to_path_buf(),
+                extracted_dir_path: md.extracted_dir_path().to_path_buf(),
+                fingerprint: md.fingerprint_str(),
+            })
+            .collect::<Vec<_>>();
+        datasets.sort_by(|d1, d2| d1.kind.cmp(&d2.kind));
+        Self {
+            location: value.location.clone(),
+            datasets,
+        }
+    }
+}
+
+impl TryFrom<ExtractDataSetResultsArchive> for ExtractDataSetResults {
+    type Error = RunnerErrorImpl;
+
+    fn try_from(value: ExtractDataSetResultsArchive) -> Result<Self, Self::Error> {
+        let mut metadata_hm = HashMap::new();
+        for d in value.datasets {
+            let kind = DatasetTypeKind::from_str(&d.kind).map_err(|_| {
+                RunnerErrorImpl::RunInformationArchive {
+                    msg: format!("Unknown kind of dataset {}", d.kind),
+                }
+            })?;
+            let fingerprint = ByteArray::base16_decode(&d.fingerprint).map_err(|_| {
+                RunnerErrorImpl::RunInformationArchive {
+                    msg: format!(
+                        "Wrong fingerprint {} of the dataset {}",
+                        d.fingerprint, d.kind
+                    ),
+                }
+            })?;
+            metadata_hm.insert(
+                kind,
+                DatasetMetadata::new(
+                    kind,
+                    &d.source_path,
+                    &d.decrypted_zip_path,
+                    &d.extracted_dir_path,
+                    &fingerprint,
+                ),
+            );
+        }
+        Ok(Self {
+            metadata_hm,
+            location: value.location,
+        })
+    }
+}
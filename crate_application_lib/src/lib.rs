@@ -18,24 +18,47 @@
 //!
 //! Following functionalities are provided
 //! - [runner::Runner] provides the possibility to run all the verifications
+//! - [RunnerEvent] are the events of the run, to which many consumers can subscribe (see [Runner::subscribe])
 //! - `extract` provides the functionalities to extract the zip files
-//! - [run_information::RunInformation] stores all the information about the current running
+//! - [run_information::RunInformation] stores all the information about the current running. It can be
+//!   saved and reloaded (see [RunInformation::save] and [RunInformation::load])
+//! - [VerificationSchedule] orders the verifications by expected duration, to run the longest first
+//! - [VerificationSession] runs the setup and the tally on the same datasets
 //! - [report] provides the possibility to report the actual stituation
+//! - [AuditLog] records the runs in an append-only, hash-chained log (see [verify_audit_log] and [RunAudit])
+//! - `server` provides a local HTTP/JSON API to run the verifications (feature `server`)
 
+mod audit_log;
 mod extract;
 pub mod report;
 mod run_information;
 mod runner;
+mod runner_events;
+mod schedule;
+#[cfg(feature = "server")]
+pub mod server;
+mod session;
 
+pub use audit_log::{
+    AuditDataset, AuditEvent, AuditLog, AuditLogError, AuditLogHead, AuditRecord, GENESIS_HASH,
+    RunAudit, verify_audit_log,
+};
 pub use extract::*;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 //pub use report::*;
-pub use run_information::RunInformation;
+pub use run_information::{
+    RunInformation, VerificationAttemptInformation, VerificationPerformance,
+};
 pub use runner::{
-    RunParallel, RunSequential, Runner, RunnerInformation, VerificationRunInformation,
-    no_action_after_fn, no_action_after_runner_fn, no_action_before_fn, no_action_before_runner_fn,
+    AnyRunStrategy, CATEGORY_GATES, RunCancellation, RunCategoryGates, RunFailFast, RunParallel,
+    RunParallelInPool, RunParallelLongestFirst, RunSequential, RunStrategy, RunStrategyKind,
+    RunThreadPoolOptions, RunThreadPoolOptionsBuilder, Runner, RunnerInformation,
+    VerificationRunInformation, no_action_after_fn, no_action_after_runner_fn, no_action_before_fn,
+    no_action_before_runner_fn,
 };
+pub use runner_events::{RunnerEvent, RunnerEventBroadcaster};
 use rust_ev_verifier_lib::{
+    VerifierConfigError,
     dataset::DatasetError,
     file_structure::{
         ContextDirectoryTrait, FileStructureError, VerificationDirectory,
@@ -43,6 +66,8 @@ use rust_ev_verifier_lib::{
     },
     verification::{VerificationError, VerificationPeriod},
 };
+pub use schedule::VerificationSchedule;
+pub use session::{SESSION_PERIODS, VerificationSession};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -85,8 +110,36 @@ enum RunnerErrorImpl {
     IsRunning,
     #[error("Runner has already run. Cannot be started before resetting it")]
     HasAlreadyRun,
+    #[error("Runner has not finished. The verifications cannot be run again")]
+    NotFinished,
+    #[error("Error building the thread pool {name}")]
+    ThreadPool {
+        name: &'static str,
+        source: rayon::ThreadPoolBuildError,
+    },
     #[error("Error collectiong the election event id")]
     ElectionEventIdCollection { source: Box<FileStructureError> },
+    #[error("Error serializing the run information")]
+    RunInformationToJson { source: serde_json::Error },
+    #[error("Error deserializing the run information")]
+    RunInformationFromJson { source: serde_json::Error },
+    #[error("Error in the archived run information: {msg}")]
+    RunInformationArchive { msg: String },
+    #[error("IO Error: {msg}")]
+    RunInformationIO { msg: String, source: std::io::Error },
+    #[error("Error loading the metadata of the verifications")]
+    LoadMetadata { source: Box<VerificationError> },
+    #[error("Error reading the configuration of the verifier")]
+    VerifierConfig { source: Box<VerifierConfigError> },
+    #[error("Error writing the audit log")]
+    AuditLog { source: Box<AuditLogError> },
+    #[error("The run information has no extracted datasets")]
+    NoExtractedDatasets,
+    #[error(
+        "The directory of the run {} does not exist anymore. The datasets must be extracted again",
+        path.display()
+    )]
+    RunDirectoryMissing { path: PathBuf },
 }
 
 fn prepare_fixed_based_optimization(dir: &VerificationDirectory) -> Result<(), RunnerError> {
@@ -101,13 +154,14 @@ fn prepare_fixed_based_optimization(dir: &VerificationDirectory) -> Result<(), R
     Ok(())
 }
 
+/// Canonical path to display. The path is kept as is if it does not exist anymore
+/// (e.g. the zip file of a reloaded run)
 #[cfg(not(target_os = "windows"))]
 fn canonicalize_path_os_dependent<P: AsRef<Path>>(p: P) -> String {
     p.as_ref()
         .canonicalize()
-        .unwrap()
-        .to_str()
-        .unwrap()
+        .unwrap_or_else(|_| p.as_ref().to_path_buf())
+        .to_string_lossy()
         .to_string()
 }
 
@@ -117,9 +171,8 @@ fn canonicalize_path_os_dependent<P: AsRef<Path>>(p: P) -> String {
     let p = p
         .as_ref()
         .canonicalize()
-        .unwrap()
-        .to_str()
-        .unwrap()
+        .unwrap_or_else(|_| p.as_ref().to_path_buf())
+        .to_string_lossy()
         .to_string();
     if p.starts_with(VERBATIM_PREFIX) {
         p.replace(VERBATIM_PREFIX, "")
@@ -127,3 +180,39 @@ fn canonicalize_path_os_dependent<P: AsRef<Path>>(p: P) -> String {
         p.to_string()
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test {
+    use rust_ev_verifier_lib::VerifierConfig;
+    use std::{path::PathBuf, sync::Arc};
+
+    pub(crate) const CONTEXT_ZIP_PATH: &str = "../datasets/Context_Post_E2E_DEV_2025-08-02.zip";
+    pub(crate) const TALLY_ZIP_PATH: &str = "../datasets/Tally_Post_E2E_DEV_2025-08-02.zip";
+    pub(crate) const TEST_DECRYPT_ZIP_PASSWORD: &str = "LongPassword_Encryption1";
+    const DIRECT_TRUST_PATH: &str = "../test_data/direct-trust";
+
+    /// Configuration of a test with its own root directory in `./test_temp_dir`
+    ///
+    /// The keystore of the verifier is copied in the root directory and set in the configuration file.
+    /// The environment of the process is ignored (e.g. an env file loaded by another test or a `.env` file
+    /// of the developer), so that the test does not depend on it
+    pub(crate) fn test_config(name: &str) -> Arc<VerifierConfig> {
+        let root = PathBuf::from(".").join("test_temp_dir").join(format!(
+            "{name}_{}",
+            chrono::Local::now().format("%Y%m%d_%H%M%S%f")
+        ));
+        let direct_trust = root.join("direct-trust");
+        std::fs::create_dir_all(&direct_trust).unwrap();
+        for entry in std::fs::read_dir(DIRECT_TRUST_PATH).unwrap() {
+            let entry = entry.unwrap();
+            std::fs::copy(entry.path(), direct_trust.join(entry.file_name())).unwrap();
+        }
+        let config_file = root.join("test_verifier_config.json");
+        std::fs::write(
+            &config_file,
+            serde_json::json!({ "direct_trust_dir_path": direct_trust }).to_string(),
+        )
+        .unwrap();
+        Arc::new(VerifierConfig::new_with_config_file(&root, &config_file).ignore_env())
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Blocks of the report containing the summary of the election results (tally only)
+
+use super::{
+    ReportError, ReportInformationTrait, ReportOutputData, ReportOutputDataBlock,
+    ReportOutputDataBlockTitle, ReportOutputDataMetaData,
+};
+use rust_ev_verifier_lib::{CountResult, CountingCircleResults, ElectionResultsSummary};
+
+fn count_to_tuple(count: &CountResult) -> (String, String) {
+    (count.text.clone(), count.count.to_string())
+}
+
+fn counting_circle_to_blocks(cc: &CountingCircleResults) -> Vec<ReportOutputDataBlock> {
+    let mut res = vec![ReportOutputDataBlock::new_with_tuples(
+        ReportOutputDataBlockTitle::CountingCircleResults(cc.counting_circle_id.clone()),
+        &[
+            (
+                "Valid voting cards".to_string(),
+                cc.valid_voting_cards.to_string(),
+            ),
+            (
+                "Invalid voting cards".to_string(),
+                cc.invalid_voting_cards.to_string(),
+            ),
+        ],
+    )];
+    for vote in cc.votes.iter() {
+        res.push(ReportOutputDataBlock::new_with_tuples(
+            ReportOutputDataBlockTitle::VoteResults(
+                cc.counting_circle_id.clone(),
+                vote.vote_identification.clone(),
+            ),
+            &vote
+                .questions
+                .iter()
+                .flat_map(|q| {
+                    q.answers
+                        .iter()
+                        .map(move |a| (format!("{} - {}", q.question, a.text), a.count.to_string()))
+                })
+                .collect::<Vec<_>>(),
+        ));
+    }
+    for election in cc.elections.iter() {
+        if !election.lists.is_empty() {
+            let mut lists = election
+                .lists
+                .iter()
+                .map(count_to_tuple)
+                .collect::<Vec<_>>();
+            lists.push((
+                "Ballots without list".to_string(),
+                election.ballots_without_list.to_string(),
+            ));
+            res.push(ReportOutputDataBlock::new_with_tuples(
+                ReportOutputDataBlockTitle::ElectionListResults(
+                    cc.counting_circle_id.clone(),
+                    election.election_identification.clone(),
+                ),
+                &lists,
+            ));
+        }
+        let mut results = vec![(
+            "Number of ballots".to_string(),
+            election.number_of_ballots.to_string(),
+        )];
+        results.extend(election.candidates.iter().map(count_to_tuple));
+        results.push(("Write-ins".to_string(), election.write_ins.to_string()));
+        results.push((
+            "Empty positions".to_string(),
+            election.empty_positions.to_string(),
+        ));
+        res.push(ReportOutputDataBlock::new_with_tuples(
+            ReportOutputDataBlockTitle::ElectionResults(
+                cc.counting_circle_id.clone(),
+                election.election_identification.clone(),
+            ),
+            &results,
+        ));
+    }
+    res
+}
+
+impl ReportInformationTrait for ElectionResultsSummary {
+    fn to_report_output(
+        &self,
+        metadata: ReportOutputDataMetaData,
+    ) -> Result<ReportOutputData, ReportError> {
+        Ok(ReportOutputData::from_vec(
+            metadata,
+            self.counting_circles
+                .iter()
+                .flat_map(counting_circle_to_blocks)
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::ReportOutputDataMetaDataBuilder;
+    use super::*;
+    use rust_ev_verifier_lib::{ElectionResults, QuestionResults, VoteResults};
+
+    fn count(id: &str, count: usize) -> CountResult {
+        CountResult {
+            identification: id.to_string(),
+            text: id.to_uppercase(),
+            count,
+        }
+    }
+
+    #[test]
+    fn test_to_report_output() {
+        let summary = ElectionResultsSummary {
+            contest_identification: "contest".to_string(),
+            counting_circles: vec![CountingCircleResults {
+                counting_circle_id: "cc1".to_string(),
+                valid_voting_cards: 10,
+                invalid_voting_cards: 1,
+                votes: vec![VoteResults {
+                    vote_identification: "v1".to_string(),
+                    questions: vec![QuestionResults {
+                        question_identification: "q1".to_string(),
+                        question: "Question".to_string(),
+                        answers: vec![count("yes", 6), count("no", 4)],
+                    }],
+                }],
+                elections: vec![
+                    ElectionResults {
+                        election_identification: "e1".to_string(),
+                        number_of_ballots: 5,
+                        lists: vec![count("l1", 3)],
+                        ballots_without_list: 2,
+                        candidates: vec![count("c1", 4)],
+                        write_ins: 1,
+                        empty_positions: 2,
+                    },
+                    ElectionResults {
+                        election_identification: "e2".to_string(),
+                        number_of_ballots: 5,
+                        lists: vec![],
+                        ballots_without_list: 5,
+                        candidates: vec![count("c2", 5)],
+                        write_ins: 0,
+                        empty_positions: 0,
+                    },
+                ],
+            }],
+        };
+        let metadata = ReportOutputDataMetaDataBuilder::default()
+            .title("Test")
+            .date_time("01.01.2025")
+            .seed("seed")
+            .build()
+            .unwrap();
+        let output = summary.to_report_output(metadata).unwrap();
+        let titles = output
+            .blocks()
+            .iter()
+            .map(|b| b.title().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            titles,
+            vec![
+                "Results of the counting circle cc1",
+                "Results of the vote v1 - Counting circle cc1",
+                "Ballots per list of the election e1 - Counting circle cc1",
+                "Results of the election e1 - Counting circle cc1",
+                "Results of the election e2 - Counting circle cc1",
+            ]
+        );
+        assert_eq!(
+            output.blocks()[1].key_value_entries(),
+            vec![("Question - YES", "6"), ("Question - NO", "4")]
+        );
+        assert_eq!(
+            output.blocks()[2].key_value_entries(),
+            vec![("L1", "3"), ("Ballots without list", "2")]
+        );
+        assert_eq!(
+            output.blocks()[3].key_value_entries(),
+            vec![
+                ("Number of ballots", "5"),
+                ("C1", "4"),
+                ("Write-ins", "1"),
+                ("Empty positions", "2")
+            ]
+        );
+    }
+}
@@ -14,34 +14,63 @@
 // a copy of the GNU General Public License along with this program. If not, see
 // <https://www.gnu.org/licenses/>.
 
+mod election_results;
+mod report_diff;
 mod report_output_data;
 mod report_output_file;
+mod report_signature;
+mod session_report;
+mod translations;
 
 use crate::canonicalize_path_os_dependent;
 
-use super::{RunnerError, run_information::RunInformation};
+use super::{
+    RunnerError,
+    run_information::{RunInformation, VerificationAttemptInformation},
+};
 use derive_builder::Builder;
 use derive_getters::Getters;
+pub use report_diff::{ReportOutputDataDiff, ReportValueChange};
+pub(crate) use report_output_data::ReportOutputDataBlock;
 use report_output_data::{
-    OutputToString, ReportOutputDataBlock, ReportOutputDataBlockTitle, ReportOutputDataEntry,
-    ReportOutputDataMetaDataBuilderError,
+    OutputToString, ReportOutputDataBlockTitle, ReportOutputDataEntry,
+    ReportOutputDataEventBuilderError, ReportOutputDataMetaDataBuilderError,
+    ReportOutputDataVerificationBuilderError,
 };
 pub use report_output_data::{
-    ReportOutputData, ReportOutputDataMetaData, ReportOutputDataMetaDataBuilder,
+    ReportOutputData, ReportOutputDataEvent, ReportOutputDataEventBuilder,
+    ReportOutputDataMetaData, ReportOutputDataMetaDataBuilder, ReportOutputDataVerification,
+    ReportOutputDataVerificationBuilder,
 };
 use report_output_file::ReportOutputFile;
 pub use report_output_file::{
-    PDFReportOptions, PDFReportOptionsBuilder, ReportOutputFileOptions,
-    ReportOutputFileOptionsBuilder, ReportOutputFileType,
+    HtmlTemplate, PDFReportBackend, PDFReportOptions, PDFReportOptionsBuilder,
+    ReportOutputFileOptions, ReportOutputFileOptionsBuilder, ReportOutputFileType,
+};
+pub use report_signature::{
+    ReportFileSignature, signature_file_path, verify_report_file_signature,
 };
+use rust_ev_system_library::rust_ev_crypto_primitives::prelude::basic_crypto_functions::BasisCryptoError;
 use rust_ev_verifier_lib::{
-    DatasetTypeKind, VerifierConfigError,
+    DatasetTypeKind, ECH0222CalculatedError, EffectiveConfigValue, ElectionResultsSummary,
+    VerifierConfigError,
+    direct_trust::{DirectTrustError, VerifySignatureError},
     file_structure::{VerificationDirectory, VerificationDirectoryTrait},
-    verification::{ManualVerificationInformationTrait, ManualVerifications, VerificationPeriod},
+    verification::{
+        ManualVerificationInformationTrait, ManualVerifications, VerificationEvent,
+        VerificationMetaDataList, VerificationPeriod, VerificationStatus,
+    },
+};
+pub use session_report::SessionReportData;
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    iter::once,
+    path::{Path, PathBuf},
 };
-use std::{fmt::Display, path::PathBuf};
 use thiserror::Error;
 use tracing::{Level, debug, error, info, trace, warn};
+pub use translations::ReportLanguage;
 
 #[derive(Error, Debug)]
 #[error(transparent)]
@@ -58,6 +87,8 @@ enum ReportErrorImpl {
     ToOutput { source: Box<ReportError> },
     #[error("Error getting the manual verifications from the inputs")]
     Manual { source: Box<RunnerError> },
+    #[error("The datasets of the run cannot be read for the report")]
+    RunDirectory { source: Box<RunnerError> },
     #[error("Error with the report output options: {0}")]
     ReportOutputOptions(String),
     #[error("IO Error: {msg}")]
@@ -75,10 +106,20 @@ enum ReportErrorImpl {
     ReportOptionBuilder { source: Box<ReportError> },
     #[error("Error exporting to json")]
     ToJson { source: serde_json::Error },
+    #[error("Error reading the report from json")]
+    FromJson { source: serde_json::Error },
     #[error("Error generating the metadata for the report")]
     MetadataError {
         source: ReportOutputDataMetaDataBuilderError,
     },
+    #[error("Error building the event for the report")]
+    EventError {
+        source: ReportOutputDataEventBuilderError,
+    },
+    #[error("Error building the result of the verification for the report")]
+    VerificationResultError {
+        source: ReportOutputDataVerificationBuilderError,
+    },
     #[error("Error reading the verifier configuration: {msg}")]
     VerifierConfig {
         msg: String,
@@ -86,6 +127,35 @@ enum ReportErrorImpl {
     },
     #[error("PDF report generation requires a browser path, but none is set")]
     BrowserPathNone,
+    #[error("Error reading the logo for the native PDF report: {0}")]
+    PdfLogo(String),
+    #[error("Error in the HTML template: {0}")]
+    HtmlTemplate(String),
+    #[error("Error reading the HTML template {path}")]
+    HtmlTemplateFile {
+        path: PathBuf,
+        source: Box<ReportError>,
+    },
+    #[error("Error calculating the summary of the election results")]
+    ElectionResults { source: Box<ECH0222CalculatedError> },
+    #[error("Error calculating the fingerprint of the report")]
+    Fingerprint { source: BasisCryptoError },
+    #[error("Error getting the signing authority of the keystore")]
+    SigningAuthority { source: Box<DirectTrustError> },
+    #[error("Error signing the report file")]
+    SignReportFile { source: Box<VerifySignatureError> },
+    #[error("Error decoding the signature of the report file: {msg}")]
+    DecodeSignature { msg: String },
+    #[error("Error verifying the signature of the report file")]
+    VerifyReportFileSignature { source: Box<VerifySignatureError> },
+    #[error(
+        "The report file is signed by the authority {found}, but only the authority {expected} is accepted"
+    )]
+    UnexpectedSigningAuthority { expected: String, found: String },
+    #[error(
+        "The authority {0} of an election participant cannot be used to sign the report files. A dedicated certificate of the verifier is required"
+    )]
+    SigningAuthorityNotDedicated(String),
 }
 
 /// General Configuration of the report
@@ -109,6 +179,18 @@ pub struct ReportConfig {
 
     /// Format of the printed date
     fromat_date: String,
+
+    /// Language of the report files
+    ///
+    /// Default: `[ReportLanguage::En]`
+    #[builder(default)]
+    language: ReportLanguage,
+
+    /// Add the summary of the election results per counting circle (only for the tally)
+    ///
+    /// Default: `false`
+    #[builder(default)]
+    election_results: bool,
 }
 
 /// Trait to collect the report information
@@ -194,10 +276,38 @@ impl<'a> ReportData<'a> {
             )
             .title(self.report_configuration.title().clone())
             .date_time(self.report_configuration.date_time().clone())
+            .language(*self.report_configuration.language())
             .build()
             .map_err(|e| ReportErrorImpl::MetadataError { source: e })
     }
 
+    /// Result of the verification with the given id and name for the report
+    fn verification_to_output(
+        &self,
+        id: &str,
+        name: &str,
+    ) -> Result<ReportOutputDataVerification, ReportErrorImpl> {
+        let mut builder = ReportOutputDataVerificationBuilder::default();
+        builder.verification_id(id).verification_name(name);
+        if !self
+            .run_information
+            .excluded_verifications()
+            .iter()
+            .any(|e| e == id)
+        {
+            builder.status(self.run_information.verifications_status().get(id).copied());
+        }
+        builder.duration(
+            self.run_information
+                .verifications_performance()
+                .get(id)
+                .map(|p| p.duration),
+        );
+        builder
+            .build()
+            .map_err(|e| ReportErrorImpl::VerificationResultError { source: e })
+    }
+
     /// Output the [ReportData] in the log according to the configuration
     pub fn output_log(&self) {
         let s = self.to_string();
@@ -217,17 +327,175 @@ impl<'a> ReportData<'a> {
     }
 }
 
+/// Generate the report files from a report archived as json (see [ReportData::to_json])
+///
+/// Returns a vector of [ReportError] encountered during the generation
 pub fn generate_files_from_json(
     json_str: &str,
     output_options: ReportOutputFileOptions,
 ) -> Vec<ReportError> {
-    let report_output: ReportOutputData = match serde_json::from_str(json_str) {
-        Ok(ro) => ro,
-        Err(e) => {
-            return vec![ReportError::from(ReportErrorImpl::ToJson { source: e })];
-        }
-    };
-    ReportOutputFile::new(output_options, &report_output).generate()
+    match ReportOutputData::from_json(json_str) {
+        Ok(report_output) => report_output.generate_files(output_options),
+        Err(e) => vec![e],
+    }
+}
+
+/// Generate the report files from a json file containing an archived report
+///
+/// Returns a vector of [ReportError] encountered during the generation
+pub fn generate_files_from_json_file(
+    path: &Path,
+    output_options: ReportOutputFileOptions,
+) -> Vec<ReportError> {
+    match ReportOutputData::from_json_file(path) {
+        Ok(report_output) => report_output.generate_files(output_options),
+        Err(e) => vec![e],
+    }
+}
+
+/// Verifications not run by a finished run (e.g. stopped by [crate::RunFailFast])
+///
+/// Return a list of tuples `<id> - <name>` and category, sorted by id
+fn verifications_not_run_to_key_value(
+    verifications_status: &HashMap<String, VerificationStatus>,
+    verification_metadata: &VerificationMetaDataList,
+) -> Vec<(String, String)> {
+    let mut res = verifications_status
+        .iter()
+        .filter(|(_, status)| **status == VerificationStatus::NotStarted)
+        .filter_map(|(id, _)| verification_metadata.meta_data_from_id(id))
+        .map(|md| {
+            (
+                format!("{} - {}", md.id(), md.name()),
+                md.category().as_ref().to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    res.sort();
+    res
+}
+
+/// Effective configuration of the verifier for the report
+///
+/// Return a list of tuples with the key of the option and the value with its source (e.g. `true (env)`)
+fn effective_configuration_to_key_value(
+    configuration: &[EffectiveConfigValue],
+) -> Vec<(String, String)> {
+    configuration
+        .iter()
+        .map(|v| {
+            (
+                v.key.to_string(),
+                format!("{} ({})", v.value.as_deref().unwrap_or("-"), v.source),
+            )
+        })
+        .collect()
+}
+
+/// Transform an error or a failure of the verification with the given id and name for the report
+fn verification_event_to_output(
+    id: &str,
+    name: &str,
+    event: &VerificationEvent,
+) -> Result<ReportOutputDataEvent, ReportErrorImpl> {
+    let mut builder = ReportOutputDataEventBuilder::default();
+    builder
+        .verification_id(id)
+        .verification_name(name)
+        .kind(event.kind().as_ref())
+        .message(event.last())
+        .context_chain(
+            once(event.source())
+                .chain(event.contexts())
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>(),
+        );
+    if let Some(location) = event.location() {
+        builder.location(location);
+    }
+    if let Some(cause) = event.signature_failure_cause() {
+        builder.cause(cause.to_string());
+    }
+    builder
+        .build()
+        .map_err(|e| ReportErrorImpl::EventError { source: e })
+}
+
+/// Transform a previous attempt of a verification for the report
+///
+/// The first line contains the status and the performance, followed by the errors and the failures
+fn attempt_to_strings(number: usize, attempt: &VerificationAttemptInformation) -> Vec<String> {
+    let mut first_line = format!("[{}] - {}", number, attempt.status.as_ref());
+    if let Some(p) = &attempt.performance {
+        first_line.push_str(&format!(" ({})", p));
+    }
+    once(first_line)
+        .chain(attempt.errors.iter().map(|e| format!("    Error: {}", e)))
+        .chain(
+            attempt
+                .failures
+                .iter()
+                .map(|f| format!("    Failure: {}", f)),
+        )
+        .collect()
+}
+
+/// Blocks of the report containing the manual verifications
+///
+/// The blocks do not depend on the [ReportConfig], so that they can be archived with the run
+/// (see [RunInformation::to_json])
+pub(crate) fn manual_verifications_to_blocks<D: VerificationDirectoryTrait>(
+    manual: &ManualVerifications<D>,
+) -> Vec<ReportOutputDataBlock> {
+    let mut res = vec![
+        ReportOutputDataBlock::new_with_tuples(
+            ReportOutputDataBlockTitle::Fingerprints,
+            &manual.dt_fingerprints_to_key_value(),
+        ),
+        ReportOutputDataBlock::new_with_tuples(
+            ReportOutputDataBlockTitle::OtherFingerprints,
+            &manual.other_fingerprints_to_key_value(),
+        ),
+        ReportOutputDataBlock::new_with_tuples(
+            ReportOutputDataBlockTitle::Information,
+            &manual.information_to_key_value(),
+        ),
+        ReportOutputDataBlock::new_with_tuples(
+            ReportOutputDataBlockTitle::VerificationResults,
+            &manual.verification_stati_to_key_value(),
+        ),
+    ];
+    res.append(
+        &mut manual
+            .verification_errors_and_failures()
+            .iter()
+            .flat_map(|(id, (errors, failures))| {
+                let mut res = vec![];
+                if !errors.is_empty() {
+                    res.push(ReportOutputDataBlock::new_with_strings(
+                        ReportOutputDataBlockTitle::VerificationErrors(id.clone()),
+                        &errors
+                            .iter()
+                            .enumerate()
+                            .map(|(i, s)| format!("[{}] - {}", i + 1, s))
+                            .collect::<Vec<_>>(),
+                    ));
+                }
+                if !failures.is_empty() {
+                    res.push(ReportOutputDataBlock::new_with_strings(
+                        ReportOutputDataBlockTitle::VerificationFailures(id.clone()),
+                        &failures
+                            .iter()
+                            .enumerate()
+                            .map(|(i, s)| format!("[{}] - {}", i + 1, s))
+                            .collect::<Vec<_>>(),
+                    ));
+                }
+                res
+            })
+            .collect::<Vec<_>>(),
+    );
+    res
 }
 
 impl<D: VerificationDirectoryTrait> ReportInformationTrait for ManualVerifications<D> {
@@ -235,55 +503,10 @@ impl<D: VerificationDirectoryTrait> ReportInformationTrait for ManualVerificatio
         &self,
         metadata: ReportOutputDataMetaData,
     ) -> Result<ReportOutputData, ReportError> {
-        let mut res = vec![
-            ReportOutputDataBlock::new_with_tuples(
-                ReportOutputDataBlockTitle::Fingerprints,
-                &self.dt_fingerprints_to_key_value(),
-            ),
-            ReportOutputDataBlock::new_with_tuples(
-                ReportOutputDataBlockTitle::OtherFingerprints,
-                &self.other_fingerprints_to_key_value(),
-            ),
-            ReportOutputDataBlock::new_with_tuples(
-                ReportOutputDataBlockTitle::Information,
-                &self.information_to_key_value(),
-            ),
-            ReportOutputDataBlock::new_with_tuples(
-                ReportOutputDataBlockTitle::VerificationResults,
-                &self.verification_stati_to_key_value(),
-            ),
-        ];
-        res.append(
-            &mut self
-                .verification_errors_and_failures()
-                .iter()
-                .flat_map(|(id, (errors, failures))| {
-                    let mut res = vec![];
-                    if !errors.is_empty() {
-                        res.push(ReportOutputDataBlock::new_with_strings(
-                            ReportOutputDataBlockTitle::VerificationErrors(id.clone()),
-                            &errors
-                                .iter()
-                                .enumerate()
-                                .map(|(i, s)| format!("[{}] - {}", i + 1, s))
-                                .collect::<Vec<_>>(),
-                        ));
-                    }
-                    if !failures.is_empty() {
-                        res.push(ReportOutputDataBlock::new_with_strings(
-                            ReportOutputDataBlockTitle::VerificationFailures(id.clone()),
-                            &failures
-                                .iter()
-                                .enumerate()
-                                .map(|(i, s)| format!("[{}] - {}", i + 1, s))
-                                .collect::<Vec<_>>(),
-                        ));
-                    }
-                    res
-                })
-                .collect::<Vec<_>>(),
-        );
-        Ok(ReportOutputData::from_vec(metadata, res))
+        Ok(ReportOutputData::from_vec(
+            metadata,
+            manual_verifications_to_blocks(self),
+        ))
     }
 }
 
@@ -333,7 +556,11 @@ impl ReportInformationTrait for ReportData<'_> {
         };
         running_information.push(ReportOutputDataEntry::from((
             "Verification directory",
-            canonicalize_path_os_dependent(self.run_information.run_directory()).as_str(),
+            self.run_information
+                .run_directory()
+                .map(canonicalize_path_os_dependent)
+                .unwrap_or_default()
+                .as_str(),
         )));
         running_information.push(ReportOutputDataEntry::from((
             "Start Time",
@@ -360,18 +587,296 @@ impl ReportInformationTrait for ReportData<'_> {
             "Duration",
             duration_string.as_str(),
         )));
+        // Anchor of the audit log, covered by the fingerprint of the report
+        if let Some(head) = &self.run_information.runner_information().audit_log_head {
+            running_information.push(ReportOutputDataEntry::from((
+                "Audit Log",
+                head.path().display().to_string().as_str(),
+            )));
+            running_information.push(ReportOutputDataEntry::from((
+                "Audit Log Records",
+                head.records().to_string().as_str(),
+            )));
+            running_information.push(ReportOutputDataEntry::from((
+                "Audit Log Last Hash",
+                head.last_hash().as_str(),
+            )));
+        }
+        for (i, runner) in self.run_information.runner_history().iter().enumerate() {
+            running_information.push(ReportOutputDataEntry::from((
+                format!("Previous run {}", i + 1).as_str(),
+                format!(
+                    "{} ({})",
+                    runner.start_time_to_string().unwrap_or_default(),
+                    runner.duration_as_secs_to_string().unwrap_or_default()
+                )
+                .as_str(),
+            )));
+        }
         let mut res = ReportOutputData::new(metadata.clone());
         res.push(running_information);
-        res.append(
-            &mut ManualVerifications::<VerificationDirectory>::try_from(self.run_information)
+        if self.run_information.is_finished()
+            && let Some(metadata_list) = self.run_information.verification_metadata()
+        {
+            let not_run = verifications_not_run_to_key_value(
+                self.run_information.verifications_status(),
+                metadata_list,
+            );
+            if !not_run.is_empty() {
+                res.push(ReportOutputDataBlock::new_with_tuples(
+                    ReportOutputDataBlockTitle::VerificationsNotRun,
+                    &not_run,
+                ));
+            }
+        }
+        // Read from the datasets, or from the archive if the datasets are deleted
+        res.append(&mut ReportOutputData::from_vec(
+            metadata.clone(),
+            self.run_information
+                .manual_verifications_blocks()
                 .map_err(|e| ReportErrorImpl::Manual {
                     source: Box::new(e),
-                })?
-                .to_report_output(metadata)
-                .map_err(|e| ReportErrorImpl::ToOutput {
-                    source: Box::new(e),
                 })?,
-        );
+        ));
+        if *self.report_configuration.election_results() && period == VerificationPeriod::Tally {
+            // The election results depend on the language of the report and are always read from the datasets
+            let run_directory = self.run_information.existing_run_directory().map_err(|e| {
+                ReportErrorImpl::RunDirectory {
+                    source: Box::new(e),
+                }
+            })?;
+            res.append(
+                &mut ElectionResultsSummary::from_verification_directory(
+                    &VerificationDirectory::new(&period, run_directory),
+                    self.report_configuration.language().as_ref(),
+                )
+                .map_err(|e| ReportErrorImpl::ElectionResults {
+                    source: Box::new(e),
+                })?
+                .to_report_output(metadata)?,
+            );
+        }
+        if !self.run_information.effective_configuration().is_empty() {
+            res.push(ReportOutputDataBlock::new_with_tuples(
+                ReportOutputDataBlockTitle::Configuration,
+                &effective_configuration_to_key_value(
+                    self.run_information.effective_configuration(),
+                ),
+            ));
+        }
+        let mut informations = self
+            .run_information
+            .verifications_informations()
+            .iter()
+            .collect::<Vec<_>>();
+        informations.sort_by_key(|(id, _)| *id);
+        for (id, infos) in informations {
+            res.push(ReportOutputDataBlock::new_with_strings(
+                ReportOutputDataBlockTitle::VerificationInformation(id.clone()),
+                &infos
+                    .iter()
+                    .enumerate()
+                    .map(|(i, s)| format!("[{}] - {}", i + 1, s))
+                    .collect::<Vec<_>>(),
+            ));
+        }
+        let mut history = self
+            .run_information
+            .verifications_history()
+            .iter()
+            .collect::<Vec<_>>();
+        history.sort_by_key(|(id, _)| *id);
+        for (id, attempts) in history {
+            res.push(ReportOutputDataBlock::new_with_strings(
+                ReportOutputDataBlockTitle::PreviousAttempts(id.clone()),
+                &attempts
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, a)| attempt_to_strings(i + 1, a))
+                    .collect::<Vec<_>>(),
+            ));
+        }
+        let mut performances = self
+            .run_information
+            .verifications_performance()
+            .iter()
+            .map(|(id, p)| (id.clone(), p.to_string()))
+            .collect::<Vec<_>>();
+        if !performances.is_empty() {
+            performances.sort_by(|(id1, _), (id2, _)| id1.cmp(id2));
+            res.push(ReportOutputDataBlock::new_with_tuples(
+                ReportOutputDataBlockTitle::Performance,
+                &performances,
+            ));
+        }
+        let mut events = self
+            .run_information
+            .verifications_events()
+            .iter()
+            .collect::<Vec<_>>();
+        events.sort_by_key(|(id, _)| *id);
+        for (id, verif_events) in events {
+            let name = self
+                .run_information
+                .verification_metadata()
+                .and_then(|m| m.meta_data_from_id(id))
+                .map(|m| m.name())
+                .unwrap_or_default();
+            for event in verif_events {
+                res.push_event(verification_event_to_output(id, name, event)?);
+            }
+        }
+        if let Some(metadata_list) = self.run_information.verification_metadata() {
+            let mut ids = metadata_list.id_list_for_period(&period);
+            ids.sort();
+            for id in ids {
+                let name = metadata_list
+                    .meta_data_from_id(id)
+                    .map(|m| m.name())
+                    .unwrap_or_default();
+                res.push_verification(self.verification_to_output(id, name)?);
+            }
+        }
         Ok(res)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        ExtractDataSetResults, RunnerInformation, VerificationPerformance,
+        test::{CONTEXT_ZIP_PATH, TEST_DECRYPT_ZIP_PASSWORD, test_config},
+    };
+    use rust_ev_verifier_lib::{ConfigValueSource, VerifierConfig};
+    use std::{
+        path::PathBuf,
+        time::{Duration, SystemTime},
+    };
+
+    #[test]
+    fn test_attempt_to_strings() {
+        let attempt = VerificationAttemptInformation {
+            status: VerificationStatus::FinishedWithErrors,
+            errors: vec!["file missing".to_string()],
+            failures: vec![],
+            performance: Some(VerificationPerformance {
+                duration: Duration::from_millis(1500),
+                process_peak_memory: None,
+                items_checked: None,
+            }),
+        };
+        assert_eq!(
+            attempt_to_strings(1, &attempt),
+            vec!["[1] - Errors (1.500s)", "    Error: file missing"]
+        );
+    }
+
+    #[test]
+    fn test_effective_configuration_to_key_value() {
+        let res = effective_configuration_to_key_value(&[
+            EffectiveConfigValue {
+                key: "report_export_pdf".to_string(),
+                env: "REPORT_EXPORT_PDF".to_string(),
+                value: Some("true".to_string()),
+                source: ConfigValueSource::Env,
+            },
+            EffectiveConfigValue {
+                key: "report_logo".to_string(),
+                env: "REPORT_LOGO".to_string(),
+                value: None,
+                source: ConfigValueSource::Default,
+            },
+        ]);
+        assert_eq!(
+            res,
+            vec![
+                ("report_export_pdf".to_string(), "true (env)".to_string()),
+                ("report_logo".to_string(), "- (default)".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verifications_not_run() {
+        let metadata =
+            VerificationMetaDataList::load(VerifierConfig::default().get_verification_list_str())
+                .unwrap();
+        let status = HashMap::from([
+            (
+                "01.01".to_string(),
+                VerificationStatus::FinishedWithFailures,
+            ),
+            ("03.01".to_string(), VerificationStatus::NotStarted),
+            ("02.01".to_string(), VerificationStatus::NotStarted),
+        ]);
+        let res = verifications_not_run_to_key_value(&status, &metadata);
+        assert_eq!(res.len(), 2);
+        assert!(res[0].0.starts_with("02.01 - "));
+        assert!(res[1].0.starts_with("03.01 - "));
+        assert_eq!(
+            res[1].1,
+            metadata
+                .meta_data_from_id("03.01")
+                .unwrap()
+                .category()
+                .as_ref()
+        );
+    }
+
+    #[test]
+    fn test_report_data_reloaded_run() {
+        let config = test_config("test_report_reloaded");
+        let metadata = VerificationMetaDataList::load(config.get_verification_list_str()).unwrap();
+        let extracted = ExtractDataSetResults::extract_datasets(
+            VerificationPeriod::Setup,
+            PathBuf::from(CONTEXT_ZIP_PATH).as_path(),
+            None,
+            TEST_DECRYPT_ZIP_PASSWORD,
+            &config,
+        )
+        .unwrap();
+        let mut ri = RunInformation::new(config.clone());
+        ri.prepare_data_for_start(VerificationPeriod::Setup, &metadata, &[])
+            .unwrap();
+        ri.add_extracted_information(&extracted);
+        let start_time = SystemTime::now();
+        ri.start_running(&start_time);
+        let mut runner_info = RunnerInformation::new(config.clone());
+        runner_info.start_time = Some(start_time);
+        runner_info.duration = Some(Duration::from_secs(1));
+        ri.finish_runner(&runner_info);
+
+        let loaded = RunInformation::from_json(config.clone(), &ri.to_json().unwrap()).unwrap();
+        let report_config = ReportConfigBuilder::default()
+            .title("Test")
+            .date_time("now")
+            .tab_size(2)
+            .fromat_date("%d.%m.%Y %H:%M:%S.%3f")
+            .build()
+            .unwrap();
+        let report = ReportData::new(report_config.clone(), &loaded).to_json();
+        assert!(report.is_ok(), "{:?}", report.err());
+        let report = ReportOutputData::from_json(&report.unwrap()).unwrap();
+
+        // The run directory is removed: the manual verifications are read from the archive
+        let archive = ri.to_json().unwrap();
+        std::fs::remove_dir_all(extracted.location()).unwrap();
+        let loaded = RunInformation::from_json(config.clone(), &archive).unwrap();
+        let report_archived = ReportData::new(report_config, &loaded).to_json();
+        assert!(report_archived.is_ok(), "{:?}", report_archived.err());
+        let report_archived = ReportOutputData::from_json(&report_archived.unwrap()).unwrap();
+        assert_eq!(
+            report_archived.blocks()[0].title(),
+            &ReportOutputDataBlockTitle::RunningInformation
+        );
+        assert_eq!(report_archived.blocks()[1..], report.blocks()[1..]);
+        assert!(
+            report_archived
+                .blocks()
+                .iter()
+                .any(|b| b.title() == &ReportOutputDataBlockTitle::Fingerprints)
+        );
+        std::fs::remove_dir_all(config.root_dir_path()).unwrap();
+    }
+}
@@ -0,0 +1,507 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Comparison of two reports, e.g. the reports before and after the delivery of a corrected
+//! dataset
+//!
+//! The comparison implements [ReportInformationTrait], so that it can be rendered in the same
+//! formats as the reports.
+
+use super::{
+    ReportError, ReportInformationTrait, ReportOutputData, ReportOutputDataBlock,
+    ReportOutputDataBlockTitle, ReportOutputDataEntry, ReportOutputDataMetaData,
+};
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Change of the value of a key between two reports
+///
+/// The value is `None` if the key is missing in the report
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Getters)]
+pub struct ReportValueChange {
+    key: String,
+    original: Option<String>,
+    new: Option<String>,
+}
+
+/// Differences between two reports
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Getters)]
+pub struct ReportOutputDataDiff {
+    /// Metadata of the original report
+    original_metadata: ReportOutputDataMetaData,
+    /// Metadata of the new report
+    new_metadata: ReportOutputDataMetaData,
+    /// Changes of the status of the verifications
+    status_changes: Vec<ReportValueChange>,
+    /// Errors only in the new report, per verification id
+    new_errors: BTreeMap<String, Vec<String>>,
+    /// Errors only in the original report, per verification id
+    resolved_errors: BTreeMap<String, Vec<String>>,
+    /// Failures only in the new report, per verification id
+    new_failures: BTreeMap<String, Vec<String>>,
+    /// Failures only in the original report, per verification id
+    resolved_failures: BTreeMap<String, Vec<String>>,
+    /// Changes of the fingerprints (including the other fingerprints)
+    fingerprint_changes: Vec<ReportValueChange>,
+    /// Changes of the values of the block [ReportOutputDataBlockTitle::Information]
+    information_changes: Vec<ReportValueChange>,
+}
+
+impl ReportValueChange {
+    fn value_to_string(value: &Option<String>) -> &str {
+        value.as_deref().unwrap_or("-")
+    }
+
+    /// Change as string `original -> new`. A missing value is represented by `-`
+    pub fn change_to_string(&self) -> String {
+        format!(
+            "{} -> {}",
+            Self::value_to_string(&self.original),
+            Self::value_to_string(&self.new)
+        )
+    }
+}
+
+/// Collect the key/values of the blocks with the given titles
+fn key_values<'a>(
+    data: &'a ReportOutputData,
+    titles: &[ReportOutputDataBlockTitle],
+) -> Vec<(&'a str, &'a str)> {
+    data.blocks()
+        .iter()
+        .filter(|b| titles.contains(b.title()))
+        .flat_map(|b| b.key_value_entries())
+        .collect()
+}
+
+/// Remove the numbering `[i] - ` added to the errors and failures in the report
+///
+/// The numbering depends on the position of the entry, and must not be compared
+fn strip_numbering(value: &str) -> &str {
+    value
+        .strip_prefix('[')
+        .and_then(|s| s.split_once("] - "))
+        .filter(|(nb, _)| !nb.is_empty() && nb.chars().all(|c| c.is_ascii_digit()))
+        .map(|(_, v)| v)
+        .unwrap_or(value)
+}
+
+/// Collect the values of the blocks per verification id, using `id_from_title` to select the blocks
+///
+/// The numbering of the values is removed (see [strip_numbering])
+fn values_per_id(
+    data: &ReportOutputData,
+    id_from_title: fn(&ReportOutputDataBlockTitle) -> Option<&str>,
+) -> BTreeMap<String, Vec<&str>> {
+    let mut res: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    for block in data.blocks() {
+        if let Some(id) = id_from_title(block.title()) {
+            res.entry(id.to_string())
+                .or_default()
+                .extend(block.only_value_entries().into_iter().map(strip_numbering));
+        }
+    }
+    res
+}
+
+/// Changes between the key/values of both reports
+///
+/// The keys are returned in the order of the original report, followed by the new keys
+fn value_changes(original: &[(&str, &str)], new: &[(&str, &str)]) -> Vec<ReportValueChange> {
+    let find = |entries: &[(&str, &str)], key: &str| {
+        entries
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.to_string())
+    };
+    let mut res = vec![];
+    for (key, value) in original.iter() {
+        let new_value = find(new, key);
+        if new_value.as_deref() != Some(*value) {
+            res.push(ReportValueChange {
+                key: key.to_string(),
+                original: Some(value.to_string()),
+                new: new_value,
+            });
+        }
+    }
+    for (key, value) in new.iter() {
+        if find(original, key).is_none() {
+            res.push(ReportValueChange {
+                key: key.to_string(),
+                original: None,
+                new: Some(value.to_string()),
+            });
+        }
+    }
+    res
+}
+
+/// Values in `values` that are not in `other`, per verification id
+fn values_not_in(
+    values: &BTreeMap<String, Vec<&str>>,
+    other: &BTreeMap<String, Vec<&str>>,
+) -> BTreeMap<String, Vec<String>> {
+    values
+        .iter()
+        .filter_map(|(id, v)| {
+            let other_values = other.get(id);
+            let diff = v
+                .iter()
+                .filter(|s| !other_values.is_some_and(|o| o.contains(s)))
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>();
+            match diff.is_empty() {
+                true => None,
+                false => Some((id.clone(), diff)),
+            }
+        })
+        .collect()
+}
+
+fn errors_id(title: &ReportOutputDataBlockTitle) -> Option<&str> {
+    match title {
+        ReportOutputDataBlockTitle::VerificationErrors(id) => Some(id.as_str()),
+        _ => None,
+    }
+}
+
+fn failures_id(title: &ReportOutputDataBlockTitle) -> Option<&str> {
+    match title {
+        ReportOutputDataBlockTitle::VerificationFailures(id) => Some(id.as_str()),
+        _ => None,
+    }
+}
+
+impl ReportOutputDataDiff {
+    /// Compare the original report with the new report
+    pub fn new(original: &ReportOutputData, new: &ReportOutputData) -> Self {
+        let (original_errors, new_errors) = (
+            values_per_id(original, errors_id),
+            values_per_id(new, errors_id),
+        );
+        let (original_failures, new_failures) = (
+            values_per_id(original, failures_id),
+            values_per_id(new, failures_id),
+        );
+        let fingerprint_titles = [
+            ReportOutputDataBlockTitle::Fingerprints,
+            ReportOutputDataBlockTitle::OtherFingerprints,
+        ];
+        let status_titles = [ReportOutputDataBlockTitle::VerificationResults];
+        let information_titles = [ReportOutputDataBlockTitle::Information];
+        Self {
+            original_metadata: original.metadata().clone(),
+            new_metadata: new.metadata().clone(),
+            status_changes: value_changes(
+                &key_values(original, &status_titles),
+                &key_values(new, &status_titles),
+            ),
+            new_errors: values_not_in(&new_errors, &original_errors),
+            resolved_errors: values_not_in(&original_errors, &new_errors),
+            new_failures: values_not_in(&new_failures, &original_failures),
+            resolved_failures: values_not_in(&original_failures, &new_failures),
+            fingerprint_changes: value_changes(
+                &key_values(original, &fingerprint_titles),
+                &key_values(new, &fingerprint_titles),
+            ),
+            information_changes: value_changes(
+                &key_values(original, &information_titles),
+                &key_values(new, &information_titles),
+            ),
+        }
+    }
+
+    /// Number of differences found
+    pub fn len(&self) -> usize {
+        let nb_values =
+            |m: &BTreeMap<String, Vec<String>>| m.values().map(|v| v.len()).sum::<usize>();
+        self.status_changes.len()
+            + nb_values(&self.new_errors)
+            + nb_values(&self.resolved_errors)
+            + nb_values(&self.new_failures)
+            + nb_values(&self.resolved_failures)
+            + self.fingerprint_changes.len()
+            + self.information_changes.len()
+    }
+
+    /// No difference found
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Ids of the verifications with new or resolved errors or failures
+    fn ids_with_changed_errors_and_failures(&self) -> BTreeSet<&String> {
+        self.new_errors
+            .keys()
+            .chain(self.resolved_errors.keys())
+            .chain(self.new_failures.keys())
+            .chain(self.resolved_failures.keys())
+            .collect()
+    }
+}
+
+fn metadata_to_string(metadata: &ReportOutputDataMetaData) -> String {
+    format!("{} ({})", metadata.title(), metadata.date_time())
+}
+
+fn changes_to_block(
+    title: ReportOutputDataBlockTitle,
+    changes: &[ReportValueChange],
+) -> ReportOutputDataBlock {
+    ReportOutputDataBlock::new_with_tuples(
+        title,
+        &changes
+            .iter()
+            .map(|c| (c.key.clone(), c.change_to_string()))
+            .collect::<Vec<_>>(),
+    )
+}
+
+impl ReportInformationTrait for ReportOutputDataDiff {
+    fn to_report_output(
+        &self,
+        metadata: ReportOutputDataMetaData,
+    ) -> Result<ReportOutputData, ReportError> {
+        let mut res = ReportOutputData::new(metadata);
+        res.push(ReportOutputDataBlock::new_with_entries(
+            ReportOutputDataBlockTitle::ComparedReports,
+            vec![
+                ReportOutputDataEntry::from((
+                    "Original report",
+                    metadata_to_string(&self.original_metadata).as_str(),
+                )),
+                ReportOutputDataEntry::from((
+                    "New report",
+                    metadata_to_string(&self.new_metadata).as_str(),
+                )),
+                ReportOutputDataEntry::from((
+                    "Number of differences",
+                    self.len().to_string().as_str(),
+                )),
+            ],
+        ));
+        if !self.status_changes.is_empty() {
+            res.push(changes_to_block(
+                ReportOutputDataBlockTitle::StatusChanges,
+                &self.status_changes,
+            ));
+        }
+        for id in self.ids_with_changed_errors_and_failures() {
+            for (values, title) in [
+                (
+                    &self.new_errors,
+                    ReportOutputDataBlockTitle::NewErrors(id.clone()),
+                ),
+                (
+                    &self.resolved_errors,
+                    ReportOutputDataBlockTitle::ResolvedErrors(id.clone()),
+                ),
+                (
+                    &self.new_failures,
+                    ReportOutputDataBlockTitle::NewFailures(id.clone()),
+                ),
+                (
+                    &self.resolved_failures,
+                    ReportOutputDataBlockTitle::ResolvedFailures(id.clone()),
+                ),
+            ] {
+                if let Some(v) = values.get(id) {
+                    res.push(ReportOutputDataBlock::new_with_strings(title, v));
+                }
+            }
+        }
+        if !self.fingerprint_changes.is_empty() {
+            res.push(changes_to_block(
+                ReportOutputDataBlockTitle::FingerprintChanges,
+                &self.fingerprint_changes,
+            ));
+        }
+        if !self.information_changes.is_empty() {
+            res.push(changes_to_block(
+                ReportOutputDataBlockTitle::InformationChanges,
+                &self.information_changes,
+            ));
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::ReportOutputDataMetaDataBuilder;
+    use super::*;
+
+    fn report(
+        date_time: &str,
+        fingerprint: &str,
+        status: &str,
+        errors: &[&str],
+        information: &[(&str, &str)],
+    ) -> ReportOutputData {
+        let mut blocks = vec![
+            ReportOutputDataBlock::new_with_tuples(
+                ReportOutputDataBlockTitle::Fingerprints,
+                &[("Context".to_string(), fingerprint.to_string())],
+            ),
+            ReportOutputDataBlock::new_with_tuples(
+                ReportOutputDataBlockTitle::Information,
+                &information
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect::<Vec<_>>(),
+            ),
+            ReportOutputDataBlock::new_with_tuples(
+                ReportOutputDataBlockTitle::VerificationResults,
+                &[
+                    (
+                        "01.01 - VerifySetupCompleteness".to_string(),
+                        "Successful".to_string(),
+                    ),
+                    (
+                        "03.01 - VerifyEncryptionGroupConsistency".to_string(),
+                        status.to_string(),
+                    ),
+                ],
+            ),
+        ];
+        if !errors.is_empty() {
+            blocks.push(ReportOutputDataBlock::new_with_strings(
+                ReportOutputDataBlockTitle::VerificationErrors("03.01".to_string()),
+                &errors
+                    .iter()
+                    .enumerate()
+                    .map(|(i, s)| format!("[{}] - {}", i + 1, s))
+                    .collect::<Vec<_>>(),
+            ));
+        }
+        ReportOutputData::from_vec(
+            ReportOutputDataMetaDataBuilder::default()
+                .title("Report")
+                .date_time(date_time)
+                .seed("KT_20250101_TT99")
+                .build()
+                .unwrap(),
+            blocks,
+        )
+    }
+
+    fn metadata() -> ReportOutputDataMetaData {
+        ReportOutputDataMetaDataBuilder::default()
+            .title("Comparison")
+            .date_time("2025-01-03")
+            .seed("KT_20250101_TT99")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_no_difference() {
+        let r = report("2025-01-01", "abc", "Successful", &[], &[("Seed", "s")]);
+        let diff = ReportOutputDataDiff::new(&r, &r);
+        assert!(diff.is_empty());
+        let output = diff.to_report_output(metadata()).unwrap();
+        assert_eq!(output.blocks().len(), 1);
+    }
+
+    #[test]
+    fn test_diff() {
+        let original = report(
+            "2025-01-01",
+            "abc",
+            "Errors",
+            &["error 1", "error 2"],
+            &[("Seed", "s"), ("Number of votes", "2")],
+        );
+        let new = report(
+            "2025-01-02",
+            "def",
+            "Errors",
+            &["error 2", "error 3"],
+            &[("Seed", "s"), ("Number of elections", "1")],
+        );
+        let diff = ReportOutputDataDiff::new(&original, &new);
+        assert!(diff.status_changes().is_empty());
+        assert_eq!(
+            diff.new_errors().get("03.01").unwrap(),
+            &vec!["error 3".to_string()]
+        );
+        assert_eq!(
+            diff.resolved_errors().get("03.01").unwrap(),
+            &vec!["error 1".to_string()]
+        );
+        assert!(diff.new_failures().is_empty());
+        assert_eq!(diff.fingerprint_changes().len(), 1);
+        assert_eq!(
+            diff.fingerprint_changes()[0].change_to_string(),
+            "abc -> def"
+        );
+        assert_eq!(diff.information_changes().len(), 2);
+        assert_eq!(diff.information_changes()[0].change_to_string(), "2 -> -");
+        assert_eq!(diff.information_changes()[1].original(), &None);
+        assert_eq!(diff.len(), 5);
+    }
+
+    #[test]
+    fn test_diff_shifted_index() {
+        let original = report(
+            "2025-01-01",
+            "abc",
+            "Errors",
+            &["error 1", "error 2", "error 3"],
+            &[],
+        );
+        let new = report("2025-01-02", "abc", "Errors", &["error 2", "error 3"], &[]);
+        let diff = ReportOutputDataDiff::new(&original, &new);
+        assert!(diff.new_errors().is_empty());
+        assert_eq!(
+            diff.resolved_errors().get("03.01").unwrap(),
+            &vec!["error 1".to_string()]
+        );
+        assert_eq!(diff.len(), 1);
+    }
+
+    #[test]
+    fn test_strip_numbering() {
+        assert_eq!(strip_numbering("[12] - error - 1"), "error - 1");
+        assert_eq!(strip_numbering("error 1"), "error 1");
+        assert_eq!(strip_numbering("[] - error 1"), "[] - error 1");
+        assert_eq!(strip_numbering("[a] - error 1"), "[a] - error 1");
+    }
+
+    #[test]
+    fn test_status_resolved() {
+        let original = report("2025-01-01", "abc", "Errors", &["error 1"], &[]);
+        let new = report("2025-01-02", "abc", "Successful", &[], &[]);
+        let diff = ReportOutputDataDiff::new(&original, &new);
+        assert_eq!(diff.status_changes().len(), 1);
+        assert_eq!(
+            diff.status_changes()[0].key(),
+            "03.01 - VerifyEncryptionGroupConsistency"
+        );
+        assert_eq!(
+            diff.status_changes()[0].change_to_string(),
+            "Errors -> Successful"
+        );
+        assert!(diff.new_errors().is_empty());
+        assert_eq!(diff.resolved_errors().len(), 1);
+        let s = diff.info_to_string(metadata(), 2).unwrap();
+        assert!(s.contains("Number of differences: 2"));
+        assert!(s.contains("Resolved errors for 03.01\nerror 1"));
+        assert!(s.contains("Errors -> Successful"));
+    }
+}
@@ -14,11 +14,20 @@
 // a copy of the GNU General Public License along with this program. If not, see
 // <https://www.gnu.org/licenses/>.
 
-use std::iter::once;
+use std::{iter::once, path::Path};
 
+use super::{
+    ReportError, ReportErrorImpl, ReportLanguage, ReportOutputFileOptions,
+    report_output_file::ReportOutputFile,
+};
 use derive_builder::Builder;
 use derive_getters::Getters;
+use rust_ev_system_library::rust_ev_crypto_primitives::prelude::{
+    ByteArray, EncodeTrait, basic_crypto_functions::sha256,
+};
+use rust_ev_verifier_lib::verification::VerificationStatus;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Enum with the title types
 #[derive(Debug, Clone, strum::Display, Serialize, Deserialize, PartialEq, Eq)]
@@ -37,6 +46,79 @@ pub enum ReportOutputDataBlockTitle {
     VerificationErrors(String),
     #[strum(to_string = "Failures for {0}")]
     VerificationFailures(String),
+    #[strum(to_string = "Information for {0}")]
+    VerificationInformation(String),
+    #[strum(to_string = "Performance")]
+    Performance,
+    #[strum(to_string = "Compared reports")]
+    ComparedReports,
+    #[strum(to_string = "Changes of the verification status")]
+    StatusChanges,
+    #[strum(to_string = "New errors for {0}")]
+    NewErrors(String),
+    #[strum(to_string = "Resolved errors for {0}")]
+    ResolvedErrors(String),
+    #[strum(to_string = "New failures for {0}")]
+    NewFailures(String),
+    #[strum(to_string = "Resolved failures for {0}")]
+    ResolvedFailures(String),
+    #[strum(to_string = "Changes of the fingerprints")]
+    FingerprintChanges,
+    #[strum(to_string = "Changes of the information")]
+    InformationChanges,
+    #[strum(to_string = "Results of the counting circle {0}")]
+    CountingCircleResults(String),
+    #[strum(to_string = "Results of the vote {1} - Counting circle {0}")]
+    VoteResults(String, String),
+    #[strum(to_string = "Ballots per list of the election {1} - Counting circle {0}")]
+    ElectionListResults(String, String),
+    #[strum(to_string = "Results of the election {1} - Counting circle {0}")]
+    ElectionResults(String, String),
+    #[strum(to_string = "Summary of the period {0}")]
+    PeriodSummary(String),
+    #[strum(to_string = "Previous attempts for {0}")]
+    PreviousAttempts(String),
+    #[strum(to_string = "Verifications not run")]
+    VerificationsNotRun,
+    #[strum(to_string = "Configuration")]
+    Configuration,
+}
+
+impl ReportOutputDataBlockTitle {
+    /// Title translated in the given language
+    pub fn to_localized_string(&self, language: ReportLanguage) -> String {
+        let with_id = |text: &str, id: &str| format!("{} {}", language.translate(text), id);
+        match self {
+            Self::VerificationErrors(id) => with_id("Errors for", id),
+            Self::VerificationFailures(id) => with_id("Failures for", id),
+            Self::VerificationInformation(id) => with_id("Information for", id),
+            Self::NewErrors(id) => with_id("New errors for", id),
+            Self::ResolvedErrors(id) => with_id("Resolved errors for", id),
+            Self::NewFailures(id) => with_id("New failures for", id),
+            Self::ResolvedFailures(id) => with_id("Resolved failures for", id),
+            Self::PreviousAttempts(id) => with_id("Previous attempts for", id),
+            Self::CountingCircleResults(cc) => with_id("Results of the counting circle", cc),
+            Self::PeriodSummary(period) => {
+                with_id("Summary of the period", &language.translate_value(period))
+            }
+            Self::VoteResults(cc, id) => format!(
+                "{} - {}",
+                with_id("Results of the vote", id),
+                with_id("Counting circle", cc)
+            ),
+            Self::ElectionListResults(cc, id) => format!(
+                "{} - {}",
+                with_id("Ballots per list of the election", id),
+                with_id("Counting circle", cc)
+            ),
+            Self::ElectionResults(cc, id) => format!(
+                "{} - {}",
+                with_id("Results of the election", id),
+                with_id("Counting circle", cc)
+            ),
+            _ => language.translate(&self.to_string()),
+        }
+    }
 }
 
 /// Trait to transform the outputs to string
@@ -87,20 +169,7 @@ pub struct ReportOutputDataBlock {
 
 impl OutputToString for ReportOutputDataBlock {
     fn output_to_string(&self, tab_size: u8) -> String {
-        let max_key_length = self.max_key_length();
-        once(self.title.to_string())
-            .chain(self.entries.iter().map(|e| match e {
-                ReportOutputDataEntry::KeyValue((k, v)) => format!(
-                    "{}{}:{} {}",
-                    " ".repeat(tab_size as usize),
-                    k,
-                    " ".repeat(max_key_length - k.len()),
-                    v,
-                ),
-                ReportOutputDataEntry::OnlyValue(v) => v.clone(),
-            }))
-            .collect::<Vec<_>>()
-            .join("\n")
+        self.localized_output_to_string(tab_size, ReportLanguage::En)
     }
 }
 
@@ -177,6 +246,42 @@ impl ReportOutputDataBlock {
             .unwrap_or(0usize)
     }
 
+    /// Transform the block to a multiline string, translated in the given language
+    pub fn localized_output_to_string(&self, tab_size: u8, language: ReportLanguage) -> String {
+        let entries = self
+            .entries
+            .iter()
+            .map(|e| match e {
+                ReportOutputDataEntry::KeyValue((k, v)) => ReportOutputDataEntry::KeyValue((
+                    language.translate(k),
+                    language.translate_value(v),
+                )),
+                ReportOutputDataEntry::OnlyValue(v) => ReportOutputDataEntry::OnlyValue(v.clone()),
+            })
+            .collect::<Vec<_>>();
+        let max_key_length = entries
+            .iter()
+            .filter_map(|e| match e {
+                ReportOutputDataEntry::KeyValue((k, _)) => Some(k.chars().count()),
+                ReportOutputDataEntry::OnlyValue(_) => None,
+            })
+            .max()
+            .unwrap_or(0usize);
+        once(self.title.to_localized_string(language))
+            .chain(entries.into_iter().map(|e| match e {
+                ReportOutputDataEntry::KeyValue((k, v)) => format!(
+                    "{}{}:{} {}",
+                    " ".repeat(tab_size as usize),
+                    k,
+                    " ".repeat(max_key_length - k.chars().count()),
+                    v,
+                ),
+                ReportOutputDataEntry::OnlyValue(v) => v,
+            }))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Get the title
     pub fn title(&self) -> &ReportOutputDataBlockTitle {
         &self.title
@@ -212,6 +317,50 @@ pub struct ReportOutputDataMetaData {
     seed: String,
     title: String,
     date_time: String,
+    /// Language of the rendered report files
+    ///
+    /// Default: English
+    #[serde(default)]
+    #[builder(default)]
+    #[getter(copy)]
+    language: ReportLanguage,
+}
+
+/// Error or failure of a verification, stored with its structure for the machine-readable exports
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Getters, Builder)]
+#[builder(setter(into))]
+pub struct ReportOutputDataEvent {
+    verification_id: String,
+    verification_name: String,
+    /// Kind of the event (`Error` or `Failure`)
+    kind: String,
+    /// Main message of the event
+    message: String,
+    /// Chain of the contexts, from the source to the main message
+    #[builder(default)]
+    context_chain: Vec<String>,
+    /// Location of the event in the dataset (e.g. the path of the file)
+    #[builder(default, setter(strip_option))]
+    location: Option<String>,
+    /// Cause of the event (e.g. the cause of a failed signature verification)
+    #[builder(default, setter(strip_option))]
+    cause: Option<String>,
+}
+
+/// Result of a verification, stored with its structure for the machine-readable exports
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Getters, Builder)]
+#[builder(setter(into))]
+pub struct ReportOutputDataVerification {
+    verification_id: String,
+    verification_name: String,
+    /// Status of the verification. `None` if the verification is excluded
+    #[builder(default)]
+    #[getter(copy)]
+    status: Option<VerificationStatus>,
+    /// Duration of the verification, if it is finished
+    #[builder(default)]
+    #[getter(copy)]
+    duration: Option<Duration>,
 }
 
 /// Store whole Report output
@@ -219,6 +368,12 @@ pub struct ReportOutputDataMetaData {
 pub struct ReportOutputData {
     metadata: ReportOutputDataMetaData,
     blocks: Vec<ReportOutputDataBlock>,
+    /// Results of the verifications. Empty for the reports archived before the export
+    #[serde(default)]
+    verifications: Vec<ReportOutputDataVerification>,
+    /// Errors and failures of the verifications. Empty for the reports archived before the export
+    #[serde(default)]
+    events: Vec<ReportOutputDataEvent>,
 }
 
 impl ReportOutputData {
@@ -227,6 +382,8 @@ impl ReportOutputData {
         Self {
             metadata,
             blocks: vec![],
+            verifications: vec![],
+            events: vec![],
         }
     }
 
@@ -235,7 +392,12 @@ impl ReportOutputData {
         metadata: ReportOutputDataMetaData,
         blocks: Vec<ReportOutputDataBlock>,
     ) -> Self {
-        Self { metadata, blocks }
+        Self {
+            metadata,
+            blocks,
+            verifications: vec![],
+            events: vec![],
+        }
     }
 
     /// Push a block
@@ -243,24 +405,87 @@ impl ReportOutputData {
         self.blocks.push(element);
     }
 
+    /// Push the result of a verification
+    pub fn push_verification(&mut self, verification: ReportOutputDataVerification) {
+        self.verifications.push(verification);
+    }
+
+    /// Push an event
+    pub fn push_event(&mut self, event: ReportOutputDataEvent) {
+        self.events.push(event);
+    }
+
+    /// Load a report archived as json (see [super::ReportData::to_json])
+    pub fn from_json(json_str: &str) -> Result<Self, ReportError> {
+        serde_json::from_str(json_str)
+            .map_err(|e| ReportError::from(ReportErrorImpl::FromJson { source: e }))
+    }
+
+    /// Load a report archived in a json file
+    pub fn from_json_file(path: &Path) -> Result<Self, ReportError> {
+        let json_str = std::fs::read_to_string(path).map_err(|e| ReportErrorImpl::IOError {
+            msg: format!("Error reading the report {}", path.display()),
+            source: e,
+        })?;
+        Self::from_json(&json_str)
+    }
+
+    /// Fingerprint of the report data
+    ///
+    /// SHA-256 (base16 encoded) of the canonical JSON of the data, i.e. the JSON archived with
+    /// [super::ReportData::to_json] rendered in the default language. The fingerprint is printed in
+    /// the report files to link them to the run. It does not depend on the language of the files.
+    pub fn fingerprint(&self) -> Result<String, ReportError> {
+        let mut data = self.clone();
+        data.metadata.language = ReportLanguage::default();
+        let json = serde_json::to_vec(&data)
+            .map_err(|e| ReportError::from(ReportErrorImpl::ToJson { source: e }))?;
+        sha256(&ByteArray::from_bytes(&json))
+            .map(|h| h.base16_encode().unwrap())
+            .map_err(|e| ReportError::from(ReportErrorImpl::Fingerprint { source: e }))
+    }
+
+    /// Change the language used to render the report files
+    pub fn with_language(mut self, language: ReportLanguage) -> Self {
+        self.metadata.language = language;
+        self
+    }
+
+    /// Generate the report files according to the specified output options
+    ///
+    /// Can be used to render an archived report again, e.g. with a new logo or new members
+    /// of the electoral board.
+    ///
+    /// Returns a vector of [ReportError] encountered during the generation
+    pub fn generate_files(&self, output_options: ReportOutputFileOptions) -> Vec<ReportError> {
+        ReportOutputFile::new(output_options, self).generate()
+    }
+
     /// Append an other [ReportOutput].
     ///
     /// `other` is emptied
     pub fn append(&mut self, other: &mut Self) {
         self.blocks.append(&mut other.blocks);
+        self.verifications.append(&mut other.verifications);
+        self.events.append(&mut other.events);
     }
 }
 
 impl OutputToString for ReportOutputData {
     fn output_to_string(&self, tab_size: u8) -> String {
+        let language = self.metadata().language();
         let mut res = String::new();
         res.push_str(&format!("{}\n", self.metadata().title()));
-        res.push_str(&format!("Date / Time: {}\n\n", self.metadata().date_time()));
+        res.push_str(&format!(
+            "{}: {}\n\n",
+            language.translate("Date / Time"),
+            self.metadata().date_time()
+        ));
         res.push_str(
             &self
                 .blocks
                 .iter()
-                .map(|b| b.output_to_string(tab_size))
+                .map(|b| b.localized_output_to_string(tab_size, language))
                 .collect::<Vec<_>>()
                 .join("\n\n"),
         );
@@ -275,6 +500,7 @@ mod tests {
     use chrono::Local;
 
     use super::*;
+    use crate::report::{ReportOutputFileOptionsBuilder, ReportOutputFileType};
 
     #[test]
     fn test_serialization() {
@@ -303,6 +529,86 @@ mod tests {
         assert_eq!(data, deserialized_data);
     }
 
+    #[test]
+    fn test_regenerate_from_json_file() {
+        let data = super::super::report_output_file::test::test_sample();
+        let dir = PathBuf::from(".").join("test_temp_dir");
+        let now: String = Local::now().format("%Y%m%d_%H%M%S").to_string();
+        let json_path = dir.join(format!("test_report_archived_{}.json", now));
+        std::fs::write(&json_path, serde_json::to_string(&data).unwrap()).unwrap();
+        let loaded = ReportOutputData::from_json_file(&json_path).unwrap();
+        assert_eq!(loaded, data);
+        let filename = format!("test_report_regenerated_{}", now);
+        let options = ReportOutputFileOptionsBuilder::default()
+            .add_output_type(ReportOutputFileType::Txt)
+            .directory(dir.as_path())
+            .filename_without_extension(filename.as_str())
+            .add_explicit_electoral_board_member("Jane Doe")
+            .build()
+            .unwrap();
+        let res = loaded
+            .with_language(ReportLanguage::Fr)
+            .generate_files(options);
+        assert!(res.is_empty(), "{:?}", res);
+        let content = std::fs::read_to_string(dir.join(format!("{}.txt", filename))).unwrap();
+        assert!(content.contains("Empreintes"));
+        assert!(content.contains("Jane Doe"));
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let data = super::super::report_output_file::test::test_sample();
+        let fingerprint = data.fingerprint().unwrap();
+        assert_eq!(fingerprint.len(), 64);
+        let loaded = ReportOutputData::from_json(&serde_json::to_string(&data).unwrap()).unwrap();
+        assert_eq!(loaded.fingerprint().unwrap(), fingerprint);
+        assert_eq!(
+            loaded
+                .clone()
+                .with_language(ReportLanguage::Fr)
+                .fingerprint()
+                .unwrap(),
+            fingerprint
+        );
+        let mut other = loaded;
+        other.push(ReportOutputDataBlock::new(
+            ReportOutputDataBlockTitle::Information,
+        ));
+        assert_ne!(other.fingerprint().unwrap(), fingerprint);
+    }
+
+    #[test]
+    fn test_from_json_error() {
+        assert!(ReportOutputData::from_json("{").is_err());
+        assert!(ReportOutputData::from_json_file(&PathBuf::from("./toto.json")).is_err());
+    }
+
+    #[test]
+    fn test_deserialization_without_language() {
+        let json = r#"{"metadata":{"seed":"s","title":"t","date_time":"d"},"blocks":[]}"#;
+        let data: ReportOutputData = serde_json::from_str(json).unwrap();
+        assert_eq!(data.metadata().language(), ReportLanguage::En);
+    }
+
+    #[test]
+    fn test_localized_output_to_string() {
+        let block = ReportOutputDataBlock::new_with_tuples(
+            ReportOutputDataBlockTitle::VerificationResults,
+            &[(
+                "01.01 - VerifySetupCompleteness".to_string(),
+                "Successful".to_string(),
+            )],
+        );
+        assert_eq!(
+            block.localized_output_to_string(2, ReportLanguage::Fr),
+            "Résultats des vérifications\n  01.01 - Complétude de la configuration: Réussi"
+        );
+        assert_eq!(
+            block.localized_output_to_string(2, ReportLanguage::En),
+            block.output_to_string(2)
+        );
+    }
+
     #[test]
     fn test_deserialization_file() {
         let data = super::super::report_output_file::test::test_sample();
@@ -0,0 +1,222 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Module exporting the errors and failures of the verifications in machine-readable formats
+//!
+//! Each line of the export is one [ReportOutputDataEvent]. The export is generated in CSV
+//! (with a header line, RFC 4180 quoting) or in JSON Lines (one JSON object per line).
+//! The context chain is joined with ` / ` in the CSV.
+//!
+//! The values of the CSV starting with a character interpreted as a formula by the spreadsheets
+//! (`=`, `+`, `-`, `@`, tab or carriage return) are prefixed with `'`, since the messages can
+//! contain values read from the dataset.
+
+use super::super::report_output_data::{ReportOutputData, ReportOutputDataEvent};
+
+const CSV_HEADER: [&str; 7] = [
+    "verification_id",
+    "verification_name",
+    "kind",
+    "message",
+    "context_chain",
+    "location",
+    "cause",
+];
+
+/// Characters starting a formula in the spreadsheets
+const CSV_FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// Neutralize the formulas and quote the field for CSV if it contains a separator, a quote or a line break
+fn csv_field(s: &str) -> String {
+    let s = match s.starts_with(CSV_FORMULA_PREFIXES) {
+        true => format!("'{s}"),
+        false => s.to_string(),
+    };
+    match s.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", s.replace('"', "\"\"")),
+        false => s,
+    }
+}
+
+fn event_to_csv_line(event: &ReportOutputDataEvent) -> String {
+    [
+        event.verification_id().as_str(),
+        event.verification_name().as_str(),
+        event.kind().as_str(),
+        event.message().as_str(),
+        event.context_chain().join(" / ").as_str(),
+        event.location().as_deref().unwrap_or_default(),
+        event.cause().as_deref().unwrap_or_default(),
+    ]
+    .iter()
+    .map(|s| csv_field(s))
+    .collect::<Vec<_>>()
+    .join(",")
+}
+
+/// Generate the CSV export of the events of the report data
+pub(super) fn report_output_data_to_csv(report_data: &ReportOutputData) -> String {
+    let mut res = CSV_HEADER.join(",");
+    res.push_str("\r\n");
+    for event in report_data.events() {
+        res.push_str(&event_to_csv_line(event));
+        res.push_str("\r\n");
+    }
+    res
+}
+
+/// Generate the JSON Lines export of the events of the report data
+pub(super) fn report_output_data_to_jsonl(
+    report_data: &ReportOutputData,
+) -> Result<String, serde_json::Error> {
+    let mut res = String::new();
+    for event in report_data.events() {
+        res.push_str(&serde_json::to_string(event)?);
+        res.push('\n');
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        super::super::report_output_data::{
+            ReportOutputDataEventBuilder, ReportOutputDataMetaDataBuilder,
+        },
+        *,
+    };
+    use rust_ev_verifier_lib::verification::VerificationEvent;
+
+    fn sample() -> ReportOutputData {
+        let mut res = ReportOutputData::new(
+            ReportOutputDataMetaDataBuilder::default()
+                .title("Verifier Test Report")
+                .date_time("01.01.2025 10:00:00.000")
+                .seed("KT_20250101_TT99")
+                .build()
+                .unwrap(),
+        );
+        res.push_event(
+            ReportOutputDataEventBuilder::default()
+                .verification_id("01.02")
+                .verification_name("VerifySignatureA")
+                .kind("Failure")
+                .message("Wrong signature")
+                .context_chain(vec!["Wrong signature".to_string()])
+                .location("context/file.json")
+                .cause("Cryptographic mismatch of the signature")
+                .build()
+                .unwrap(),
+        );
+        res.push_event(
+            ReportOutputDataEventBuilder::default()
+                .verification_id("01.03")
+                .verification_name("VerifyB")
+                .kind("Error")
+                .message("Error reading \"file\", line 2")
+                .context_chain(vec![
+                    "io error".to_string(),
+                    "Error reading \"file\", line 2".to_string(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        res
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = report_output_data_to_csv(&sample());
+        let lines = csv.split("\r\n").collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[0],
+            "verification_id,verification_name,kind,message,context_chain,location,cause"
+        );
+        assert_eq!(
+            lines[1],
+            "01.02,VerifySignatureA,Failure,Wrong signature,Wrong signature,context/file.json,Cryptographic mismatch of the signature"
+        );
+        assert_eq!(
+            lines[2],
+            "01.03,VerifyB,Error,\"Error reading \"\"file\"\", line 2\",\"io error / Error reading \"\"file\"\", line 2\",,"
+        );
+        assert!(lines[3].is_empty());
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("abc"), "abc");
+        assert_eq!(csv_field("=1+2"), "'=1+2");
+        assert_eq!(csv_field("+41 00"), "'+41 00");
+        assert_eq!(csv_field("-1"), "'-1");
+        assert_eq!(csv_field("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_field("=HYPERLINK(\"x\")"), "\"'=HYPERLINK(\"\"x\"\")\"");
+        assert_eq!(csv_field("a=b"), "a=b");
+    }
+
+    #[test]
+    fn test_csv_verification_events() {
+        let mut data = sample();
+        let error = VerificationEvent::new_error("=cmd|' /C calc'!A0")
+            .add_context("Error reading the contest")
+            .with_location("tally/contest.xml");
+        let failure = VerificationEvent::new_failure("@SUM(1+1)*cmd")
+            .add_context("-Wrong value, in vote")
+            .with_location("+tally/ballot_box.json");
+        for event in [error, failure] {
+            data.push_event(
+                crate::report::verification_event_to_output("07.01", "VerifyTally", &event)
+                    .unwrap(),
+            );
+        }
+        let csv = report_output_data_to_csv(&data);
+        let lines = csv.split("\r\n").collect::<Vec<_>>();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines[3],
+            "07.01,VerifyTally,Error,Error reading the contest,'=cmd|' /C calc'!A0 / Error reading the contest,tally/contest.xml,"
+        );
+        assert_eq!(
+            lines[4],
+            "07.01,VerifyTally,Failure,\"'-Wrong value, in vote\",\"'@SUM(1+1)*cmd / -Wrong value, in vote\",'+tally/ballot_box.json,"
+        );
+    }
+
+    #[test]
+    fn test_jsonl() {
+        let data = sample();
+        let jsonl = report_output_data_to_jsonl(&data).unwrap();
+        let events = jsonl
+            .lines()
+            .map(|l| serde_json::from_str::<ReportOutputDataEvent>(l).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(events, data.events().to_vec());
+    }
+
+    #[test]
+    fn test_no_event() {
+        let mut data = sample();
+        let mut empty = ReportOutputData::new(data.metadata().clone());
+        assert_eq!(
+            report_output_data_to_csv(&empty),
+            format!("{}\r\n", CSV_HEADER.join(","))
+        );
+        assert!(report_output_data_to_jsonl(&empty).unwrap().is_empty());
+        empty.append(&mut data);
+        assert_eq!(empty.events().len(), 2);
+    }
+}
@@ -0,0 +1,384 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! User-defined template for the HTML report (used also for the PDF generated with the browser)
+//!
+//! The template is an HTML file with the following tags:
+//! - `{{name}}`: Replaced by the value `name` (escaped, except the values containing HTML)
+//! - `{{#name}}...{{/name}}`: Section repeated for each element of the list `name`. If `name` is not a
+//!   list, the section is rendered once if the value is not empty
+//! - `{{^name}}...{{/name}}`: Section rendered only if `name` is empty
+//! - `{{t:text}}`: Text translated in the language of the report
+//!
+//! The following values are available:
+//!
+//! | Name             | Description                                                                  |
+//! | ---------------- | ---------------------------------------------------------------------------- |
+//! | `title`          | Title of the report                                                          |
+//! | `date_time`      | Date and time of the report                                                  |
+//! | `seed`           | Seed of the election event                                                   |
+//! | `language`       | Language of the report (`en`, `de`, `fr` or `it`)                            |
+//! | `fingerprint`    | Fingerprint of the report data (SHA-256), to be printed on each page         |
+//! | `style`          | Default style sheet of the report (HTML)                                     |
+//! | `logo`           | Image of the logo, empty if no logo (HTML)                                   |
+//! | `logo_base64`    | Logo as base64 string, empty if no logo                                      |
+//! | `blocks`         | All the blocks with the default layout (HTML)                                |
+//! | `signatures`     | Signatures of the electoral board with the default layout (HTML)             |
+//! | `block_list`     | List of the blocks, with the values `title`, `entries` (default layout of    |
+//! |                  | the entries as HTML), `key_values` (list with `key` and `value`) and         |
+//! |                  | `values` (list with `value`)                                                 |
+//! | `signature_list` | List of the members of the electoral board, with the value `name`            |
+//!
+//! Example:
+//! ```html
+//! <html>
+//! <head><style>{{style}}</style></head>
+//! <body>
+//!   <h1>{{title}}</h1>
+//!   <p>{{t:Date / Time}}: {{date_time}}</p>
+//!   {{#block_list}}
+//!     <h2>{{title}}</h2>
+//!     <table>{{#key_values}}<tr><td>{{key}}</td><td>{{value}}</td></tr>{{/key_values}}</table>
+//!     {{#values}}<p style="white-space:pre">{{value}}</p>{{/values}}
+//!   {{/block_list}}
+//!   {{signatures}}
+//!   <footer>Legal notice of the canton</footer>
+//! </body>
+//! </html>
+//! ```
+
+use super::super::{ReportError, ReportErrorImpl, ReportLanguage};
+use std::{collections::HashMap, path::Path};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplateNode {
+    Text(String),
+    Value(String),
+    Translation(String),
+    Section {
+        name: String,
+        inverted: bool,
+        children: Vec<TemplateNode>,
+    },
+}
+
+/// Parsed template for the HTML report
+///
+/// See the [module documentation](self) for the syntax
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlTemplate {
+    nodes: Vec<TemplateNode>,
+}
+
+/// Value that can be used in a template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum TemplateValue {
+    /// Text, that will be escaped
+    Text(String),
+    /// HTML code, that will be inserted as it is
+    Html(String),
+    /// List for the sections
+    List(Vec<TemplateContext>),
+}
+
+/// Values available to render a template
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct TemplateContext(HashMap<&'static str, TemplateValue>);
+
+impl TemplateValue {
+    fn is_empty(&self) -> bool {
+        match self {
+            TemplateValue::Text(s) | TemplateValue::Html(s) => s.is_empty(),
+            TemplateValue::List(l) => l.is_empty(),
+        }
+    }
+}
+
+impl TemplateContext {
+    /// Add a text value
+    pub fn with_text(mut self, name: &'static str, value: &str) -> Self {
+        self.0.insert(name, TemplateValue::Text(value.to_string()));
+        self
+    }
+
+    /// Add a HTML value
+    pub fn with_html(mut self, name: &'static str, value: &str) -> Self {
+        self.0.insert(name, TemplateValue::Html(value.to_string()));
+        self
+    }
+
+    /// Add a list
+    pub fn with_list(mut self, name: &'static str, value: Vec<TemplateContext>) -> Self {
+        self.0.insert(name, TemplateValue::List(value));
+        self
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => res.push_str("&amp;"),
+            '<' => res.push_str("&lt;"),
+            '>' => res.push_str("&gt;"),
+            '"' => res.push_str("&quot;"),
+            '\'' => res.push_str("&#39;"),
+            _ => res.push(c),
+        }
+    }
+    res
+}
+
+fn parse(template: &str) -> Result<Vec<TemplateNode>, ReportErrorImpl> {
+    // Stack of the open sections (name, inverted, children). The first element is the root
+    let mut stack: Vec<(String, bool, Vec<TemplateNode>)> = vec![(String::new(), false, vec![])];
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        if start > 0 {
+            stack
+                .last_mut()
+                .unwrap()
+                .2
+                .push(TemplateNode::Text(rest[..start].to_string()));
+        }
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or_else(|| {
+            ReportErrorImpl::HtmlTemplate(format!(
+                "Tag not closed: {}",
+                after.chars().take(20).collect::<String>()
+            ))
+        })?;
+        let tag = after[..end].trim();
+        rest = &after[end + 2..];
+        if let Some(name) = tag.strip_prefix('#').or_else(|| tag.strip_prefix('^')) {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(ReportErrorImpl::HtmlTemplate(
+                    "Section without name".to_string(),
+                ));
+            }
+            stack.push((name.to_string(), tag.starts_with('^'), vec![]));
+        } else if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim();
+            if stack.len() == 1 || stack.last().unwrap().0 != name {
+                return Err(ReportErrorImpl::HtmlTemplate(format!(
+                    "Unexpected end of the section {name}"
+                )));
+            }
+            let (name, inverted, children) = stack.pop().unwrap();
+            stack.last_mut().unwrap().2.push(TemplateNode::Section {
+                name,
+                inverted,
+                children,
+            });
+        } else if let Some(text) = tag.strip_prefix("t:") {
+            stack
+                .last_mut()
+                .unwrap()
+                .2
+                .push(TemplateNode::Translation(text.trim().to_string()));
+        } else if tag.is_empty() {
+            return Err(ReportErrorImpl::HtmlTemplate("Empty tag".to_string()));
+        } else {
+            stack
+                .last_mut()
+                .unwrap()
+                .2
+                .push(TemplateNode::Value(tag.to_string()));
+        }
+    }
+    if !rest.is_empty() {
+        stack
+            .last_mut()
+            .unwrap()
+            .2
+            .push(TemplateNode::Text(rest.to_string()));
+    }
+    if stack.len() > 1 {
+        return Err(ReportErrorImpl::HtmlTemplate(format!(
+            "Section {} not closed",
+            stack.last().unwrap().0
+        )));
+    }
+    Ok(stack.pop().unwrap().2)
+}
+
+fn lookup<'a>(name: &str, contexts: &[&'a TemplateContext]) -> Option<&'a TemplateValue> {
+    contexts.iter().rev().find_map(|c| c.0.get(name))
+}
+
+fn render_nodes(
+    nodes: &[TemplateNode],
+    contexts: &[&TemplateContext],
+    language: ReportLanguage,
+    output: &mut String,
+) -> Result<(), ReportErrorImpl> {
+    for node in nodes {
+        match node {
+            TemplateNode::Text(t) => output.push_str(t),
+            TemplateNode::Translation(t) => output.push_str(&escape_html(&language.translate(t))),
+            TemplateNode::Value(name) => match lookup(name, contexts) {
+                Some(TemplateValue::Text(t)) => output.push_str(&escape_html(t)),
+                Some(TemplateValue::Html(h)) => output.push_str(h),
+                Some(TemplateValue::List(_)) => {
+                    return Err(ReportErrorImpl::HtmlTemplate(format!(
+                        "{name} is a list and can only be used as section"
+                    )));
+                }
+                None => {
+                    return Err(ReportErrorImpl::HtmlTemplate(format!(
+                        "Unknown value {name}"
+                    )));
+                }
+            },
+            TemplateNode::Section {
+                name,
+                inverted,
+                children,
+            } => {
+                let value = lookup(name, contexts);
+                match (inverted, value) {
+                    (true, v) => {
+                        if v.is_none_or(|v| v.is_empty()) {
+                            render_nodes(children, contexts, language, output)?;
+                        }
+                    }
+                    (false, Some(TemplateValue::List(list))) => {
+                        for element in list {
+                            let mut inner = contexts.to_vec();
+                            inner.push(element);
+                            render_nodes(children, &inner, language, output)?;
+                        }
+                    }
+                    (false, Some(v)) => {
+                        if !v.is_empty() {
+                            render_nodes(children, contexts, language, output)?;
+                        }
+                    }
+                    (false, None) => {
+                        return Err(ReportErrorImpl::HtmlTemplate(format!(
+                            "Unknown section {name}"
+                        )));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+impl HtmlTemplate {
+    /// Parse the template
+    ///
+    /// Return an error if the syntax of the template is not correct
+    pub fn new(template: &str) -> Result<Self, ReportError> {
+        Ok(Self {
+            nodes: parse(template)?,
+        })
+    }
+
+    /// Read and parse the template from a file
+    pub fn from_file(path: &Path) -> Result<Self, ReportError> {
+        let template = std::fs::read_to_string(path).map_err(|e| ReportErrorImpl::IOError {
+            msg: format!("Error reading the html template {}", path.display()),
+            source: e,
+        })?;
+        Self::new(&template)
+    }
+
+    /// Render the template with the context
+    pub(super) fn render(
+        &self,
+        context: &TemplateContext,
+        language: ReportLanguage,
+    ) -> Result<String, ReportErrorImpl> {
+        let mut res = String::new();
+        render_nodes(&self.nodes, &[context], language, &mut res)?;
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn context() -> TemplateContext {
+        TemplateContext::default()
+            .with_text("title", "Report <1>")
+            .with_html("logo", "<img src=\"logo.png\">")
+            .with_text("empty", "")
+            .with_list(
+                "block_list",
+                vec![
+                    TemplateContext::default()
+                        .with_text("title", "Block 1")
+                        .with_list(
+                            "values",
+                            vec![
+                                TemplateContext::default().with_text("value", "a"),
+                                TemplateContext::default().with_text("value", "b"),
+                            ],
+                        ),
+                    TemplateContext::default()
+                        .with_text("title", "Block 2")
+                        .with_list("values", vec![]),
+                ],
+            )
+    }
+
+    fn render(template: &str) -> Result<String, ReportErrorImpl> {
+        parse(template)
+            .and_then(|nodes| HtmlTemplate { nodes }.render(&context(), ReportLanguage::De))
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render("<h1>{{ title }}</h1>{{logo}}").unwrap(),
+            "<h1>Report &lt;1&gt;</h1><img src=\"logo.png\">"
+        );
+        assert_eq!(
+            render("{{#block_list}}[{{title}}:{{#values}}{{value}}{{/values}}{{^values}}-{{/values}}]{{/block_list}}")
+                .unwrap(),
+            "[Block 1:ab][Block 2:-]"
+        );
+        assert_eq!(
+            render("{{#logo}}L{{/logo}}{{#empty}}E{{/empty}}{{^empty}}N{{/empty}}{{^unknown}}U{{/unknown}}")
+                .unwrap(),
+            "LNU"
+        );
+        assert_eq!(render("{{t:Signatures}}").unwrap(), "Unterschriften");
+        assert_eq!(render("no tag").unwrap(), "no tag");
+    }
+
+    #[test]
+    fn test_render_errors() {
+        assert!(render("{{unknown}}").is_err());
+        assert!(render("{{block_list}}").is_err());
+        assert!(render("{{#unknown}}{{/unknown}}").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(HtmlTemplate::new("{{title").is_err());
+        assert!(HtmlTemplate::new("{{}}").is_err());
+        assert!(HtmlTemplate::new("{{#block_list}}").is_err());
+        assert!(HtmlTemplate::new("{{/block_list}}").is_err());
+        assert!(HtmlTemplate::new("{{#a}}{{#b}}{{/a}}{{/b}}").is_err());
+        assert!(HtmlTemplate::new("{{#}}{{/}}").is_err());
+        assert!(HtmlTemplate::new("{{#a}}{{title}}{{/a}}").is_ok());
+    }
+}
@@ -0,0 +1,317 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Module generating the report in the JUnit XML format, to integrate the results in CI pipelines
+//!
+//! Each verification is a testcase. The errors and failures of the verifications are mapped to
+//! `<error>` and `<failure>`, the excluded verifications and the verifications that did not finish are
+//! marked as `<skipped>`
+
+use super::super::report_output_data::{
+    ReportOutputData, ReportOutputDataEvent, ReportOutputDataVerification,
+};
+use rust_ev_verifier_lib::verification::VerificationStatus;
+
+const KIND_ERROR: &str = "Error";
+const KIND_FAILURE: &str = "Failure";
+
+/// Testcase collected from the report data
+struct JUnitTestCase<'a> {
+    name: String,
+    duration: Option<f64>,
+    skipped: Option<String>,
+    errors: Vec<&'a ReportOutputDataEvent>,
+    failures: Vec<&'a ReportOutputDataEvent>,
+}
+
+impl<'a> JUnitTestCase<'a> {
+    fn new(
+        verification: &ReportOutputDataVerification,
+        events: &'a [ReportOutputDataEvent],
+    ) -> Self {
+        let events_of_kind = |kind: &str| {
+            events
+                .iter()
+                .filter(|e| {
+                    e.verification_id() == verification.verification_id() && e.kind() == kind
+                })
+                .collect::<Vec<_>>()
+        };
+        Self {
+            name: format!(
+                "{} - {}",
+                verification.verification_id(),
+                verification.verification_name()
+            ),
+            duration: verification.duration().map(|d| d.as_secs_f64()),
+            skipped: skipped_message(verification.status()),
+            errors: events_of_kind(KIND_ERROR),
+            failures: events_of_kind(KIND_FAILURE),
+        }
+    }
+
+    fn to_xml(&self, classname: &str) -> String {
+        let time = self
+            .duration
+            .map(|d| format!(" time=\"{:.3}\"", d))
+            .unwrap_or_default();
+        let mut res = format!(
+            "    <testcase name=\"{}\" classname=\"{}\"{}>\n",
+            escape(&self.name),
+            escape(classname),
+            time
+        );
+        if let Some(msg) = &self.skipped {
+            res.push_str(&format!("      <skipped message=\"{}\"/>\n", escape(msg)));
+        }
+        if !self.failures.is_empty() {
+            res.push_str(&format!(
+                "      <failure message=\"{} failure(s)\" type=\"failure\">{}</failure>\n",
+                self.failures.len(),
+                escape(&events_to_text(&self.failures))
+            ));
+        }
+        if !self.errors.is_empty() {
+            res.push_str(&format!(
+                "      <error message=\"{} error(s)\" type=\"error\">{}</error>\n",
+                self.errors.len(),
+                escape(&events_to_text(&self.errors))
+            ));
+        }
+        res.push_str("    </testcase>\n");
+        res
+    }
+}
+
+/// Text of the events, one numbered line per event with the chain of the contexts
+fn events_to_text(events: &[&ReportOutputDataEvent]) -> String {
+    events
+        .iter()
+        .enumerate()
+        .map(|(i, e)| {
+            let mut line = format!("[{}] - {}", i + 1, e.message());
+            if e.context_chain().len() > 1 {
+                line.push_str(&format!(" ({})", e.context_chain().join(" / ")));
+            }
+            if let Some(location) = e.location() {
+                line.push_str(&format!(" [{location}]"));
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Escape the characters for XML, removing the control characters that are not allowed in XML
+fn escape(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => res.push_str("&amp;"),
+            '<' => res.push_str("&lt;"),
+            '>' => res.push_str("&gt;"),
+            '"' => res.push_str("&quot;"),
+            '\'' => res.push_str("&apos;"),
+            '\n' | '\r' | '\t' => res.push(c),
+            c if c.is_control() => {}
+            c => res.push(c),
+        }
+    }
+    res
+}
+
+/// Message for skipped testcase according to the status of the verification
+///
+/// The status `None` means that the verification is excluded. Return `None` if the verification has run
+fn skipped_message(status: Option<VerificationStatus>) -> Option<String> {
+    match status {
+        None => Some("Verification excluded".to_string()),
+        Some(s @ (VerificationStatus::NotStarted | VerificationStatus::Running)) => Some(format!(
+            "Verification not finished (status: {})",
+            s.as_ref()
+        )),
+        Some(_) => None,
+    }
+}
+
+/// Generate the JUnit XML from the report data
+///
+/// The testcases are the results of the verifications ([ReportOutputData::verifications]),
+/// with the errors and the failures taken from the events ([ReportOutputData::events]).
+/// The rendered blocks of the report are not used, so that the XML does not depend on
+/// the language of the report.
+pub(super) fn report_output_data_to_junit(report_data: &ReportOutputData) -> String {
+    let testcases = report_data
+        .verifications()
+        .iter()
+        .map(|v| JUnitTestCase::new(v, report_data.events()))
+        .collect::<Vec<_>>();
+
+    let title = report_data.metadata().title();
+    let nb_tests = testcases.len();
+    let nb_failures = testcases.iter().filter(|t| !t.failures.is_empty()).count();
+    let nb_errors = testcases.iter().filter(|t| !t.errors.is_empty()).count();
+    let nb_skipped = testcases.iter().filter(|t| t.skipped.is_some()).count();
+    let time = testcases.iter().filter_map(|t| t.duration).sum::<f64>();
+    let counters = format!(
+        "tests=\"{nb_tests}\" failures=\"{nb_failures}\" errors=\"{nb_errors}\" skipped=\"{nb_skipped}\" time=\"{time:.3}\""
+    );
+
+    let mut res = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    res.push_str(&format!(
+        "<testsuites name=\"{}\" {}>\n",
+        escape(title),
+        counters
+    ));
+    res.push_str(&format!(
+        "  <testsuite name=\"{}\" {}>\n",
+        escape(title),
+        counters
+    ));
+    res.push_str("    <properties>\n");
+    res.push_str(&format!(
+        "      <property name=\"seed\" value=\"{}\"/>\n",
+        escape(report_data.metadata().seed())
+    ));
+    res.push_str(&format!(
+        "      <property name=\"date_time\" value=\"{}\"/>\n",
+        escape(report_data.metadata().date_time())
+    ));
+    res.push_str("    </properties>\n");
+    for testcase in testcases.iter() {
+        res.push_str(&testcase.to_xml(title));
+    }
+    res.push_str("  </testsuite>\n");
+    res.push_str("</testsuites>\n");
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        super::super::report_output_data::{
+            ReportOutputDataBlock, ReportOutputDataBlockTitle, ReportOutputDataEventBuilder,
+            ReportOutputDataMetaDataBuilder, ReportOutputDataVerificationBuilder,
+        },
+        *,
+    };
+    use std::time::Duration;
+
+    fn verification(
+        id: &str,
+        name: &str,
+        status: Option<VerificationStatus>,
+        duration_ms: Option<u64>,
+    ) -> ReportOutputDataVerification {
+        ReportOutputDataVerificationBuilder::default()
+            .verification_id(id)
+            .verification_name(name)
+            .status(status)
+            .duration(duration_ms.map(Duration::from_millis))
+            .build()
+            .unwrap()
+    }
+
+    fn event(id: &str, kind: &str, message: &str) -> ReportOutputDataEvent {
+        ReportOutputDataEventBuilder::default()
+            .verification_id(id)
+            .verification_name("Verify")
+            .kind(kind)
+            .message(message)
+            .build()
+            .unwrap()
+    }
+
+    fn sample() -> ReportOutputData {
+        let mut res = ReportOutputData::from_vec(
+            ReportOutputDataMetaDataBuilder::default()
+                .title("Verifier Test Report")
+                .date_time("01.01.2025 10:00:00.000")
+                .seed("KT_20250101_TT99")
+                .build()
+                .unwrap(),
+            vec![],
+        );
+        res.push_verification(verification(
+            "01.01",
+            "VerifyA",
+            Some(VerificationStatus::FinishedSuccessfully),
+            Some(1500),
+        ));
+        res.push_verification(verification(
+            "01.02",
+            "VerifyB",
+            Some(VerificationStatus::FinishedWithFailures),
+            Some(250),
+        ));
+        res.push_verification(verification(
+            "01.03",
+            "VerifyC",
+            Some(VerificationStatus::FinishedWithErrors),
+            Some(250),
+        ));
+        res.push_verification(verification("01.04", "VerifyD", None, None));
+        res.push_event(event("01.02", KIND_FAILURE, "failure <1>"));
+        res.push_event(event("01.03", KIND_ERROR, "error 1"));
+        res.push_event(event("01.03", KIND_ERROR, "error 2"));
+        res
+    }
+
+    #[test]
+    fn test_junit() {
+        let xml = report_output_data_to_junit(&sample());
+        assert!(xml.contains(
+            "<testsuites name=\"Verifier Test Report\" tests=\"4\" failures=\"1\" errors=\"1\" skipped=\"1\" time=\"2.000\">"
+        ));
+        assert!(xml.contains(
+            "<testcase name=\"01.01 - VerifyA\" classname=\"Verifier Test Report\" time=\"1.500\">\n    </testcase>"
+        ));
+        assert!(xml.contains(
+            "<failure message=\"1 failure(s)\" type=\"failure\">[1] - failure &lt;1&gt;</failure>"
+        ));
+        assert!(xml.contains(
+            "<error message=\"2 error(s)\" type=\"error\">[1] - error 1\n[2] - error 2</error>"
+        ));
+        assert!(xml.contains(
+            "<testcase name=\"01.04 - VerifyD\" classname=\"Verifier Test Report\">\n      <skipped message=\"Verification excluded\"/>"
+        ));
+        assert!(xml.contains("<property name=\"seed\" value=\"KT_20250101_TT99\"/>"));
+    }
+
+    #[test]
+    fn test_junit_independent_of_rendered_blocks() {
+        let mut report = sample();
+        report.push(ReportOutputDataBlock::new_with_tuples(
+            ReportOutputDataBlockTitle::Performance,
+            &[("01.04".to_string(), "0,500s".to_string())],
+        ));
+        let translated = report
+            .clone()
+            .with_language(crate::report::ReportLanguage::De);
+        let xml = report_output_data_to_junit(&report);
+        assert!(xml.contains("time=\"2.000\">"));
+        assert_eq!(xml, report_output_data_to_junit(&translated));
+    }
+
+    #[test]
+    fn test_skipped_message() {
+        assert!(skipped_message(Some(VerificationStatus::FinishedSuccessfully)).is_none());
+        assert!(skipped_message(Some(VerificationStatus::FinishedWithFailuresAndErrors)).is_none());
+        assert!(skipped_message(None).is_some());
+        assert!(skipped_message(Some(VerificationStatus::Running)).is_some());
+        assert!(skipped_message(Some(VerificationStatus::NotStarted)).is_some());
+    }
+}
@@ -14,21 +14,45 @@
 // a copy of the GNU General Public License along with this program. If not, see
 // <https://www.gnu.org/licenses/>.
 
+mod events;
+mod html_template;
+mod junit;
 mod options;
+mod pdf_native;
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::canonicalize_path_os_dependent;
 
-use super::{OutputToString, ReportError, ReportErrorImpl, ReportOutputData};
+use super::{
+    OutputToString, ReportError, ReportErrorImpl, ReportLanguage, ReportOutputData,
+    report_output_data::ReportOutputDataBlock, report_signature::ReportFileSignature,
+};
 use build_html::{
     Container, ContainerType, Html, HtmlContainer, HtmlElement, HtmlPage, HtmlTag, Table,
     TableCell, TableCellType, TableRow,
 };
+pub use html_template::HtmlTemplate;
+use html_template::TemplateContext;
 pub use options::*;
 use tracing::{error, info};
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, strum::Display, PartialOrd, Ord)]
+/// Type of the report file
+///
+/// The type can be parsed from its extension (e.g. `junit.xml`)
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    PartialOrd,
+    Ord,
+)]
 pub enum ReportOutputFileType {
     #[default]
     #[strum(to_string = "txt")]
@@ -37,6 +61,14 @@ pub enum ReportOutputFileType {
     Html,
     #[strum(to_string = "pdf")]
     Pdf,
+    #[strum(to_string = "junit.xml")]
+    JUnit,
+    /// Errors and failures of the verifications as CSV
+    #[strum(to_string = "events.csv")]
+    EventsCsv,
+    /// Errors and failures of the verifications as JSON Lines
+    #[strum(to_string = "events.jsonl")]
+    EventsJsonl,
 }
 
 const STYLE: &str = r#"
@@ -64,6 +96,15 @@ const STYLE: &str = r#"
         vertical-align: top;
         font-size: 90%;
     }
+    .fingerprint {
+        font-size: 70%;
+    }
+    @media print {
+        .fingerprint {
+            position: fixed;
+            bottom: 0;
+        }
+    }
 "#;
 
 /// Struct to handle report generation and output
@@ -74,6 +115,7 @@ pub struct ReportOutputFile<'a> {
     txt_filepath: Option<PathBuf>,
     html_filepath: Option<PathBuf>,
     pdf_filepath: Option<PathBuf>,
+    fingerprint: Option<String>,
 }
 
 impl<'a> ReportOutputFile<'a> {
@@ -85,41 +127,74 @@ impl<'a> ReportOutputFile<'a> {
             txt_filepath: None,
             html_filepath: None,
             pdf_filepath: None,
+            fingerprint: None,
         }
     }
 
+    fn language(&self) -> ReportLanguage {
+        self.report_data.metadata().language()
+    }
+
+    /// Text with the fingerprint of the report data, if calculated
+    fn fingerprint_text(&self) -> Option<String> {
+        self.fingerprint
+            .as_ref()
+            .map(|f| format!("{}: {}", self.language().translate("Report fingerprint"), f))
+    }
+
     fn generate_txt(&self) -> Result<Vec<u8>, ReportErrorImpl> {
+        let language = self.language();
         let mut content: String = self.report_data.output_to_string(4);
-        content.push_str("\n\nSignatures:\n\n");
-        content.push_str(&self.options.signatures().join("\n\n"));
+        if let Some(fingerprint) = self.fingerprint_text() {
+            content.push_str(&format!("\n\n{}", fingerprint));
+        }
+        content.push_str(&format!("\n\n{}:\n\n", language.translate("Signatures")));
+        content.push_str(&self.options.localized_signatures(language).join("\n\n"));
         Ok(content.into_bytes())
     }
 
-    fn generate_html(&self) -> Result<Vec<u8>, ReportErrorImpl> {
-        let sections = self.report_data.blocks().iter().map(|b| {
-            let mut section_container =
-                Container::new(ContainerType::Div).with_header(2, b.title());
-
-            let key_value_entries = b.key_value_entries();
-            if !key_value_entries.is_empty() {
-                let mut table = Table::new().with_attributes(vec![("class", "key_value_table")]);
-                for (key, value) in key_value_entries.iter() {
-                    table.add_body_row(vec![key, value]);
-                }
-                section_container.add_table(table);
-            }
+    fn html_sections(&self) -> Vec<Container> {
+        let language = self.language();
+        self.report_data
+            .blocks()
+            .iter()
+            .map(|b| {
+                let mut section_container = Container::new(ContainerType::Div)
+                    .with_header(2, b.title().to_localized_string(language));
+                Self::add_html_block_entries(&mut section_container, b, language);
+                section_container
+            })
+            .collect()
+    }
 
-            for elem in b.only_value_entries().iter() {
-                section_container.add_html(
-                    HtmlElement::new(HtmlTag::ParagraphText)
-                        .with_attribute("style", "white-space:pre")
-                        .with_raw(elem),
-                );
+    fn add_html_block_entries(
+        container: &mut Container,
+        block: &ReportOutputDataBlock,
+        language: ReportLanguage,
+    ) {
+        let key_value_entries = block.key_value_entries();
+        if !key_value_entries.is_empty() {
+            let mut table = Table::new().with_attributes(vec![("class", "key_value_table")]);
+            for (key, value) in key_value_entries.iter() {
+                table.add_body_row(vec![
+                    language.translate(key),
+                    language.translate_value(value),
+                ]);
             }
-            section_container
-        });
+            container.add_table(table);
+        }
+        for elem in block.only_value_entries().iter() {
+            container.add_html(
+                HtmlElement::new(HtmlTag::ParagraphText)
+                    .with_attribute("style", "white-space:pre")
+                    .with_raw(elem),
+            );
+        }
+    }
 
-        let signatures = self.options.signatures();
+    fn html_signatures(&self) -> Container {
+        let language = self.language();
+        let signatures = self.options.localized_signatures(language);
         let style_row = format!("width:{}%", 100 / signatures.len());
 
         let mut signature_header_row = TableRow::new();
@@ -134,32 +209,136 @@ impl<'a> ReportOutputFile<'a> {
             .with_attributes(vec![("style", "width: 100%")])
             .with_custom_header_row(signature_header_row);
 
-        let signature_container = Container::new(ContainerType::Div)
-            .with_header(2, "Signatures")
-            .with_table(signature_table);
-
-        let mut content =
-            Container::new(ContainerType::Div).with_attributes(vec![("class", "content")]);
+        Container::new(ContainerType::Div)
+            .with_header(2, language.translate("Signatures"))
+            .with_table(signature_table)
+    }
 
-        if let Some(logo_base64) = self.options.logo_base64() {
-            let logo = HtmlElement::new(HtmlTag::Image)
+    fn html_logo(&self) -> Option<HtmlElement> {
+        self.options.logo_base64().map(|logo_base64| {
+            HtmlElement::new(HtmlTag::Image)
                 .with_attribute(
                     "src",
                     format!("data:image/png;base64,{}", logo_base64).as_str(),
                 )
                 .with_attribute("alt", "Logo")
-                .with_attribute("class", "logo");
+                .with_attribute("class", "logo")
+        })
+    }
+
+    fn html_template_context(&self) -> TemplateContext {
+        let language = self.language();
+        let metadata = self.report_data.metadata();
+        let block_list = self
+            .report_data
+            .blocks()
+            .iter()
+            .map(|b| {
+                TemplateContext::default()
+                    .with_text("title", &b.title().to_localized_string(language))
+                    .with_html("entries", &{
+                        let mut entries = Container::new(ContainerType::Div);
+                        Self::add_html_block_entries(&mut entries, b, language);
+                        entries.to_html_string()
+                    })
+                    .with_list(
+                        "key_values",
+                        b.key_value_entries()
+                            .iter()
+                            .map(|(k, v)| {
+                                TemplateContext::default()
+                                    .with_text("key", &language.translate(k))
+                                    .with_text("value", &language.translate_value(v))
+                            })
+                            .collect(),
+                    )
+                    .with_list(
+                        "values",
+                        b.only_value_entries()
+                            .iter()
+                            .map(|v| TemplateContext::default().with_text("value", v))
+                            .collect(),
+                    )
+            })
+            .collect();
+        TemplateContext::default()
+            .with_text("title", metadata.title())
+            .with_text("date_time", metadata.date_time())
+            .with_text("seed", metadata.seed())
+            .with_text("language", language.as_ref())
+            .with_text(
+                "fingerprint",
+                self.fingerprint.as_deref().unwrap_or_default(),
+            )
+            .with_html("style", STYLE)
+            .with_html(
+                "logo",
+                &self
+                    .html_logo()
+                    .map(|l| l.to_html_string())
+                    .unwrap_or_default(),
+            )
+            .with_text(
+                "logo_base64",
+                &self.options.logo_base64().unwrap_or_default(),
+            )
+            .with_html(
+                "blocks",
+                &self
+                    .html_sections()
+                    .iter()
+                    .map(|s| s.to_html_string())
+                    .collect::<String>(),
+            )
+            .with_html("signatures", &self.html_signatures().to_html_string())
+            .with_list("block_list", block_list)
+            .with_list(
+                "signature_list",
+                self.options
+                    .localized_signatures(language)
+                    .iter()
+                    .map(|s| TemplateContext::default().with_text("name", s))
+                    .collect(),
+            )
+    }
+
+    fn generate_html(&self) -> Result<Vec<u8>, ReportErrorImpl> {
+        if let Some(template) = self.options.html_template() {
+            return template
+                .render(&self.html_template_context(), self.language())
+                .map(|s| s.into_bytes());
+        }
+        let language = self.language();
+        let mut content =
+            Container::new(ContainerType::Div).with_attributes(vec![("class", "content")]);
+
+        if let Some(logo) = self.html_logo() {
             content.add_html(logo);
         }
 
         content.add_header(1, self.report_data.metadata().title());
-        content.add_html(HtmlElement::new(HtmlTag::ParagraphText).with_raw(
-            format!("Date / Time: {}", self.report_data.metadata().date_time()).as_str(),
-        ));
-        for section in sections {
+        content.add_html(
+            HtmlElement::new(HtmlTag::ParagraphText).with_raw(
+                format!(
+                    "{}: {}",
+                    language.translate("Date / Time"),
+                    self.report_data.metadata().date_time()
+                )
+                .as_str(),
+            ),
+        );
+        if let Some(fingerprint) = self.fingerprint_text() {
+            // Printed at the bottom of each page of the PDF (see the style)
+            content.add_html(
+                HtmlElement::new(HtmlTag::ParagraphText)
+                    .with_attribute("class", "fingerprint")
+                    .with_raw(fingerprint.as_str()),
+            );
+        }
+        for section in self.html_sections() {
             content.add_container(section);
         }
-        content.add_container(signature_container);
+        content.add_container(self.html_signatures());
 
         Ok(HtmlPage::new()
             .with_style(STYLE)
@@ -168,8 +347,36 @@ impl<'a> ReportOutputFile<'a> {
             .into_bytes())
     }
 
+    fn generate_junit(&self) -> Result<Vec<u8>, ReportErrorImpl> {
+        Ok(junit::report_output_data_to_junit(self.report_data).into_bytes())
+    }
+
+    fn generate_events_csv(&self) -> Result<Vec<u8>, ReportErrorImpl> {
+        Ok(events::report_output_data_to_csv(self.report_data).into_bytes())
+    }
+
+    fn generate_events_jsonl(&self) -> Result<Vec<u8>, ReportErrorImpl> {
+        events::report_output_data_to_jsonl(self.report_data)
+            .map(|s| s.into_bytes())
+            .map_err(|e| ReportErrorImpl::ToJson { source: e })
+    }
+
     fn generate_pdf(&self) -> Result<Vec<u8>, ReportErrorImpl> {
         let pdf_options = self.options.pdf_options().as_ref().unwrap();
+        match pdf_options.backend() {
+            PDFReportBackend::Browser => self.generate_pdf_with_browser(pdf_options),
+            PDFReportBackend::Native => pdf_native::report_output_data_to_pdf(
+                self.report_data,
+                &self.options,
+                self.fingerprint_text().as_deref(),
+            ),
+        }
+    }
+
+    fn generate_pdf_with_browser(
+        &self,
+        pdf_options: &PDFReportOptions,
+    ) -> Result<Vec<u8>, ReportErrorImpl> {
         let browser = pdf_options.browser()?;
         let file_path = format!(
             "file://{}",
@@ -197,15 +404,34 @@ impl<'a> ReportOutputFile<'a> {
             })
     }
 
+    /// Sign the content of the file and write the detached signature next to the file
+    fn sign_file(&self, filepath: &Path, content: &[u8]) -> Option<Result<PathBuf, ReportError>> {
+        let keystore = self.options.signing_keystore().as_ref()?;
+        Some(
+            ReportFileSignature::sign(
+                content,
+                self.fingerprint.as_deref().unwrap_or_default(),
+                keystore,
+            )
+            .and_then(|s| s.write_for_report_file(filepath)),
+        )
+    }
+
     /// Generate the reports in the specified formats and write to files
+    ///
+    /// The fingerprint of the report data is printed in the files. If a signing keystore is set in the options,
+    /// each file is signed with a detached signature (see [ReportFileSignature])
     pub fn generate(&mut self) -> Vec<ReportError> {
         let mut res = vec![];
+        match self.report_data.fingerprint() {
+            Ok(f) => self.fingerprint = Some(f),
+            Err(e) => {
+                error!("Error calculating the fingerprint of the report: {:?}", e);
+                return vec![e];
+            }
+        }
         for output_type in self.options.output_types().iter() {
-            let filepath = self.options.directory().join(format!(
-                "{}.{}",
-                self.options.filename_without_extension(),
-                output_type
-            ));
+            let filepath = self.options.output_file_path(*output_type);
             let content_res = match output_type {
                 ReportOutputFileType::Txt => {
                     let res = self.generate_txt();
@@ -222,10 +448,23 @@ impl<'a> ReportOutputFile<'a> {
                     self.pdf_filepath = Some(filepath.clone());
                     res
                 }
+                ReportOutputFileType::JUnit => self.generate_junit(),
+                ReportOutputFileType::EventsCsv => self.generate_events_csv(),
+                ReportOutputFileType::EventsJsonl => self.generate_events_jsonl(),
             };
             match content_res {
-                Ok(content) => match std::fs::write(&filepath, content) {
-                    Ok(_) => info!("Generated report {}: {}", output_type, filepath.display()),
+                Ok(content) => match std::fs::write(&filepath, &content) {
+                    Ok(_) => {
+                        info!("Generated report {}: {}", output_type, filepath.display());
+                        match self.sign_file(&filepath, &content) {
+                            Some(Ok(path)) => info!("Signed report file: {}", path.display()),
+                            Some(Err(e)) => {
+                                error!("Error signing the file {}: {:?}", filepath.display(), e);
+                                res.push(e);
+                            }
+                            None => {}
+                        }
+                    }
                     Err(e) => {
                         error!(
                             "Error writing {} file {}: {:?}",
@@ -350,6 +589,40 @@ lines.";
         assert!(content.contains("ResultKey: ResultValue"));
     }
 
+    #[test]
+    fn generate_txt_report_localized() {
+        let options = ReportOutputFileOptionsBuilder::default()
+            .add_output_type(ReportOutputFileType::Txt)
+            .directory(std::env::temp_dir().as_path())
+            .filename_without_extension("test_report")
+            .nb_electoral_board(2usize)
+            .build()
+            .unwrap();
+        let sample = test_sample();
+        let report_data = ReportOutputData::from_vec(
+            ReportOutputDataMetaDataBuilder::default()
+                .title(sample.metadata().title().as_str())
+                .date_time(sample.metadata().date_time().as_str())
+                .seed(sample.metadata().seed().as_str())
+                .language(ReportLanguage::De)
+                .build()
+                .unwrap(),
+            sample.blocks().clone(),
+        );
+        let content = String::from_utf8(
+            ReportOutputFile::new(options, &report_data)
+                .generate_txt()
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(content.contains("Datum / Zeit: "));
+        assert!(content.contains("Fingerabdrücke"));
+        assert!(content.contains("Fehler für test"));
+        assert!(content.contains("Unterschriften:"));
+        assert!(content.contains("Mitglied 2"));
+        assert!(content.contains("Key1: Value1"));
+    }
+
     #[test]
     fn generate_html_report() {
         let dir = PathBuf::from(".").join("test_temp_dir");
@@ -370,6 +643,126 @@ lines.";
         assert!(res_gen.is_empty());
     }
 
+    #[test]
+    fn generate_html_report_with_template() {
+        let template = HtmlTemplate::new(
+            r#"<html><head><style>{{style}}</style></head><body>
+<h1>{{title}}</h1>
+{{#block_list}}<h2>{{title}}</h2>{{#key_values}}<p>{{key}} = {{value}}</p>{{/key_values}}{{/block_list}}
+{{#signature_list}}<span>{{name}}</span>{{/signature_list}}
+<footer>{{t:Signatures}} - Legal footer</footer>
+</body></html>"#,
+        )
+        .unwrap();
+        let options = ReportOutputFileOptionsBuilder::default()
+            .add_output_type(ReportOutputFileType::Html)
+            .directory(std::env::temp_dir().as_path())
+            .filename_without_extension("test_report")
+            .nb_electoral_board(2usize)
+            .html_template(template)
+            .build()
+            .unwrap();
+        let report_data = test_sample();
+        let content = String::from_utf8(
+            ReportOutputFile::new(options, &report_data)
+                .generate_html()
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(content.contains("<h1>Verifier Test Report</h1>"));
+        assert!(content.contains("<h2>Fingerprints</h2><p>Key1 = Value1</p>"));
+        assert!(content.contains("<span>Member 1</span><span>Member 2</span>"));
+        assert!(content.contains("<footer>Signatures - Legal footer</footer>"));
+        assert!(content.contains(".key_value_table"));
+    }
+
+    #[test]
+    fn generate_junit_report() {
+        let dir = PathBuf::from(".").join("test_temp_dir");
+        let now: String = Local::now().format("%Y%m%d_%H%M%S").to_string();
+        let filenname = format!("test_report_junit_{}", now);
+        let options = ReportOutputFileOptionsBuilder::default()
+            .add_output_type(ReportOutputFileType::JUnit)
+            .directory(dir.as_path())
+            .filename_without_extension(filenname.as_str())
+            .build()
+            .unwrap();
+
+        let report_data = test_sample();
+
+        let mut report_output = ReportOutputFile::new(options, &report_data);
+        let res_gen = report_output.generate();
+        assert!(res_gen.is_empty());
+        assert!(dir.join(format!("{}.junit.xml", filenname)).is_file());
+    }
+
+    #[test]
+    fn generate_events_reports() {
+        let dir = PathBuf::from(".").join("test_temp_dir");
+        let now: String = Local::now().format("%Y%m%d_%H%M%S").to_string();
+        let filenname = format!("test_report_events_{}", now);
+        let options = ReportOutputFileOptionsBuilder::default()
+            .add_output_type(ReportOutputFileType::EventsCsv)
+            .add_output_type(ReportOutputFileType::EventsJsonl)
+            .directory(dir.as_path())
+            .filename_without_extension(filenname.as_str())
+            .build()
+            .unwrap();
+
+        let report_data = test_sample();
+
+        let mut report_output = ReportOutputFile::new(options, &report_data);
+        let res_gen = report_output.generate();
+        assert!(res_gen.is_empty());
+        assert!(dir.join(format!("{}.events.csv", filenname)).is_file());
+        assert!(dir.join(format!("{}.events.jsonl", filenname)).is_file());
+    }
+
+    #[test]
+    fn generate_signed_reports() {
+        use super::super::report_signature::{
+            signature_file_path,
+            test::{VERIFIER_AUTHORITY, signing_keystore, verifier_keystore},
+            verify_report_file_signature,
+        };
+        let dir = PathBuf::from(".").join("test_temp_dir");
+        let now: String = Local::now().format("%Y%m%d_%H%M%S").to_string();
+        let filenname = format!("test_report_signed_{}", now);
+        let options = ReportOutputFileOptionsBuilder::default()
+            .add_output_type(ReportOutputFileType::Txt)
+            .add_output_type(ReportOutputFileType::Html)
+            .directory(dir.as_path())
+            .filename_without_extension(filenname.as_str())
+            .signing_keystore(signing_keystore())
+            .build()
+            .unwrap();
+
+        let report_data = test_sample();
+        let fingerprint = report_data.fingerprint().unwrap();
+
+        let mut report_output = ReportOutputFile::new(options, &report_data);
+        let res_gen = report_output.generate();
+        assert!(res_gen.is_empty(), "{:?}", res_gen);
+        let ks = verifier_keystore();
+        for ext in ["txt", "html"] {
+            let path = dir.join(format!("{}.{}", filenname, ext));
+            assert!(
+                std::fs::read_to_string(&path)
+                    .unwrap()
+                    .contains(&format!("Report fingerprint: {}", fingerprint))
+            );
+            assert!(
+                verify_report_file_signature(
+                    &path,
+                    &signature_file_path(&path),
+                    &ks,
+                    VERIFIER_AUTHORITY
+                )
+                .unwrap()
+            );
+        }
+    }
+
     #[test]
     fn generate_html_report_with_logo() {
         let dir = PathBuf::from(".").join("test_temp_dir");
@@ -391,6 +784,34 @@ lines.";
         assert!(res_gen.is_empty());
     }
 
+    #[test]
+    fn generate_native_pdf_report_with_logo() {
+        let dir = PathBuf::from(".").join("test_temp_dir");
+        let now: String = Local::now().format("%Y%m%d_%H%M%S").to_string();
+        let filenname = format!("test_report_native_with_logo_{}", now);
+        let options = ReportOutputFileOptionsBuilder::default()
+            .add_output_type(ReportOutputFileType::Pdf)
+            .directory(dir.as_path())
+            .filename_without_extension(filenname.as_str())
+            .logo_bytes(test_logo())
+            .nb_electoral_board(3usize)
+            .pdf_options(
+                PDFReportOptionsBuilder::default()
+                    .backend(PDFReportBackend::Native)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let report_data = test_sample();
+
+        let mut report_output = ReportOutputFile::new(options, &report_data);
+        let res_gen = report_output.generate();
+        assert!(res_gen.is_empty(), "{:?}", res_gen);
+        assert!(dir.join(format!("{}.pdf", filenname)).is_file());
+    }
+
     #[test]
     fn generate_pdf_report_with_logo() {
         let dir = PathBuf::from(".").join("test_temp_dir");
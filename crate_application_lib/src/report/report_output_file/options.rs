@@ -14,15 +14,16 @@
 // a copy of the GNU General Public License along with this program. If not, see
 // <https://www.gnu.org/licenses/>.
 
-use super::super::{ReportError, ReportErrorImpl};
-use super::ReportOutputFileType;
+use super::super::{ReportError, ReportErrorImpl, ReportLanguage};
+use super::{HtmlTemplate, ReportOutputFileType};
 use derive_builder::{Builder, UninitializedFieldError};
 use derive_getters::Getters;
 use headless_chrome::Browser;
 use rust_ev_system_library::rust_ev_crypto_primitives::prelude::{ByteArray, EncodeTrait};
-use rust_ev_verifier_lib::VerifierConfig;
+use rust_ev_verifier_lib::{VerifierConfig, VerifierConfigError, direct_trust::Keystore};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tracing::{error, info};
 
 /// Options for report output
@@ -39,9 +40,25 @@ pub struct ReportOutputFileOptions {
     explicit_electoral_board_members: Vec<String>,
     #[builder(setter(strip_option))]
     pdf_options: Option<PDFReportOptions>,
+    /// Template for the HTML report, used also for the PDF generated with the browser.
+    /// If not set, the default layout is used
+    #[builder(setter(strip_option))]
+    html_template: Option<HtmlTemplate>,
+    /// Keystore with the secret key to sign the report files (detached signatures).
+    /// If not set, the files are not signed
+    #[builder(setter(strip_option))]
+    signing_keystore: Option<Arc<Keystore>>,
 }
 
 impl ReportOutputFileOptions {
+    /// Path of the generated file of the output type
+    pub fn output_file_path(&self, output_type: ReportOutputFileType) -> PathBuf {
+        self.directory.join(format!(
+            "{}.{}",
+            self.filename_without_extension, output_type
+        ))
+    }
+
     /// Returns the logo bytes as base64 string, if present.
     pub fn logo_base64(&self) -> Option<String> {
         if self.logo_bytes.is_empty() {
@@ -55,9 +72,14 @@ impl ReportOutputFileOptions {
     }
 
     pub fn signatures(&self) -> Vec<String> {
+        self.localized_signatures(ReportLanguage::En)
+    }
+
+    /// Signatures, where the generic names of the members are translated in the given language
+    pub fn localized_signatures(&self, language: ReportLanguage) -> Vec<String> {
         match self.explicit_electoral_board_members().len() {
             0 => (0..(*self.nb_electoral_board()))
-                .map(|n| format!("Member {}", n + 1))
+                .map(|n| format!("{} {}", language.translate("Member"), n + 1))
                 .collect::<Vec<_>>(),
             _ => self
                 .explicit_electoral_board_members()
@@ -81,41 +103,63 @@ impl ReportOutputFileOptions {
         output_dir: &Path,
         config: &VerifierConfig,
     ) -> Result<Self, ReportErrorImpl> {
+        let option = |value: Result<bool, VerifierConfigError>, name: &str| {
+            value.map_err(|e| ReportErrorImpl::VerifierConfig {
+                msg: format!("getting the option {name}"),
+                source: Box::new(e),
+            })
+        };
         let mut options_builder = ReportOutputFileOptionsBuilder::default()
             .directory(output_dir)
             .filename_without_extension(filename_without_extension);
 
-        if config.report_export_txt() {
+        if option(config.report_export_txt(), "report_export_txt")? {
             options_builder = options_builder.add_output_type(ReportOutputFileType::Txt);
         }
 
-        if config.report_export_pdf() {
-            let browser_path =
-                config
-                    .pdf_report_browser_path()
-                    .map_err(|e| ReportErrorImpl::VerifierConfig {
-                        msg: "getting PDF browser path".to_string(),
-                        source: Box::new(e),
+        if option(config.report_export_pdf(), "report_export_pdf")? {
+            let mut pdf_options_builder = PDFReportOptionsBuilder::default();
+            match option(config.report_pdf_native(), "report_pdf_native")? {
+                true => {
+                    pdf_options_builder.backend(PDFReportBackend::Native);
+                }
+                false => {
+                    let browser_path = config.pdf_report_browser_path().map_err(|e| {
+                        ReportErrorImpl::VerifierConfig {
+                            msg: "getting PDF browser path".to_string(),
+                            source: Box::new(e),
+                        }
                     })?;
-            match browser_path {
-                Some(bp) => {
-                    options_builder = options_builder.add_output_type(ReportOutputFileType::Pdf);
-                    options_builder = options_builder.pdf_options(
-                        PDFReportOptionsBuilder::default()
-                            .path_to_browser(bp)
-                            .sandbox(config.report_sandbox())
-                            .build()
-                            .map_err(|e| ReportErrorImpl::PdfReportOptionBuilder {
-                                source: Box::new(e),
-                            })?,
-                    );
+                    match browser_path {
+                        Some(bp) => {
+                            pdf_options_builder
+                                .path_to_browser(bp)
+                                .sandbox(option(config.report_sandbox(), "report_sandbox")?);
+                        }
+                        None => return Err(ReportErrorImpl::BrowserPathNone),
+                    }
                 }
-                None => return Err(ReportErrorImpl::BrowserPathNone),
             }
+            options_builder = options_builder.add_output_type(ReportOutputFileType::Pdf);
+            options_builder =
+                options_builder.pdf_options(pdf_options_builder.build().map_err(|e| {
+                    ReportErrorImpl::PdfReportOptionBuilder {
+                        source: Box::new(e),
+                    }
+                })?);
         }
-        if config.report_export_html() {
+        if option(config.report_export_html(), "report_export_html")? {
             options_builder = options_builder.add_output_type(ReportOutputFileType::Html);
         }
+        if option(config.report_export_junit(), "report_export_junit")? {
+            options_builder = options_builder.add_output_type(ReportOutputFileType::JUnit);
+        }
+        if option(config.report_export_csv(), "report_export_csv")? {
+            options_builder = options_builder.add_output_type(ReportOutputFileType::EventsCsv);
+        }
+        if option(config.report_export_jsonl(), "report_export_jsonl")? {
+            options_builder = options_builder.add_output_type(ReportOutputFileType::EventsJsonl);
+        }
 
         let logo_path = match config.report_logo_path() {
             Ok(logo_path) => logo_path,
@@ -134,6 +178,47 @@ impl ReportOutputFileOptions {
             };
         }
 
+        let html_template_path =
+            config
+                .report_html_template_path()
+                .map_err(|e| ReportErrorImpl::VerifierConfig {
+                    msg: "getting the HTML template path".to_string(),
+                    source: Box::new(e),
+                })?;
+        if let Some(path) = html_template_path {
+            options_builder =
+                options_builder.html_template(HtmlTemplate::from_file(&path).map_err(|e| {
+                    ReportErrorImpl::HtmlTemplateFile {
+                        path,
+                        source: Box::new(e),
+                    }
+                })?);
+        }
+
+        let signing_keystore =
+            config
+                .report_signing_keystore()
+                .map_err(|e| ReportErrorImpl::VerifierConfig {
+                    msg: "getting the keystore to sign the report files".to_string(),
+                    source: Box::new(e),
+                })?;
+        if let Some(keystore) = signing_keystore {
+            let authority =
+                keystore
+                    .signing_authority()
+                    .map_err(|e| ReportErrorImpl::SigningAuthority {
+                        source: Box::new(e),
+                    })?;
+            let expected = config.report_signing_authority();
+            if authority != expected {
+                return Err(ReportErrorImpl::UnexpectedSigningAuthority {
+                    expected,
+                    found: authority,
+                });
+            }
+            options_builder = options_builder.signing_keystore(keystore);
+        }
+
         let electoral_board_members = config.report_electoral_board_members();
         for member in &electoral_board_members {
             options_builder = options_builder.add_explicit_electoral_board_member(member);
@@ -149,7 +234,7 @@ impl ReportOutputFileOptions {
 
 impl ReportOutputFileOptionsBuilder {
     /// Add an output type to the report options
-    /// If PDF is added, HTML is also added automatically
+    /// If PDF is added, HTML is also added automatically (needed by the browser backend)
     /// Duplicates are ignored
     pub fn add_output_type(mut self, output_type: ReportOutputFileType) -> Self {
         match self.output_types.as_mut() {
@@ -254,16 +339,33 @@ impl ReportOutputFileOptionsBuilder {
             nb_electoral_board,
             explicit_electoral_board_members,
             pdf_options: self.pdf_options.flatten(),
+            html_template: self.html_template.flatten(),
+            signing_keystore: self.signing_keystore.flatten(),
         })
     }
 }
 
+/// Backend used to render the PDF report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, strum::Display)]
+pub enum PDFReportBackend {
+    /// Print the HTML report with a headless browser. The path to the browser must be set
+    #[default]
+    #[strum(to_string = "browser")]
+    Browser,
+    /// Render the PDF natively, without browser
+    #[strum(to_string = "native")]
+    Native,
+}
+
 /// Options specific to PDF report generation
 #[derive(Debug, Clone, Builder, Getters)]
 #[builder(build_fn(error = "ReportError"))]
 pub struct PDFReportOptions {
-    #[builder(setter(into))]
-    path_to_browser: PathBuf,
+    #[builder(default)]
+    #[getter(copy)]
+    backend: PDFReportBackend,
+    #[builder(setter(into, strip_option), default)]
+    path_to_browser: Option<PathBuf>,
     #[builder(default = "true")]
     sandbox: bool,
 }
@@ -301,9 +403,13 @@ impl PDFReportOptions {
 
     #[cfg(not(feature = "fetch"))]
     pub(super) fn browser(&self) -> Result<Browser, ReportErrorImpl> {
+        let path_to_browser = self
+            .path_to_browser
+            .as_ref()
+            .ok_or(ReportErrorImpl::BrowserPathNone)?;
         Browser::new(
             headless_chrome::LaunchOptionsBuilder::default()
-                .path(Some(self.path_to_browser.to_path_buf()))
+                .path(Some(path_to_browser.to_path_buf()))
                 .sandbox(self.sandbox)
                 .headless(true)
                 .build()
@@ -345,6 +451,18 @@ mod test {
         assert!(browser.get_process_id().is_some());
     }
 
+    #[test]
+    fn pdf_options_native() {
+        let pdf_options = PDFReportOptionsBuilder::default()
+            .backend(PDFReportBackend::Native)
+            .build()
+            .unwrap();
+        assert_eq!(pdf_options.backend(), PDFReportBackend::Native);
+        assert!(pdf_options.path_to_browser().is_none());
+        let pdf_options = PDFReportOptionsBuilder::default().build().unwrap();
+        assert_eq!(pdf_options.backend(), PDFReportBackend::Browser);
+    }
+
     #[test]
     fn builder_sets_all_fields() {
         let dir = test_dir();
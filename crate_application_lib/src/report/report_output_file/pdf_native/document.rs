@@ -0,0 +1,455 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Low level writer of PDF documents
+//!
+//! The documents use the standard fonts Helvetica and Helvetica-Bold (with the encoding `WinAnsiEncoding`),
+//! so that no font has to be embedded
+
+use super::png::PngImage;
+use chrono::Local;
+use flate2::{Compression, write::ZlibEncoder};
+use std::io::Write;
+
+/// Width of an A4 page in points
+pub(super) const PAGE_WIDTH: f32 = 595.0;
+/// Height of an A4 page in points
+pub(super) const PAGE_HEIGHT: f32 = 842.0;
+
+const CATALOG_ID: usize = 1;
+const PAGES_ID: usize = 2;
+const FONT_REGULAR_ID: usize = 3;
+const FONT_BOLD_ID: usize = 4;
+const INFO_ID: usize = 5;
+const IMAGE_ID: usize = 6;
+const IMAGE_MASK_ID: usize = 7;
+const FIRST_PAGE_ID: usize = 8;
+
+/// Widths of the characters 32 to 126 of Helvetica (in 1/1000 of the font size)
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+/// Widths of the characters 32 to 126 of Helvetica-Bold (in 1/1000 of the font size)
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611, 975, 722, 722, 722, 722, 667,
+    611, 778, 722, 278, 556, 722, 611, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 333, 278, 333, 584, 556, 333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556,
+    278, 889, 611, 611, 611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+/// Standard fonts used in the document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn resource_name(&self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+        }
+    }
+
+    fn base_font(&self) -> &'static str {
+        match self {
+            Font::Regular => "Helvetica",
+            Font::Bold => "Helvetica-Bold",
+        }
+    }
+
+    /// Width of the character in 1/1000 of the font size
+    ///
+    /// For the characters outside of ASCII, the width is approximated
+    fn char_width(&self, c: char) -> u16 {
+        let widths = match self {
+            Font::Regular => &HELVETICA_WIDTHS,
+            Font::Bold => &HELVETICA_BOLD_WIDTHS,
+        };
+        match c {
+            ' '..='~' => widths[c as usize - 32],
+            '…' | '—' | 'Œ' => 1000,
+            'œ' => 944,
+            '•' => 350,
+            '‘' | '’' | '‚' => 278,
+            c if c.is_uppercase() => 722,
+            _ => match self {
+                Font::Regular => 556,
+                Font::Bold => 611,
+            },
+        }
+    }
+
+    /// Width of the text in points
+    pub fn text_width(&self, text: &str, size: f32) -> f32 {
+        text.chars().map(|c| self.char_width(c) as f32).sum::<f32>() * size / 1000.0
+    }
+}
+
+/// Encode the character in `WinAnsiEncoding`
+///
+/// The characters that cannot be encoded are replaced by `?`
+fn to_win_ansi(c: char) -> u8 {
+    match c {
+        ' '..='~' | '\u{A0}'..='\u{FF}' => c as u32 as u8,
+        '€' => 0x80,
+        '‚' => 0x82,
+        '„' => 0x84,
+        '…' => 0x85,
+        'Œ' => 0x8C,
+        '‘' => 0x91,
+        '’' => 0x92,
+        '“' => 0x93,
+        '”' => 0x94,
+        '•' => 0x95,
+        '–' => 0x96,
+        '—' => 0x97,
+        'œ' => 0x9C,
+        _ => b'?',
+    }
+}
+
+/// Encode the text as PDF literal string
+fn pdf_literal_string(text: &str) -> String {
+    let mut res = String::from("(");
+    for b in text.chars().map(to_win_ansi) {
+        match b {
+            b'(' | b')' | b'\\' => {
+                res.push('\\');
+                res.push(b as char);
+            }
+            0x20..=0x7E => res.push(b as char),
+            _ => res.push_str(&format!("\\{:03o}", b)),
+        }
+    }
+    res.push(')');
+    res
+}
+
+/// Encode the text as PDF text string (UTF-16BE), used for the metadata
+fn pdf_text_string(text: &str) -> String {
+    let mut res = String::from("<FEFF");
+    for u in text.encode_utf16() {
+        res.push_str(&format!("{:04X}", u));
+    }
+    res.push('>');
+    res
+}
+
+fn compress(data: &[u8]) -> Result<Vec<u8>, std::io::Error> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data)?;
+    encoder.finish()
+}
+
+/// Content of a page
+#[derive(Debug, Default)]
+pub(super) struct PdfPage {
+    content: String,
+}
+
+impl PdfPage {
+    /// Write the text. `(x, y)` is the start of the baseline
+    pub fn text(&mut self, x: f32, y: f32, font: Font, size: f32, text: &str) {
+        self.content.push_str(&format!(
+            "BT /{} {:.2} Tf {:.2} {:.2} Td {} Tj ET\n",
+            font.resource_name(),
+            size,
+            x,
+            y,
+            pdf_literal_string(text)
+        ));
+    }
+
+    /// Draw a line
+    pub fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, width: f32) {
+        self.content.push_str(&format!(
+            "{:.2} w {:.2} {:.2} m {:.2} {:.2} l S\n",
+            width, x1, y1, x2, y2
+        ));
+    }
+
+    /// Draw a rectangle. `(x, y)` is the lower left corner
+    pub fn rect(&mut self, x: f32, y: f32, w: f32, h: f32, width: f32) {
+        self.content.push_str(&format!(
+            "{:.2} w {:.2} {:.2} {:.2} {:.2} re S\n",
+            width, x, y, w, h
+        ));
+    }
+
+    /// Draw the image of the document. `(x, y)` is the lower left corner
+    pub fn image(&mut self, x: f32, y: f32, w: f32, h: f32) {
+        self.content.push_str(&format!(
+            "q {:.2} 0 0 {:.2} {:.2} {:.2} cm /Im1 Do Q\n",
+            w, h, x, y
+        ));
+    }
+}
+
+/// PDF document with the pages and an optional image (the logo)
+pub(super) struct PdfDocument {
+    title: String,
+    image: Option<PngImage>,
+    pages: Vec<PdfPage>,
+}
+
+impl PdfDocument {
+    /// New document with one empty page
+    pub fn new(title: &str, image: Option<PngImage>) -> Self {
+        Self {
+            title: title.to_string(),
+            image,
+            pages: vec![PdfPage::default()],
+        }
+    }
+
+    /// Size of the image (width, height) in pixels
+    pub fn image_size(&self) -> Option<(u32, u32)> {
+        self.image.as_ref().map(|i| (i.width, i.height))
+    }
+
+    /// Add a new page, that becomes the current page
+    pub fn new_page(&mut self) {
+        self.pages.push(PdfPage::default());
+    }
+
+    /// The current page (the last one)
+    pub fn current_page(&mut self) -> &mut PdfPage {
+        self.pages.last_mut().unwrap()
+    }
+
+    /// Mutable iterator over the pages
+    pub fn pages_mut(&mut self) -> impl Iterator<Item = &mut PdfPage> {
+        self.pages.iter_mut()
+    }
+
+    /// Number of pages
+    pub fn nb_pages(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Serialize the document to the bytes of the pdf file
+    pub fn to_bytes(&self) -> Result<Vec<u8>, std::io::Error> {
+        let nb_objects = FIRST_PAGE_ID + 2 * self.pages.len();
+        let mut offsets = vec![0usize; nb_objects];
+        let mut out: Vec<u8> = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+
+        let kids = (0..self.pages.len())
+            .map(|i| format!("{} 0 R", FIRST_PAGE_ID + 2 * i))
+            .collect::<Vec<_>>()
+            .join(" ");
+        write_object(
+            &mut out,
+            &mut offsets,
+            CATALOG_ID,
+            &format!("<< /Type /Catalog /Pages {} 0 R >>", PAGES_ID),
+            None,
+        );
+        write_object(
+            &mut out,
+            &mut offsets,
+            PAGES_ID,
+            &format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                kids,
+                self.pages.len()
+            ),
+            None,
+        );
+        for (id, font) in [(FONT_REGULAR_ID, Font::Regular), (FONT_BOLD_ID, Font::Bold)] {
+            write_object(
+                &mut out,
+                &mut offsets,
+                id,
+                &format!(
+                    "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+                    font.base_font()
+                ),
+                None,
+            );
+        }
+        write_object(
+            &mut out,
+            &mut offsets,
+            INFO_ID,
+            &format!(
+                "<< /Title {} /Producer {} /CreationDate (D:{}) >>",
+                pdf_text_string(&self.title),
+                pdf_text_string(env!("CARGO_PKG_NAME")),
+                Local::now().format("%Y%m%d%H%M%S")
+            ),
+            None,
+        );
+        // The objects of the image are always reserved, so that the ids of the pages are fixed
+        match &self.image {
+            Some(image) => {
+                let smask = match &image.alpha {
+                    Some(alpha) => {
+                        write_object(
+                            &mut out,
+                            &mut offsets,
+                            IMAGE_MASK_ID,
+                            &image_dict(image.width, image.height, 1, None),
+                            Some(&compress(alpha)?),
+                        );
+                        Some(IMAGE_MASK_ID)
+                    }
+                    None => {
+                        write_object(&mut out, &mut offsets, IMAGE_MASK_ID, "null", None);
+                        None
+                    }
+                };
+                write_object(
+                    &mut out,
+                    &mut offsets,
+                    IMAGE_ID,
+                    &image_dict(image.width, image.height, image.components, smask),
+                    Some(&compress(&image.color)?),
+                );
+            }
+            None => {
+                write_object(&mut out, &mut offsets, IMAGE_ID, "null", None);
+                write_object(&mut out, &mut offsets, IMAGE_MASK_ID, "null", None);
+            }
+        }
+        let xobject = match self.image.is_some() {
+            true => format!(" /XObject << /Im1 {} 0 R >>", IMAGE_ID),
+            false => String::new(),
+        };
+        for (i, page) in self.pages.iter().enumerate() {
+            let page_id = FIRST_PAGE_ID + 2 * i;
+            write_object(
+                &mut out,
+                &mut offsets,
+                page_id,
+                &format!(
+                    "<< /Type /Page /Parent {} 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 {} 0 R /F2 {} 0 R >>{} >> /Contents {} 0 R >>",
+                    PAGES_ID,
+                    PAGE_WIDTH,
+                    PAGE_HEIGHT,
+                    FONT_REGULAR_ID,
+                    FONT_BOLD_ID,
+                    xobject,
+                    page_id + 1
+                ),
+                None,
+            );
+            write_object(
+                &mut out,
+                &mut offsets,
+                page_id + 1,
+                "<< /Filter /FlateDecode",
+                Some(&compress(page.content.as_bytes())?),
+            );
+        }
+
+        let xref_offset = out.len();
+        out.extend_from_slice(format!("xref\n0 {}\n", nb_objects).as_bytes());
+        out.extend_from_slice(b"0000000000 65535 f \n");
+        for offset in offsets.iter().skip(1) {
+            out.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+        out.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root {} 0 R /Info {} 0 R >>\nstartxref\n{}\n%%EOF\n",
+                nb_objects, CATALOG_ID, INFO_ID, xref_offset
+            )
+            .as_bytes(),
+        );
+        Ok(out)
+    }
+}
+
+/// Dictionary of an image (without the closing `>>`, added with the length of the stream)
+fn image_dict(width: u32, height: u32, components: u8, smask: Option<usize>) -> String {
+    format!(
+        "<< /Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /{} /BitsPerComponent 8 /Filter /FlateDecode{}",
+        width,
+        height,
+        match components {
+            1 => "DeviceGray",
+            _ => "DeviceRGB",
+        },
+        smask
+            .map(|id| format!(" /SMask {} 0 R", id))
+            .unwrap_or_default()
+    )
+}
+
+/// Write the object and store its offset
+///
+/// If a stream is given, `dict` must not be closed: the length and the closing `>>` are added
+fn write_object(
+    out: &mut Vec<u8>,
+    offsets: &mut [usize],
+    id: usize,
+    dict: &str,
+    stream: Option<&[u8]>,
+) {
+    offsets[id] = out.len();
+    out.extend_from_slice(format!("{} 0 obj\n", id).as_bytes());
+    match stream {
+        Some(s) => {
+            out.extend_from_slice(format!("{} /Length {} >>\nstream\n", dict, s.len()).as_bytes());
+            out.extend_from_slice(s);
+            out.extend_from_slice(b"\nendstream");
+        }
+        None => out.extend_from_slice(dict.as_bytes()),
+    }
+    out.extend_from_slice(b"\nendobj\n");
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_text_width() {
+        assert_eq!(Font::Regular.text_width("", 10.0), 0.0);
+        assert_eq!(Font::Regular.text_width("a", 10.0), 5.56);
+        assert!(Font::Bold.text_width("abc", 10.0) > Font::Regular.text_width("abc", 10.0));
+    }
+
+    #[test]
+    fn test_pdf_literal_string() {
+        assert_eq!(pdf_literal_string("a(b)\\"), "(a\\(b\\)\\\\)");
+        assert_eq!(pdf_literal_string("é€"), "(\\351\\200)");
+        assert_eq!(pdf_literal_string("日"), "(?)");
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        let mut doc = PdfDocument::new("Test", None);
+        doc.current_page()
+            .text(50.0, 800.0, Font::Regular, 10.0, "Hello");
+        doc.new_page();
+        let bytes = doc.to_bytes().unwrap();
+        let s = String::from_utf8_lossy(&bytes);
+        assert!(s.starts_with("%PDF-1.4"));
+        assert!(s.contains("/Count 2"));
+        assert!(s.ends_with("%%EOF\n"));
+        let startxref = s.rsplit("startxref\n").next().unwrap();
+        let xref_offset: usize = startxref.lines().next().unwrap().parse().unwrap();
+        assert!(bytes[xref_offset..].starts_with(b"xref"));
+    }
+}
@@ -0,0 +1,415 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Module generating the PDF report natively (without browser)
+//!
+//! The layout follows the HTML report: title with the logo, a table for the key/value entries of each block,
+//! the other entries as paragraphs, and the signature lines of the electoral board members. The pages are numbered
+//! and the fingerprint of the report data is printed on each page.
+
+mod document;
+mod png;
+
+use super::{
+    super::{ReportErrorImpl, ReportLanguage},
+    ReportOutputData, ReportOutputFileOptions,
+};
+use document::{Font, PAGE_HEIGHT, PAGE_WIDTH, PdfDocument};
+
+const MARGIN: f32 = 50.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+const TITLE_SIZE: f32 = 18.0;
+const HEADING_SIZE: f32 = 13.0;
+const TEXT_SIZE: f32 = 9.0;
+const FOOTER_SIZE: f32 = 8.0;
+const LINE_HEIGHT_FACTOR: f32 = 1.3;
+const CELL_PADDING: f32 = 4.0;
+const BORDER_WIDTH: f32 = 0.5;
+const LOGO_MAX_HEIGHT: f32 = 60.0;
+const LOGO_MAX_WIDTH: f32 = 150.0;
+const MAX_KEY_COLUMN_RATIO: f32 = 0.4;
+const SIGNATURES_PER_ROW: usize = 3;
+const SIGNATURE_SPACE: f32 = 45.0;
+
+/// Wrap the text to the given width
+///
+/// The line breaks of the text are kept. The words longer than the width are cut
+fn wrap_text(text: &str, font: Font, size: f32, max_width: f32) -> Vec<String> {
+    let mut res = vec![];
+    for line in text.replace('\t', "    ").lines() {
+        let mut current: Option<String> = None;
+        for word in line.split(' ') {
+            let candidate = match &current {
+                Some(c) => format!("{} {}", c, word),
+                None => word.to_string(),
+            };
+            if font.text_width(&candidate, size) <= max_width {
+                current = Some(candidate);
+                continue;
+            }
+            if let Some(c) = current.take() {
+                res.push(c);
+            }
+            let mut part = String::new();
+            for c in word.chars() {
+                part.push(c);
+                if part.chars().count() > 1 && font.text_width(&part, size) > max_width {
+                    part.pop();
+                    res.push(std::mem::take(&mut part));
+                    part.push(c);
+                }
+            }
+            current = Some(part);
+        }
+        res.push(current.unwrap_or_default());
+    }
+    if res.is_empty() {
+        res.push(String::new());
+    }
+    res
+}
+
+/// Layout of the document, with the current vertical position
+struct Layout {
+    document: PdfDocument,
+    y: f32,
+    language: ReportLanguage,
+}
+
+impl Layout {
+    fn new(document: PdfDocument, language: ReportLanguage) -> Self {
+        Self {
+            document,
+            y: PAGE_HEIGHT - MARGIN,
+            language,
+        }
+    }
+
+    fn new_page(&mut self) {
+        self.document.new_page();
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    /// Start a new page if the height is not available on the current page
+    fn ensure_space(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.new_page();
+        }
+    }
+
+    fn header(&mut self, title: &str, date_time: &str) {
+        let mut title_width = CONTENT_WIDTH;
+        if let Some((w, h)) = self.document.image_size() {
+            let scale = (LOGO_MAX_HEIGHT / h as f32).min(LOGO_MAX_WIDTH / w as f32);
+            let (logo_w, logo_h) = (w as f32 * scale, h as f32 * scale);
+            self.document.current_page().image(
+                PAGE_WIDTH - MARGIN - logo_w,
+                PAGE_HEIGHT - MARGIN - logo_h,
+                logo_w,
+                logo_h,
+            );
+            title_width -= logo_w + 10.0;
+        }
+        for line in wrap_text(title, Font::Bold, TITLE_SIZE, title_width) {
+            self.y -= TITLE_SIZE * LINE_HEIGHT_FACTOR;
+            self.document
+                .current_page()
+                .text(MARGIN, self.y, Font::Bold, TITLE_SIZE, &line);
+        }
+        self.y -= 2.0 * TEXT_SIZE;
+        self.document.current_page().text(
+            MARGIN,
+            self.y,
+            Font::Regular,
+            TEXT_SIZE,
+            &format!("{}: {}", self.language.translate("Date / Time"), date_time),
+        );
+        if self.document.image_size().is_some() {
+            self.y = self.y.min(PAGE_HEIGHT - MARGIN - LOGO_MAX_HEIGHT);
+        }
+        self.y -= TEXT_SIZE;
+    }
+
+    fn heading(&mut self, text: &str) {
+        let line_height = HEADING_SIZE * LINE_HEIGHT_FACTOR;
+        // Avoid a heading alone at the bottom of the page
+        self.ensure_space(2.0 * line_height + 3.0 * TEXT_SIZE * LINE_HEIGHT_FACTOR);
+        self.y -= line_height + HEADING_SIZE / 2.0;
+        self.document
+            .current_page()
+            .text(MARGIN, self.y, Font::Bold, HEADING_SIZE, text);
+        self.y -= HEADING_SIZE / 2.0;
+    }
+
+    fn paragraph(&mut self, text: &str) {
+        let line_height = TEXT_SIZE * LINE_HEIGHT_FACTOR;
+        for line in wrap_text(text, Font::Regular, TEXT_SIZE, CONTENT_WIDTH) {
+            self.ensure_space(line_height);
+            self.y -= line_height;
+            self.document
+                .current_page()
+                .text(MARGIN, self.y, Font::Regular, TEXT_SIZE, &line);
+        }
+        self.y -= TEXT_SIZE / 2.0;
+    }
+
+    /// Table with two columns. The rows longer than a page are split over the pages
+    fn key_value_table(&mut self, entries: &[(&str, &str)]) {
+        let line_height = TEXT_SIZE * LINE_HEIGHT_FACTOR;
+        let key_width = entries
+            .iter()
+            .map(|(k, _)| Font::Regular.text_width(k, TEXT_SIZE) + 2.0 * CELL_PADDING)
+            .fold(0f32, f32::max)
+            .min(CONTENT_WIDTH * MAX_KEY_COLUMN_RATIO);
+        let value_width = CONTENT_WIDTH - key_width;
+        let lines_per_page =
+            ((PAGE_HEIGHT - 2.0 * MARGIN - 2.0 * CELL_PADDING) / line_height).floor() as usize;
+        self.y -= TEXT_SIZE / 2.0;
+        for (key, value) in entries {
+            let key_lines = wrap_text(
+                key,
+                Font::Regular,
+                TEXT_SIZE,
+                key_width - 2.0 * CELL_PADDING,
+            );
+            let value_lines = wrap_text(
+                value,
+                Font::Regular,
+                TEXT_SIZE,
+                value_width - 2.0 * CELL_PADDING,
+            );
+            let nb_lines = key_lines.len().max(value_lines.len());
+            let mut start = 0;
+            while start < nb_lines {
+                let available =
+                    ((self.y - MARGIN - 2.0 * CELL_PADDING) / line_height).floor() as usize;
+                let end = nb_lines.min(start + available);
+                // Split the row only if it does not fit on an empty page
+                if available == 0 || (start == 0 && end < nb_lines && nb_lines <= lines_per_page) {
+                    self.new_page();
+                    continue;
+                }
+                let height = (end - start) as f32 * line_height + 2.0 * CELL_PADDING;
+                let page = self.document.current_page();
+                page.rect(MARGIN, self.y - height, key_width, height, BORDER_WIDTH);
+                page.rect(
+                    MARGIN + key_width,
+                    self.y - height,
+                    value_width,
+                    height,
+                    BORDER_WIDTH,
+                );
+                for (column_x, lines) in [(MARGIN, &key_lines), (MARGIN + key_width, &value_lines)]
+                {
+                    for (i, line) in lines.iter().enumerate().take(end).skip(start) {
+                        page.text(
+                            column_x + CELL_PADDING,
+                            self.y - CELL_PADDING - (i - start) as f32 * line_height - TEXT_SIZE,
+                            Font::Regular,
+                            TEXT_SIZE,
+                            line,
+                        );
+                    }
+                }
+                self.y -= height;
+                start = end;
+            }
+        }
+        self.y -= TEXT_SIZE / 2.0;
+    }
+
+    fn signatures(&mut self, signatures: &[String]) {
+        if signatures.is_empty() {
+            return;
+        }
+        let title = self.language.translate("Signatures");
+        self.heading(&title);
+        let nb_columns = signatures.len().min(SIGNATURES_PER_ROW);
+        let column_width = CONTENT_WIDTH / nb_columns as f32;
+        for row in signatures.chunks(SIGNATURES_PER_ROW) {
+            self.ensure_space(SIGNATURE_SPACE + 2.0 * TEXT_SIZE * LINE_HEIGHT_FACTOR);
+            self.y -= SIGNATURE_SPACE;
+            let page = self.document.current_page();
+            for (i, name) in row.iter().enumerate() {
+                let x = MARGIN + i as f32 * column_width;
+                page.line(
+                    x + 10.0,
+                    self.y,
+                    x + column_width - 10.0,
+                    self.y,
+                    BORDER_WIDTH,
+                );
+                let name_width = Font::Regular.text_width(name, TEXT_SIZE);
+                page.text(
+                    x + (column_width - name_width).max(0.0) / 2.0,
+                    self.y - TEXT_SIZE * LINE_HEIGHT_FACTOR,
+                    Font::Regular,
+                    TEXT_SIZE,
+                    name,
+                );
+            }
+            self.y -= 2.0 * TEXT_SIZE * LINE_HEIGHT_FACTOR;
+        }
+    }
+
+    /// Print the text (e.g. the fingerprint of the report) above the page number on each page
+    fn footer_text(&mut self, text: &str) {
+        for page in self.document.pages_mut() {
+            let width = Font::Regular.text_width(text, FOOTER_SIZE);
+            page.text(
+                (PAGE_WIDTH - width).max(0.0) / 2.0,
+                MARGIN / 2.0 + FOOTER_SIZE * LINE_HEIGHT_FACTOR,
+                Font::Regular,
+                FOOTER_SIZE,
+                text,
+            );
+        }
+    }
+
+    fn page_numbers(&mut self) {
+        let nb_pages = self.document.nb_pages();
+        let label = self.language.translate("Page");
+        for (i, page) in self.document.pages_mut().enumerate() {
+            let text = format!("{} {} / {}", label, i + 1, nb_pages);
+            let width = Font::Regular.text_width(&text, FOOTER_SIZE);
+            page.text(
+                (PAGE_WIDTH - width) / 2.0,
+                MARGIN / 2.0,
+                Font::Regular,
+                FOOTER_SIZE,
+                &text,
+            );
+        }
+    }
+}
+
+/// Generate the PDF report from the report data
+///
+/// The fingerprint text, if given, is printed on each page
+pub(super) fn report_output_data_to_pdf(
+    report_data: &ReportOutputData,
+    options: &ReportOutputFileOptions,
+    fingerprint: Option<&str>,
+) -> Result<Vec<u8>, ReportErrorImpl> {
+    let logo = match options.logo_bytes().is_empty() {
+        true => None,
+        false => Some(png::decode_png(options.logo_bytes()).map_err(ReportErrorImpl::PdfLogo)?),
+    };
+    let language = report_data.metadata().language();
+    let mut layout = Layout::new(
+        PdfDocument::new(report_data.metadata().title(), logo),
+        language,
+    );
+    layout.header(
+        report_data.metadata().title(),
+        report_data.metadata().date_time(),
+    );
+    for block in report_data.blocks() {
+        layout.heading(&block.title().to_localized_string(language));
+        let key_value_entries = block
+            .key_value_entries()
+            .into_iter()
+            .map(|(k, v)| (language.translate(k), language.translate_value(v)))
+            .collect::<Vec<_>>();
+        if !key_value_entries.is_empty() {
+            layout.key_value_table(
+                &key_value_entries
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v.as_str()))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        for elem in block.only_value_entries() {
+            layout.paragraph(elem);
+        }
+    }
+    layout.signatures(&options.localized_signatures(language));
+    layout.page_numbers();
+    if let Some(text) = fingerprint {
+        layout.footer_text(text);
+    }
+    layout
+        .document
+        .to_bytes()
+        .map_err(|e| ReportErrorImpl::IOError {
+            msg: "Error generating the native PDF".to_string(),
+            source: e,
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        super::{ReportOutputFileOptionsBuilder, test::test_logo, test::test_sample},
+        *,
+    };
+
+    #[test]
+    fn test_wrap_text() {
+        assert_eq!(wrap_text("", Font::Regular, 10.0, 100.0), vec![""]);
+        assert_eq!(
+            wrap_text("abc def", Font::Regular, 10.0, 100.0),
+            vec!["abc def"]
+        );
+        assert_eq!(
+            wrap_text("abc\ndef", Font::Regular, 10.0, 100.0),
+            vec!["abc", "def"]
+        );
+        assert_eq!(
+            wrap_text("abc def", Font::Regular, 10.0, 20.0),
+            vec!["abc", "def"]
+        );
+        assert_eq!(
+            wrap_text("  abc", Font::Regular, 10.0, 100.0),
+            vec!["  abc"]
+        );
+        let long = wrap_text(&"a".repeat(100), Font::Regular, 10.0, 100.0);
+        assert!(long.len() > 1);
+        assert!(
+            long.iter()
+                .all(|l| Font::Regular.text_width(l, 10.0) <= 100.0)
+        );
+        assert_eq!(long.concat(), "a".repeat(100));
+    }
+
+    #[test]
+    fn test_generate_pdf() {
+        let options = ReportOutputFileOptionsBuilder::default()
+            .directory(std::env::temp_dir())
+            .filename_without_extension("test_native")
+            .logo_bytes(test_logo())
+            .add_explicit_electoral_board_member("Alice")
+            .add_explicit_electoral_board_member("Bob")
+            .build()
+            .unwrap();
+        let bytes =
+            report_output_data_to_pdf(&test_sample(), &options, Some("Report fingerprint: 1234"))
+                .unwrap();
+        assert!(bytes.starts_with(b"%PDF-1.4"));
+        assert!(bytes.ends_with(b"%%EOF\n"));
+    }
+
+    #[test]
+    fn test_page_break() {
+        let mut layout = Layout::new(PdfDocument::new("Test", None), ReportLanguage::En);
+        for i in 0..200 {
+            layout.paragraph(&format!("Line {}", i));
+        }
+        layout.page_numbers();
+        assert!(layout.document.nb_pages() > 1);
+        assert!(layout.document.to_bytes().is_ok());
+    }
+}
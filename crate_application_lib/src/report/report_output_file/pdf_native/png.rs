@@ -0,0 +1,282 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Minimal decoder of PNG images, to embed the logo in the native PDF
+//!
+//! Only the non-interlaced images with 8 bits per channel are supported.
+//!
+//! The size of the image is limited (see [MAX_DIMENSION] and [MAX_PIXELS]), and the decompression
+//! stops at the size expected from the header, so that a crafted logo cannot exhaust the memory.
+
+use flate2::read::ZlibDecoder;
+use std::io::Read;
+
+const PNG_SIGNATURE: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+/// Maximal width or height of the image in pixels
+const MAX_DIMENSION: u32 = 16_384;
+
+/// Maximal number of pixels of the image
+const MAX_PIXELS: usize = 50_000_000;
+
+/// Decoded PNG image, with the color and the alpha channels separated
+pub(super) struct PngImage {
+    pub width: u32,
+    pub height: u32,
+    /// Number of color components (1 for gray, 3 for RGB)
+    pub components: u8,
+    /// Color values (row by row)
+    pub color: Vec<u8>,
+    /// Alpha values (row by row), `None` if the image is opaque
+    pub alpha: Option<Vec<u8>>,
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let pa = (p - a as i16).abs();
+    let pb = (p - b as i16).abs();
+    let pc = (p - c as i16).abs();
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+/// Reverse the filters of the scanlines
+fn unfilter(data: &[u8], height: usize, stride: usize, bpp: usize) -> Result<Vec<u8>, String> {
+    if data.len() < height * (stride + 1) {
+        return Err("The image data are too short".to_string());
+    }
+    let mut res = vec![0u8; height * stride];
+    let mut prev = vec![0u8; stride];
+    for row in 0..height {
+        let start = row * (stride + 1);
+        let filter = data[start];
+        let line = &data[start + 1..start + 1 + stride];
+        let mut cur = vec![0u8; stride];
+        for i in 0..stride {
+            let a = if i >= bpp { cur[i - bpp] } else { 0 };
+            let b = prev[i];
+            let c = if i >= bpp { prev[i - bpp] } else { 0 };
+            cur[i] = match filter {
+                0 => line[i],
+                1 => line[i].wrapping_add(a),
+                2 => line[i].wrapping_add(b),
+                3 => line[i].wrapping_add(((a as u16 + b as u16) / 2) as u8),
+                4 => line[i].wrapping_add(paeth(a, b, c)),
+                _ => return Err(format!("Unknown filter type {filter}")),
+            };
+        }
+        res[row * stride..(row + 1) * stride].copy_from_slice(&cur);
+        prev = cur;
+    }
+    Ok(res)
+}
+
+/// Decode the PNG image
+pub(super) fn decode_png(bytes: &[u8]) -> Result<PngImage, String> {
+    if bytes.len() < 8 || bytes[..8] != PNG_SIGNATURE {
+        return Err("The logo is not a PNG image".to_string());
+    }
+    let mut pos = 8;
+    let mut header = None;
+    let mut palette: &[u8] = &[];
+    let mut transparency: &[u8] = &[];
+    let mut idat = vec![];
+    while pos + 8 <= bytes.len() {
+        let length = read_u32(&bytes[pos..]) as usize;
+        let chunk_type = &bytes[pos + 4..pos + 8];
+        let data_start = pos + 8;
+        let data_end = data_start
+            .checked_add(length)
+            .filter(|end| end.checked_add(4).is_some_and(|e| e <= bytes.len()))
+            .ok_or_else(|| "The PNG chunk is truncated".to_string())?;
+        let data = &bytes[data_start..data_end];
+        match chunk_type {
+            b"IHDR" => {
+                if length < 13 {
+                    return Err("The PNG header is too short".to_string());
+                }
+                header = Some((
+                    read_u32(data),
+                    read_u32(&data[4..]),
+                    data[8],
+                    data[9],
+                    data[12],
+                ))
+            }
+            b"PLTE" => palette = data,
+            b"tRNS" => transparency = data,
+            b"IDAT" => idat.extend_from_slice(data),
+            b"IEND" => break,
+            _ => {}
+        }
+        pos = data_end + 4;
+    }
+    let (width, height, bit_depth, color_type, interlace) =
+        header.ok_or_else(|| "The PNG header is missing".to_string())?;
+    if bit_depth != 8 {
+        return Err(format!("Bit depth {bit_depth} not supported"));
+    }
+    if interlace != 0 {
+        return Err("Interlaced PNG images are not supported".to_string());
+    }
+    let channels = match color_type {
+        0 | 3 => 1,
+        4 => 2,
+        2 => 3,
+        6 => 4,
+        _ => return Err(format!("Color type {color_type} not supported")),
+    };
+    if width == 0 || height == 0 || width > MAX_DIMENSION || height > MAX_DIMENSION {
+        return Err(format!(
+            "The size {width}x{height} of the image is not supported (max {MAX_DIMENSION} pixels per dimension)"
+        ));
+    }
+    let nb_pixels = (width as usize)
+        .checked_mul(height as usize)
+        .filter(|nb| *nb <= MAX_PIXELS)
+        .ok_or_else(|| format!("The image has more than {MAX_PIXELS} pixels"))?;
+    let stride = width as usize * channels;
+    let expected_len = height as usize * (stride + 1);
+    let mut decompressed = Vec::with_capacity(expected_len);
+    ZlibDecoder::new(idat.as_slice())
+        .take(expected_len as u64)
+        .read_to_end(&mut decompressed)
+        .map_err(|e| format!("Error decompressing the image data: {e}"))?;
+    let pixels = unfilter(&decompressed, height as usize, stride, channels)?;
+
+    let mut color = Vec::with_capacity(nb_pixels * 3);
+    let mut alpha = Vec::with_capacity(nb_pixels);
+    let components = match color_type {
+        0 => {
+            color = pixels;
+            1
+        }
+        2 => {
+            color = pixels;
+            3
+        }
+        3 => {
+            for &index in pixels.iter() {
+                let i = index as usize;
+                if 3 * i + 2 >= palette.len() {
+                    return Err(format!("Palette index {i} out of range"));
+                }
+                color.extend_from_slice(&palette[3 * i..3 * i + 3]);
+                alpha.push(transparency.get(i).copied().unwrap_or(255));
+            }
+            3
+        }
+        4 => {
+            for p in pixels.chunks_exact(2) {
+                color.push(p[0]);
+                alpha.push(p[1]);
+            }
+            1
+        }
+        _ => {
+            for p in pixels.chunks_exact(4) {
+                color.extend_from_slice(&p[..3]);
+                alpha.push(p[3]);
+            }
+            3
+        }
+    };
+    Ok(PngImage {
+        width,
+        height,
+        components,
+        color,
+        alpha: match alpha.iter().any(|a| *a != 255) {
+            true => Some(alpha),
+            false => None,
+        },
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::super::test::test_logo;
+    use super::*;
+
+    #[test]
+    fn test_decode_logo() {
+        let image = decode_png(&test_logo()).unwrap();
+        assert_eq!(image.width, 1280);
+        assert_eq!(image.height, 992);
+        assert_eq!(image.components, 3);
+        assert_eq!(image.color.len(), 1280 * 992 * 3);
+        if let Some(alpha) = image.alpha {
+            assert_eq!(alpha.len(), 1280 * 992);
+        }
+    }
+
+    /// PNG with the given header and image data (the CRC are not checked by the decoder)
+    fn png_with_header(width: u32, height: u32, idat: &[u8]) -> Vec<u8> {
+        let mut header = vec![];
+        header.extend_from_slice(&width.to_be_bytes());
+        header.extend_from_slice(&height.to_be_bytes());
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+        let mut res = PNG_SIGNATURE.to_vec();
+        for (chunk_type, data) in [(b"IHDR", header.as_slice()), (b"IDAT", idat)] {
+            res.extend_from_slice(&(data.len() as u32).to_be_bytes());
+            res.extend_from_slice(chunk_type);
+            res.extend_from_slice(data);
+            res.extend_from_slice(&[0; 4]);
+        }
+        res
+    }
+
+    #[test]
+    fn test_decode_not_png() {
+        assert!(decode_png(&[1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_decode_size_limits() {
+        assert!(decode_png(&png_with_header(u32::MAX, u32::MAX, &[])).is_err());
+        assert!(decode_png(&png_with_header(MAX_DIMENSION + 1, 1, &[])).is_err());
+        assert!(decode_png(&png_with_header(MAX_DIMENSION, MAX_DIMENSION, &[])).is_err());
+        assert!(decode_png(&png_with_header(0, 1, &[])).is_err());
+    }
+
+    #[test]
+    fn test_decode_chunk_length_overflow() {
+        let mut bytes = PNG_SIGNATURE.to_vec();
+        bytes.extend_from_slice(&u32::MAX.to_be_bytes());
+        bytes.extend_from_slice(b"IHDR");
+        assert!(decode_png(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_decode_too_much_data() {
+        use flate2::{Compression, write::ZlibEncoder};
+        use std::io::Write;
+        // One black pixel, followed by data that must not be decompressed
+        let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+        encoder.write_all(&[0; 1_000_000]).unwrap();
+        let image = decode_png(&png_with_header(1, 1, &encoder.finish().unwrap())).unwrap();
+        assert_eq!(image.color, vec![0, 0, 0]);
+    }
+}
@@ -0,0 +1,329 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Detached signatures of the report files
+//!
+//! A report file is signed with the secret key of the verifier keystore (see
+//! [rust_ev_verifier_lib::VerifierConfig::report_signing_keystore]). The signature is stored in a
+//! json file next to the report file, with the extension `.sig` appended (e.g. `report.pdf.sig`).
+//!
+//! The signature covers the content of the file and the fingerprint of the report data
+//! (see [ReportOutputData::fingerprint]), so that the file is linked to the run.
+//!
+//! The authority written in the signature file cannot be trusted. The verification accepts only
+//! the authority of the verifier given by the caller (see
+//! [rust_ev_verifier_lib::VerifierConfig::report_signing_authority]), which must be a dedicated
+//! certificate and not one of the [CertificateAuthority] of the election participants. Otherwise
+//! a file signed by any participant of the election would be accepted as a report of the verifier.
+//!
+//! [ReportOutputData::fingerprint]: super::ReportOutputData::fingerprint
+
+use super::{ReportError, ReportErrorImpl};
+use derive_getters::Getters;
+use rust_ev_system_library::rust_ev_crypto_primitives::prelude::{
+    ByteArray, DecodeTrait, EncodeTrait, HashableMessage,
+};
+use rust_ev_verifier_lib::direct_trust::{CertificateAuthority, Keystore};
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Extension appended to the name of the report file for the signature file
+const SIGNATURE_EXTENSION: &str = "sig";
+
+/// Context of the signature, to avoid the reuse of the signature for another purpose
+const SIGNATURE_CONTEXT: &str = "verifier report file";
+
+/// Detached signature of a report file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Getters)]
+pub struct ReportFileSignature {
+    /// Fingerprint of the report data, printed in the report file
+    report_fingerprint: String,
+    /// Authority of the certificate used to sign
+    authority: String,
+    /// Signature encoded in base64
+    signature: String,
+}
+
+fn signature_context(report_fingerprint: &str) -> HashableMessage<'_> {
+    HashableMessage::from(vec![
+        HashableMessage::from(SIGNATURE_CONTEXT),
+        HashableMessage::from(report_fingerprint),
+    ])
+}
+
+/// Path of the detached signature of the report file
+pub fn signature_file_path(report_file: &Path) -> PathBuf {
+    let mut res = report_file.as_os_str().to_os_string();
+    res.push(format!(".{SIGNATURE_EXTENSION}"));
+    PathBuf::from(res)
+}
+
+impl ReportFileSignature {
+    /// Sign the content of a report file with the secret key of the keystore
+    pub fn sign(
+        content: &[u8],
+        report_fingerprint: &str,
+        keystore: &Keystore,
+    ) -> Result<Self, ReportError> {
+        Self::sign_impl(content, report_fingerprint, keystore).map_err(ReportError::from)
+    }
+
+    fn sign_impl(
+        content: &[u8],
+        report_fingerprint: &str,
+        keystore: &Keystore,
+    ) -> Result<Self, ReportErrorImpl> {
+        let authority =
+            keystore
+                .signing_authority()
+                .map_err(|e| ReportErrorImpl::SigningAuthority {
+                    source: Box::new(e),
+                })?;
+        let content = ByteArray::from_bytes(content);
+        let signature = keystore
+            .sign_message(
+                &HashableMessage::from(&content),
+                &signature_context(report_fingerprint),
+            )
+            .map_err(|e| ReportErrorImpl::SignReportFile {
+                source: Box::new(e),
+            })?;
+        Ok(Self {
+            report_fingerprint: report_fingerprint.to_string(),
+            authority,
+            signature: signature.base64_encode().unwrap(),
+        })
+    }
+
+    /// Verify the signature of the content of a report file with the certificates of the keystore
+    ///
+    /// `authority` is the only authority accepted for the signature (see the [module documentation](self)).
+    /// Return an error if the signature file names another authority, or if `authority` is the authority
+    /// of an election participant. Return `false` if the content or the signature has been modified
+    pub fn verify(
+        &self,
+        content: &[u8],
+        keystore: &Keystore,
+        authority: &str,
+    ) -> Result<bool, ReportError> {
+        if CertificateAuthority::from_str(authority).is_ok() {
+            return Err(ReportError::from(
+                ReportErrorImpl::SigningAuthorityNotDedicated(authority.to_string()),
+            ));
+        }
+        if self.authority != authority {
+            return Err(ReportError::from(
+                ReportErrorImpl::UnexpectedSigningAuthority {
+                    expected: authority.to_string(),
+                    found: self.authority.clone(),
+                },
+            ));
+        }
+        let signature = ByteArray::base64_decode(&self.signature)
+            .map_err(|e| ReportErrorImpl::DecodeSignature { msg: e.to_string() })?;
+        let content = ByteArray::from_bytes(content);
+        keystore
+            .verify_message_signature(
+                authority,
+                &HashableMessage::from(&content),
+                &signature_context(&self.report_fingerprint),
+                &signature,
+            )
+            .map_err(|e| {
+                ReportError::from(ReportErrorImpl::VerifyReportFileSignature {
+                    source: Box::new(e),
+                })
+            })
+    }
+
+    /// Read the signature from a signature file
+    pub fn from_file(path: &Path) -> Result<Self, ReportError> {
+        let json_str = std::fs::read_to_string(path).map_err(|e| ReportErrorImpl::IOError {
+            msg: format!("Error reading the signature file {}", path.display()),
+            source: e,
+        })?;
+        serde_json::from_str(&json_str)
+            .map_err(|e| ReportError::from(ReportErrorImpl::FromJson { source: e }))
+    }
+
+    /// Write the signature to the signature file of the report file
+    ///
+    /// Return the path of the signature file
+    pub fn write_for_report_file(&self, report_file: &Path) -> Result<PathBuf, ReportError> {
+        let path = signature_file_path(report_file);
+        let json_str = serde_json::to_string_pretty(self)
+            .map_err(|e| ReportErrorImpl::ToJson { source: e })?;
+        std::fs::write(&path, json_str).map_err(|e| ReportErrorImpl::IOError {
+            msg: format!("Error writing the signature file {}", path.display()),
+            source: e,
+        })?;
+        Ok(path)
+    }
+}
+
+/// Verify a report file against its detached signature
+///
+/// The file must be signed by the given authority, whose certificate must be in the keystore
+/// (see [ReportFileSignature::verify]). Return `false` if the file or the signature has been modified
+pub fn verify_report_file_signature(
+    report_file: &Path,
+    signature_file: &Path,
+    keystore: &Keystore,
+    authority: &str,
+) -> Result<bool, ReportError> {
+    let content = std::fs::read(report_file).map_err(|e| ReportErrorImpl::IOError {
+        msg: format!("Error reading the report file {}", report_file.display()),
+        source: e,
+    })?;
+    ReportFileSignature::from_file(signature_file)?.verify(&content, keystore, authority)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use rust_ev_system_library::rust_ev_crypto_primitives::prelude::direct_trust::Keystore as BasisKeystore;
+
+    pub const VERIFIER_AUTHORITY: &str = "verifier";
+
+    fn test_data_path() -> PathBuf {
+        PathBuf::from("..").join("test_data")
+    }
+
+    /// Keystore of the verifier, with the secret key of the authority [VERIFIER_AUTHORITY]
+    pub fn signing_keystore() -> Keystore {
+        Keystore::try_from(
+            test_data_path()
+                .join("report_signing")
+                .join("signing")
+                .as_path(),
+        )
+        .unwrap()
+    }
+
+    /// Keystore of the canton, with the secret key of the authority `canton`
+    pub fn canton_keystore() -> Keystore {
+        let dir = test_data_path().join("signing_keystore");
+        Keystore::from(
+            BasisKeystore::from_pkcs12(
+                &dir.join("local_direct_trust_keystore_canton.p12"),
+                &dir.join("local_direct_trust_pw_canton.txt"),
+            )
+            .unwrap(),
+        )
+    }
+
+    /// Direct trust keystore, with the certificate of the verifier added as trusted keystore
+    pub fn verifier_keystore() -> Keystore {
+        let mut res = Keystore::try_from(test_data_path().join("direct-trust").as_path()).unwrap();
+        res.add_trusted_keystores(
+            Keystore::try_from(
+                test_data_path()
+                    .join("report_signing")
+                    .join("trust")
+                    .as_path(),
+            )
+            .unwrap(),
+        );
+        res
+    }
+
+    #[test]
+    fn test_signature_file_path() {
+        assert_eq!(
+            signature_file_path(Path::new("dir/report.pdf")),
+            PathBuf::from("dir/report.pdf.sig")
+        );
+    }
+
+    #[test]
+    fn test_sign_verify() {
+        let signature = ReportFileSignature::sign(b"content", "1234", &signing_keystore()).unwrap();
+        assert_eq!(signature.authority(), VERIFIER_AUTHORITY);
+        let ks = verifier_keystore();
+        assert!(
+            signature
+                .verify(b"content", &ks, VERIFIER_AUTHORITY)
+                .unwrap()
+        );
+        assert!(
+            !signature
+                .verify(b"modified content", &ks, VERIFIER_AUTHORITY)
+                .unwrap()
+        );
+        let mut other_fingerprint = signature.clone();
+        other_fingerprint.report_fingerprint = "5678".to_string();
+        assert!(
+            !other_fingerprint
+                .verify(b"content", &ks, VERIFIER_AUTHORITY)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_canton_signature() {
+        let ks = verifier_keystore();
+        let signature = ReportFileSignature::sign(b"content", "1234", &canton_keystore()).unwrap();
+        assert_eq!(signature.authority(), "canton");
+        // The signature of the canton is valid, but the canton is not the verifier
+        assert!(
+            signature
+                .verify(b"content", &ks, VERIFIER_AUTHORITY)
+                .is_err()
+        );
+        assert!(signature.verify(b"content", &ks, "canton").is_err());
+        // The authority of the signature file is replaced by the verifier
+        let mut forged = signature.clone();
+        forged.authority = VERIFIER_AUTHORITY.to_string();
+        assert!(!forged.verify(b"content", &ks, VERIFIER_AUTHORITY).unwrap());
+    }
+
+    #[test]
+    fn test_verify_report_file_signature() {
+        let dir = PathBuf::from(".").join("test_temp_dir");
+        std::fs::create_dir_all(&dir).unwrap();
+        let report_file = dir.join("test_report_signature.txt");
+        std::fs::write(&report_file, "report content").unwrap();
+        let signature_file =
+            ReportFileSignature::sign(b"report content", "1234", &signing_keystore())
+                .unwrap()
+                .write_for_report_file(&report_file)
+                .unwrap();
+        assert_eq!(signature_file, signature_file_path(&report_file));
+        let ks = verifier_keystore();
+        assert!(
+            verify_report_file_signature(&report_file, &signature_file, &ks, VERIFIER_AUTHORITY)
+                .unwrap()
+        );
+        assert!(verify_report_file_signature(&report_file, &signature_file, &ks, "other").is_err());
+        std::fs::write(&report_file, "tampered content").unwrap();
+        assert!(
+            !verify_report_file_signature(&report_file, &signature_file, &ks, VERIFIER_AUTHORITY)
+                .unwrap()
+        );
+        assert!(
+            verify_report_file_signature(
+                &report_file,
+                &dir.join("toto.sig"),
+                &ks,
+                VERIFIER_AUTHORITY
+            )
+            .is_err()
+        );
+    }
+}
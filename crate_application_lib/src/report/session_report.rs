@@ -0,0 +1,175 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Consolidated report of a [VerificationSession] (setup and tally)
+//!
+//! The report contains one section per period. Each section starts with the summary of the
+//! period, followed by the blocks of the report of the period (see [ReportData]).
+
+use super::{
+    ReportConfig, ReportData, ReportError, ReportErrorImpl, ReportInformationTrait,
+    ReportOutputData, ReportOutputDataBlock, ReportOutputDataBlockTitle, ReportOutputDataMetaData,
+};
+use crate::{SESSION_PERIODS, VerificationSession};
+use rust_ev_verifier_lib::verification::{VerificationPeriod, VerificationStatus};
+use std::{collections::HashMap, fmt::Display};
+
+/// Order of the status in the summary of the period
+const SUMMARY_STATUS: [VerificationStatus; 5] = [
+    VerificationStatus::FinishedSuccessfully,
+    VerificationStatus::FinishedWithFailures,
+    VerificationStatus::FinishedWithErrors,
+    VerificationStatus::FinishedWithFailuresAndErrors,
+    VerificationStatus::NotStarted,
+];
+
+/// Structure containing the data of the consolidated report of a session
+pub struct SessionReportData<'a> {
+    report_configuration: ReportConfig,
+    session: &'a VerificationSession,
+}
+
+impl Display for SessionReportData<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.info_to_string(
+            match self.report_output_data_meta_data() {
+                Ok(md) => md,
+                Err(e) => {
+                    return write!(f, "ERROR generating metadata for report display: {:?}", e);
+                }
+            },
+            *self.report_configuration.tab_size(),
+        ) {
+            Ok(s) => write!(f, "{}", s),
+            Err(e) => write!(f, "ERROR generating text of report {}", e),
+        }
+    }
+}
+
+impl<'a> SessionReportData<'a> {
+    /// Create new [SessionReportData]
+    pub fn new(report_configuration: ReportConfig, session: &'a VerificationSession) -> Self {
+        Self {
+            report_configuration,
+            session,
+        }
+    }
+
+    /// The metadata are the same than for the report of the tally
+    fn report_output_data_meta_data(&self) -> Result<ReportOutputDataMetaData, ReportErrorImpl> {
+        ReportData::new(
+            self.report_configuration.clone(),
+            &self.session.tally_information(),
+        )
+        .report_output_data_meta_data()
+    }
+
+    /// Export to json
+    pub fn to_json(&self) -> Result<String, ReportError> {
+        serde_json::to_string(&self.to_report_output(self.report_output_data_meta_data()?)?)
+            .map_err(|e| ReportError::from(ReportErrorImpl::ToJson { source: e }))
+    }
+}
+
+/// Block with the number of verifications per status for the period
+fn period_summary_block(
+    period: VerificationPeriod,
+    verifications_status: &HashMap<String, VerificationStatus>,
+    excluded_verifications: &[String],
+) -> ReportOutputDataBlock {
+    let mut entries = vec![(
+        "Number of verifications".to_string(),
+        verifications_status.len().to_string(),
+    )];
+    entries.extend(SUMMARY_STATUS.iter().map(|status| {
+        (
+            status.as_ref().to_string(),
+            verifications_status
+                .values()
+                .filter(|s| *s == status)
+                .count()
+                .to_string(),
+        )
+    }));
+    entries.push((
+        "Excluded".to_string(),
+        excluded_verifications.len().to_string(),
+    ));
+    ReportOutputDataBlock::new_with_tuples(
+        ReportOutputDataBlockTitle::PeriodSummary(period.as_ref().to_string()),
+        &entries,
+    )
+}
+
+impl ReportInformationTrait for SessionReportData<'_> {
+    fn to_report_output(
+        &self,
+        metadata: ReportOutputDataMetaData,
+    ) -> Result<ReportOutputData, ReportError> {
+        let mut res = ReportOutputData::new(metadata.clone());
+        for period in SESSION_PERIODS {
+            let run_information = self.session.run_information(period);
+            res.push(period_summary_block(
+                period,
+                run_information.verifications_status(),
+                run_information.excluded_verifications(),
+            ));
+            res.append(
+                &mut ReportData::new(self.report_configuration.clone(), &run_information)
+                    .to_report_output(metadata.clone())
+                    .map_err(|e| ReportErrorImpl::ToOutput {
+                        source: Box::new(e),
+                    })?,
+            );
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_period_summary_block() {
+        let status = HashMap::from([
+            (
+                "01.01".to_string(),
+                VerificationStatus::FinishedSuccessfully,
+            ),
+            (
+                "02.01".to_string(),
+                VerificationStatus::FinishedSuccessfully,
+            ),
+            ("02.02".to_string(), VerificationStatus::FinishedWithErrors),
+        ]);
+        let block =
+            period_summary_block(VerificationPeriod::Setup, &status, &["03.01".to_string()]);
+        assert_eq!(block.title().to_string(), "Summary of the period setup");
+        assert_eq!(
+            block.key_value_entries(),
+            vec![
+                ("Number of verifications", "3"),
+                ("Successful", "2"),
+                ("Failures", "0"),
+                ("Errors", "1"),
+                ("Failures and Errors", "0"),
+                ("Not started", "0"),
+                ("Excluded", "1")
+            ]
+        );
+    }
+}
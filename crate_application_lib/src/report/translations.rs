@@ -0,0 +1,1115 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Translations of the reports
+//!
+//! The report data are always collected in English. The translation is applied when
+//! the report files are rendered, so that the exported json remains independent of the
+//! language.
+
+use serde::{Deserialize, Serialize};
+
+/// Language of the report
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+    strum::AsRefStr,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ReportLanguage {
+    #[default]
+    En,
+    De,
+    Fr,
+    It,
+}
+
+/// Translations of the texts (English, [German, French, Italian])
+const TEXTS: &[(&str, [&str; 3])] = &[
+    // Titles of the blocks
+    (
+        "Fingerprints",
+        ["Fingerabdrücke", "Empreintes", "Impronte digitali"],
+    ),
+    (
+        "Other fingerprints",
+        [
+            "Weitere Fingerabdrücke",
+            "Autres empreintes",
+            "Altre impronte digitali",
+        ],
+    ),
+    (
+        "Information",
+        ["Informationen", "Informations", "Informazioni"],
+    ),
+    (
+        "Verification results",
+        [
+            "Ergebnisse der Verifikationen",
+            "Résultats des vérifications",
+            "Risultati delle verifiche",
+        ],
+    ),
+    (
+        "Running Information",
+        [
+            "Informationen zur Ausführung",
+            "Informations d'exécution",
+            "Informazioni sull'esecuzione",
+        ],
+    ),
+    ("Errors for", ["Fehler für", "Erreurs pour", "Errori per"]),
+    (
+        "Failures for",
+        ["Mängel für", "Échecs pour", "Fallimenti per"],
+    ),
+    (
+        "Information for",
+        ["Informationen für", "Informations pour", "Informazioni per"],
+    ),
+    (
+        "Previous attempts for",
+        [
+            "Frühere Versuche für",
+            "Tentatives précédentes pour",
+            "Tentativi precedenti per",
+        ],
+    ),
+    (
+        "Verifications not run",
+        [
+            "Nicht ausgeführte Verifikationen",
+            "Vérifications non exécutées",
+            "Verifiche non eseguite",
+        ],
+    ),
+    (
+        "Configuration",
+        ["Konfiguration", "Configuration", "Configurazione"],
+    ),
+    (
+        "completness",
+        ["Vollständigkeit", "Complétude", "Completezza"],
+    ),
+    (
+        "authenticity",
+        ["Authentizität", "Authenticité", "Autenticità"],
+    ),
+    ("integrity", ["Integrität", "Intégrité", "Integrità"]),
+    ("consistency", ["Konsistenz", "Cohérence", "Coerenza"]),
+    ("evidence", ["Nachweis", "Preuve", "Prova"]),
+    ("Performance", ["Leistung", "Performance", "Prestazioni"]),
+    (
+        "Compared reports",
+        [
+            "Verglichene Berichte",
+            "Rapports comparés",
+            "Rapporti confrontati",
+        ],
+    ),
+    (
+        "Changes of the verification status",
+        [
+            "Änderungen des Status der Verifikationen",
+            "Changements du statut des vérifications",
+            "Modifiche dello stato delle verifiche",
+        ],
+    ),
+    (
+        "New errors for",
+        [
+            "Neue Fehler für",
+            "Nouvelles erreurs pour",
+            "Nuovi errori per",
+        ],
+    ),
+    (
+        "Resolved errors for",
+        [
+            "Behobene Fehler für",
+            "Erreurs résolues pour",
+            "Errori risolti per",
+        ],
+    ),
+    (
+        "New failures for",
+        [
+            "Neue Mängel für",
+            "Nouveaux échecs pour",
+            "Nuovi fallimenti per",
+        ],
+    ),
+    (
+        "Resolved failures for",
+        [
+            "Behobene Mängel für",
+            "Échecs résolus pour",
+            "Fallimenti risolti per",
+        ],
+    ),
+    (
+        "Changes of the fingerprints",
+        [
+            "Änderungen der Fingerabdrücke",
+            "Changements des empreintes",
+            "Modifiche delle impronte digitali",
+        ],
+    ),
+    (
+        "Changes of the information",
+        [
+            "Änderungen der Informationen",
+            "Changements des informations",
+            "Modifiche delle informazioni",
+        ],
+    ),
+    (
+        "Results of the counting circle",
+        [
+            "Ergebnisse des Zählkreises",
+            "Résultats du cercle de dépouillement",
+            "Risultati del circolo di conteggio",
+        ],
+    ),
+    (
+        "Results of the vote",
+        [
+            "Ergebnisse der Abstimmung",
+            "Résultats de la votation",
+            "Risultati della votazione",
+        ],
+    ),
+    (
+        "Ballots per list of the election",
+        [
+            "Wahlzettel pro Liste der Wahl",
+            "Bulletins par liste de l'élection",
+            "Schede per lista dell'elezione",
+        ],
+    ),
+    (
+        "Results of the election",
+        [
+            "Ergebnisse der Wahl",
+            "Résultats de l'élection",
+            "Risultati dell'elezione",
+        ],
+    ),
+    (
+        "Counting circle",
+        [
+            "Zählkreis",
+            "Cercle de dépouillement",
+            "Circolo di conteggio",
+        ],
+    ),
+    (
+        "Summary of the period",
+        [
+            "Zusammenfassung der Periode",
+            "Résumé de la période",
+            "Riepilogo del periodo",
+        ],
+    ),
+    (
+        "Number of verifications",
+        [
+            "Anzahl Verifikationen",
+            "Nombre de vérifications",
+            "Numero di verifiche",
+        ],
+    ),
+    // Labels of the report files
+    (
+        "Date / Time",
+        ["Datum / Zeit", "Date / Heure", "Data / Ora"],
+    ),
+    ("Signatures", ["Unterschriften", "Signatures", "Firme"]),
+    ("Member", ["Mitglied", "Membre", "Membro"]),
+    ("Page", ["Seite", "Page", "Pagina"]),
+    (
+        "Report fingerprint",
+        [
+            "Fingerabdruck des Berichts",
+            "Empreinte du rapport",
+            "Impronta del rapporto",
+        ],
+    ),
+    // Comparison of reports
+    (
+        "Original report",
+        [
+            "Ursprünglicher Bericht",
+            "Rapport original",
+            "Rapporto originale",
+        ],
+    ),
+    (
+        "New report",
+        ["Neuer Bericht", "Nouveau rapport", "Nuovo rapporto"],
+    ),
+    (
+        "Number of differences",
+        [
+            "Anzahl Unterschiede",
+            "Nombre de différences",
+            "Numero di differenze",
+        ],
+    ),
+    // Election results
+    (
+        "Valid voting cards",
+        [
+            "Gültige Stimmrechtsausweise",
+            "Cartes de vote valables",
+            "Carte di legittimazione valide",
+        ],
+    ),
+    (
+        "Invalid voting cards",
+        [
+            "Ungültige Stimmrechtsausweise",
+            "Cartes de vote non valables",
+            "Carte di legittimazione non valide",
+        ],
+    ),
+    (
+        "Number of ballots",
+        [
+            "Anzahl Wahlzettel",
+            "Nombre de bulletins",
+            "Numero di schede",
+        ],
+    ),
+    (
+        "Ballots without list",
+        [
+            "Wahlzettel ohne Liste",
+            "Bulletins sans liste",
+            "Schede senza lista",
+        ],
+    ),
+    (
+        "Write-ins",
+        ["Freie Eingaben", "Saisies libres", "Voti liberi"],
+    ),
+    (
+        "Empty positions",
+        ["Leere Linien", "Lignes vides", "Righe vuote"],
+    ),
+    // Running information
+    ("Period", ["Periode", "Période", "Periodo"]),
+    (
+        "Context Dataset",
+        [
+            "Kontext-Datensatz",
+            "Jeu de données contexte",
+            "Set di dati contesto",
+        ],
+    ),
+    (
+        "Context Dataset Fingerprint",
+        [
+            "Fingerabdruck des Kontext-Datensatzes",
+            "Empreinte du jeu de données contexte",
+            "Impronta del set di dati contesto",
+        ],
+    ),
+    (
+        "Setup Dataset",
+        [
+            "Setup-Datensatz",
+            "Jeu de données de configuration",
+            "Set di dati di configurazione",
+        ],
+    ),
+    (
+        "Setup Dataset Fingerprint",
+        [
+            "Fingerabdruck des Setup-Datensatzes",
+            "Empreinte du jeu de données de configuration",
+            "Impronta del set di dati di configurazione",
+        ],
+    ),
+    (
+        "Tally Dataset",
+        [
+            "Auszählungs-Datensatz",
+            "Jeu de données du dépouillement",
+            "Set di dati dello spoglio",
+        ],
+    ),
+    (
+        "Tally Dataset Fingerprint",
+        [
+            "Fingerabdruck des Auszählungs-Datensatzes",
+            "Empreinte du jeu de données du dépouillement",
+            "Impronta del set di dati dello spoglio",
+        ],
+    ),
+    (
+        "Verification directory",
+        [
+            "Verifikationsverzeichnis",
+            "Répertoire de vérification",
+            "Cartella di verifica",
+        ],
+    ),
+    (
+        "Start Time",
+        ["Startzeit", "Heure de début", "Ora di inizio"],
+    ),
+    ("Stop Time", ["Endzeit", "Heure de fin", "Ora di fine"]),
+    ("Duration", ["Dauer", "Durée", "Durata"]),
+    (
+        "Audit Log",
+        ["Audit-Protokoll", "Journal d'audit", "Registro di audit"],
+    ),
+    (
+        "Audit Log Records",
+        [
+            "Einträge des Audit-Protokolls",
+            "Entrées du journal d'audit",
+            "Voci del registro di audit",
+        ],
+    ),
+    (
+        "Audit Log Last Hash",
+        [
+            "Letzter Hash des Audit-Protokolls",
+            "Dernier hash du journal d'audit",
+            "Ultimo hash del registro di audit",
+        ],
+    ),
+    // Information
+    (
+        "Election Event Identification",
+        [
+            "Identifikation des Wahlereignisses",
+            "Identification de l'événement électoral",
+            "Identificazione dell'evento elettorale",
+        ],
+    ),
+    ("Seed", ["Seed", "Seed", "Seed"]),
+    (
+        "Contest Identification",
+        [
+            "Identifikation des Urnengangs",
+            "Identification du scrutin",
+            "Identificazione dello scrutinio",
+        ],
+    ),
+    (
+        "Contest Date",
+        [
+            "Datum des Urnengangs",
+            "Date du scrutin",
+            "Data dello scrutinio",
+        ],
+    ),
+    (
+        "Number of votes",
+        [
+            "Anzahl Abstimmungen",
+            "Nombre de votations",
+            "Numero di votazioni",
+        ],
+    ),
+    (
+        "Number of vote objects",
+        [
+            "Anzahl Abstimmungsvorlagen",
+            "Nombre d'objets de votation",
+            "Numero di oggetti in votazione",
+        ],
+    ),
+    (
+        "Number of elections",
+        ["Anzahl Wahlen", "Nombre d'élections", "Numero di elezioni"],
+    ),
+    (
+        "Number of voters (productive)",
+        [
+            "Anzahl Stimmberechtigte (produktiv)",
+            "Nombre d'électeurs (productif)",
+            "Numero di aventi diritto di voto (produttivo)",
+        ],
+    ),
+    (
+        "Number of voters (test)",
+        [
+            "Anzahl Stimmberechtigte (Test)",
+            "Nombre d'électeurs (test)",
+            "Numero di aventi diritto di voto (test)",
+        ],
+    ),
+    (
+        "Number of ballot boxes (productive)",
+        [
+            "Anzahl Urnen (produktiv)",
+            "Nombre d'urnes (productif)",
+            "Numero di urne (produttivo)",
+        ],
+    ),
+    (
+        "Number of ballot boxes (test)",
+        [
+            "Anzahl Urnen (Test)",
+            "Nombre d'urnes (test)",
+            "Numero di urne (test)",
+        ],
+    ),
+    (
+        "Number of productive voting cards used",
+        [
+            "Anzahl verwendeter produktiver Stimmrechtsausweise",
+            "Nombre de cartes de vote productives utilisées",
+            "Numero di carte di legittimazione produttive utilizzate",
+        ],
+    ),
+    (
+        "Number of test voting cards used",
+        [
+            "Anzahl verwendeter Test-Stimmrechtsausweise",
+            "Nombre de cartes de vote de test utilisées",
+            "Numero di carte di legittimazione di test utilizzate",
+        ],
+    ),
+    (
+        "Number of verifications",
+        [
+            "Anzahl Verifikationen",
+            "Nombre de vérifications",
+            "Numero di verifiche",
+        ],
+    ),
+    (
+        "Excluded verifications",
+        [
+            "Ausgeschlossene Verifikationen",
+            "Vérifications exclues",
+            "Verifiche escluse",
+        ],
+    ),
+    (
+        "Run status",
+        [
+            "Status der Ausführung",
+            "Statut de l'exécution",
+            "Stato dell'esecuzione",
+        ],
+    ),
+    (
+        "Number of running verifications",
+        [
+            "Anzahl laufender Verifikationen",
+            "Nombre de vérifications en cours",
+            "Numero di verifiche in corso",
+        ],
+    ),
+    (
+        "Number of verifications with errors",
+        [
+            "Anzahl Verifikationen mit Fehlern",
+            "Nombre de vérifications avec des erreurs",
+            "Numero di verifiche con errori",
+        ],
+    ),
+    (
+        "Number of verifications with failures",
+        [
+            "Anzahl Verifikationen mit Mängeln",
+            "Nombre de vérifications avec des échecs",
+            "Numero di verifiche con fallimenti",
+        ],
+    ),
+];
+
+/// Translations of the fixed values of the report (statuses, periods, ...)
+///
+/// Only these values are translated. The other values (e.g. collected from the dataset) are
+/// kept unchanged
+const VALUES: &[(&str, [&str; 3])] = &[
+    ("setup", ["Setup", "Configuration", "Configurazione"]),
+    ("tally", ["Auszählung", "Dépouillement", "Spoglio"]),
+    (
+        "Not finished",
+        ["Nicht beendet", "Non terminé", "Non terminato"],
+    ),
+    ("None", ["Keine", "Aucune", "Nessuna"]),
+    ("Finished", ["Beendet", "Terminé", "Terminato"]),
+    // Status of the verifications
+    (
+        "Not started",
+        ["Nicht gestartet", "Non démarré", "Non avviato"],
+    ),
+    ("Running", ["Läuft", "En cours", "In corso"]),
+    ("Successful", ["Erfolgreich", "Réussi", "Riuscito"]),
+    ("Failures", ["Mängel", "Échecs", "Fallimenti"]),
+    ("Errors", ["Fehler", "Erreurs", "Errori"]),
+    (
+        "Failures and Errors",
+        [
+            "Mängel und Fehler",
+            "Échecs et erreurs",
+            "Fallimenti ed errori",
+        ],
+    ),
+    ("Excluded", ["Ausgeschlossen", "Exclu", "Escluso"]),
+    ("Unknown", ["Unbekannt", "Inconnu", "Sconosciuto"]),
+];
+
+/// Translations of the descriptions of the verifications
+const DESCRIPTIONS: &[(&str, [&str; 3])] = &[
+    (
+        "The required elements for the setup verification, along with their path, are present",
+        [
+            "Die für die Verifikation des Setups erforderlichen Elemente sind mit ihrem Pfad vorhanden",
+            "Les éléments requis pour la vérification de la configuration sont présents avec leur chemin",
+            "Gli elementi necessari per la verifica della configurazione sono presenti con il loro percorso",
+        ],
+    ),
+    (
+        "The signature of ElectionEventConfiguration is valid",
+        [
+            "Die Signatur von ElectionEventConfiguration ist gültig",
+            "La signature de ElectionEventConfiguration est valide",
+            "La firma di ElectionEventConfiguration è valida",
+        ],
+    ),
+    (
+        "The signature of SetupComponentPublicKeysPayload is valid",
+        [
+            "Die Signatur von SetupComponentPublicKeysPayload ist gültig",
+            "La signature de SetupComponentPublicKeysPayload est valide",
+            "La firma di SetupComponentPublicKeysPayload è valida",
+        ],
+    ),
+    (
+        "The signature of ControlComponentPublicKeysPayload is valid",
+        [
+            "Die Signatur von ControlComponentPublicKeysPayload ist gültig",
+            "La signature de ControlComponentPublicKeysPayload est valide",
+            "La firma di ControlComponentPublicKeysPayload è valida",
+        ],
+    ),
+    (
+        "All encryption group parameters are identical",
+        [
+            "Alle Parameter der Verschlüsselungsgruppe sind identisch",
+            "Tous les paramètres du groupe de chiffrement sont identiques",
+            "Tutti i parametri del gruppo di cifratura sono identici",
+        ],
+    ),
+    (
+        "The encryption group parameters cannot be regenerated from seed and are the same",
+        [
+            "Die Parameter der Verschlüsselungsgruppe können aus dem Seed nicht neu erzeugt werden und sind identisch",
+            "Les paramètres du groupe de chiffrement ne peuvent pas être régénérés à partir du seed et sont identiques",
+            "I parametri del gruppo di cifratura non possono essere rigenerati dal seed e sono identici",
+        ],
+    ),
+];
+
+/// Translations of the names of the verifications (English, [German, French, Italian])
+const VERIFICATION_NAMES: &[(&str, [&str; 3])] = &[
+    (
+        "VerifySetupCompleteness",
+        [
+            "Vollständigkeit des Setups",
+            "Complétude de la configuration",
+            "Completezza della configurazione",
+        ],
+    ),
+    (
+        "VerifySignatureCantonConfig",
+        [
+            "Signatur der Kantonskonfiguration",
+            "Signature de la configuration cantonale",
+            "Firma della configurazione cantonale",
+        ],
+    ),
+    (
+        "VerifySignatureSetupComponentPublicKeys",
+        [
+            "Signatur der öffentlichen Schlüssel der Setup-Komponente",
+            "Signature des clés publiques du composant de configuration",
+            "Firma delle chiavi pubbliche del componente di configurazione",
+        ],
+    ),
+    (
+        "VerifySignatureControlComponentPublicKeys",
+        [
+            "Signatur der öffentlichen Schlüssel der Kontrollkomponenten",
+            "Signature des clés publiques des composants de contrôle",
+            "Firma delle chiavi pubbliche dei componenti di controllo",
+        ],
+    ),
+    (
+        "VerifySignatureSetupComponentTallyData",
+        [
+            "Signatur der Auszählungsdaten der Setup-Komponente",
+            "Signature des données de dépouillement du composant de configuration",
+            "Firma dei dati di spoglio del componente di configurazione",
+        ],
+    ),
+    (
+        "VerifySignatureElectionEventContext",
+        [
+            "Signatur des Kontexts des Wahlereignisses",
+            "Signature du contexte de l'événement électoral",
+            "Firma del contesto dell'evento elettorale",
+        ],
+    ),
+    (
+        "VerifyEncryptionGroupConsistency",
+        [
+            "Konsistenz der Verschlüsselungsgruppe",
+            "Cohérence du groupe de chiffrement",
+            "Coerenza del gruppo di cifratura",
+        ],
+    ),
+    (
+        "VerifyNodeIdsConsistency",
+        [
+            "Konsistenz der Knoten-IDs",
+            "Cohérence des identifiants des nœuds",
+            "Coerenza degli identificativi dei nodi",
+        ],
+    ),
+    (
+        "VerifyFileNameNodeIdsConsistency",
+        [
+            "Konsistenz der Knoten-IDs in den Dateinamen",
+            "Cohérence des identifiants des nœuds dans les noms de fichier",
+            "Coerenza degli identificativi dei nodi nei nomi dei file",
+        ],
+    ),
+    (
+        "VerifyElectionEventIdConsistency",
+        [
+            "Konsistenz der ID des Wahlereignisses",
+            "Cohérence de l'identifiant de l'événement électoral",
+            "Coerenza dell'identificativo dell'evento elettorale",
+        ],
+    ),
+    (
+        "VerifyVerificationCardSetIdsConsistency",
+        [
+            "Konsistenz der IDs der Verifikationskartensets",
+            "Cohérence des identifiants des ensembles de cartes de vérification",
+            "Coerenza degli identificativi dei set di carte di verifica",
+        ],
+    ),
+    (
+        "VerifyFileNameVerificationCardSetIdsConsistency",
+        [
+            "Konsistenz der IDs der Verifikationskartensets in den Dateinamen",
+            "Cohérence des identifiants des ensembles de cartes de vérification dans les noms de fichier",
+            "Coerenza degli identificativi dei set di carte di verifica nei nomi dei file",
+        ],
+    ),
+    (
+        "VerifyVerificationCardIdsConsistency",
+        [
+            "Konsistenz der IDs der Verifikationskarten",
+            "Cohérence des identifiants des cartes de vérification",
+            "Coerenza degli identificativi delle carte di verifica",
+        ],
+    ),
+    (
+        "VerifyCCRChoiceReturnCodesPublicKeyConsistency",
+        [
+            "Konsistenz der öffentlichen Schlüssel der Rückgabecodes (CCR)",
+            "Cohérence des clés publiques des codes de retour (CCR)",
+            "Coerenza delle chiavi pubbliche dei codici di ritorno (CCR)",
+        ],
+    ),
+    (
+        "VerifyCCMElectionPublicKeyConsistency",
+        [
+            "Konsistenz der öffentlichen Wahlschlüssel (CCM)",
+            "Cohérence des clés publiques électorales (CCM)",
+            "Coerenza delle chiavi pubbliche elettorali (CCM)",
+        ],
+    ),
+    (
+        "VerifyCCMAndCCRSchnorrProofsConsistency",
+        [
+            "Konsistenz der Schnorr-Beweise (CCM und CCR)",
+            "Cohérence des preuves de Schnorr (CCM et CCR)",
+            "Coerenza delle prove di Schnorr (CCM e CCR)",
+        ],
+    ),
+    (
+        "VerifyChoiceReturnCodesPublicKeyConsistency",
+        [
+            "Konsistenz des öffentlichen Schlüssels der Rückgabecodes",
+            "Cohérence de la clé publique des codes de retour",
+            "Coerenza della chiave pubblica dei codici di ritorno",
+        ],
+    ),
+    (
+        "VerifyElectionPublicKeyConsistency",
+        [
+            "Konsistenz des öffentlichen Wahlschlüssels",
+            "Cohérence de la clé publique électorale",
+            "Coerenza della chiave pubblica elettorale",
+        ],
+    ),
+    (
+        "VerifyPrimesMappingTableConsistency",
+        [
+            "Konsistenz der Primzahlen-Zuordnungstabelle",
+            "Cohérence de la table de correspondance des nombres premiers",
+            "Coerenza della tabella di corrispondenza dei numeri primi",
+        ],
+    ),
+    (
+        "VerifyTotalVotersConsistency",
+        [
+            "Konsistenz der Gesamtzahl der Stimmberechtigten",
+            "Cohérence du nombre total d'électeurs",
+            "Coerenza del numero totale di aventi diritto di voto",
+        ],
+    ),
+    (
+        "VerifySetupIntegrity",
+        [
+            "Integrität des Setups",
+            "Intégrité de la configuration",
+            "Integrità della configurazione",
+        ],
+    ),
+    (
+        "VerifyEncryptionParameters",
+        [
+            "Verschlüsselungsparameter",
+            "Paramètres de chiffrement",
+            "Parametri di cifratura",
+        ],
+    ),
+    (
+        "VerifySmallPrimeGroupMembers",
+        [
+            "Kleine Primzahlen der Gruppe",
+            "Petits nombres premiers du groupe",
+            "Piccoli numeri primi del gruppo",
+        ],
+    ),
+    (
+        "VerifyVotingOptions",
+        ["Stimmoptionen", "Options de vote", "Opzioni di voto"],
+    ),
+    (
+        "VerifySchnorrProofs",
+        ["Schnorr-Beweise", "Preuves de Schnorr", "Prove di Schnorr"],
+    ),
+    (
+        "VerifyTallyCompleteness",
+        [
+            "Vollständigkeit der Auszählung",
+            "Complétude du dépouillement",
+            "Completezza dello spoglio",
+        ],
+    ),
+    (
+        "VerifySignatureControlComponentBallotBox",
+        [
+            "Signatur der Urnen der Kontrollkomponenten",
+            "Signature des urnes des composants de contrôle",
+            "Firma delle urne dei componenti di controllo",
+        ],
+    ),
+    (
+        "VerifySignatureControlComponentShuffle",
+        [
+            "Signatur der Mischung der Kontrollkomponenten",
+            "Signature du mélange des composants de contrôle",
+            "Firma del rimescolamento dei componenti di controllo",
+        ],
+    ),
+    (
+        "VerifySignatureTallyComponentShuffle",
+        [
+            "Signatur der Mischung der Auszählungskomponente",
+            "Signature du mélange du composant de dépouillement",
+            "Firma del rimescolamento del componente di spoglio",
+        ],
+    ),
+    (
+        "VerifySignatureTallyComponentVotes",
+        [
+            "Signatur der Stimmen der Auszählungskomponente",
+            "Signature des votes du composant de dépouillement",
+            "Firma dei voti del componente di spoglio",
+        ],
+    ),
+    (
+        "VerifySignatureTallyComponentEch0222",
+        [
+            "Signatur von eCH-0222 der Auszählungskomponente",
+            "Signature de eCH-0222 du composant de dépouillement",
+            "Firma di eCH-0222 del componente di spoglio",
+        ],
+    ),
+    (
+        "VerifyBallotBoxIdsConsistency",
+        [
+            "Konsistenz der Urnen-IDs",
+            "Cohérence des identifiants des urnes",
+            "Coerenza degli identificativi delle urne",
+        ],
+    ),
+    (
+        "VerifyFileNameBallotBoxIdsConsistency",
+        [
+            "Konsistenz der Urnen-IDs in den Dateinamen",
+            "Cohérence des identifiants des urnes dans les noms de fichier",
+            "Coerenza degli identificativi delle urne nei nomi dei file",
+        ],
+    ),
+    (
+        "VerifyConfirmedEncryptedVotesConsistency",
+        [
+            "Konsistenz der bestätigten verschlüsselten Stimmen",
+            "Cohérence des votes chiffrés confirmés",
+            "Coerenza dei voti cifrati confermati",
+        ],
+    ),
+    (
+        "VerifyCiphertextsConsistency",
+        [
+            "Konsistenz der Chiffrate",
+            "Cohérence des chiffrés",
+            "Coerenza dei testi cifrati",
+        ],
+    ),
+    (
+        "VerifyPlaintextsConsistency",
+        [
+            "Konsistenz der Klartexte",
+            "Cohérence des textes en clair",
+            "Coerenza dei testi in chiaro",
+        ],
+    ),
+    (
+        "VerifyNumberConfirmedEncryptedVotesConsistency",
+        [
+            "Konsistenz der Anzahl bestätigter verschlüsselter Stimmen",
+            "Cohérence du nombre de votes chiffrés confirmés",
+            "Coerenza del numero di voti cifrati confermati",
+        ],
+    ),
+    (
+        "VerifyTallyIntegrity",
+        [
+            "Integrität der Auszählung",
+            "Intégrité du dépouillement",
+            "Integrità dello spoglio",
+        ],
+    ),
+    (
+        "VerifyOnlineControlComponents",
+        [
+            "Online-Kontrollkomponenten",
+            "Composants de contrôle en ligne",
+            "Componenti di controllo online",
+        ],
+    ),
+    (
+        "VerifyTallyControlComponent",
+        [
+            "Auszählungs-Kontrollkomponente",
+            "Composant de contrôle du dépouillement",
+            "Componente di controllo dello spoglio",
+        ],
+    ),
+];
+
+impl ReportLanguage {
+    /// Index in the translation tables. `None` for English
+    fn index(&self) -> Option<usize> {
+        match self {
+            ReportLanguage::En => None,
+            ReportLanguage::De => Some(0),
+            ReportLanguage::Fr => Some(1),
+            ReportLanguage::It => Some(2),
+        }
+    }
+
+    fn lookup(&self, table: &[(&str, [&'static str; 3])], text: &str) -> Option<&'static str> {
+        let index = self.index()?;
+        table
+            .iter()
+            .find(|(en, _)| *en == text)
+            .map(|(_, translations)| translations[index])
+    }
+
+    /// Translate the name of a verification
+    ///
+    /// Return the name unchanged if no translation is found
+    pub fn translate_verification_name(&self, name: &str) -> String {
+        self.lookup(VERIFICATION_NAMES, name)
+            .unwrap_or(name)
+            .to_string()
+    }
+
+    /// Translate a title or a key of the report
+    ///
+    /// The keys of the verification results (`<id> - <name>`) are translated using the name
+    /// of the verification. Return the text unchanged if no translation is found
+    pub fn translate(&self, text: &str) -> String {
+        if let Some(t) = self
+            .lookup(TEXTS, text)
+            .or_else(|| self.lookup(VALUES, text))
+        {
+            return t.to_string();
+        }
+        if let Some((id, name)) = text.split_once(" - ")
+            && let Some(t) = self.lookup(VERIFICATION_NAMES, name)
+        {
+            return format!("{} - {}", id, t);
+        }
+        text.to_string()
+    }
+
+    /// Translate a value of the report
+    ///
+    /// Only the fixed values (e.g. the statuses) are translated. Return the value unchanged
+    /// otherwise, in particular for the values collected from the dataset
+    pub fn translate_value(&self, value: &str) -> String {
+        self.lookup(VALUES, value).unwrap_or(value).to_string()
+    }
+
+    /// Translate the description of a verification
+    ///
+    /// Return the description unchanged if no translation is found
+    pub fn translate_description(&self, description: &str) -> String {
+        self.lookup(DESCRIPTIONS, description)
+            .unwrap_or(description)
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_ev_verifier_lib::VerifierConfig;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_language_from_str() {
+        assert_eq!(ReportLanguage::from_str("de").unwrap(), ReportLanguage::De);
+        assert_eq!(ReportLanguage::It.to_string(), "it");
+        assert!(ReportLanguage::from_str("es").is_err());
+    }
+
+    #[test]
+    fn test_translate() {
+        assert_eq!(ReportLanguage::En.translate("Fingerprints"), "Fingerprints");
+        assert_eq!(
+            ReportLanguage::De.translate("Fingerprints"),
+            "Fingerabdrücke"
+        );
+        assert_eq!(ReportLanguage::Fr.translate("Successful"), "Réussi");
+        assert_eq!(ReportLanguage::It.translate("Errors"), "Errori");
+        assert_eq!(ReportLanguage::De.translate("abcdef"), "abcdef");
+        assert_eq!(
+            ReportLanguage::Fr.translate("05.04 - VerifySchnorrProofs"),
+            "05.04 - Preuves de Schnorr"
+        );
+        assert_eq!(
+            ReportLanguage::En.translate("05.04 - VerifySchnorrProofs"),
+            "05.04 - VerifySchnorrProofs"
+        );
+    }
+
+    #[test]
+    fn test_translate_value() {
+        assert_eq!(
+            ReportLanguage::De.translate_value("Successful"),
+            "Erfolgreich"
+        );
+        assert_eq!(ReportLanguage::Fr.translate_value("tally"), "Dépouillement");
+        assert_eq!(
+            ReportLanguage::De.translate_value("Fingerprints"),
+            "Fingerprints"
+        );
+        assert_eq!(
+            ReportLanguage::It.translate_value("05.04 - VerifySchnorrProofs"),
+            "05.04 - VerifySchnorrProofs"
+        );
+    }
+
+    #[test]
+    fn test_translate_description() {
+        let description =
+            "The encryption group parameters cannot be regenerated from seed and are the same";
+        assert!(
+            ReportLanguage::De
+                .translate_description(description)
+                .contains("nicht neu erzeugt")
+        );
+        assert!(
+            ReportLanguage::Fr
+                .translate_description(description)
+                .contains("ne peuvent pas")
+        );
+        assert!(
+            ReportLanguage::It
+                .translate_description(description)
+                .contains("non possono")
+        );
+        assert_eq!(ReportLanguage::De.translate(description), description);
+    }
+
+    #[test]
+    fn test_all_verifications_translated() {
+        let config = VerifierConfig::default();
+        let list = config.get_verification_list_str();
+        let verifications: serde_json::Value = serde_json::from_str(list).unwrap();
+        for v in verifications.as_array().unwrap() {
+            let name = v["name"].as_str().unwrap();
+            let description = v["description"].as_str().unwrap();
+            for language in [ReportLanguage::De, ReportLanguage::Fr, ReportLanguage::It] {
+                assert_ne!(
+                    language.translate_verification_name(name),
+                    name,
+                    "{name} not translated in {language}"
+                );
+                if !description.is_empty() {
+                    assert_ne!(
+                        language.translate_description(description),
+                        description,
+                        "Description of {name} not translated in {language}"
+                    );
+                }
+            }
+        }
+    }
+}
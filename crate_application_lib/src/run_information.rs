@@ -14,47 +14,185 @@
 // a copy of the GNU General Public License along with this program. If not, see
 // <https://www.gnu.org/licenses/>.
 
-use crate::RunnerErrorImpl;
+use crate::{
+    RunnerErrorImpl,
+    report::{ReportOutputDataBlock, manual_verifications_to_blocks},
+};
 
 use super::{
-    ExtractDataSetResults, RunnerError, RunnerInformation, runner::VerificationRunInformation,
+    AuditLogHead, ExtractDataSetResults, RunnerError, RunnerInformation,
+    extract::ExtractDataSetResultsArchive, runner::VerificationRunInformation,
 };
 use rust_ev_verifier_lib::{
-    VerifierConfig,
+    DatasetTypeKind, EffectiveConfigValue, VerifierConfig,
     file_structure::VerificationDirectory,
     verification::{
-        ManualVerifications, VerficationsWithErrorAndFailures, VerificationMetaDataList,
-        VerificationPeriod, VerificationStatus, get_verifications_setup, get_verifications_tally,
+        ManualVerifications, VerficationsWithErrorAndFailures, VerificationEvent,
+        VerificationMetaDataList, VerificationPeriod, VerificationStatus, get_verifications_setup,
+        get_verifications_tally,
     },
 };
-use std::{collections::HashMap, path::Path, sync::Arc, time::SystemTime};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::Path,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+/// Version of the format of the archived [RunInformation] (see [RunInformation::to_json])
+const RUN_INFORMATION_ARCHIVE_VERSION: u32 = 1;
+
+/// Performance of a finished verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationPerformance {
+    /// Duration of the verification
+    pub duration: Duration,
+    /// Peak memory of the whole process in bytes, read at the end of the verification
+    ///
+    /// High-water mark since the start of the process, including the memory of the previous and of
+    /// the concurrent verifications. It is not the memory used by the verification. `None` if not on Linux
+    pub process_peak_memory: Option<u64>,
+    /// Number of items checked by the verification (if available)
+    pub items_checked: Option<usize>,
+}
+
+impl Display for VerificationPerformance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.3}s", self.duration.as_secs_f64())?;
+        if let Some(m) = self.process_peak_memory {
+            write!(
+                f,
+                ", process peak memory {:.1} MiB",
+                m as f64 / (1024.0 * 1024.0)
+            )?;
+        }
+        if let Some(nb) = self.items_checked {
+            write!(f, ", {nb} items checked")?;
+        }
+        Ok(())
+    }
+}
+
+/// Information of a previous attempt of a verification, run again with [crate::Runner::rerun]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationAttemptInformation {
+    /// Status at the end of the attempt
+    pub status: VerificationStatus,
+    /// List of errors as [String]
+    pub errors: Vec<String>,
+    /// List of failures as [String]
+    pub failures: Vec<String>,
+    /// Performance of the attempt
+    pub performance: Option<VerificationPerformance>,
+}
+
+/// [RunnerInformation] as archived with the [RunInformation]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RunnerInformationArchive {
+    election_event_id: Option<String>,
+    seed: Option<String>,
+    start_time: Option<SystemTime>,
+    duration: Option<Duration>,
+    #[serde(default)]
+    audit_log_head: Option<AuditLogHead>,
+}
+
+impl From<&RunnerInformation> for RunnerInformationArchive {
+    fn from(value: &RunnerInformation) -> Self {
+        Self {
+            election_event_id: value.election_event_id.clone(),
+            seed: value.seed.clone(),
+            start_time: value.start_time,
+            duration: value.duration,
+            audit_log_head: value.audit_log_head.clone(),
+        }
+    }
+}
+
+impl RunnerInformationArchive {
+    fn to_runner_information(&self, config: &Arc<VerifierConfig>) -> RunnerInformation {
+        let mut res = RunnerInformation::new(config.clone());
+        res.election_event_id = self.election_event_id.clone();
+        res.seed = self.seed.clone();
+        res.start_time = self.start_time;
+        res.duration = self.duration;
+        res.audit_log_head = self.audit_log_head.clone();
+        res
+    }
+}
+
+/// [RunInformation] as archived (see [RunInformation::to_json])
+///
+/// The configuration is not archived. The effective configuration at the start of the run, the metadata
+/// of the verifications and the manual verifications of a finished run are archived
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RunInformationArchive {
+    version: u32,
+    verification_period: Option<VerificationPeriod>,
+    extracted_dataset_result: Option<ExtractDataSetResultsArchive>,
+    excluded_verifications: Vec<String>,
+    verifications_status: HashMap<String, VerificationStatus>,
+    verifications_with_errors_and_failures: VerficationsWithErrorAndFailures,
+    verifications_informations: HashMap<String, Vec<String>>,
+    verifications_performance: HashMap<String, VerificationPerformance>,
+    verifications_events: HashMap<String, Vec<VerificationEvent>>,
+    verifications_history: HashMap<String, Vec<VerificationAttemptInformation>>,
+    runner_information: RunnerInformationArchive,
+    #[serde(default)]
+    runner_history: Vec<RunnerInformationArchive>,
+    #[serde(default)]
+    effective_configuration: Vec<EffectiveConfigValue>,
+    /// `None` for the archives without the metadata, which are then loaded from the configuration
+    #[serde(default)]
+    verification_metadata: Option<VerificationMetaDataList>,
+    /// Blocks of the report with the manual verifications, read from the datasets of a finished run
+    #[serde(default)]
+    manual_verifications: Option<Vec<ReportOutputDataBlock>>,
+}
 
 /// This structure contains all the information about the actual run.
 ///
 /// It helps, the collect the necessary information outside the runner, and to avoid the borrow of mutable borrow.
 pub struct RunInformation {
-    config: &'static VerifierConfig,
+    config: Arc<VerifierConfig>,
     verification_period: Option<VerificationPeriod>,
     extracted_dataset_result: Option<ExtractDataSetResults>,
     verification_metadata: Option<VerificationMetaDataList>,
     excluded_verifications: Vec<String>,
     verifications_status: HashMap<String, VerificationStatus>,
     verifications_with_errors_and_failures: VerficationsWithErrorAndFailures,
+    verifications_informations: HashMap<String, Vec<String>>,
+    verifications_performance: HashMap<String, VerificationPerformance>,
+    verifications_events: HashMap<String, Vec<VerificationEvent>>,
+    verifications_history: HashMap<String, Vec<VerificationAttemptInformation>>,
     runner_information: RunnerInformation,
+    runner_history: Vec<RunnerInformation>,
+    effective_configuration: Vec<EffectiveConfigValue>,
+    /// Manual verifications loaded from the archive (see [Self::manual_verifications_blocks])
+    archived_manual_verifications: Option<Vec<ReportOutputDataBlock>>,
 }
 
 impl RunInformation {
     /// New [RunInformation] containung the config
-    pub fn new(config: &'static VerifierConfig) -> Self {
+    pub fn new(config: Arc<VerifierConfig>) -> Self {
         Self {
-            config,
+            config: config.clone(),
             verification_period: None,
             extracted_dataset_result: None,
             verification_metadata: None,
             verifications_status: HashMap::default(),
             excluded_verifications: vec![],
             verifications_with_errors_and_failures: VerficationsWithErrorAndFailures::default(),
+            verifications_informations: HashMap::default(),
+            verifications_performance: HashMap::default(),
+            verifications_events: HashMap::default(),
+            verifications_history: HashMap::default(),
             runner_information: RunnerInformation::new(config),
+            runner_history: vec![],
+            effective_configuration: vec![],
+            archived_manual_verifications: None,
         }
     }
 
@@ -68,24 +206,19 @@ impl RunInformation {
         self.verification_period = Some(verification_period);
         self.verification_metadata = Some(verification_metadata.clone());
         self.excluded_verifications = excluded_verifications.to_vec();
-        let all_verifs = match verification_period {
-            VerificationPeriod::Setup => {
-                get_verifications_setup(verification_metadata, self.config).map_err(|e| {
-                    RunnerErrorImpl::CollectVerifications {
+        let all_verifs =
+            match verification_period {
+                VerificationPeriod::Setup => get_verifications_setup(verification_metadata)
+                    .map_err(|e| RunnerErrorImpl::CollectVerifications {
                         period: verification_period,
                         source: Box::new(e),
-                    }
-                })?
-            }
-            VerificationPeriod::Tally => {
-                get_verifications_tally(verification_metadata, self.config).map_err(|e| {
-                    RunnerErrorImpl::CollectVerifications {
+                    })?,
+                VerificationPeriod::Tally => get_verifications_tally(verification_metadata)
+                    .map_err(|e| RunnerErrorImpl::CollectVerifications {
                         period: verification_period,
                         source: Box::new(e),
-                    }
-                })?
-            }
-        };
+                    })?,
+            };
         self.verifications_status = all_verifs
             .0
             .iter()
@@ -107,12 +240,47 @@ impl RunInformation {
     }
 
     /// Update information starting the runner
+    ///
+    /// If the runner has already finished (run again), the information of the previous run is moved
+    /// to the history (see [Self::runner_history]). The effective configuration is collected
+    /// (see [Self::effective_configuration])
     pub fn start_running(&mut self, start_time: &SystemTime) {
-        self.runner_information.start_time = Some(*start_time)
+        if self.runner_information.is_finished() {
+            self.runner_history.push(self.runner_information.clone());
+        }
+        self.runner_information.start_time = Some(*start_time);
+        self.runner_information.duration = None;
+        self.effective_configuration = self.config.effective_configuration();
     }
 
     /// Update information starting the given verification if the id
+    ///
+    /// If the verification has already finished (run again), the information of the previous
+    /// attempt is moved to the history (see [Self::verifications_history])
     pub fn start_verification(&mut self, id: &str) {
+        if let Some(status) = self.verifications_status.get(id).copied()
+            && !matches!(
+                status,
+                VerificationStatus::NotStarted | VerificationStatus::Running
+            )
+        {
+            let (errors, failures) = self
+                .verifications_with_errors_and_failures
+                .remove(id)
+                .unwrap_or_default();
+            self.verifications_events.remove(id);
+            self.verifications_informations.remove(id);
+            let attempt = VerificationAttemptInformation {
+                status,
+                errors,
+                failures,
+                performance: self.verifications_performance.remove(id),
+            };
+            self.verifications_history
+                .entry(id.to_string())
+                .or_default()
+                .push(attempt);
+        }
         self.update_verif_status(id, VerificationStatus::Running);
     }
 
@@ -122,8 +290,28 @@ impl RunInformation {
         if verif_info.status != VerificationStatus::FinishedSuccessfully {
             self.verifications_with_errors_and_failures.insert(
                 verif_info.id.clone(),
-                verif_info.errors.clone(),
-                verif_info.failures.clone(),
+                verif_info.errors(),
+                verif_info.failures(),
+            );
+        }
+        let events = verif_info.errors_and_failures();
+        if !events.is_empty() {
+            self.verifications_events
+                .insert(verif_info.id.clone(), events.into_iter().cloned().collect());
+        }
+        let informations = verif_info.informations();
+        if !informations.is_empty() {
+            self.verifications_informations
+                .insert(verif_info.id.clone(), informations);
+        }
+        if let Some(duration) = verif_info.duration {
+            self.verifications_performance.insert(
+                verif_info.id.clone(),
+                VerificationPerformance {
+                    duration,
+                    process_peak_memory: verif_info.process_peak_memory,
+                    items_checked: verif_info.items_checked(),
+                },
             );
         }
     }
@@ -159,8 +347,8 @@ impl RunInformation {
     }
 
     /// Configuration of the verifier
-    pub fn config(&self) -> &'static VerifierConfig {
-        self.config
+    pub fn config(&self) -> &Arc<VerifierConfig> {
+        &self.config
     }
 
     /// Verification period
@@ -224,14 +412,240 @@ impl RunInformation {
         &self.verifications_with_errors_and_failures
     }
 
+    /// Hashmap of the informations of the verifications (e.g. the certificates matching the signatures)
+    ///
+    /// Key of the [HashMap] is the id of the verification. Only the verifications with informations are contained
+    pub fn verifications_informations(&self) -> &HashMap<String, Vec<String>> {
+        &self.verifications_informations
+    }
+
+    /// Hashmap of the performance (duration, process peak memory, items checked) of the finished verifications
+    ///
+    /// Key of the [HashMap] is the id of the verification
+    pub fn verifications_performance(&self) -> &HashMap<String, VerificationPerformance> {
+        &self.verifications_performance
+    }
+
+    /// Errors and failures of the verifications as [VerificationEvent]
+    ///
+    /// Key of the [HashMap] is the id of the verification. Only the verifications with
+    /// errors or failures are contained
+    pub fn verifications_events(&self) -> &HashMap<String, Vec<VerificationEvent>> {
+        &self.verifications_events
+    }
+
+    /// Previous attempts of the verifications run again, from the oldest to the newest
+    ///
+    /// Key of the [HashMap] is the id of the verification. Only the verifications run again are contained
+    pub fn verifications_history(&self) -> &HashMap<String, Vec<VerificationAttemptInformation>> {
+        &self.verifications_history
+    }
+
     /// Information about the runner
+    ///
+    /// If the verifications have been run again, the information of the last run
     pub fn runner_information(&self) -> &RunnerInformation {
         &self.runner_information
     }
 
+    /// The effective configuration of the verifier at the start of the last run
+    ///
+    /// Empty if the run has not started
+    pub fn effective_configuration(&self) -> &[EffectiveConfigValue] {
+        &self.effective_configuration
+    }
+
+    /// Information about the previous runs of the runner, from the oldest to the newest
+    ///
+    /// Empty if no verification has been run again
+    pub fn runner_history(&self) -> &[RunnerInformation] {
+        &self.runner_history
+    }
+
     /// The directory where the datasets (decrypted and unzipped) are stored
-    pub fn run_directory(&self) -> &Path {
-        self.extracted_dataset_result.as_ref().unwrap().location()
+    ///
+    /// `None` if the datasets are not extracted. For a reloaded run, the directory may not exist anymore
+    pub fn run_directory(&self) -> Option<&Path> {
+        self.extracted_dataset_result
+            .as_ref()
+            .map(|extracted| extracted.location())
+    }
+
+    /// The directory of the run, which must exist (see [Self::run_directory])
+    pub(crate) fn existing_run_directory(&self) -> Result<&Path, RunnerError> {
+        let path = self
+            .run_directory()
+            .ok_or(RunnerErrorImpl::NoExtractedDatasets)?;
+        match path.is_dir() {
+            true => Ok(path),
+            false => Err(RunnerError::from(RunnerErrorImpl::RunDirectoryMissing {
+                path: path.to_path_buf(),
+            })),
+        }
+    }
+
+    /// Blocks of the report with the manual verifications
+    ///
+    /// The blocks are read from the datasets, as long as the directory of the run exists. Otherwise the
+    /// blocks archived with the run are used (see [Self::to_json]).
+    pub(crate) fn manual_verifications_blocks(
+        &self,
+    ) -> Result<Vec<ReportOutputDataBlock>, RunnerError> {
+        match (
+            self.existing_run_directory(),
+            &self.archived_manual_verifications,
+        ) {
+            (Err(_), Some(blocks)) => Ok(blocks.clone()),
+            _ => ManualVerifications::<VerificationDirectory>::try_from(self)
+                .map(|manual| manual_verifications_to_blocks(&manual)),
+        }
+    }
+
+    /// Serialize the information to json, e.g. to browse the results after the application is closed
+    ///
+    /// The configuration is not serialized (see [Self::from_json]). The effective configuration at the
+    /// start of the run (see [Self::effective_configuration]) and the metadata of the verifications are
+    /// serialized. For a finished run, the manual verifications are serialized too (see
+    /// [Self::manual_verifications_blocks]), so that the report can be generated after the datasets
+    /// are deleted. If they cannot be read, they are missing in the archive.
+    pub fn to_json(&self) -> Result<String, RunnerError> {
+        let manual_verifications = match self.is_finished() {
+            true => self.manual_verifications_blocks().ok(),
+            false => None,
+        };
+        let archive = RunInformationArchive {
+            version: RUN_INFORMATION_ARCHIVE_VERSION,
+            verification_period: self.verification_period,
+            extracted_dataset_result: self
+                .extracted_dataset_result
+                .as_ref()
+                .map(ExtractDataSetResultsArchive::from),
+            excluded_verifications: self.excluded_verifications.clone(),
+            verifications_status: self.verifications_status.clone(),
+            verifications_with_errors_and_failures: self
+                .verifications_with_errors_and_failures
+                .clone(),
+            verifications_informations: self.verifications_informations.clone(),
+            verifications_performance: self.verifications_performance.clone(),
+            verifications_events: self.verifications_events.clone(),
+            verifications_history: self.verifications_history.clone(),
+            runner_information: RunnerInformationArchive::from(&self.runner_information),
+            runner_history: self
+                .runner_history
+                .iter()
+                .map(RunnerInformationArchive::from)
+                .collect(),
+            effective_configuration: self.effective_configuration.clone(),
+            verification_metadata: self.verification_metadata.clone(),
+            manual_verifications,
+        };
+        serde_json::to_string(&archive)
+            .map_err(|e| RunnerErrorImpl::RunInformationToJson { source: e })
+            .map_err(RunnerError::from)
+    }
+
+    /// Load the information serialized with [Self::to_json]
+    ///
+    /// The metadata of the verifications are loaded from the configuration, if they are not archived.
+    /// The information of a finished run can be used directly with [crate::report::ReportData], also
+    /// when the directory of the run (see [Self::run_directory]) does not exist anymore, except for the
+    /// election results, which are read from the datasets.
+    ///
+    /// Return an error if the run is finished, but the extracted datasets are missing
+    pub fn from_json(config: Arc<VerifierConfig>, json: &str) -> Result<Self, RunnerError> {
+        Self::from_json_impl(config, json).map_err(RunnerError::from)
+    }
+
+    fn from_json_impl(config: Arc<VerifierConfig>, json: &str) -> Result<Self, RunnerErrorImpl> {
+        let archive: RunInformationArchive = serde_json::from_str(json)
+            .map_err(|e| RunnerErrorImpl::RunInformationFromJson { source: e })?;
+        if archive.version != RUN_INFORMATION_ARCHIVE_VERSION {
+            return Err(RunnerErrorImpl::RunInformationArchive {
+                msg: format!("Unsupported version {}", archive.version),
+            });
+        }
+        if archive.runner_information.duration.is_some()
+            && archive.extracted_dataset_result.is_none()
+        {
+            return Err(RunnerErrorImpl::RunInformationArchive {
+                msg: "The run is finished, but the extracted datasets are missing".to_string(),
+            });
+        }
+        let verification_metadata =
+            match (archive.verification_metadata, archive.verification_period) {
+                (Some(metadata), _) => Some(metadata),
+                (None, Some(_)) => Some(
+                    VerificationMetaDataList::load(config.get_verification_list_str()).map_err(
+                        |e| RunnerErrorImpl::LoadMetadata {
+                            source: Box::new(e),
+                        },
+                    )?,
+                ),
+                (None, None) => None,
+            };
+        let extracted_dataset_result = archive
+            .extracted_dataset_result
+            .map(ExtractDataSetResults::try_from)
+            .transpose()?;
+        if let (Some(period), Some(extracted)) =
+            (archive.verification_period, &extracted_dataset_result)
+        {
+            let kinds = match period {
+                VerificationPeriod::Setup => vec![DatasetTypeKind::Context],
+                VerificationPeriod::Tally => vec![DatasetTypeKind::Context, DatasetTypeKind::Tally],
+            };
+            if let Some(kind) = kinds
+                .iter()
+                .find(|kind| extracted.dataset_metadata(kind).is_none())
+            {
+                return Err(RunnerErrorImpl::RunInformationArchive {
+                    msg: format!("The extracted dataset {} is missing", kind.as_ref()),
+                });
+            }
+        }
+        let runner_information = archive.runner_information.to_runner_information(&config);
+        let runner_history = archive
+            .runner_history
+            .iter()
+            .map(|r| r.to_runner_information(&config))
+            .collect();
+        Ok(Self {
+            config,
+            verification_period: archive.verification_period,
+            extracted_dataset_result,
+            verification_metadata,
+            excluded_verifications: archive.excluded_verifications,
+            verifications_status: archive.verifications_status,
+            verifications_with_errors_and_failures: archive.verifications_with_errors_and_failures,
+            verifications_informations: archive.verifications_informations,
+            verifications_performance: archive.verifications_performance,
+            verifications_events: archive.verifications_events,
+            verifications_history: archive.verifications_history,
+            runner_information,
+            runner_history,
+            effective_configuration: archive.effective_configuration,
+            archived_manual_verifications: archive.manual_verifications,
+        })
+    }
+
+    /// Save the information in the file as json (see [Self::to_json])
+    pub fn save(&self, path: &Path) -> Result<(), RunnerError> {
+        std::fs::write(path, self.to_json()?)
+            .map_err(|e| RunnerErrorImpl::RunInformationIO {
+                msg: format!("Error writing the run information to {}", path.display()),
+                source: e,
+            })
+            .map_err(RunnerError::from)
+    }
+
+    /// Load the information saved in the file with [Self::save] (see [Self::from_json])
+    pub fn load(config: Arc<VerifierConfig>, path: &Path) -> Result<Self, RunnerError> {
+        let json =
+            std::fs::read_to_string(path).map_err(|e| RunnerErrorImpl::RunInformationIO {
+                msg: format!("Error reading the run information from {}", path.display()),
+                source: e,
+            })?;
+        Self::from_json(config, &json)
     }
 }
 
@@ -239,19 +653,19 @@ impl TryFrom<&RunInformation> for ManualVerifications<VerificationDirectory> {
     type Error = RunnerError;
 
     fn try_from(value: &RunInformation) -> Result<Self, Self::Error> {
-        if !value.is_prepared() {
-            return Err(RunnerError::from(
-                RunnerErrorImpl::ManualRunInformationNotPrepared,
-            ));
-        }
-        let dir = VerificationDirectory::new(
-            value.verification_period.as_ref().unwrap(),
-            value.run_directory(),
-        );
+        let period = match (value.is_prepared(), value.verification_period) {
+            (true, Some(period)) => period,
+            _ => {
+                return Err(RunnerError::from(
+                    RunnerErrorImpl::ManualRunInformationNotPrepared,
+                ));
+            }
+        };
+        let dir = VerificationDirectory::new(&period, value.existing_run_directory()?);
         Self::try_new(
-            value.verification_period.unwrap(),
+            period,
             Arc::new(dir),
-            value.config,
+            &value.config,
             &value.verifications_status,
             value.verifications_with_errors_and_failures(),
             &value.excluded_verifications,
@@ -262,3 +676,210 @@ impl TryFrom<&RunInformation> for ManualVerifications<VerificationDirectory> {
         .map_err(RunnerError::from)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_ev_verifier_lib::verification::{VerificationEventKind, VerificationResult};
+
+    const NOT_EXISTING_RUN_DIRECTORY: &str = "./not_existing_run_directory";
+
+    /// Extracted context in the directory, that does not need to exist
+    fn extracted_context(run_directory: &Path) -> ExtractDataSetResults {
+        let archive: ExtractDataSetResultsArchive = serde_json::from_value(serde_json::json!({
+            "location": run_directory,
+            "datasets": [{
+                "kind": "context",
+                "source_path": "context.zip",
+                "decrypted_zip_path": run_directory.join("context.zip"),
+                "extracted_dir_path": run_directory.join("context"),
+                "fingerprint": "00FF"
+            }]
+        }))
+        .unwrap();
+        ExtractDataSetResults::try_from(archive).unwrap()
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let config = Arc::new(VerifierConfig::default());
+        let mut ri = RunInformation::new(config.clone());
+        ri.verification_period = Some(VerificationPeriod::Setup);
+        ri.verification_metadata = Some(
+            VerificationMetaDataList::load_period(
+                config.get_verification_list_str(),
+                &VerificationPeriod::Setup,
+            )
+            .unwrap(),
+        );
+        ri.excluded_verifications = vec!["05.01".to_string()];
+        ri.verifications_status = HashMap::from([
+            (
+                "01.01".to_string(),
+                VerificationStatus::FinishedSuccessfully,
+            ),
+            ("02.01".to_string(), VerificationStatus::FinishedWithErrors),
+        ]);
+        ri.verifications_with_errors_and_failures.insert(
+            "02.01",
+            vec!["file missing".to_string()],
+            vec![],
+        );
+        ri.verifications_events.insert(
+            "02.01".to_string(),
+            vec![VerificationEvent::new(
+                VerificationEventKind::Error,
+                "file missing",
+            )],
+        );
+        ri.verifications_performance.insert(
+            "01.01".to_string(),
+            VerificationPerformance {
+                duration: Duration::from_millis(1500),
+                process_peak_memory: Some(1024),
+                items_checked: None,
+            },
+        );
+        ri.runner_information.seed = Some("seed".to_string());
+        ri.runner_information.start_time = Some(SystemTime::UNIX_EPOCH);
+        ri.runner_information.duration = Some(Duration::from_secs(10));
+        // A finished run must have the extracted datasets
+        assert!(RunInformation::from_json(config.clone(), &ri.to_json().unwrap()).is_err());
+        let run_directory = Path::new(NOT_EXISTING_RUN_DIRECTORY);
+        ri.extracted_dataset_result = Some(extracted_context(run_directory));
+        let loaded = RunInformation::from_json(config, &ri.to_json().unwrap()).unwrap();
+        assert!(loaded.is_finished());
+        assert_eq!(
+            loaded.verification_period(),
+            Some(VerificationPeriod::Setup)
+        );
+        // The archived metadata are used, not the metadata of the configuration
+        assert_eq!(
+            loaded.verification_metadata().unwrap().len(),
+            ri.verification_metadata().unwrap().len()
+        );
+        assert!(
+            loaded
+                .verification_metadata()
+                .unwrap()
+                .id_list_for_period(&VerificationPeriod::Tally)
+                .is_empty()
+        );
+        assert_eq!(loaded.run_directory(), Some(run_directory));
+        // The directory of the run does not exist anymore
+        let err = ManualVerifications::<VerificationDirectory>::try_from(&loaded)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("does not exist anymore"), "{err}");
+        // The manual verifications could not be archived
+        assert!(loaded.manual_verifications_blocks().is_err());
+        assert_eq!(loaded.excluded_verifications(), ["05.01"]);
+        assert_eq!(loaded.verifications_status(), ri.verifications_status());
+        assert_eq!(
+            loaded
+                .verifications_with_errors_and_failures()
+                .has_errors("02.01"),
+            Some(true)
+        );
+        assert_eq!(
+            loaded.verifications_events()["02.01"][0].to_string(),
+            ri.verifications_events()["02.01"][0].to_string()
+        );
+        assert_eq!(
+            loaded.verifications_performance(),
+            ri.verifications_performance()
+        );
+        assert_eq!(loaded.runner_information().seed.as_deref(), Some("seed"));
+        assert_eq!(
+            loaded.runner_information().stop_time(),
+            ri.runner_information().stop_time()
+        );
+    }
+
+    #[test]
+    fn test_rerun_history() {
+        let config = Arc::new(VerifierConfig::default());
+        let mut ri = RunInformation::new(config.clone());
+        ri.verifications_status =
+            HashMap::from([("01.01".to_string(), VerificationStatus::NotStarted)]);
+        let mut result = VerificationResult::new();
+        result.push(VerificationEvent::new_error("file missing"));
+        let first_start = SystemTime::UNIX_EPOCH;
+        ri.start_running(&first_start);
+        assert_eq!(
+            ri.effective_configuration(),
+            config.effective_configuration()
+        );
+        ri.start_verification("01.01");
+        ri.finish_verification(&VerificationRunInformation {
+            id: "01.01".to_string(),
+            status: VerificationStatus::FinishedWithErrors,
+            duration: Some(Duration::from_secs(1)),
+            process_peak_memory: None,
+            result,
+        });
+        let mut runner_information = RunnerInformation::new(config.clone());
+        runner_information.start_time = Some(first_start);
+        runner_information.duration = Some(Duration::from_secs(10));
+        ri.finish_runner(&runner_information);
+        assert!(ri.is_finished());
+        let second_start = first_start + Duration::from_secs(100);
+        ri.start_running(&second_start);
+        assert!(ri.is_running());
+        assert!(!ri.is_finished());
+        assert_eq!(ri.runner_history().len(), 1);
+        ri.start_verification("01.01");
+        assert!(
+            ri.verifications_with_errors_and_failures()
+                .has_errors("01.01")
+                .is_none()
+        );
+        ri.finish_verification(&VerificationRunInformation {
+            id: "01.01".to_string(),
+            status: VerificationStatus::FinishedSuccessfully,
+            duration: Some(Duration::from_secs(1)),
+            process_peak_memory: None,
+            result: VerificationResult::new(),
+        });
+        runner_information.start_time = Some(second_start);
+        runner_information.duration = Some(Duration::from_secs(2));
+        ri.finish_runner(&runner_information);
+        assert!(ri.is_finished());
+        assert_eq!(ri.runner_information().start_time(), Some(second_start));
+        assert_eq!(ri.runner_history()[0].start_time(), Some(first_start));
+        assert_eq!(
+            ri.runner_history()[0].duration(),
+            Some(Duration::from_secs(10))
+        );
+        let attempts = &ri.verifications_history()["01.01"];
+        assert_eq!(attempts.len(), 1);
+        assert_eq!(attempts[0].status, VerificationStatus::FinishedWithErrors);
+        assert_eq!(attempts[0].errors.len(), 1);
+        ri.extracted_dataset_result =
+            Some(extracted_context(Path::new(NOT_EXISTING_RUN_DIRECTORY)));
+        let loaded = RunInformation::from_json(config, &ri.to_json().unwrap()).unwrap();
+        assert_eq!(
+            loaded.runner_history()[0].stop_time(),
+            ri.runner_history()[0].stop_time()
+        );
+        assert_eq!(loaded.verifications_history()["01.01"].len(), 1);
+        assert_eq!(
+            loaded.effective_configuration(),
+            ri.effective_configuration()
+        );
+    }
+
+    #[test]
+    fn test_from_json_wrong_version() {
+        let config = Arc::new(VerifierConfig::default());
+        let json = RunInformation::new(config.clone())
+            .to_json()
+            .unwrap()
+            .replace(
+                &format!("\"version\":{RUN_INFORMATION_ARCHIVE_VERSION}"),
+                "\"version\":0",
+            );
+        assert!(RunInformation::from_json(config, &json).is_err());
+    }
+}
@@ -14,23 +14,41 @@
 // a copy of the GNU General Public License along with this program. If not, see
 // <https://www.gnu.org/licenses/>.
 
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, NaiveDate};
 //use futures::{stream::FuturesUnordered, StreamExt};
 use crate::RunnerErrorImpl;
 use rust_ev_verifier_lib::{
-    VerifierConfig,
+    VerifierConfig, VerifierConfigError,
+    direct_trust::SignatureVerificationCache,
     file_structure::{ContextDirectoryTrait, VerificationDirectory, VerificationDirectoryTrait},
-    startup_checks::{check_complete, check_verification_dir, start_check},
+    startup_checks::{
+        check_complete, check_verification_dir, start_check, start_check_with_shared_state,
+    },
     verification::{
-        VerificationMetaDataList, VerificationPeriod, VerificationStatus, VerificationSuite,
+        RunContext, SharedRunState, VerificationCategory, VerificationEvent,
+        VerificationMetaDataList, VerificationPeriod, VerificationResult, VerificationStatus,
+        VerificationSuite,
     },
 };
-use tracing::{info, warn};
+use tracing::{error, info, warn};
 
 //use std::future::Future;
-use super::{RunnerError, prepare_fixed_based_optimization};
-use rayon::prelude::*;
-use std::{iter::zip, sync::Mutex};
+use super::{
+    AuditEvent, AuditLogError, AuditLogHead, RunAudit, RunInformation, RunnerError, RunnerEvent,
+    RunnerEventBroadcaster, VerificationSchedule, prepare_fixed_based_optimization,
+};
+use derive_builder::Builder;
+use derive_getters::Getters;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc::Receiver,
+    },
+};
 use std::{
     path::{Path, PathBuf},
     time::{Duration, SystemTime},
@@ -42,35 +60,69 @@ pub fn no_action_after_fn(_: VerificationRunInformation) {}
 pub fn no_action_after_runner_fn(_: RunnerInformation) {}
 
 /// Srtucture to collect the information of the run of a verification
+///
+/// The errors, the failures and the other information are taken from the [VerificationResult]
 pub struct VerificationRunInformation {
     /// id of the verification
     pub id: String,
     /// status of the verification
     pub status: VerificationStatus,
-    /// List of failures as [String]
-    pub failures: Vec<String>,
+    /// Duration of the verification
+    pub duration: Option<Duration>,
+    /// Peak memory of the whole process in bytes at the end of the verification (only on Linux)
+    pub process_peak_memory: Option<u64>,
+    /// Full result of the verification
+    pub result: VerificationResult,
+}
+
+impl VerificationRunInformation {
     /// List of errors as [String]
-    pub errors: Vec<String>,
+    pub fn errors(&self) -> Vec<String> {
+        self.result.errors_to_string()
+    }
+
+    /// List of failures as [String]
+    pub fn failures(&self) -> Vec<String> {
+        self.result.failures_to_string()
+    }
+
+    /// List of informations as [String] (e.g. the certificates matching the signatures)
+    pub fn informations(&self) -> Vec<String> {
+        self.result.informations_to_string()
+    }
+
+    /// Errors and failures as [VerificationEvent], with the context chain and the location
+    pub fn errors_and_failures(&self) -> Vec<&VerificationEvent> {
+        self.result.errors_and_failures()
+    }
+
+    /// Number of items checked by the verification (if available)
+    pub fn items_checked(&self) -> Option<usize> {
+        self.result.items_checked()
+    }
 }
 
 /// Information of the runner, that can be used to know some information about the runner.
 #[derive(Clone)]
 pub struct RunnerInformation {
-    config: &'static VerifierConfig,
+    config: Arc<VerifierConfig>,
     pub election_event_id: Option<String>,
     pub seed: Option<String>,
     pub start_time: Option<SystemTime>,
     pub duration: Option<Duration>,
+    /// Head of the audit log at the end of the run, if the run is audited (see [Runner::set_audit])
+    pub audit_log_head: Option<AuditLogHead>,
 }
 
 impl RunnerInformation {
-    pub fn new(config: &'static VerifierConfig) -> Self {
+    pub fn new(config: Arc<VerifierConfig>) -> Self {
         Self {
             config,
             election_event_id: None,
             seed: None,
             start_time: None,
             duration: None,
+            audit_log_head: None,
         }
     }
 
@@ -143,8 +195,11 @@ impl RunnerInformation {
 pub trait RunStrategy<'a> {
     /// Run function
     ///
-    /// - `verifications`: The suite of verifications, which will be modified during the run
+    /// - `verifications`: The suite of verifications, which will be modified during the run.
+    ///   Only the verifications not started are run (all by the first run, the reset verifications by a rerun)
     /// - `directory`: Verification directoy containing the datasets extracted
+    /// - `context`: Context of the run (keystore and cache of the signatures), given to each verification
+    /// - `cancellation`: The verifications not started when the run is cancelled must not be started
     /// - `action_before_verification`:
     ///   Function that will be call before the run of each verification. As parameter take the id of the verification
     /// - `action_after_verification`:
@@ -152,37 +207,227 @@ pub trait RunStrategy<'a> {
     ///   As parameter take the information regarding the run of the verification
     fn run(
         &self,
-        verifications: &'a mut VerificationSuite<'a>,
+        verifications: &mut VerificationSuite<'a>,
         directory: &VerificationDirectory,
+        context: &RunContext,
+        cancellation: &RunCancellation,
         action_before_verification: impl Fn(&str) + Send + Sync,
         action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync,
     );
 }
 
 /// Strategy to run the tests sequentially
+#[derive(Clone, Copy)]
 pub struct RunSequential;
 
 /// Strategy to run the tests concurrently
+#[derive(Clone, Copy)]
 pub struct RunParallel;
 
+/// Strategy to run the tests concurrently, starting with the longest verifications
+///
+/// The verifications are started from the longest to the shortest expected duration
+/// (see [VerificationSchedule]), to minimise the total time of the run on multi-core machines.
+#[derive(Clone)]
+pub struct RunParallelLongestFirst {
+    schedule: Arc<VerificationSchedule>,
+}
+
+impl RunParallelLongestFirst {
+    /// Create the strategy with the schedule
+    pub fn new(schedule: VerificationSchedule) -> Self {
+        Self {
+            schedule: Arc::new(schedule),
+        }
+    }
+
+    /// The schedule of the strategy
+    pub fn schedule(&self) -> &VerificationSchedule {
+        &self.schedule
+    }
+}
+
+/// Strategy to run the tests sequentially, stopping after the first verification with errors or failures
+///
+/// The verifications not run keep the status [VerificationStatus::NotStarted].
+#[derive(Clone, Copy)]
+pub struct RunFailFast;
+
+/// Order of the categories run by [RunCategoryGates]
+pub const CATEGORY_GATES: [VerificationCategory; 5] = [
+    VerificationCategory::Completness,
+    VerificationCategory::Authenticity,
+    VerificationCategory::Integrity,
+    VerificationCategory::Consistency,
+    VerificationCategory::Evidence,
+];
+
+/// Strategy to run the categories as gates, stopping at the first failing category
+///
+/// The categories are run in the order of [CATEGORY_GATES]. The verifications of a category run
+/// concurrently. If a verification of the category has errors or failures, the verifications of
+/// the next categories are not run and keep the status [VerificationStatus::NotStarted].
+#[derive(Clone, Copy)]
+pub struct RunCategoryGates;
+
+/// Token to cancel a run from another thread (see [Runner::cancellation])
+///
+/// The verifications already started are finished. The verifications not started keep
+/// the status [VerificationStatus::NotStarted] and can be run later with [Runner::rerun].
+#[derive(Debug, Clone, Default)]
+pub struct RunCancellation(Arc<AtomicBool>);
+
+impl RunCancellation {
+    /// Request the cancellation of the run
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst)
+    }
+
+    /// The cancellation has been requested
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Clear the request, e.g. before a rerun
+    pub fn reset(&self) {
+        self.0.store(false, Ordering::SeqCst)
+    }
+}
+
 impl<'a> RunStrategy<'a> for RunSequential {
     fn run(
         &self,
-        verifications: &'a mut VerificationSuite<'a>,
+        verifications: &mut VerificationSuite<'a>,
         directory: &VerificationDirectory,
+        context: &RunContext,
+        cancellation: &RunCancellation,
         action_before_verification: impl Fn(&str) + Send + Sync,
         action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync,
     ) {
-        let it = verifications.verifications_mut().0.iter_mut();
-        for v in it {
-            action_before_verification(v.id());
-            v.run(directory);
-            action_after_verification(VerificationRunInformation {
-                id: v.id().to_string(),
-                status: v.status(),
-                failures: v.verification_result().errors_to_string(),
-                errors: v.verification_result().failures_to_string(),
-            });
+        run_sequential(
+            verifications,
+            directory,
+            context,
+            cancellation,
+            false,
+            action_before_verification,
+            action_after_verification,
+        )
+    }
+}
+
+impl<'a> RunStrategy<'a> for RunFailFast {
+    fn run(
+        &self,
+        verifications: &mut VerificationSuite<'a>,
+        directory: &VerificationDirectory,
+        context: &RunContext,
+        cancellation: &RunCancellation,
+        action_before_verification: impl Fn(&str) + Send + Sync,
+        action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync,
+    ) {
+        run_sequential(
+            verifications,
+            directory,
+            context,
+            cancellation,
+            true,
+            action_before_verification,
+            action_after_verification,
+        )
+    }
+}
+
+impl<'a> RunStrategy<'a> for RunCategoryGates {
+    fn run(
+        &self,
+        verifications: &mut VerificationSuite<'a>,
+        directory: &VerificationDirectory,
+        context: &RunContext,
+        cancellation: &RunCancellation,
+        action_before_verification: impl Fn(&str) + Send + Sync,
+        action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync,
+    ) {
+        for category in CATEGORY_GATES {
+            run_parallel(
+                verifications,
+                directory,
+                context,
+                cancellation,
+                None,
+                None,
+                Some(category),
+                &action_before_verification,
+                &action_after_verification,
+            );
+            if cancellation.is_cancelled() {
+                break;
+            }
+            if has_category_errors_or_failures(verifications, category) {
+                let not_run = verifications
+                    .collect_id_with_status(&[VerificationStatus::NotStarted])
+                    .len();
+                warn!(
+                    "Verifications of the category {} with errors or failures. {} verifications not run",
+                    category.as_ref(),
+                    not_run
+                );
+                break;
+            }
+        }
+    }
+}
+
+/// At least one verification of the category has errors or failures
+fn has_category_errors_or_failures(
+    verifications: &VerificationSuite<'_>,
+    category: VerificationCategory,
+) -> bool {
+    verifications
+        .verifications()
+        .0
+        .iter()
+        .filter(|v| *v.meta_data().category() == category)
+        .any(|v| v.is_ok() == Some(false))
+}
+
+/// Run the verifications sequentially
+///
+/// If `stop_on_errors_or_failures` is `true`, the run stops after the first verification with
+/// errors or failures
+fn run_sequential(
+    verifications: &mut VerificationSuite<'_>,
+    directory: &VerificationDirectory,
+    context: &RunContext,
+    cancellation: &RunCancellation,
+    stop_on_errors_or_failures: bool,
+    action_before_verification: impl Fn(&str) + Send + Sync,
+    action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync,
+) {
+    let it = verifications
+        .verifications_mut()
+        .0
+        .iter_mut()
+        .filter(|v| v.status() == VerificationStatus::NotStarted);
+    for v in it {
+        if cancellation.is_cancelled() {
+            break;
+        }
+        action_before_verification(v.id());
+        v.run(directory, context);
+        action_after_verification(VerificationRunInformation {
+            id: v.id().to_string(),
+            status: v.status(),
+            duration: v.duration(),
+            process_peak_memory: v.process_peak_memory(),
+            result: v.verification_result().clone(),
+        });
+        if stop_on_errors_or_failures && v.is_ok() == Some(false) {
+            warn!(
+                "Verification {} with errors or failures. The next verifications are not run",
+                v.id()
+            );
+            break;
         }
     }
 }
@@ -190,53 +435,450 @@ impl<'a> RunStrategy<'a> for RunSequential {
 impl<'a> RunStrategy<'a> for RunParallel {
     fn run(
         &self,
-        verifications: &'a mut VerificationSuite<'a>,
+        verifications: &mut VerificationSuite<'a>,
+        directory: &VerificationDirectory,
+        context: &RunContext,
+        cancellation: &RunCancellation,
+        action_before_verification: impl Fn(&str) + Send + Sync,
+        action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync,
+    ) {
+        run_parallel(
+            verifications,
+            directory,
+            context,
+            cancellation,
+            None,
+            None,
+            None,
+            action_before_verification,
+            action_after_verification,
+        )
+    }
+}
+
+impl<'a> RunStrategy<'a> for RunParallelLongestFirst {
+    fn run(
+        &self,
+        verifications: &mut VerificationSuite<'a>,
         directory: &VerificationDirectory,
+        context: &RunContext,
+        cancellation: &RunCancellation,
         action_before_verification: impl Fn(&str) + Send + Sync,
         action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync,
     ) {
-        let dirs = vec![directory; verifications.len()];
-        zip(
-            verifications
-                .verifications_mut()
-                .0
-                .iter_mut()
-                .map(Mutex::new),
-            dirs,
+        run_parallel(
+            verifications,
+            directory,
+            context,
+            cancellation,
+            None,
+            Some(&self.schedule),
+            None,
+            action_before_verification,
+            action_after_verification,
         )
-        .par_bridge()
-        .for_each(|(vm, d)| {
-            let mut v = vm.lock().unwrap();
+    }
+}
+
+/// Run the verifications concurrently in the current thread pool
+///
+/// If `inner_pool` is given, each verification runs inside this pool, so that the parallelism
+/// inside the verification (e.g. over the ballot boxes) does not use the current pool.
+///
+/// If `schedule` is given, the verifications are started from the longest to the shortest expected
+/// duration. The verifications are put in a queue, and each thread of the pool takes the next
+/// verification of the queue when it is free, so that the order of the start is the order of the queue.
+///
+/// If `category` is given, only the verifications of the category are run.
+///
+/// The verifications not started when the run is cancelled are skipped.
+#[allow(clippy::too_many_arguments)]
+fn run_parallel(
+    verifications: &mut VerificationSuite<'_>,
+    directory: &VerificationDirectory,
+    context: &RunContext,
+    cancellation: &RunCancellation,
+    inner_pool: Option<&ThreadPool>,
+    schedule: Option<&VerificationSchedule>,
+    category: Option<VerificationCategory>,
+    action_before_verification: impl Fn(&str) + Send + Sync,
+    action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync,
+) {
+    let mut to_run = verifications
+        .verifications_mut()
+        .0
+        .iter_mut()
+        .filter(|v| v.status() == VerificationStatus::NotStarted)
+        .filter(|v| category.is_none_or(|c| *v.meta_data().category() == c))
+        .collect::<Vec<_>>();
+    if let Some(schedule) = schedule {
+        schedule.sort_by_expected_duration(&mut to_run, |v| v.id());
+    }
+    let queue = Mutex::new(VecDeque::from(to_run));
+    rayon::broadcast(|_| {
+        loop {
+            if cancellation.is_cancelled() {
+                return;
+            }
+            let Some(v) = queue.lock().unwrap().pop_front() else {
+                return;
+            };
             action_before_verification(v.id());
-            v.run(d);
+            match inner_pool {
+                Some(pool) => pool.install(|| v.run(directory, context)),
+                None => v.run(directory, context),
+            }
             action_after_verification(VerificationRunInformation {
                 id: v.id().to_string(),
                 status: v.status(),
-                errors: v.verification_result().errors_to_string(),
-                failures: v.verification_result().failures_to_string(),
+                duration: v.duration(),
+                process_peak_memory: v.process_peak_memory(),
+                result: v.verification_result().clone(),
             });
-        });
+        }
+    });
+}
+
+/// Options of the thread pools of [RunParallelInPool]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Builder, Getters)]
+#[builder(default)]
+pub struct RunThreadPoolOptions {
+    /// Number of threads running the verifications
+    ///
+    /// Default: `None` (number of logical CPUs, chosen by rayon)
+    #[builder(setter(strip_option))]
+    #[getter(copy)]
+    threads: Option<usize>,
+
+    /// Number of threads for the parallelism inside the verifications (e.g. over the ballot boxes)
+    ///
+    /// Default: `None` (the verifications use the pool running the verifications)
+    #[builder(setter(strip_option))]
+    #[getter(copy)]
+    inner_threads: Option<usize>,
+
+    /// Start the threads with a low priority (only on Linux, without effect on other platforms)
+    ///
+    /// Default: `false`
+    #[getter(copy)]
+    low_priority: bool,
+}
+
+impl RunThreadPoolOptions {
+    /// Options from the configuration of the verifier
+    ///
+    /// Return an error if an option has an invalid value
+    pub fn from_config(config: &VerifierConfig) -> Result<Self, RunnerError> {
+        Self::from_config_impl(config)
+            .map_err(|e| RunnerErrorImpl::VerifierConfig {
+                source: Box::new(e),
+            })
+            .map_err(RunnerError::from)
+    }
+
+    fn from_config_impl(config: &VerifierConfig) -> Result<Self, VerifierConfigError> {
+        Ok(Self {
+            threads: config.run_threads()?,
+            inner_threads: config.run_inner_threads()?,
+            low_priority: config.run_low_priority()?,
+        })
+    }
+}
+
+/// Strategy to run the tests concurrently in a dedicated thread pool
+///
+/// The global pool of rayon is not used, so that the integrators (e.g. a GUI) keep the control
+/// of the CPU usage. The pools are shared by the clones of the strategy.
+#[derive(Clone)]
+pub struct RunParallelInPool {
+    pool: Arc<ThreadPool>,
+    inner_pool: Option<Arc<ThreadPool>>,
+    schedule: Option<Arc<VerificationSchedule>>,
+}
+
+/// Nice value of the threads in low priority mode
+#[cfg(target_os = "linux")]
+const LOW_PRIORITY_NICE: libc::c_int = 10;
+
+/// Lower the priority of the current thread
+#[cfg(target_os = "linux")]
+fn lower_current_thread_priority() {
+    // On Linux, the nice value is set per thread: `0` is the calling thread
+    // SAFETY: setpriority only changes the scheduling of the thread and takes no pointer
+    let res = unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, LOW_PRIORITY_NICE) };
+    if res != 0 {
+        warn!("Cannot lower the priority of the thread of the verifications");
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn lower_current_thread_priority() {}
+
+fn build_thread_pool(
+    name: &'static str,
+    threads: Option<usize>,
+    low_priority: bool,
+) -> Result<ThreadPool, RunnerErrorImpl> {
+    let mut builder = ThreadPoolBuilder::new().thread_name(move |i| format!("verifier-{name}-{i}"));
+    if let Some(n) = threads {
+        builder = builder.num_threads(n);
+    }
+    if low_priority {
+        builder = builder.start_handler(|_| lower_current_thread_priority());
+    }
+    builder
+        .build()
+        .map_err(|e| RunnerErrorImpl::ThreadPool { name, source: e })
+}
+
+impl RunParallelInPool {
+    /// Create the strategy with new thread pools according to the options
+    pub fn new(options: &RunThreadPoolOptions) -> Result<Self, RunnerError> {
+        Ok(Self {
+            pool: Arc::new(build_thread_pool(
+                "run",
+                options.threads(),
+                options.low_priority(),
+            )?),
+            inner_pool: match options.inner_threads() {
+                Some(n) => Some(Arc::new(build_thread_pool(
+                    "inner",
+                    Some(n),
+                    options.low_priority(),
+                )?)),
+                None => None,
+            },
+            schedule: None,
+        })
+    }
+
+    /// Start the longest verifications first, according to the schedule (see [RunParallelLongestFirst])
+    pub fn with_schedule(mut self, schedule: VerificationSchedule) -> Self {
+        self.schedule = Some(Arc::new(schedule));
+        self
+    }
+
+    /// Create the strategy with the options of the configuration (see [RunThreadPoolOptions::from_config])
+    pub fn from_config(config: &VerifierConfig) -> Result<Self, RunnerError> {
+        Self::new(&RunThreadPoolOptions::from_config(config)?)
+    }
+
+    /// Number of threads running the verifications
+    pub fn num_threads(&self) -> usize {
+        self.pool.current_num_threads()
+    }
+
+    /// Number of threads for the parallelism inside the verifications, if a dedicated pool is used
+    pub fn inner_num_threads(&self) -> Option<usize> {
+        self.inner_pool.as_ref().map(|p| p.current_num_threads())
+    }
+}
+
+impl<'a> RunStrategy<'a> for RunParallelInPool {
+    fn run(
+        &self,
+        verifications: &mut VerificationSuite<'a>,
+        directory: &VerificationDirectory,
+        context: &RunContext,
+        cancellation: &RunCancellation,
+        action_before_verification: impl Fn(&str) + Send + Sync,
+        action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync,
+    ) {
+        self.pool.install(|| {
+            run_parallel(
+                verifications,
+                directory,
+                context,
+                cancellation,
+                self.inner_pool.as_deref(),
+                self.schedule.as_deref(),
+                None,
+                action_before_verification,
+                action_after_verification,
+            )
+        })
+    }
+}
+
+/// Kind of the strategies, e.g. to choose the strategy in the configuration of an application
+///
+/// The strategy is created with [RunStrategyKind::strategy].
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    strum::AsRefStr,
+    strum::EnumString,
+    strum::EnumIter,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RunStrategyKind {
+    /// [RunSequential]
+    Sequential,
+    /// [RunParallel]
+    #[default]
+    Parallel,
+    /// [RunParallelLongestFirst]
+    LongestFirst,
+    /// [RunFailFast]
+    FailFast,
+    /// [RunCategoryGates]
+    CategoryGates,
+    /// [RunParallelInPool], with the options of the configuration
+    Pool,
+}
+
+impl RunStrategyKind {
+    /// Create the strategy of the kind
+    ///
+    /// The schedule of [RunParallelLongestFirst] is calculated with the metadata.
+    /// The thread pools of [RunParallelInPool] are created with the options of the configuration
+    pub fn strategy(
+        &self,
+        metadata: &VerificationMetaDataList,
+        config: &VerifierConfig,
+    ) -> Result<AnyRunStrategy, RunnerError> {
+        Ok(match self {
+            Self::Sequential => AnyRunStrategy::Sequential(RunSequential),
+            Self::Parallel => AnyRunStrategy::Parallel(RunParallel),
+            Self::LongestFirst => AnyRunStrategy::LongestFirst(RunParallelLongestFirst::new(
+                VerificationSchedule::new(metadata),
+            )),
+            Self::FailFast => AnyRunStrategy::FailFast(RunFailFast),
+            Self::CategoryGates => AnyRunStrategy::CategoryGates(RunCategoryGates),
+            Self::Pool => AnyRunStrategy::Pool(RunParallelInPool::from_config(config)?),
+        })
+    }
+}
+
+/// Strategy choosen at runtime (see [RunStrategyKind::strategy])
+#[derive(Clone)]
+pub enum AnyRunStrategy {
+    Sequential(RunSequential),
+    Parallel(RunParallel),
+    LongestFirst(RunParallelLongestFirst),
+    FailFast(RunFailFast),
+    CategoryGates(RunCategoryGates),
+    Pool(RunParallelInPool),
+}
+
+impl<'a> RunStrategy<'a> for AnyRunStrategy {
+    fn run(
+        &self,
+        verifications: &mut VerificationSuite<'a>,
+        directory: &VerificationDirectory,
+        context: &RunContext,
+        cancellation: &RunCancellation,
+        action_before_verification: impl Fn(&str) + Send + Sync,
+        action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync,
+    ) {
+        match self {
+            Self::Sequential(s) => s.run(
+                verifications,
+                directory,
+                context,
+                cancellation,
+                action_before_verification,
+                action_after_verification,
+            ),
+            Self::Parallel(s) => s.run(
+                verifications,
+                directory,
+                context,
+                cancellation,
+                action_before_verification,
+                action_after_verification,
+            ),
+            Self::LongestFirst(s) => s.run(
+                verifications,
+                directory,
+                context,
+                cancellation,
+                action_before_verification,
+                action_after_verification,
+            ),
+            Self::FailFast(s) => s.run(
+                verifications,
+                directory,
+                context,
+                cancellation,
+                action_before_verification,
+                action_after_verification,
+            ),
+            Self::CategoryGates(s) => s.run(
+                verifications,
+                directory,
+                context,
+                cancellation,
+                action_before_verification,
+                action_after_verification,
+            ),
+            Self::Pool(s) => s.run(
+                verifications,
+                directory,
+                context,
+                cancellation,
+                action_before_verification,
+                action_after_verification,
+            ),
+        }
     }
 }
 
+/// Checks before creating a runner (see [start_check] and [check_verification_dir])
+///
+/// With a shared state, the keystore of the state is checked instead of reading the keystore
+fn check_before_new(
+    config: &VerifierConfig,
+    period: &VerificationPeriod,
+    path: &Path,
+    shared_state: Option<&SharedRunState>,
+) -> Result<(), RunnerErrorImpl> {
+    match shared_state {
+        Some(state) => start_check_with_shared_state(config, state),
+        None => start_check(config),
+    }
+    .map_err(|msg| RunnerErrorImpl::CheckError {
+        function: "start_check",
+        msg,
+    })?;
+    check_verification_dir(period, path).map_err(|msg| RunnerErrorImpl::CheckError {
+        function: "check_verification_dir",
+        msg,
+    })
+}
+
 /// Structure defining the runner
 ///
 /// The runner can run only once. The runner has to be reseted to restart.
+/// Some verifications can be run again on the finished runner with [Runner::rerun].
 pub struct Runner<'a, T: RunStrategy<'a>> {
     path: PathBuf,
-    verification_directory: Box<VerificationDirectory>,
+    verification_directory: Arc<VerificationDirectory>,
     verifications: Box<VerificationSuite<'a>>,
     start_time: Option<SystemTime>,
     duration: Option<Duration>,
     election_event_id: String,
     seed: String,
+    reference_date: NaiveDate,
     run_strategy: T,
-    config: &'static VerifierConfig,
+    config: Arc<VerifierConfig>,
+    signature_cache: Arc<SignatureVerificationCache>,
+    shared_state: Option<SharedRunState>,
     action_before_runner: Box<dyn Fn(SystemTime) + Send + Sync>,
     action_before_verification: Box<dyn Fn(&str) + Send + Sync>,
     #[allow(clippy::type_complexity)]
     action_after_verification: Box<dyn Fn(VerificationRunInformation) + Send + Sync>,
     action_after_runner: Box<dyn Fn(RunnerInformation) + Send + Sync>,
+    event_broadcaster: RunnerEventBroadcaster,
+    cancellation: RunCancellation,
+    audit: Option<RunAudit>,
 }
 
 impl<'a, T> Runner<'a, T>
@@ -290,8 +932,8 @@ where
     ///             verifs_res_mut.insert(
     ///                 verif_information.id.clone(),
     ///                 (
-    ///                     verif_information.errors.len() as u8,
-    ///                     verif_information.failures.len() as u8,
+    ///                     verif_information.errors().len() as u8,
+    ///                     verif_information.failures().len() as u8,
     ///                 ),
     ///             );
     ///         }
@@ -307,6 +949,8 @@ where
     /// Comments to the above example:
     /// - The data used [std::sync::Arc] and [std::sync::RwLock] in order to ensure the thread safety and clone
     /// - The clone of verifications_not_finished is the avoid an error having the lock of the value in both methods, then in the same thread (see `write` of [std::sync::RwLock])
+    ///
+    /// Alternatively, the events of the run can be received over a channel with [Runner::subscribe]
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         path: &Path,
@@ -314,7 +958,7 @@ where
         metadata: &'a VerificationMetaDataList,
         exclusion: &[String],
         run_strategy: T,
-        config: &'static VerifierConfig,
+        config: Arc<VerifierConfig>,
         action_before_runner: impl Fn(SystemTime) + Send + Sync + 'static,
         action_before_verification: impl Fn(&str) + Send + Sync + 'static,
         action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync + 'static,
@@ -335,6 +979,166 @@ where
         .map_err(RunnerError::from)
     }
 
+    /// Create a new runner without actions
+    ///
+    /// The events of the run are collected by subscribing to the runner (see [Runner::subscribe]).
+    /// The parameters are the same than [Runner::new].
+    pub fn new_without_actions(
+        path: &Path,
+        period: &VerificationPeriod,
+        metadata: &'a VerificationMetaDataList,
+        exclusion: &[String],
+        run_strategy: T,
+        config: Arc<VerifierConfig>,
+    ) -> Result<Runner<'a, T>, RunnerError> {
+        Self::new(
+            path,
+            period,
+            metadata,
+            exclusion,
+            run_strategy,
+            config,
+            no_action_before_runner_fn,
+            no_action_before_fn,
+            no_action_after_fn,
+            no_action_after_runner_fn,
+        )
+    }
+
+    /// Create a new runner on an existing verification directory
+    ///
+    /// The directory can be shared between many runners, e.g. to run the setup and the tally
+    /// verifications on the same datasets (a directory of the tally can be used for the setup,
+    /// since the setup verifications read only the context).
+    ///
+    /// The other parameters are the same than [Runner::new].
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_directory(
+        directory: Arc<VerificationDirectory>,
+        period: &VerificationPeriod,
+        metadata: &'a VerificationMetaDataList,
+        exclusion: &[String],
+        run_strategy: T,
+        config: Arc<VerifierConfig>,
+        action_before_runner: impl Fn(SystemTime) + Send + Sync + 'static,
+        action_before_verification: impl Fn(&str) + Send + Sync + 'static,
+        action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync + 'static,
+        action_after_runner: impl Fn(RunnerInformation) + Send + Sync + 'static,
+    ) -> Result<Runner<'a, T>, RunnerError> {
+        check_before_new(&config, period, directory.path(), None)?;
+        Self::new_with_directory_impl(
+            directory,
+            period,
+            metadata,
+            exclusion,
+            run_strategy,
+            config,
+            action_before_runner,
+            action_before_verification,
+            action_after_verification,
+            action_after_runner,
+        )
+        .map_err(RunnerError::from)
+    }
+
+    /// Create a new runner on an existing verification directory, collecting the information
+    /// of the run in `run_information`
+    ///
+    /// The actions of the runner call [RunInformation::start_running],
+    /// [RunInformation::start_verification], [RunInformation::finish_verification] and
+    /// [RunInformation::finish_runner]. The run information must be prepared
+    /// (see [RunInformation::prepare_data_for_start]).
+    ///
+    /// The other parameters are the same than [Runner::new_with_directory].
+    pub fn new_with_run_information(
+        directory: Arc<VerificationDirectory>,
+        period: &VerificationPeriod,
+        metadata: &'a VerificationMetaDataList,
+        exclusion: &[String],
+        run_strategy: T,
+        config: Arc<VerifierConfig>,
+        run_information: Arc<Mutex<RunInformation>>,
+    ) -> Result<Runner<'a, T>, RunnerError> {
+        check_before_new(&config, period, directory.path(), None)?;
+        Self::new_with_run_information_impl(
+            directory,
+            period,
+            metadata,
+            exclusion,
+            run_strategy,
+            config,
+            run_information,
+        )
+        .map_err(RunnerError::from)
+    }
+
+    /// Create a new runner like [Runner::new_with_run_information], using the state shared with other runners
+    ///
+    /// The keystore of the state is used for the checks and for the runs, without reading it again.
+    /// The cache of the signature verifications is shared too (see [SharedRunState]), e.g. between the
+    /// runners of a [crate::VerificationSession].
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_shared_state(
+        directory: Arc<VerificationDirectory>,
+        period: &VerificationPeriod,
+        metadata: &'a VerificationMetaDataList,
+        exclusion: &[String],
+        run_strategy: T,
+        config: Arc<VerifierConfig>,
+        run_information: Arc<Mutex<RunInformation>>,
+        shared_state: SharedRunState,
+    ) -> Result<Runner<'a, T>, RunnerError> {
+        check_before_new(&config, period, directory.path(), Some(&shared_state))?;
+        let mut runner = Self::new_with_run_information_impl(
+            directory,
+            period,
+            metadata,
+            exclusion,
+            run_strategy,
+            config,
+            run_information,
+        )?;
+        runner.shared_state = Some(shared_state);
+        Ok(runner)
+    }
+
+    fn new_with_run_information_impl(
+        directory: Arc<VerificationDirectory>,
+        period: &VerificationPeriod,
+        metadata: &'a VerificationMetaDataList,
+        exclusion: &[String],
+        run_strategy: T,
+        config: Arc<VerifierConfig>,
+        run_information: Arc<Mutex<RunInformation>>,
+    ) -> Result<Runner<'a, T>, RunnerErrorImpl> {
+        let ri_before_runner = run_information.clone();
+        let ri_before_verification = run_information.clone();
+        let ri_after_verification = run_information.clone();
+        let ri_after_runner = run_information;
+        Self::new_with_directory_impl(
+            directory,
+            period,
+            metadata,
+            exclusion,
+            run_strategy,
+            config,
+            move |start_time| ri_before_runner.lock().unwrap().start_running(&start_time),
+            move |id| {
+                ri_before_verification
+                    .lock()
+                    .unwrap()
+                    .start_verification(id)
+            },
+            move |verif_info| {
+                ri_after_verification
+                    .lock()
+                    .unwrap()
+                    .finish_verification(&verif_info)
+            },
+            move |runner_info| ri_after_runner.lock().unwrap().finish_runner(&runner_info),
+        )
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn new_impl(
         path: &Path,
@@ -342,21 +1146,40 @@ where
         metadata: &'a VerificationMetaDataList,
         exclusion: &[String],
         run_strategy: T,
-        config: &'static VerifierConfig,
+        config: Arc<VerifierConfig>,
+        action_before_runner: impl Fn(SystemTime) + Send + Sync + 'static,
+        action_before_verification: impl Fn(&str) + Send + Sync + 'static,
+        action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync + 'static,
+        action_after_runner: impl Fn(RunnerInformation) + Send + Sync + 'static,
+    ) -> Result<Runner<'a, T>, RunnerErrorImpl> {
+        check_before_new(&config, period, path, None)?;
+        Self::new_with_directory_impl(
+            Arc::new(VerificationDirectory::new(period, path)),
+            period,
+            metadata,
+            exclusion,
+            run_strategy,
+            config,
+            action_before_runner,
+            action_before_verification,
+            action_after_verification,
+            action_after_runner,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn new_with_directory_impl(
+        directory: Arc<VerificationDirectory>,
+        period: &VerificationPeriod,
+        metadata: &'a VerificationMetaDataList,
+        exclusion: &[String],
+        run_strategy: T,
+        config: Arc<VerifierConfig>,
         action_before_runner: impl Fn(SystemTime) + Send + Sync + 'static,
         action_before_verification: impl Fn(&str) + Send + Sync + 'static,
         action_after_verification: impl Fn(VerificationRunInformation) + Send + Sync + 'static,
         action_after_runner: impl Fn(RunnerInformation) + Send + Sync + 'static,
     ) -> Result<Runner<'a, T>, RunnerErrorImpl> {
-        start_check(config).map_err(|msg| RunnerErrorImpl::CheckError {
-            function: "start_check",
-            msg,
-        })?;
-        check_verification_dir(period, path).map_err(|msg| RunnerErrorImpl::CheckError {
-            function: "check_verification_dir",
-            msg,
-        })?;
-        let directory = VerificationDirectory::new(period, path);
         check_complete(period, &directory).map_err(|msg| RunnerErrorImpl::CheckError {
             function: "check_complete",
             msg,
@@ -374,64 +1197,65 @@ where
             })?;
         let eeid = ee_context.election_event_context.election_event_id.clone();
         let seed = ee_context.seed.clone();
+        // The signatures of the setup are made before the election event, the signatures of the tally after
+        let reference_date = match period {
+            VerificationPeriod::Setup => ee_context.election_event_context.start_time.date(),
+            VerificationPeriod::Tally => ee_context.election_event_context.finish_time.date(),
+        };
         Ok(Runner {
-            path: path.to_path_buf(),
-            verification_directory: Box::new(directory),
-            verifications: Box::new(
-                VerificationSuite::new(period, metadata, exclusion, config).map_err(|e| {
-                    RunnerErrorImpl::Suite {
-                        function: "new runner",
-                        source: Box::new(e),
-                    }
-                })?,
-            ),
+            path: directory.path().to_path_buf(),
+            verification_directory: directory,
+            verifications: Box::new(VerificationSuite::new(period, metadata, exclusion).map_err(
+                |e| RunnerErrorImpl::Suite {
+                    function: "new runner",
+                    source: Box::new(e),
+                },
+            )?),
             election_event_id: eeid,
             seed,
+            reference_date,
             start_time: None,
             duration: None,
             run_strategy,
             config,
+            signature_cache: Arc::new(SignatureVerificationCache::new()),
+            shared_state: None,
             action_before_runner: Box::new(action_before_runner),
             action_before_verification: Box::new(action_before_verification),
             action_after_verification: Box::new(action_after_verification),
             action_after_runner: Box::new(action_after_runner),
+            event_broadcaster: RunnerEventBroadcaster::default(),
+            cancellation: RunCancellation::default(),
+            audit: None,
         })
     }
 
     /// Reset the verifications
     pub fn reset(
-        &'a mut self,
+        &mut self,
         metadata_list: &'a VerificationMetaDataList,
     ) -> Result<(), RunnerError> {
         self.start_time = None;
         self.duration = None;
+        self.cancellation.reset();
         self.verifications = Box::new(
-            VerificationSuite::new(
-                self.period(),
-                metadata_list,
-                self.verifications.exclusion(),
-                self.config,
-            )
-            .map_err(|e| RunnerErrorImpl::Suite {
-                function: "reset runner",
-                source: Box::new(e),
-            })?,
+            VerificationSuite::new(self.period(), metadata_list, self.verifications.exclusion())
+                .map_err(|e| RunnerErrorImpl::Suite {
+                    function: "reset runner",
+                    source: Box::new(e),
+                })?,
         );
         Ok(())
     }
 
     /// Run all tests
-    pub fn run_all<'c: 'a>(
-        &'c mut self,
-        metadata_list: &'a VerificationMetaDataList,
-    ) -> Result<(), RunnerError> {
+    pub fn run_all(&mut self, metadata_list: &VerificationMetaDataList) -> Result<(), RunnerError> {
         if self.is_running() {
             return Err(RunnerError::from(RunnerErrorImpl::IsRunning));
         }
         if self.is_finished() {
             return Err(RunnerError::from(RunnerErrorImpl::HasAlreadyRun));
         }
-        self.start_time = Some(SystemTime::now());
         info!(
             "Start all verifications ({} verifications; {} excluded)",
             self.verifications.len(),
@@ -444,37 +1268,209 @@ where
                 id
             );
         }
-        let len = self.verifications.len();
+        if let Some(audit) = &self.audit
+            && !self.verifications.exclusion().is_empty()
+        {
+            audit
+                .append(audit.exclusion_event(*self.period(), self.verifications.exclusion()))
+                .map_err(|e| RunnerErrorImpl::AuditLog {
+                    source: Box::new(e),
+                })?;
+        }
+        self.run_not_started()
+    }
+
+    /// Run again the verifications with the given ids on the finished runner
+    ///
+    /// The verifications are reset. The actions and the events of the runner are called like for
+    /// [Runner::run_all], only for the verifications run again, so that the previous results can be kept
+    /// (see [crate::RunInformation::verifications_history]).
+    ///
+    /// The ids of the verifications to run again can be collected with [Runner::ids_with_status],
+    /// e.g. all the verifications finished with errors.
+    ///
+    /// The verifications not run by a strategy stopping the run (e.g. [RunFailFast] or
+    /// [RunCategoryGates]) are run too.
+    pub fn rerun(&mut self, ids: &[String]) -> Result<(), RunnerError> {
+        if self.is_running() {
+            return Err(RunnerError::from(RunnerErrorImpl::IsRunning));
+        }
+        if !self.is_finished() {
+            return Err(RunnerError::from(RunnerErrorImpl::NotFinished));
+        }
+        self.verifications
+            .reset_verifications(ids)
+            .map_err(|e| RunnerErrorImpl::Suite {
+                function: "rerun",
+                source: Box::new(e),
+            })?;
+        info!("Run again {} verifications ({})", ids.len(), ids.join(", "));
+        self.cancellation.reset();
+        self.run_not_started()
+    }
+
+    /// Ids of the verifications in one of the given status
+    pub fn ids_with_status(&self, status: &[VerificationStatus]) -> Vec<String> {
+        self.verifications
+            .collect_id_with_status(status)
+            .into_iter()
+            .map(|id| id.to_string())
+            .collect()
+    }
+
+    /// Run the verifications not started, with the actions and the events of the runner
+    ///
+    /// If the run is audited, the run is not stopped by an error writing the audit log.
+    /// The first error is returned at the end of the run
+    fn run_not_started(&mut self) -> Result<(), RunnerError> {
+        let len = self
+            .verifications
+            .collect_id_with_status(&[VerificationStatus::NotStarted])
+            .len();
+        self.start_time = Some(SystemTime::now());
+        self.duration = None;
+        // The keystore is read once per run (or once for all the runners sharing the state) and shared
+        // by all the verifications. The cache of the signatures is kept between the runs of the runner
+        let context = match &self.shared_state {
+            Some(state) => RunContext::new_with_shared_state(&self.config, state),
+            None => RunContext::new_with_signature_cache(&self.config, &self.signature_cache),
+        }
+        .with_reference_date(self.reference_date);
+        if let Err(e) = context.keystore() {
+            warn!(
+                "Cannot read the keystore at the start of the run. The verifications of the signatures will fail: {}",
+                e
+            );
+        }
+        let period = *self.period();
+        let audit_error = Mutex::new(None);
+        let append_audit = |event: Result<AuditEvent, AuditLogError>| {
+            if let Some(audit) = &self.audit
+                && let Err(e) = event.and_then(|event| audit.append(event))
+            {
+                error!("Error writing the audit log: {}", e);
+                audit_error.lock().unwrap().get_or_insert(e);
+            }
+        };
+        let audit_runner_event = |event: &RunnerEvent| {
+            if let Some(event) = AuditEvent::from_runner_event(period, event) {
+                append_audit(Ok(event))
+            }
+        };
+        if self.audit.is_some()
+            && let Ok(keystore) = context.keystore()
+        {
+            append_audit(AuditEvent::keystore_fingerprints(keystore));
+        }
         (self.action_before_runner)(self.start_time.unwrap());
+        let event = RunnerEvent::RunStarted {
+            start_time: self.start_time.unwrap(),
+            number_of_verifications: len,
+        };
+        audit_runner_event(&event);
+        self.event_broadcaster.send(event);
+        let finished = AtomicUsize::new(0);
         {
+            let event_broadcaster = &self.event_broadcaster;
+            let action_before_verification = &self.action_before_verification;
+            let action_after_verification = &self.action_after_verification;
+            let audit_runner_event = &audit_runner_event;
+            let finished = &finished;
             self.run_strategy.run(
                 &mut self.verifications,
                 &self.verification_directory,
-                &self.action_before_verification,
-                &self.action_after_verification,
+                &context,
+                &self.cancellation,
+                |id: &str| {
+                    event_broadcaster.send(RunnerEvent::VerificationStarted { id: id.to_string() });
+                    action_before_verification(id)
+                },
+                |verif_information: VerificationRunInformation| {
+                    let event = RunnerEvent::VerificationFinished {
+                        id: verif_information.id.clone(),
+                        status: verif_information.status,
+                        duration: verif_information.duration,
+                        result: verif_information.result.clone(),
+                    };
+                    audit_runner_event(&event);
+                    event_broadcaster.send(event);
+                    event_broadcaster.send(RunnerEvent::Progress {
+                        finished: finished.fetch_add(1, Ordering::SeqCst) + 1,
+                        total: len,
+                    });
+                    action_after_verification(verif_information)
+                },
             );
         }
         self.duration = Some(self.start_time.unwrap().elapsed().unwrap());
-        (self.action_after_runner)(RunnerInformation {
-            config: self.config,
+        if self.cancellation.is_cancelled() {
+            warn!("Run cancelled. The verifications not started are not run");
+        }
+        let mut runner_information = RunnerInformation {
+            config: self.config.clone(),
             start_time: self.start_time,
             duration: self.duration,
             election_event_id: Some(self.election_event_id.clone()),
             seed: Some(self.seed.clone()),
-        });
+            audit_log_head: None,
+        };
+        audit_runner_event(&RunnerEvent::RunFinished(runner_information.clone()));
+        runner_information.audit_log_head = self.audit.as_ref().map(|audit| audit.head());
+        (self.action_after_runner)(runner_information.clone());
+        self.event_broadcaster
+            .send(RunnerEvent::RunFinished(runner_information));
         info!(
-            "{} verifications run (duration: {}s)",
+            "{} of {} verifications run (duration: {}s)",
+            finished.load(Ordering::SeqCst),
             &len,
             self.duration.unwrap().as_secs_f32()
         );
-        Ok(())
+        match audit_error.into_inner().unwrap() {
+            Some(e) => Err(RunnerError::from(RunnerErrorImpl::AuditLog {
+                source: Box::new(e),
+            })),
+            None => Ok(()),
+        }
+    }
+
+    /// Audit the runs in the log (see [RunAudit])
+    ///
+    /// The audit must be set before [Runner::run_all]
+    pub fn set_audit(&mut self, audit: RunAudit) {
+        self.audit = Some(audit);
+    }
+
+    /// Subscribe to the events of the runner
+    ///
+    /// Each subscriber receives all the events sent after the subscription on its own
+    /// [Receiver], so that many consumers (GUI, log, report writer, etc.) can listen
+    /// independently. The subscription must be done before [Runner::run_all].
+    pub fn subscribe(&self) -> Receiver<RunnerEvent> {
+        self.event_broadcaster.subscribe()
+    }
+
+    /// Token to cancel the run from another thread
+    ///
+    /// The token can be cloned before [Runner::run_all] and cancelled during the run
+    pub fn cancellation(&self) -> &RunCancellation {
+        &self.cancellation
+    }
+
+    /// Use the given token to cancel the run, e.g. a token created before the runner
+    pub fn set_cancellation(&mut self, cancellation: RunCancellation) {
+        self.cancellation = cancellation;
+    }
+
+    /// Broadcaster of the events, which can be cloned to subscribe from another thread
+    pub fn event_broadcaster(&self) -> &RunnerEventBroadcaster {
+        &self.event_broadcaster
     }
 
-    pub fn verifications(&'a self) -> &'a VerificationSuite<'a> {
+    pub fn verifications(&self) -> &VerificationSuite<'a> {
         &self.verifications
     }
 
-    pub fn verifications_mut(&'a mut self) -> &'a mut VerificationSuite<'a> {
+    pub fn verifications_mut(&mut self) -> &mut VerificationSuite<'a> {
         &mut self.verifications
     }
 
@@ -494,6 +1490,13 @@ where
         self.verifications.period()
     }
 
+    /// Date at which the validity of the trusted keystores is checked
+    ///
+    /// The start date of the election event for the setup, the finish date for the tally
+    pub fn reference_date(&self) -> NaiveDate {
+        self.reference_date
+    }
+
     pub fn path(&self) -> &Path {
         &self.path
     }
@@ -506,6 +1509,11 @@ where
         &self.verification_directory
     }
 
+    /// Verification directory, that can be shared with another runner (see [Runner::new_with_directory])
+    pub fn shared_verification_directory(&self) -> Arc<VerificationDirectory> {
+        self.verification_directory.clone()
+    }
+
     pub fn start_time(&self) -> Option<SystemTime> {
         self.start_time
     }
@@ -521,3 +1529,128 @@ where
         None
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::RunInformation;
+
+    #[test]
+    fn test_run_parallel_in_pool() {
+        let strategy = RunParallelInPool::new(
+            &RunThreadPoolOptionsBuilder::default()
+                .threads(2)
+                .inner_threads(3)
+                .low_priority(true)
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(strategy.num_threads(), 2);
+        assert_eq!(strategy.inner_num_threads(), Some(3));
+        let cloned = strategy.clone();
+        assert!(Arc::ptr_eq(&strategy.pool, &cloned.pool));
+        let strategy = RunParallelInPool::new(&RunThreadPoolOptions::default()).unwrap();
+        assert!(strategy.inner_num_threads().is_none());
+    }
+
+    #[test]
+    fn test_run_parallel_schedule_order() {
+        let config = Arc::new(VerifierConfig::default());
+        let metadata = VerificationMetaDataList::load(config.get_verification_list_str()).unwrap();
+        let mut suite = VerificationSuite::new(&VerificationPeriod::Setup, &metadata, &[]).unwrap();
+        let ids = suite
+            .collect_id_with_status(&[VerificationStatus::NotStarted])
+            .into_iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+        let mut schedule = VerificationSchedule::new(&metadata);
+        schedule.add_recorded_duration(&ids[ids.len() - 1], Duration::from_secs(2000));
+        schedule.add_recorded_duration(&ids[1], Duration::from_secs(1000));
+        let directory =
+            VerificationDirectory::new(&VerificationPeriod::Setup, Path::new("./not_existing"));
+        let started = Mutex::new(vec![]);
+        let finished = AtomicUsize::new(0);
+        ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap()
+            .install(|| {
+                run_parallel(
+                    &mut suite,
+                    &directory,
+                    &RunContext::new(&config),
+                    &RunCancellation::default(),
+                    None,
+                    Some(&schedule),
+                    None,
+                    |id| started.lock().unwrap().push(id.to_string()),
+                    |_| {
+                        finished.fetch_add(1, Ordering::SeqCst);
+                    },
+                )
+            });
+        let started = started.into_inner().unwrap();
+        assert_eq!(started.len(), ids.len());
+        assert_eq!(finished.into_inner(), ids.len());
+        assert_eq!(started[0], ids[ids.len() - 1]);
+        assert_eq!(started[1], ids[1]);
+    }
+
+    #[test]
+    fn test_run_sequential_errors_and_failures() {
+        let config = Arc::new(VerifierConfig::default());
+        let metadata = VerificationMetaDataList::load(config.get_verification_list_str()).unwrap();
+        let mut suite = VerificationSuite::new(&VerificationPeriod::Setup, &metadata, &[]).unwrap();
+        let mut run_information = RunInformation::new(config.clone());
+        run_information
+            .prepare_data_for_start(VerificationPeriod::Setup, &metadata, &[])
+            .unwrap();
+        let run_information = Mutex::new(run_information);
+        let directory =
+            VerificationDirectory::new(&VerificationPeriod::Setup, Path::new("./not_existing"));
+        run_sequential(
+            &mut suite,
+            &directory,
+            &RunContext::new(&config),
+            &RunCancellation::default(),
+            false,
+            |id| run_information.lock().unwrap().start_verification(id),
+            |verif_info| {
+                run_information
+                    .lock()
+                    .unwrap()
+                    .finish_verification(&verif_info)
+            },
+        );
+        let run_information = run_information.into_inner().unwrap();
+        let errors_and_failures = run_information.verifications_with_errors_and_failures();
+        assert!(errors_and_failures.number_of_verifications_with_errors() > 0);
+        for v in suite.verifications().0.iter() {
+            let result = v.verification_result();
+            match errors_and_failures.iter().find(|(id, _)| *id == v.id()) {
+                Some((_, (errors, failures))) => {
+                    assert_eq!(errors.len(), result.errors().len(), "errors of {}", v.id());
+                    assert_eq!(
+                        failures.len(),
+                        result.failures().len(),
+                        "failures of {}",
+                        v.id()
+                    );
+                }
+                None => assert!(result.is_ok(), "{} not ok", v.id()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_cancellation() {
+        let cancellation = RunCancellation::default();
+        let cloned = cancellation.clone();
+        assert!(!cancellation.is_cancelled());
+        cloned.cancel();
+        assert!(cancellation.is_cancelled());
+        cancellation.reset();
+        assert!(!cloned.is_cancelled());
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Stream of the events of the runner over channels
+//!
+//! Each consumer (GUI, log, report writer, etc.) subscribes to the runner (see
+//! [crate::Runner::subscribe]) and receives all the events of the run on its own
+//! [Receiver], independently of the other consumers.
+//!
+//! The events are sent from the threads running the verifications. A consumer that does
+//! not want to block the run should read the receiver in a separate thread.
+
+use crate::RunnerInformation;
+use rust_ev_verifier_lib::verification::{VerificationResult, VerificationStatus};
+use std::{
+    sync::{
+        Arc, Mutex,
+        mpsc::{Receiver, Sender, channel},
+    },
+    time::{Duration, SystemTime},
+};
+
+/// Event sent by the runner during the run
+#[derive(Clone)]
+pub enum RunnerEvent {
+    /// The run has started
+    RunStarted {
+        start_time: SystemTime,
+        /// Number of verifications to run (without the excluded verifications)
+        number_of_verifications: usize,
+    },
+    /// The verification with the id has started
+    VerificationStarted { id: String },
+    /// Progress of the run, sent after each finished verification
+    Progress { finished: usize, total: usize },
+    /// The verification has finished
+    VerificationFinished {
+        id: String,
+        status: VerificationStatus,
+        duration: Option<Duration>,
+        /// The full result of the verification
+        result: VerificationResult,
+    },
+    /// The run has finished
+    RunFinished(RunnerInformation),
+}
+
+/// Broadcaster of the [RunnerEvent] to all the subscribers
+///
+/// The subscribers whose receiver has been dropped are removed at the next event.
+#[derive(Clone, Default)]
+pub struct RunnerEventBroadcaster {
+    senders: Arc<Mutex<Vec<Sender<RunnerEvent>>>>,
+}
+
+impl RunnerEventBroadcaster {
+    /// Subscribe to the events. Each subscriber receives all the events sent after the subscription
+    pub fn subscribe(&self) -> Receiver<RunnerEvent> {
+        let (tx, rx) = channel();
+        self.senders.lock().unwrap().push(tx);
+        rx
+    }
+
+    /// Number of active subscribers
+    pub fn len(&self) -> usize {
+        self.senders.lock().unwrap().len()
+    }
+
+    /// No active subscriber
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Send the event to all the subscribers
+    pub fn send(&self, event: RunnerEvent) {
+        self.senders
+            .lock()
+            .unwrap()
+            .retain(|tx| tx.send(event.clone()).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_broadcast() {
+        let broadcaster = RunnerEventBroadcaster::default();
+        let rx1 = broadcaster.subscribe();
+        let rx2 = broadcaster.subscribe();
+        broadcaster.send(RunnerEvent::VerificationStarted {
+            id: "01.01".to_string(),
+        });
+        broadcaster.send(RunnerEvent::Progress {
+            finished: 1,
+            total: 2,
+        });
+        for rx in [rx1, rx2] {
+            let events = rx.try_iter().collect::<Vec<_>>();
+            assert_eq!(events.len(), 2);
+            assert!(matches!(
+                &events[0],
+                RunnerEvent::VerificationStarted { id } if id == "01.01"
+            ));
+            assert!(matches!(
+                events[1],
+                RunnerEvent::Progress {
+                    finished: 1,
+                    total: 2
+                }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_dropped_subscriber() {
+        let broadcaster = RunnerEventBroadcaster::default();
+        let rx = broadcaster.subscribe();
+        {
+            let _dropped = broadcaster.subscribe();
+        }
+        assert_eq!(broadcaster.len(), 2);
+        broadcaster.send(RunnerEvent::VerificationStarted {
+            id: "01.01".to_string(),
+        });
+        assert_eq!(broadcaster.len(), 1);
+        assert_eq!(rx.try_iter().count(), 1);
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Schedule of the verifications according to their expected cost
+//!
+//! Running the longest verifications first reduces the total time of a parallel run, since
+//! a long verification started at the end would leave the other threads idle.
+//!
+//! The expected duration of a verification is, in this order:
+//! - The duration recorded in an earlier run (see [VerificationSchedule::add_run_information])
+//! - The hint in the metadata (see [rust_ev_verifier_lib::verification::VerificationMetaData::expected_duration_hint])
+//! - Zero
+
+use crate::RunInformation;
+use rust_ev_verifier_lib::verification::VerificationMetaDataList;
+use std::{cmp::Reverse, collections::HashMap, time::Duration};
+
+/// Expected durations of the verifications, used to run the longest verifications first
+#[derive(Debug, Clone, Default)]
+pub struct VerificationSchedule {
+    hints: HashMap<String, Duration>,
+    recorded: HashMap<String, Duration>,
+}
+
+impl VerificationSchedule {
+    /// New schedule with the hints of the metadata
+    pub fn new(verification_metadata: &VerificationMetaDataList) -> Self {
+        Self {
+            hints: verification_metadata
+                .iter()
+                .filter_map(|md| {
+                    md.expected_duration_hint()
+                        .map(|s| (md.id().to_string(), Duration::from_secs(s)))
+                })
+                .collect(),
+            recorded: HashMap::default(),
+        }
+    }
+
+    /// Add the duration of a verification recorded in an earlier run
+    ///
+    /// The last recorded duration of the verification is used
+    pub fn add_recorded_duration(&mut self, id: &str, duration: Duration) {
+        self.recorded.insert(id.to_string(), duration);
+    }
+
+    /// Add the durations of the verifications of an earlier run
+    pub fn add_run_information(&mut self, run_information: &RunInformation) {
+        for (id, performance) in run_information.verifications_performance() {
+            self.add_recorded_duration(id, performance.duration);
+        }
+    }
+
+    /// Expected duration of the verification with the id
+    pub fn expected_duration(&self, id: &str) -> Duration {
+        self.recorded
+            .get(id)
+            .or_else(|| self.hints.get(id))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Sort the items from the longest to the shortest expected duration
+    ///
+    /// `id` returns the id of the verification of an item. The order of the items with the same
+    /// expected duration is kept
+    pub fn sort_by_expected_duration<T>(&self, items: &mut [T], id: impl Fn(&T) -> &str) {
+        items.sort_by_cached_key(|item| Reverse(self.expected_duration(id(item))));
+    }
+
+    /// Order the ids from the longest to the shortest expected duration
+    ///
+    /// The order of the ids with the same expected duration is kept
+    pub fn order<'b>(&self, ids: &[&'b str]) -> Vec<&'b str> {
+        let mut res = ids.to_vec();
+        self.sort_by_expected_duration(&mut res, |id| id);
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_ev_verifier_lib::VerifierConfig;
+
+    fn verification_metadata() -> VerificationMetaDataList {
+        VerificationMetaDataList::load(VerifierConfig::default().get_verification_list_str())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_order() {
+        let mut schedule = VerificationSchedule::new(&verification_metadata());
+        assert_eq!(schedule.expected_duration("01.01"), Duration::ZERO);
+        assert_eq!(
+            schedule.order(&["06.01", "10.02", "07.01", "10.01"]),
+            vec!["10.02", "10.01", "06.01", "07.01"]
+        );
+        schedule.add_recorded_duration("07.01", Duration::from_secs(1000));
+        schedule.add_recorded_duration("10.01", Duration::from_secs(10));
+        assert_eq!(
+            schedule.order(&["06.01", "10.02", "07.01", "10.01"]),
+            vec!["07.01", "10.02", "10.01", "06.01"]
+        );
+    }
+}
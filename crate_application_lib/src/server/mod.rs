@@ -0,0 +1,584 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Local HTTP/JSON server to run the verifications (feature `server`)
+//!
+//! The server offers the functionalities of the crate to a front end, without calling the
+//! console application. There is no authentication: the server listens only on a loopback address,
+//! unless the remote access is explicitly allowed (see [ServerOptions::allow_remote]).
+//!
+//! The requests are handled by a fixed number of threads (see [ServerOptions::request_threads]).
+//! The size of the body of a request is limited (see [ServerOptions::max_body_size]).
+//!
+//! | Method | Path                                  | Description                                             |
+//! |--------|---------------------------------------|---------------------------------------------------------|
+//! | GET    | `/health`                             | Check that the server is running                        |
+//! | PUT    | `/datasets/files/{name}`              | Upload a zip file. The body is the content of the file  |
+//! | POST   | `/datasets`                           | Extract the datasets of the zip files                   |
+//! | POST   | `/runs`                               | Start a run of the setup or the tally                   |
+//! | GET    | `/runs/{run_id}`                      | Status of the run and of each verification              |
+//! | GET    | `/runs/{run_id}/verifications/{id}`   | Result of the verification                              |
+//! | POST   | `/runs/{run_id}/cancel`               | Cancel the run (see [crate::RunCancellation])           |
+//! | GET    | `/runs/{run_id}/reports/{type}`       | Report of the finished run (see [ReportOutputFileType]) |
+//!
+//! The body of `POST /datasets` points to the uploaded zip files, with the names of the files or
+//! the paths returned by the upload. Only the files of the upload directory can be extracted:
+//! ```json
+//! { "period": "tally", "context_zip": "context.zip", "tally_zip": "tally.zip", "password": "..." }
+//! ```
+//! If the password is not given, the password of the configuration is used.
+//!
+//! The body of `POST /runs` gives the id of the extracted dataset. The period (default: the period of
+//! the dataset), the excluded verifications and the strategy (`sequential`, `parallel` (default),
+//! `longest_first`, `fail_fast`, `category_gates` or `pool`) are optional:
+//! ```json
+//! {
+//!   "dataset_id": 1, "period": "setup", "strategy": "parallel",
+//!   "exclusion": ["05.01"], "excluded_by": "Jane Doe", "exclusion_reason": "Known issue"
+//! }
+//! ```
+//! The person excluding the verifications and the reason are mandatory if verifications are excluded.
+//!
+//! The extractions, the runs (with the exclusions) and the reports are recorded in the audit log
+//! (see [ServerOptions::audit_log]).
+//!
+//! The errors are returned with the corresponding HTTP status and the body `{ "error": "..." }`.
+//!
+//! [ReportOutputFileType]: crate::report::ReportOutputFileType
+
+mod routes;
+mod state;
+
+use crate::{AuditLog, AuditLogError};
+use derive_builder::Builder;
+use derive_getters::Getters;
+use routes::{ApiResponse, Route};
+use rust_ev_verifier_lib::VerifierConfig;
+use state::ServerState;
+use std::{
+    io::Read,
+    net::{SocketAddr, ToSocketAddrs},
+    path::PathBuf,
+    sync::{
+        Arc, Mutex,
+        mpsc::{Receiver, TrySendError, sync_channel},
+    },
+    thread::JoinHandle,
+};
+use thiserror::Error;
+use tiny_http::{Header, Request, Response, Server};
+use tracing::{debug, info, warn};
+
+/// Default maximal size of the body of a request (1 GiB)
+const DEFAULT_MAX_BODY_SIZE: u64 = 1 << 30;
+
+/// Default number of threads handling the requests
+const DEFAULT_REQUEST_THREADS: usize = 8;
+
+/// Default number of requests waiting for a free thread
+const DEFAULT_REQUEST_QUEUE_SIZE: usize = 32;
+
+#[derive(Error, Debug)]
+#[error(transparent)]
+/// Error of the server
+pub struct ServerError(#[from] ServerErrorImpl);
+
+#[derive(Error, Debug)]
+enum ServerErrorImpl {
+    #[error("Error starting the server on {addr}: {msg}")]
+    Start { addr: String, msg: String },
+    #[error("The server on {addr} does not listen on an IP address")]
+    NoIpAddress { addr: String },
+    #[error(
+        "The address {addr} is not a loopback address. The remote access must be allowed explicitly"
+    )]
+    NotLoopback { addr: String },
+    #[error("Error opening the audit log")]
+    AuditLog { source: AuditLogError },
+}
+
+/// Options of the [VerifierServer]
+#[derive(Debug, Clone, PartialEq, Eq, Builder, Getters)]
+#[builder(default)]
+pub struct ServerOptions {
+    /// Allow to listen on an address that is not a loopback address
+    ///
+    /// The server has no authentication. Default: `false`
+    #[getter(copy)]
+    allow_remote: bool,
+
+    /// Maximal size of the body of a request in bytes (e.g. of an uploaded zip file)
+    ///
+    /// Default: 1 GiB
+    #[getter(copy)]
+    max_body_size: u64,
+
+    /// Number of threads handling the requests
+    ///
+    /// Default: 8
+    #[getter(copy)]
+    request_threads: usize,
+
+    /// Number of requests waiting for a free thread. The further requests are rejected with the status 503
+    ///
+    /// Default: 32
+    #[getter(copy)]
+    request_queue_size: usize,
+
+    /// Audit log of the extractions, the runs and the reports (see [crate::AuditLog])
+    ///
+    /// Default: the audit log of the configuration ([VerifierConfig::audit_log_file_path])
+    #[builder(setter(into, strip_option))]
+    audit_log: Option<PathBuf>,
+}
+
+impl Default for ServerOptions {
+    fn default() -> Self {
+        Self {
+            allow_remote: false,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            request_threads: DEFAULT_REQUEST_THREADS,
+            request_queue_size: DEFAULT_REQUEST_QUEUE_SIZE,
+            audit_log: None,
+        }
+    }
+}
+
+/// Server handling the requests of the API in a background thread
+///
+/// The requests are handled by a fixed number of threads and each run in a further thread, so that
+/// the status of a run can be polled while it is running.
+pub struct VerifierServer {
+    addr: SocketAddr,
+    server: Arc<Server>,
+    state: Arc<ServerState>,
+    thread: Option<JoinHandle<()>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl VerifierServer {
+    /// Start the server on the loopback address (e.g. `127.0.0.1:8080`) with the default options
+    ///
+    /// With the port `0`, a free port is chosen (see [Self::addr])
+    pub fn start(addr: &str, config: Arc<VerifierConfig>) -> Result<Self, ServerError> {
+        Self::start_with_options(addr, config, &ServerOptions::default())
+    }
+
+    /// Start the server on the address with the options
+    ///
+    /// Return an error if the address is not a loopback address and the remote access is not
+    /// allowed (see [ServerOptions::allow_remote]), or if the audit log cannot be opened (e.g. if
+    /// it is used by another instance)
+    pub fn start_with_options(
+        addr: &str,
+        config: Arc<VerifierConfig>,
+        options: &ServerOptions,
+    ) -> Result<Self, ServerError> {
+        if !options.allow_remote() && !is_loopback(addr)? {
+            return Err(ServerError::from(ServerErrorImpl::NotLoopback {
+                addr: addr.to_string(),
+            }));
+        }
+        let audit_log_path = options
+            .audit_log()
+            .clone()
+            .unwrap_or_else(|| config.audit_log_file_path());
+        let audit_log =
+            AuditLog::open(&audit_log_path).map_err(|e| ServerErrorImpl::AuditLog { source: e })?;
+        let server = Arc::new(Server::http(addr).map_err(|e| ServerErrorImpl::Start {
+            addr: addr.to_string(),
+            msg: e.to_string(),
+        })?);
+        let local_addr =
+            server
+                .server_addr()
+                .to_ip()
+                .ok_or_else(|| ServerErrorImpl::NoIpAddress {
+                    addr: addr.to_string(),
+                })?;
+        let state = Arc::new(ServerState::new(config, Arc::new(Mutex::new(audit_log))));
+        let (sender, receiver) = sync_channel::<Request>(options.request_queue_size());
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = (0..options.request_threads().max(1))
+            .map(|_| {
+                let state = state.clone();
+                let receiver = receiver.clone();
+                let max_body_size = options.max_body_size();
+                std::thread::spawn(move || handle_requests(&state, &receiver, max_body_size))
+            })
+            .collect();
+        // The sender is dropped at the end of the thread, which stops the workers
+        let thread = {
+            let server = server.clone();
+            std::thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    if let Err(TrySendError::Full(request)) = sender.try_send(request) {
+                        respond(request, ApiResponse::error(503, "Too many requests"));
+                    }
+                }
+            })
+        };
+        info!("Server listening on {local_addr}");
+        Ok(Self {
+            addr: local_addr,
+            server,
+            state,
+            thread: Some(thread),
+            workers,
+        })
+    }
+
+    /// Address on which the server listens
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Block the current thread until the server is stopped
+    pub fn join(mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+
+    /// Stop the server and cancel the runs
+    pub fn stop(self) {
+        drop(self)
+    }
+}
+
+impl Drop for VerifierServer {
+    fn drop(&mut self) {
+        self.state.cancel_all();
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+        info!("Server on {} stopped", self.addr);
+    }
+}
+
+/// All the addresses resolved from `addr` are loopback addresses
+fn is_loopback(addr: &str) -> Result<bool, ServerErrorImpl> {
+    let addrs = addr
+        .to_socket_addrs()
+        .map_err(|e| ServerErrorImpl::Start {
+            addr: addr.to_string(),
+            msg: e.to_string(),
+        })?
+        .collect::<Vec<_>>();
+    Ok(!addrs.is_empty() && addrs.iter().all(|a| a.ip().is_loopback()))
+}
+
+/// Handle the requests of the queue until the queue is closed
+fn handle_requests(state: &ServerState, receiver: &Mutex<Receiver<Request>>, max_body_size: u64) {
+    loop {
+        let request = match receiver.lock().unwrap().recv() {
+            Ok(r) => r,
+            Err(_) => return,
+        };
+        handle_request(state, request, max_body_size);
+    }
+}
+
+fn handle_request(state: &ServerState, mut request: Request, max_body_size: u64) {
+    let method = request.method().as_str().to_string();
+    let url = request.url().to_string();
+    let response = match Route::parse(&method, &url) {
+        Ok(_)
+            if request
+                .body_length()
+                .is_some_and(|l| l as u64 > max_body_size) =>
+        {
+            ApiResponse::error(413, &format!("Body larger than {max_body_size} bytes"))
+        }
+        Ok(route) => {
+            let mut body = vec![];
+            match request
+                .as_reader()
+                .take(max_body_size + 1)
+                .read_to_end(&mut body)
+            {
+                Ok(_) if body.len() as u64 > max_body_size => {
+                    ApiResponse::error(413, &format!("Body larger than {max_body_size} bytes"))
+                }
+                Ok(_) => state.handle(route, &body),
+                Err(e) => ApiResponse::error(400, &format!("Error reading the body: {e}")),
+            }
+        }
+        Err(r) => r,
+    };
+    debug!("{method} {url}: {}", response.status);
+    respond(request, response);
+}
+
+fn respond(request: Request, response: ApiResponse) {
+    let method = request.method().as_str().to_string();
+    let url = request.url().to_string();
+    let mut http_response = Response::from_data(response.body).with_status_code(response.status);
+    if let Ok(header) = Header::from_bytes(&b"Content-Type"[..], response.content_type.as_bytes()) {
+        http_response = http_response.with_header(header);
+    }
+    if let Err(e) = request.respond(http_response) {
+        warn!("Error sending the response to {method} {url}: {e}");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        AuditEvent,
+        test::{CONTEXT_ZIP_PATH, TEST_DECRYPT_ZIP_PASSWORD, test_config},
+        verify_audit_log,
+    };
+    use rust_ev_verifier_lib::verification::{VerificationMetaDataList, VerificationPeriod};
+    use serde_json::Value;
+    use std::{
+        io::Write,
+        net::TcpStream,
+        path::PathBuf,
+        time::{Duration, Instant},
+    };
+
+    /// Options with an audit log of the test, so that the tests do not share the audit log
+    fn test_options(name: &str) -> ServerOptionsBuilder {
+        let mut builder = ServerOptionsBuilder::default();
+        builder.audit_log(PathBuf::from(".").join("test_temp_dir").join(format!(
+            "test_server_audit_{name}_{}.jsonl",
+            chrono::Local::now().format("%Y%m%d_%H%M%S%f")
+        )));
+        builder
+    }
+
+    /// Send the request and return the status and the body of the response
+    fn send_bytes(addr: SocketAddr, method: &str, path: &str, body: &[u8]) -> (u16, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\nContent-Length: {}\r\n\r\n",
+            body.len()
+        )
+        .unwrap();
+        // The server can answer before the end of the body (e.g. body too large)
+        let _ = stream.write_all(body);
+        let mut response = vec![];
+        let _ = stream.read_to_end(&mut response);
+        let response = String::from_utf8_lossy(&response).to_string();
+        let status = response
+            .split_whitespace()
+            .nth(1)
+            .unwrap()
+            .parse::<u16>()
+            .unwrap();
+        let body = response
+            .split_once("\r\n\r\n")
+            .map(|(_, b)| b.to_string())
+            .unwrap_or_default();
+        (status, body)
+    }
+
+    fn send(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+        send_bytes(addr, method, path, body.as_bytes())
+    }
+
+    fn send_json(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, Value) {
+        let (status, body) = send(addr, method, path, body);
+        (status, serde_json::from_str(&body).unwrap())
+    }
+
+    #[test]
+    fn test_server() {
+        let server = VerifierServer::start_with_options(
+            "127.0.0.1:0",
+            Arc::new(VerifierConfig::default()),
+            &test_options("server").build().unwrap(),
+        )
+        .unwrap();
+        let addr = server.addr();
+        assert_eq!(
+            send(addr, "GET", "/health", ""),
+            (200, r#"{"status":"ok"}"#.to_string())
+        );
+        assert_eq!(send(addr, "GET", "/runs/1", "").0, 404);
+        assert_eq!(send(addr, "POST", "/runs/1/cancel", "").0, 404);
+        assert_eq!(send(addr, "POST", "/runs", "not json").0, 400);
+        assert_eq!(send(addr, "POST", "/runs", r#"{"dataset_id": 1}"#).0, 404);
+        assert_eq!(
+            send(
+                addr,
+                "POST",
+                "/datasets",
+                r#"{"period": "other", "context_zip": "c.zip"}"#
+            )
+            .0,
+            400
+        );
+        assert_eq!(send(addr, "PUT", "/datasets/files/..", "x").0, 400);
+        assert_eq!(send(addr, "DELETE", "/health", "").0, 405);
+        server.stop();
+    }
+
+    #[test]
+    fn test_server_options() {
+        let config = Arc::new(VerifierConfig::default());
+        assert!(VerifierServer::start("0.0.0.0:0", config.clone()).is_err());
+        let options = test_options("options")
+            .max_body_size(10)
+            .request_threads(1)
+            .build()
+            .unwrap();
+        let server =
+            VerifierServer::start_with_options("localhost:0", config.clone(), &options).unwrap();
+        // The audit log is used by the server
+        assert!(VerifierServer::start_with_options("localhost:0", config, &options).is_err());
+        let addr = server.addr();
+        assert_eq!(
+            send(
+                addr,
+                "PUT",
+                "/datasets/files/big.zip",
+                "more than ten bytes"
+            )
+            .0,
+            413
+        );
+        assert_eq!(send(addr, "GET", "/health", "").0, 200);
+        server.stop();
+    }
+
+    #[test]
+    fn test_server_happy_path() {
+        let config = test_config("test_server");
+        let server = VerifierServer::start("127.0.0.1:0", config.clone()).unwrap();
+        let addr = server.addr();
+
+        let (status, upload) = send_bytes(
+            addr,
+            "PUT",
+            "/datasets/files/context.zip",
+            &std::fs::read(CONTEXT_ZIP_PATH).unwrap(),
+        );
+        assert_eq!(status, 201, "{upload}");
+        // Only the uploaded files can be extracted
+        let (status, _) = send_json(
+            addr,
+            "POST",
+            "/datasets",
+            &serde_json::json!({
+                "period": "setup",
+                "context_zip": std::fs::canonicalize(CONTEXT_ZIP_PATH).unwrap(),
+                "password": TEST_DECRYPT_ZIP_PASSWORD
+            })
+            .to_string(),
+        );
+        assert_eq!(status, 400);
+        let (status, dataset) = send_json(
+            addr,
+            "POST",
+            "/datasets",
+            &serde_json::json!({
+                "period": "setup",
+                "context_zip": "context.zip",
+                "password": TEST_DECRYPT_ZIP_PASSWORD
+            })
+            .to_string(),
+        );
+        assert_eq!(status, 201, "{dataset}");
+
+        let metadata = VerificationMetaDataList::load(config.get_verification_list_str()).unwrap();
+        let exclusion = metadata
+            .id_list_for_period(&VerificationPeriod::Setup)
+            .into_iter()
+            .filter(|id| *id != "01.01")
+            .collect::<Vec<_>>();
+        // The exclusion must be justified
+        let (status, _) = send_json(
+            addr,
+            "POST",
+            "/runs",
+            &serde_json::json!({
+                "dataset_id": dataset["dataset_id"],
+                "exclusion": exclusion,
+            })
+            .to_string(),
+        );
+        assert_eq!(status, 400);
+        let (status, run) = send_json(
+            addr,
+            "POST",
+            "/runs",
+            &serde_json::json!({
+                "dataset_id": dataset["dataset_id"],
+                "exclusion": exclusion,
+                "excluded_by": "Jane Doe",
+                "exclusion_reason": "Test of the server",
+                "strategy": "sequential"
+            })
+            .to_string(),
+        );
+        assert_eq!(status, 202, "{run}");
+        let run_id = run["run_id"].as_u64().unwrap();
+
+        let start = Instant::now();
+        let run_status = loop {
+            let (status, run_status) = send_json(addr, "GET", &format!("/runs/{run_id}"), "");
+            assert_eq!(status, 200);
+            if run_status["state"] == "finished" || run_status["state"] == "failed" {
+                break run_status;
+            }
+            assert!(start.elapsed() < Duration::from_secs(120), "{run_status}");
+            std::thread::sleep(Duration::from_millis(100));
+        };
+        assert_eq!(run_status["state"], "finished", "{run_status}");
+        assert_eq!(run_status["total"], 1);
+
+        let (status, verification) = send_json(
+            addr,
+            "GET",
+            &format!("/runs/{run_id}/verifications/01.01"),
+            "",
+        );
+        assert_eq!(status, 200);
+        assert_eq!(verification["id"], "01.01");
+        assert!(verification["duration_secs"].is_number());
+
+        let (status, report) = send(addr, "GET", &format!("/runs/{run_id}/reports/txt"), "");
+        assert_eq!(status, 200);
+        assert!(report.contains("01.01"));
+        server.stop();
+
+        let records = verify_audit_log(&config.audit_log_file_path()).unwrap();
+        assert!(records.iter().any(|r| matches!(
+            r.event(),
+            AuditEvent::VerificationsExcluded { by, reason, .. }
+                if by == "Jane Doe" && reason == "Test of the server"
+        )));
+        // The head of the log at the end of the run is anchored in the report
+        let run_finished = records
+            .iter()
+            .find(|r| matches!(r.event(), AuditEvent::RunFinished { .. }))
+            .unwrap();
+        assert!(report.contains(run_finished.hash()), "{report}");
+        assert!(matches!(
+            records.last().unwrap().event(),
+            AuditEvent::ReportGenerated { .. }
+        ));
+        let _ = std::fs::remove_dir_all(config.root_dir_path());
+    }
+}
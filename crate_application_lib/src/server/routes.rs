@@ -0,0 +1,222 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Routes of the API and responses, independent of the HTTP transport
+
+use crate::report::ReportOutputFileType;
+use serde::Serialize;
+use std::str::FromStr;
+
+/// Route of a request (see the list of the endpoints in [super])
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Route {
+    Health,
+    UploadDatasetFile {
+        name: String,
+    },
+    ExtractDataset,
+    StartRun,
+    RunStatus {
+        run_id: u64,
+    },
+    VerificationResult {
+        run_id: u64,
+        id: String,
+    },
+    CancelRun {
+        run_id: u64,
+    },
+    Report {
+        run_id: u64,
+        output_type: ReportOutputFileType,
+    },
+}
+
+impl Route {
+    /// Find the route of the method and the url (the query string is ignored)
+    ///
+    /// Return the error response if no route matches
+    pub(super) fn parse(method: &str, url: &str) -> Result<Self, ApiResponse> {
+        let path = url.split(['?', '#']).next().unwrap_or_default();
+        let segments = path
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+        let route = match segments.as_slice() {
+            ["health"] => Some(("GET", Self::Health)),
+            ["datasets"] => Some(("POST", Self::ExtractDataset)),
+            ["datasets", "files", name] => Some((
+                "PUT",
+                Self::UploadDatasetFile {
+                    name: name.to_string(),
+                },
+            )),
+            ["runs"] => Some(("POST", Self::StartRun)),
+            ["runs", run_id] => Some((
+                "GET",
+                Self::RunStatus {
+                    run_id: parse_id(run_id)?,
+                },
+            )),
+            ["runs", run_id, "verifications", id] => Some((
+                "GET",
+                Self::VerificationResult {
+                    run_id: parse_id(run_id)?,
+                    id: id.to_string(),
+                },
+            )),
+            ["runs", run_id, "cancel"] => Some((
+                "POST",
+                Self::CancelRun {
+                    run_id: parse_id(run_id)?,
+                },
+            )),
+            ["runs", run_id, "reports", output_type] => Some((
+                "GET",
+                Self::Report {
+                    run_id: parse_id(run_id)?,
+                    output_type: ReportOutputFileType::from_str(output_type).map_err(|_| {
+                        ApiResponse::error(404, &format!("Unknown report type {output_type}"))
+                    })?,
+                },
+            )),
+            _ => None,
+        };
+        match route {
+            Some((m, r)) if m == method => Ok(r),
+            Some((m, _)) => Err(ApiResponse::error(
+                405,
+                &format!("Method {method} not allowed for {path}. Expected: {m}"),
+            )),
+            None => Err(ApiResponse::error(404, &format!("No route for {path}"))),
+        }
+    }
+}
+
+fn parse_id(s: &str) -> Result<u64, ApiResponse> {
+    s.parse::<u64>()
+        .map_err(|_| ApiResponse::error(404, &format!("Invalid id {s}")))
+}
+
+/// Content type of the report file
+pub(super) fn report_content_type(output_type: ReportOutputFileType) -> &'static str {
+    match output_type {
+        ReportOutputFileType::Txt => "text/plain; charset=utf-8",
+        ReportOutputFileType::Html => "text/html; charset=utf-8",
+        ReportOutputFileType::Pdf => "application/pdf",
+        ReportOutputFileType::JUnit => "application/xml",
+        ReportOutputFileType::EventsCsv => "text/csv; charset=utf-8",
+        ReportOutputFileType::EventsJsonl => "application/jsonl",
+    }
+}
+
+/// Response of the API
+#[derive(Debug)]
+pub(super) struct ApiResponse {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    error: &'a str,
+}
+
+impl ApiResponse {
+    /// Response with the value as json
+    pub(super) fn json<T: Serialize>(status: u16, value: &T) -> Self {
+        match serde_json::to_vec(value) {
+            Ok(body) => Self {
+                status,
+                content_type: "application/json",
+                body,
+            },
+            Err(e) => Self::error(500, &format!("Error serializing the response: {e}")),
+        }
+    }
+
+    /// Error response, with the message as json (`{"error": "..."}`)
+    pub(super) fn error(status: u16, msg: &str) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: serde_json::to_vec(&ErrorBody { error: msg }).unwrap_or_default(),
+        }
+    }
+
+    /// Response with the content of a file
+    pub(super) fn file(content_type: &'static str, body: Vec<u8>) -> Self {
+        Self {
+            status: 200,
+            content_type,
+            body,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Route::parse("GET", "/health").unwrap(), Route::Health);
+        assert_eq!(
+            Route::parse("PUT", "/datasets/files/context.zip").unwrap(),
+            Route::UploadDatasetFile {
+                name: "context.zip".to_string()
+            }
+        );
+        assert_eq!(
+            Route::parse("GET", "/runs/3/verifications/05.01?x=1").unwrap(),
+            Route::VerificationResult {
+                run_id: 3,
+                id: "05.01".to_string()
+            }
+        );
+        assert_eq!(
+            Route::parse("GET", "/runs/3/reports/junit.xml").unwrap(),
+            Route::Report {
+                run_id: 3,
+                output_type: ReportOutputFileType::JUnit
+            }
+        );
+        assert_eq!(
+            Route::parse("POST", "/runs/3/cancel").unwrap(),
+            Route::CancelRun { run_id: 3 }
+        );
+        assert_eq!(Route::parse("GET", "/runs").unwrap_err().status, 405);
+        assert_eq!(Route::parse("GET", "/runs/x").unwrap_err().status, 404);
+        assert_eq!(
+            Route::parse("GET", "/runs/3/reports/doc")
+                .unwrap_err()
+                .status,
+            404
+        );
+        assert_eq!(Route::parse("GET", "/unknown").unwrap_err().status, 404);
+    }
+
+    #[test]
+    fn test_error_response() {
+        let r = ApiResponse::error(404, "not \"found\"");
+        assert_eq!(r.content_type, "application/json");
+        assert_eq!(
+            String::from_utf8(r.body).unwrap(),
+            r#"{"error":"not \"found\""}"#
+        );
+    }
+}
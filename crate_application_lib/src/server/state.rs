@@ -0,0 +1,608 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! State of the server: the extracted datasets and the runs
+
+use super::routes::{ApiResponse, Route, report_content_type};
+use crate::{
+    AnyRunStrategy, AuditEvent, AuditLog, ExtractDataSetResults, RunAudit, RunCancellation,
+    RunInformation, RunStrategyKind, Runner,
+    report::{
+        PDFReportBackend, PDFReportOptionsBuilder, ReportConfigBuilder, ReportData,
+        ReportOutputFileOptionsBuilder, ReportOutputFileType, generate_files_from_json,
+    },
+};
+use rust_ev_verifier_lib::{
+    VerifierConfig,
+    file_structure::VerificationDirectory,
+    verification::{VerificationMetaDataList, VerificationPeriod, VerificationStatus},
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+use tracing::{error, info};
+
+/// Directory in the data directory where the uploaded files are stored
+const UPLOAD_DIR_NAME: &str = "uploads";
+
+#[derive(Deserialize)]
+struct ExtractDatasetRequest {
+    period: String,
+    /// Name or path of the uploaded file
+    context_zip: PathBuf,
+    /// Name or path of the uploaded file
+    tally_zip: Option<PathBuf>,
+    /// If not set, the password of the configuration is used
+    password: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct StartRunRequest {
+    dataset_id: u64,
+    /// If not set, the period of the dataset
+    period: Option<String>,
+    #[serde(default)]
+    exclusion: Vec<String>,
+    #[serde(default)]
+    strategy: RunStrategyKind,
+    /// Mandatory if verifications are excluded
+    excluded_by: Option<String>,
+    /// Mandatory if verifications are excluded
+    exclusion_reason: Option<String>,
+}
+
+#[derive(Serialize)]
+struct UploadResponse {
+    path: PathBuf,
+}
+
+#[derive(Serialize)]
+struct DatasetResponse {
+    dataset_id: u64,
+    period: String,
+    location: PathBuf,
+}
+
+#[derive(Serialize)]
+struct StartRunResponse {
+    run_id: u64,
+}
+
+#[derive(Serialize)]
+struct RunStatusResponse {
+    run_id: u64,
+    period: String,
+    /// `prepared`, `running`, `finished` or `failed`
+    state: &'static str,
+    cancelled: bool,
+    error: Option<String>,
+    finished: usize,
+    total: usize,
+    verifications: BTreeMap<String, String>,
+}
+
+#[derive(Serialize)]
+struct VerificationResultResponse {
+    id: String,
+    name: Option<String>,
+    status: String,
+    errors: Vec<String>,
+    failures: Vec<String>,
+    informations: Vec<String>,
+    duration_secs: Option<f64>,
+}
+
+/// Dataset extracted over the API
+struct Dataset {
+    period: VerificationPeriod,
+    extracted: ExtractDataSetResults,
+}
+
+/// Run started over the API
+struct RunJob {
+    period: VerificationPeriod,
+    run_information: Arc<Mutex<RunInformation>>,
+    cancellation: RunCancellation,
+    error: Mutex<Option<String>>,
+}
+
+/// State shared by the threads handling the requests
+pub(super) struct ServerState {
+    config: Arc<VerifierConfig>,
+    audit_log: Arc<Mutex<AuditLog>>,
+    next_id: AtomicU64,
+    datasets: Mutex<HashMap<u64, Arc<Dataset>>>,
+    runs: Mutex<HashMap<u64, Arc<RunJob>>>,
+}
+
+fn parse_body<T: DeserializeOwned>(body: &[u8]) -> Result<T, ApiResponse> {
+    serde_json::from_slice(body).map_err(|e| ApiResponse::error(400, &format!("Invalid body: {e}")))
+}
+
+fn parse_period(period: &str) -> Result<VerificationPeriod, ApiResponse> {
+    VerificationPeriod::from_str(period)
+        .map_err(|_| ApiResponse::error(400, &format!("Unknown period {period}")))
+}
+
+/// The name of an uploaded file cannot contain a path
+fn is_valid_file_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+}
+
+impl ServerState {
+    pub(super) fn new(config: Arc<VerifierConfig>, audit_log: Arc<Mutex<AuditLog>>) -> Self {
+        Self {
+            config,
+            audit_log,
+            next_id: AtomicU64::new(1),
+            datasets: Mutex::new(HashMap::new()),
+            runs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn next_id(&self) -> u64 {
+        self.next_id.fetch_add(1, Ordering::SeqCst)
+    }
+
+    fn run_job(&self, run_id: u64) -> Result<Arc<RunJob>, ApiResponse> {
+        self.runs
+            .lock()
+            .unwrap()
+            .get(&run_id)
+            .cloned()
+            .ok_or_else(|| ApiResponse::error(404, &format!("Run {run_id} not found")))
+    }
+
+    /// Cancel all the runs, e.g. when the server stops
+    pub(super) fn cancel_all(&self) {
+        for job in self.runs.lock().unwrap().values() {
+            job.cancellation.cancel();
+        }
+    }
+
+    /// Handle the request with the route and the body
+    pub(super) fn handle(&self, route: Route, body: &[u8]) -> ApiResponse {
+        match route {
+            Route::Health => Ok(ApiResponse::json(200, &BTreeMap::from([("status", "ok")]))),
+            Route::UploadDatasetFile { name } => self.upload_dataset_file(&name, body),
+            Route::ExtractDataset => self.extract_dataset(body),
+            Route::StartRun => self.start_run(body),
+            Route::RunStatus { run_id } => self.run_status(run_id),
+            Route::VerificationResult { run_id, id } => self.verification_result(run_id, &id),
+            Route::CancelRun { run_id } => self.cancel_run(run_id),
+            Route::Report {
+                run_id,
+                output_type,
+            } => self.report(run_id, output_type),
+        }
+        .unwrap_or_else(|e| e)
+    }
+
+    /// Append the event to the audit log
+    fn audit(&self, event: AuditEvent) -> Result<(), ApiResponse> {
+        self.audit_log
+            .lock()
+            .unwrap()
+            .append(event)
+            .map(|_| ())
+            .map_err(|e| ApiResponse::error(500, &format!("Error writing the audit log: {e}")))
+    }
+
+    fn upload_dir(&self) -> PathBuf {
+        self.config.data_dir_path().join(UPLOAD_DIR_NAME)
+    }
+
+    /// Path of an uploaded file, given with its name or its path
+    ///
+    /// Return an error if the file is not in the upload directory
+    fn uploaded_file_path(&self, path: &Path) -> Result<PathBuf, ApiResponse> {
+        let not_uploaded = || {
+            ApiResponse::error(
+                400,
+                &format!("The file {} is not an uploaded file", path.display()),
+            )
+        };
+        let dir = self
+            .upload_dir()
+            .canonicalize()
+            .map_err(|_| not_uploaded())?;
+        let res = dir.join(path).canonicalize().map_err(|_| not_uploaded())?;
+        match res.starts_with(&dir) && res.is_file() {
+            true => Ok(res),
+            false => Err(not_uploaded()),
+        }
+    }
+
+    fn upload_dataset_file(&self, name: &str, body: &[u8]) -> Result<ApiResponse, ApiResponse> {
+        if !is_valid_file_name(name) {
+            return Err(ApiResponse::error(
+                400,
+                &format!("Invalid file name {name}"),
+            ));
+        }
+        let dir = self.upload_dir();
+        let path = dir.join(name);
+        std::fs::create_dir_all(&dir)
+            .and_then(|_| std::fs::write(&path, body))
+            .map_err(|e| {
+                ApiResponse::error(500, &format!("Error writing {}: {e}", path.display()))
+            })?;
+        info!("Uploaded file {} ({} bytes)", path.display(), body.len());
+        Ok(ApiResponse::json(201, &UploadResponse { path }))
+    }
+
+    fn extract_dataset(&self, body: &[u8]) -> Result<ApiResponse, ApiResponse> {
+        let request: ExtractDatasetRequest = parse_body(body)?;
+        let period = parse_period(&request.period)?;
+        let password = match request.password {
+            Some(p) => p,
+            None => self
+                .config
+                .decrypt_password()
+                .map_err(|e| ApiResponse::error(400, &e.to_string()))?,
+        };
+        let context_zip = self.uploaded_file_path(&request.context_zip)?;
+        let tally_zip = request
+            .tally_zip
+            .as_deref()
+            .map(|p| self.uploaded_file_path(p))
+            .transpose()?;
+        let extracted = ExtractDataSetResults::extract_datasets(
+            period,
+            &context_zip,
+            tally_zip.as_deref(),
+            &password,
+            &self.config,
+        )
+        .map_err(|e| ApiResponse::error(400, &e.to_string()))?;
+        self.audit(AuditEvent::datasets_extracted(period, &extracted))?;
+        let dataset_id = self.next_id();
+        let location = extracted.location().to_path_buf();
+        self.datasets
+            .lock()
+            .unwrap()
+            .insert(dataset_id, Arc::new(Dataset { period, extracted }));
+        Ok(ApiResponse::json(
+            201,
+            &DatasetResponse {
+                dataset_id,
+                period: period.as_ref().to_string(),
+                location,
+            },
+        ))
+    }
+
+    fn start_run(&self, body: &[u8]) -> Result<ApiResponse, ApiResponse> {
+        let request: StartRunRequest = parse_body(body)?;
+        let dataset = self
+            .datasets
+            .lock()
+            .unwrap()
+            .get(&request.dataset_id)
+            .cloned()
+            .ok_or_else(|| {
+                ApiResponse::error(404, &format!("Dataset {} not found", request.dataset_id))
+            })?;
+        let period = match &request.period {
+            Some(p) => parse_period(p)?,
+            None => dataset.period,
+        };
+        // The dataset of the tally contains also the context for the setup
+        if period == VerificationPeriod::Tally && dataset.period == VerificationPeriod::Setup {
+            return Err(ApiResponse::error(
+                400,
+                "The tally cannot be run on a dataset of the setup",
+            ));
+        }
+        let audit = match (
+            request.exclusion.is_empty(),
+            &request.excluded_by,
+            &request.exclusion_reason,
+        ) {
+            (true, _, _) => RunAudit::new(self.audit_log.clone()),
+            (false, Some(by), Some(reason)) => {
+                RunAudit::new(self.audit_log.clone()).with_exclusion_justification(by, reason)
+            }
+            (false, _, _) => {
+                return Err(ApiResponse::error(
+                    400,
+                    "The person excluding the verifications and the reason must be given",
+                ));
+            }
+        };
+        let metadata = VerificationMetaDataList::load(self.config.get_verification_list_str())
+            .map_err(|e| ApiResponse::error(500, &e.to_string()))?;
+        let mut run_information = RunInformation::new(self.config.clone());
+        run_information
+            .prepare_data_for_start(period, &metadata, &request.exclusion)
+            .map_err(|e| ApiResponse::error(400, &e.to_string()))?;
+        run_information.add_extracted_information(&dataset.extracted);
+        let job = Arc::new(RunJob {
+            period,
+            run_information: Arc::new(Mutex::new(run_information)),
+            cancellation: RunCancellation::default(),
+            error: Mutex::new(None),
+        });
+        let run_id = self.next_id();
+        let location = dataset.extracted.location().to_path_buf();
+        let config = self.config.clone();
+        let exclusion = request.exclusion;
+        let strategy = request
+            .strategy
+            .strategy(&metadata, &config)
+            .map_err(|e| ApiResponse::error(500, &e.to_string()))?;
+        spawn_run(config, location, exclusion, strategy, audit, job.clone());
+        self.runs.lock().unwrap().insert(run_id, job);
+        info!("Run {run_id} of the {} started", period.as_ref());
+        Ok(ApiResponse::json(202, &StartRunResponse { run_id }))
+    }
+
+    fn run_status(&self, run_id: u64) -> Result<ApiResponse, ApiResponse> {
+        let job = self.run_job(run_id)?;
+        let error = job.error.lock().unwrap().clone();
+        let ri = job.run_information.lock().unwrap();
+        let state = if ri.is_finished() {
+            "finished"
+        } else if error.is_some() {
+            "failed"
+        } else if ri.is_running() {
+            "running"
+        } else {
+            "prepared"
+        };
+        let verifications = ri
+            .verifications_status()
+            .iter()
+            .map(|(id, s)| (id.clone(), s.as_ref().to_string()))
+            .collect::<BTreeMap<_, _>>();
+        let finished = ri
+            .verifications_status()
+            .values()
+            .filter(|s| {
+                !matches!(
+                    s,
+                    VerificationStatus::NotStarted | VerificationStatus::Running
+                )
+            })
+            .count();
+        Ok(ApiResponse::json(
+            200,
+            &RunStatusResponse {
+                run_id,
+                period: job.period.as_ref().to_string(),
+                state,
+                cancelled: job.cancellation.is_cancelled(),
+                error,
+                finished,
+                total: verifications.len(),
+                verifications,
+            },
+        ))
+    }
+
+    fn verification_result(&self, run_id: u64, id: &str) -> Result<ApiResponse, ApiResponse> {
+        let job = self.run_job(run_id)?;
+        let ri = job.run_information.lock().unwrap();
+        let status = ri.verifications_status().get(id).ok_or_else(|| {
+            ApiResponse::error(404, &format!("Verification {id} not found in run {run_id}"))
+        })?;
+        let (errors, failures) = ri
+            .verifications_with_errors_and_failures()
+            .iter()
+            .find(|(k, _)| k.as_str() == id)
+            .map(|(_, v)| v.clone())
+            .unwrap_or_default();
+        Ok(ApiResponse::json(
+            200,
+            &VerificationResultResponse {
+                id: id.to_string(),
+                name: ri
+                    .verification_metadata()
+                    .and_then(|md| md.meta_data_from_id(id))
+                    .map(|md| md.name().to_string()),
+                status: status.as_ref().to_string(),
+                errors,
+                failures,
+                informations: ri
+                    .verifications_informations()
+                    .get(id)
+                    .cloned()
+                    .unwrap_or_default(),
+                duration_secs: ri
+                    .verifications_performance()
+                    .get(id)
+                    .map(|p| p.duration.as_secs_f64()),
+            },
+        ))
+    }
+
+    fn cancel_run(&self, run_id: u64) -> Result<ApiResponse, ApiResponse> {
+        let job = self.run_job(run_id)?;
+        if job.run_information.lock().unwrap().is_finished() {
+            return Err(ApiResponse::error(
+                409,
+                &format!("Run {run_id} already finished"),
+            ));
+        }
+        job.cancellation.cancel();
+        info!("Run {run_id} cancelled");
+        Ok(ApiResponse::json(202, &StartRunResponse { run_id }))
+    }
+
+    fn report(
+        &self,
+        run_id: u64,
+        output_type: ReportOutputFileType,
+    ) -> Result<ApiResponse, ApiResponse> {
+        let job = self.run_job(run_id)?;
+        let format_date = self.config.report_format_date();
+        let json = {
+            let ri = job.run_information.lock().unwrap();
+            if !ri.is_finished() {
+                return Err(ApiResponse::error(
+                    409,
+                    &format!("Run {run_id} not finished"),
+                ));
+            }
+            let report_config = ReportConfigBuilder::default()
+                .title(format!("Verification of the {}", job.period.as_ref()))
+                .date_time(chrono::Local::now().format(&format_date).to_string())
+                .tab_size(
+                    self.config
+                        .txt_report_tab_size()
+                        .map_err(|e| ApiResponse::error(500, &e.to_string()))?,
+                )
+                .fromat_date(format_date.clone())
+                .build()
+                .map_err(|e| ApiResponse::error(500, &e.to_string()))?;
+            ReportData::new(report_config, &ri)
+                .to_json()
+                .map_err(|e| ApiResponse::error(500, &e.to_string()))?
+        };
+        // Each request generates the report in its own directory, so that concurrent requests
+        // do not write the same file
+        let directory = self
+            .config
+            .report_dir_path()
+            .join(format!("server-run-{run_id}-{}", self.next_id()));
+        let res = self
+            .generate_report_file(&directory, run_id, output_type, &json)
+            .and_then(|path| {
+                let content = std::fs::read(&path).map_err(|e| {
+                    ApiResponse::error(500, &format!("Error reading {}: {e}", path.display()))
+                })?;
+                self.audit(
+                    AuditEvent::report_generated(&path)
+                        .map_err(|e| ApiResponse::error(500, &e.to_string()))?,
+                )?;
+                Ok(content)
+            });
+        let _ = std::fs::remove_dir_all(&directory);
+        Ok(ApiResponse::file(report_content_type(output_type), res?))
+    }
+
+    /// Generate the report file in the directory and return its path
+    ///
+    /// The PDF is rendered natively, so that no browser is needed
+    fn generate_report_file(
+        &self,
+        directory: &Path,
+        run_id: u64,
+        output_type: ReportOutputFileType,
+        json: &str,
+    ) -> Result<PathBuf, ApiResponse> {
+        let filename = format!("report-{run_id}");
+        let mut builder = ReportOutputFileOptionsBuilder::default()
+            .directory(directory)
+            .filename_without_extension(filename.as_str())
+            .add_output_type(output_type);
+        if output_type == ReportOutputFileType::Pdf {
+            let mut pdf_builder = PDFReportOptionsBuilder::default();
+            pdf_builder.backend(PDFReportBackend::Native);
+            builder = builder.pdf_options(
+                pdf_builder
+                    .build()
+                    .map_err(|e| ApiResponse::error(500, &e.to_string()))?,
+            );
+        }
+        for member in self.config.report_electoral_board_members() {
+            builder = builder.add_explicit_electoral_board_member(&member);
+        }
+        let options = builder
+            .build()
+            .map_err(|e| ApiResponse::error(500, &e.to_string()))?;
+        let errors = generate_files_from_json(json, options);
+        if !errors.is_empty() {
+            let msg = errors
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>()
+                .join(" / ");
+            return Err(ApiResponse::error(
+                500,
+                &format!("Error generating the report: {msg}"),
+            ));
+        }
+        Ok(directory.join(format!("{filename}.{output_type}")))
+    }
+}
+
+/// Run the verifications in a new thread, collecting the information in the job
+fn spawn_run(
+    config: Arc<VerifierConfig>,
+    location: PathBuf,
+    exclusion: Vec<String>,
+    strategy: AnyRunStrategy,
+    audit: RunAudit,
+    job: Arc<RunJob>,
+) {
+    std::thread::spawn(move || {
+        if let Err(msg) = run(config, &location, &exclusion, strategy, audit, &job) {
+            error!("Error during the run: {msg}");
+            *job.error.lock().unwrap() = Some(msg);
+        }
+    });
+}
+
+fn run(
+    config: Arc<VerifierConfig>,
+    location: &Path,
+    exclusion: &[String],
+    strategy: AnyRunStrategy,
+    audit: RunAudit,
+    job: &RunJob,
+) -> Result<(), String> {
+    let metadata = VerificationMetaDataList::load(config.get_verification_list_str())
+        .map_err(|e| e.to_string())?;
+    let mut runner = Runner::new_with_run_information(
+        Arc::new(VerificationDirectory::new(&job.period, location)),
+        &job.period,
+        &metadata,
+        exclusion,
+        strategy,
+        config,
+        job.run_information.clone(),
+    )
+    .map_err(|e| e.to_string())?;
+    runner.set_cancellation(job.cancellation.clone());
+    runner.set_audit(audit);
+    runner.run_all(&metadata).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_file_name() {
+        assert!(is_valid_file_name("context-export_1.zip"));
+        assert!(!is_valid_file_name("../context.zip"));
+        assert!(!is_valid_file_name("dir/context.zip"));
+        assert!(!is_valid_file_name(".hidden"));
+        assert!(!is_valid_file_name(""));
+    }
+}
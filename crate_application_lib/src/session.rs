@@ -0,0 +1,279 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Session running the setup and the tally verifications on the same datasets
+//!
+//! The session uses the context and the tally datasets extracted once (see
+//! [ExtractDataSetResults::extract_datasets] with the period [VerificationPeriod::Tally]).
+//! Both periods share one [VerificationDirectory], the keystore, which is read once at
+//! the creation of the session, and the cache of the signature verifications (see [SharedRunState]).
+//! The information of each period is collected in its own
+//! [RunInformation], so that the consolidated report (see [crate::report::SessionReportData])
+//! contains one section per period.
+
+use crate::{
+    ExtractDataSetResults, RunAudit, RunInformation, RunStrategy, Runner, RunnerError,
+    RunnerErrorImpl,
+};
+use rust_ev_verifier_lib::{
+    DatasetTypeKind, VerifierConfig,
+    file_structure::VerificationDirectory,
+    verification::{SharedRunState, VerificationMetaDataList, VerificationPeriod},
+};
+use std::sync::{Arc, Mutex, MutexGuard};
+use tracing::info;
+
+/// Periods of the session, in the order of the run
+pub const SESSION_PERIODS: [VerificationPeriod; 2] =
+    [VerificationPeriod::Setup, VerificationPeriod::Tally];
+
+/// Session running the setup and the tally verifications
+pub struct VerificationSession {
+    config: Arc<VerifierConfig>,
+    verification_directory: Arc<VerificationDirectory>,
+    shared_state: SharedRunState,
+    setup_information: Arc<Mutex<RunInformation>>,
+    tally_information: Arc<Mutex<RunInformation>>,
+    audit: Option<RunAudit>,
+}
+
+impl VerificationSession {
+    /// Create a new session
+    ///
+    /// - `extracted_dataset_result`: The extracted datasets. The tally dataset must be extracted
+    /// - `verification_metadata`: The list of the metadata of the verifications
+    /// - `excluded_verifications`: The list of verifications excluded (list of ids of both periods)
+    /// - `config`: The configuration of the verifier
+    pub fn new(
+        extracted_dataset_result: &ExtractDataSetResults,
+        verification_metadata: &VerificationMetaDataList,
+        excluded_verifications: &[String],
+        config: Arc<VerifierConfig>,
+    ) -> Result<Self, RunnerError> {
+        if extracted_dataset_result
+            .dataset_metadata(&DatasetTypeKind::Tally)
+            .is_none()
+        {
+            return Err(RunnerError::from(RunnerErrorImpl::ExtractFileMissing {
+                period: "tally",
+            }));
+        }
+        let prepare =
+            |period: VerificationPeriod| -> Result<Arc<Mutex<RunInformation>>, RunnerError> {
+                let ids = verification_metadata.id_list_for_period(&period);
+                let exclusion = excluded_verifications
+                    .iter()
+                    .filter(|id| ids.contains(&id.as_str()))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let mut run_information = RunInformation::new(config.clone());
+                run_information.prepare_data_for_start(
+                    period,
+                    verification_metadata,
+                    &exclusion,
+                )?;
+                run_information.add_extracted_information(extracted_dataset_result);
+                Ok(Arc::new(Mutex::new(run_information)))
+            };
+        let setup_information = prepare(VerificationPeriod::Setup)?;
+        let tally_information = prepare(VerificationPeriod::Tally)?;
+        Ok(Self {
+            shared_state: SharedRunState::new(&config),
+            config,
+            verification_directory: Arc::new(VerificationDirectory::new(
+                &VerificationPeriod::Tally,
+                extracted_dataset_result.location(),
+            )),
+            setup_information,
+            tally_information,
+            audit: None,
+        })
+    }
+
+    /// Audit the runs of both periods in the log (see [RunAudit])
+    pub fn set_audit(&mut self, audit: RunAudit) {
+        self.audit = Some(audit);
+    }
+
+    /// Run the verifications of the setup and then of the tally
+    ///
+    /// The run of the tally starts even if the setup has errors or failures, so that the
+    /// consolidated report is complete.
+    pub fn run_all<S>(
+        &mut self,
+        verification_metadata: &VerificationMetaDataList,
+        run_strategy: S,
+    ) -> Result<(), RunnerError>
+    where
+        S: for<'b> RunStrategy<'b> + Clone,
+    {
+        if self.is_running_or_finished() {
+            return Err(RunnerError::from(RunnerErrorImpl::HasAlreadyRun));
+        }
+        info!("Start the session (setup and tally)");
+        for period in SESSION_PERIODS {
+            self.run_period(period, verification_metadata, run_strategy.clone())?;
+        }
+        Ok(())
+    }
+
+    fn run_period<S>(
+        &self,
+        period: VerificationPeriod,
+        verification_metadata: &VerificationMetaDataList,
+        run_strategy: S,
+    ) -> Result<(), RunnerError>
+    where
+        S: for<'b> RunStrategy<'b>,
+    {
+        let config = self.config.clone();
+        let run_information = self.shared_run_information(period).clone();
+        let exclusion = run_information
+            .lock()
+            .unwrap()
+            .excluded_verifications()
+            .to_vec();
+        let mut runner = Runner::new_with_shared_state(
+            self.verification_directory.clone(),
+            &period,
+            verification_metadata,
+            &exclusion,
+            run_strategy,
+            config,
+            run_information,
+            self.shared_state.clone(),
+        )?;
+        if let Some(audit) = &self.audit {
+            runner.set_audit(audit.clone());
+        }
+        runner.run_all(verification_metadata)
+    }
+
+    /// Shared information of the run of the given period, updated by the actions of the runner
+    pub fn shared_run_information(
+        &self,
+        period: VerificationPeriod,
+    ) -> &Arc<Mutex<RunInformation>> {
+        match period {
+            VerificationPeriod::Setup => &self.setup_information,
+            VerificationPeriod::Tally => &self.tally_information,
+        }
+    }
+
+    /// Information of the run of the given period
+    ///
+    /// The information is locked as long as the guard is kept
+    pub fn run_information(&self, period: VerificationPeriod) -> MutexGuard<'_, RunInformation> {
+        self.shared_run_information(period).lock().unwrap()
+    }
+
+    /// Information of the run of the setup
+    pub fn setup_information(&self) -> MutexGuard<'_, RunInformation> {
+        self.run_information(VerificationPeriod::Setup)
+    }
+
+    /// Information of the run of the tally
+    pub fn tally_information(&self) -> MutexGuard<'_, RunInformation> {
+        self.run_information(VerificationPeriod::Tally)
+    }
+
+    /// The verification directory shared by both periods
+    pub fn verification_directory(&self) -> &VerificationDirectory {
+        &self.verification_directory
+    }
+
+    /// State shared by the runs of both periods (keystore and cache of the signature verifications)
+    pub fn shared_state(&self) -> &SharedRunState {
+        &self.shared_state
+    }
+
+    /// Configuration of the verifier
+    pub fn config(&self) -> &Arc<VerifierConfig> {
+        &self.config
+    }
+
+    /// The session is running or finished
+    pub fn is_running_or_finished(&self) -> bool {
+        SESSION_PERIODS
+            .iter()
+            .any(|p| self.run_information(*p).is_running_or_finished())
+    }
+
+    /// Both periods are finished
+    pub fn is_finished(&self) -> bool {
+        SESSION_PERIODS
+            .iter()
+            .all(|p| self.run_information(*p).is_finished())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        RunSequential,
+        test::{CONTEXT_ZIP_PATH, TALLY_ZIP_PATH, TEST_DECRYPT_ZIP_PASSWORD, test_config},
+    };
+    use rust_ev_verifier_lib::verification::VerificationStatus;
+    use std::path::Path;
+
+    #[test]
+    fn test_keystore_read_once() {
+        let config = test_config("test_session");
+        let extracted = ExtractDataSetResults::extract_datasets(
+            VerificationPeriod::Tally,
+            Path::new(CONTEXT_ZIP_PATH),
+            Some(Path::new(TALLY_ZIP_PATH)),
+            TEST_DECRYPT_ZIP_PASSWORD,
+            &config,
+        )
+        .unwrap();
+        let metadata = VerificationMetaDataList::load(config.get_verification_list_str()).unwrap();
+        // Only the verifications of the signatures of the canton config (setup) and of the eCH-0222 (tally)
+        let run_ids = ["02.01", "07.05"];
+        let exclusion = SESSION_PERIODS
+            .iter()
+            .flat_map(|p| metadata.id_list_for_period(p))
+            .filter(|id| !run_ids.contains(id))
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+        let mut session =
+            VerificationSession::new(&extracted, &metadata, &exclusion, config.clone()).unwrap();
+        assert!(session.shared_state().keystore().is_ok());
+        // The keystore cannot be read anymore: the runs of both periods use the keystore read by the session
+        for entry in std::fs::read_dir(config.direct_trust_dir_path()).unwrap() {
+            std::fs::remove_file(entry.unwrap().path()).unwrap();
+        }
+        assert!(config.keystore().is_err());
+        session.run_all(&metadata, RunSequential).unwrap();
+        assert!(session.is_finished());
+        assert_eq!(
+            session
+                .setup_information()
+                .verifications_status()
+                .get("02.01"),
+            Some(&VerificationStatus::FinishedSuccessfully)
+        );
+        assert_eq!(
+            session
+                .tally_information()
+                .verifications_status()
+                .get("07.05"),
+            Some(&VerificationStatus::FinishedSuccessfully)
+        );
+        let _ = std::fs::remove_dir_all(config.root_dir_path());
+    }
+}
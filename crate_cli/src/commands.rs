@@ -0,0 +1,413 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Execution of the subcommands
+//!
+//! The errors are returned as message, with the chain of the sources (see [Report])
+
+use super::{CheckArgs, Command, DatasetArgs, EXIT_FAILED, ListArgs, ReportArgs, RunArgs};
+use rust_ev_system_library::rust_ev_crypto_primitives::prelude::EncodeTrait;
+use rust_ev_verifier_application_lib::{
+    AuditEvent, AuditLog, ExtractDataSetResults, GENESIS_HASH, RunAudit, RunInformation,
+    RunStrategyKind, Runner,
+    report::{
+        PDFReportBackend, PDFReportOptionsBuilder, ReportConfigBuilder, ReportData,
+        ReportOutputFileOptions, ReportOutputFileOptionsBuilder, ReportOutputFileType,
+        generate_files_from_json, generate_files_from_json_file,
+    },
+    verify_audit_log,
+};
+use rust_ev_verifier_lib::{
+    Report, VerifierConfig,
+    file_structure::VerificationDirectory,
+    startup_checks::{check_complete, check_verification_dir, start_check},
+    verification::{VerificationMetaDataList, VerificationPeriod, VerificationStatus},
+};
+use std::{
+    io::BufRead,
+    path::Path,
+    process::ExitCode,
+    sync::{Arc, Mutex},
+};
+use strum::IntoEnumIterator;
+use tracing::info;
+
+/// Format the error with the chain of its sources
+fn report_error(e: impl std::error::Error) -> String {
+    Report::new(&e).to_string()
+}
+
+/// Execute the subcommand and return the exit code
+///
+/// An error is returned if the subcommand cannot be executed
+pub(super) fn execute(
+    command: Command,
+    config: Arc<VerifierConfig>,
+    audit_log_path: &Path,
+) -> Result<ExitCode, String> {
+    match command {
+        Command::Extract(args) => {
+            let audit_log = open_audit_log(audit_log_path)?;
+            extract(&config, &args, &audit_log).map(|_| ExitCode::SUCCESS)
+        }
+        Command::Check(args) => check(&config, &args),
+        Command::Run(args) => {
+            let audit_log = open_audit_log(audit_log_path)?;
+            run(config, &args, &audit_log)
+        }
+        Command::Report(args) => {
+            let audit_log = open_audit_log(audit_log_path)?;
+            report(&config, &args, &audit_log)
+        }
+        Command::Fingerprints => fingerprints(&config),
+        Command::List(args) => list(&config, &args),
+        Command::VerifyAuditLog => verify(audit_log_path),
+    }
+}
+
+/// Append the event to the audit log
+fn audit(audit_log: &Arc<Mutex<AuditLog>>, event: AuditEvent) -> Result<(), String> {
+    audit_log
+        .lock()
+        .unwrap()
+        .append(event)
+        .map(|_| ())
+        .map_err(report_error)
+}
+
+/// Open the audit log, shared with the runner
+fn open_audit_log(path: &Path) -> Result<Arc<Mutex<AuditLog>>, String> {
+    AuditLog::open(path)
+        .map(|log| Arc::new(Mutex::new(log)))
+        .map_err(report_error)
+}
+
+/// Record the generated report files in the audit log
+fn audit_report_files(
+    audit_log: &Arc<Mutex<AuditLog>>,
+    options: &ReportOutputFileOptions,
+) -> Result<(), String> {
+    for output_type in options.output_types().iter() {
+        let path = options.output_file_path(*output_type);
+        if path.is_file() {
+            audit(
+                audit_log,
+                AuditEvent::report_generated(&path).map_err(report_error)?,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn extract(
+    config: &VerifierConfig,
+    args: &DatasetArgs,
+    audit_log: &Arc<Mutex<AuditLog>>,
+) -> Result<ExtractDataSetResults, String> {
+    let password = match args.password_stdin {
+        true => read_password(std::io::stdin().lock())?,
+        false => config.decrypt_password().map_err(report_error)?,
+    };
+    let extracted = ExtractDataSetResults::extract_datasets(
+        args.period.into(),
+        &args.context,
+        args.tally.as_deref(),
+        &password,
+        config,
+    )
+    .map_err(report_error)?;
+    audit(
+        audit_log,
+        AuditEvent::datasets_extracted(args.period.into(), &extracted),
+    )?;
+    println!("Datasets extracted in {}", extracted.location().display());
+    Ok(extracted)
+}
+
+/// Read the password from the first line of the input
+pub(super) fn read_password(mut input: impl BufRead) -> Result<String, String> {
+    let mut line = String::new();
+    input
+        .read_line(&mut line)
+        .map_err(|e| format!("Error reading the password from stdin: {e}"))?;
+    let password = line.trim_end_matches(['\r', '\n']);
+    match password.is_empty() {
+        true => Err("The password read from stdin is empty".to_string()),
+        false => Ok(password.to_string()),
+    }
+}
+
+fn check(config: &VerifierConfig, args: &CheckArgs) -> Result<ExitCode, String> {
+    let mut res = vec![];
+    if let Err(msg) = start_check(config) {
+        res.push(msg);
+    }
+    if let (Some(period), Some(dir)) = (args.period, &args.dir) {
+        let period = VerificationPeriod::from(period);
+        match check_verification_dir(&period, dir) {
+            Ok(()) => {
+                if let Err(msg) = check_complete(&period, &VerificationDirectory::new(&period, dir))
+                {
+                    res.push(msg);
+                }
+            }
+            Err(msg) => res.push(msg),
+        }
+    }
+    if res.is_empty() {
+        println!("Checks successful");
+        return Ok(ExitCode::SUCCESS);
+    }
+    for msg in res {
+        println!("Check failed: {msg}");
+    }
+    Ok(ExitCode::from(EXIT_FAILED))
+}
+
+fn run(
+    config: Arc<VerifierConfig>,
+    args: &RunArgs,
+    audit_log: &Arc<Mutex<AuditLog>>,
+) -> Result<ExitCode, String> {
+    let period = VerificationPeriod::from(args.dataset.period);
+    let extracted = extract(&config, &args.dataset, audit_log)?;
+    let metadata =
+        VerificationMetaDataList::load(config.get_verification_list_str()).map_err(report_error)?;
+    let mut run_information = RunInformation::new(config.clone());
+    run_information
+        .prepare_data_for_start(period, &metadata, &args.exclusion)
+        .map_err(report_error)?;
+    run_information.add_extracted_information(&extracted);
+    let run_information = Arc::new(Mutex::new(run_information));
+    let strategy = RunStrategyKind::from(args.strategy)
+        .strategy(&metadata, &config)
+        .map_err(report_error)?;
+    let mut runner = Runner::new_with_run_information(
+        Arc::new(VerificationDirectory::new(&period, extracted.location())),
+        &period,
+        &metadata,
+        &args.exclusion,
+        strategy,
+        config.clone(),
+        run_information.clone(),
+    )
+    .map_err(report_error)?;
+    runner.set_audit(
+        RunAudit::new(audit_log.clone()).with_exclusion_justification(
+            &args.excluded_by.clone().unwrap_or_else(system_user),
+            args.exclusion_reason.as_deref().unwrap_or_default(),
+        ),
+    );
+    runner.run_all(&metadata).map_err(report_error)?;
+    let ri = run_information.lock().unwrap();
+    let mut ids = ri.verifications();
+    ids.sort();
+    for id in ids {
+        let status = ri
+            .verifications_status()
+            .get(id)
+            .map(|s| s.as_ref())
+            .unwrap_or_default();
+        let name = metadata
+            .meta_data_from_id(id)
+            .map(|md| md.name())
+            .unwrap_or_default();
+        println!("{id} {name}: {status}");
+    }
+    if args.json.is_some() || args.report_dir.is_some() {
+        let format_date = config.report_format_date();
+        let report_config = ReportConfigBuilder::default()
+            .title(format!("Verification of the {}", period.as_ref()))
+            .date_time(chrono::Local::now().format(&format_date).to_string())
+            .tab_size(config.txt_report_tab_size().map_err(report_error)?)
+            .fromat_date(format_date.clone())
+            .build()
+            .map_err(report_error)?;
+        let json = ReportData::new(report_config, &ri)
+            .to_json()
+            .map_err(report_error)?;
+        if let Some(path) = &args.json {
+            std::fs::write(path, &json)
+                .map_err(|e| format!("Error writing the report to {}: {e}", path.display()))?;
+            audit(
+                audit_log,
+                AuditEvent::report_generated(path).map_err(report_error)?,
+            )?;
+            info!("Report archived in {}", path.display());
+        }
+        if let Some(dir) = &args.report_dir {
+            let options = ReportOutputFileOptions::generate_from_config(
+                &format!("report-{}", period.as_ref()),
+                dir,
+                &config,
+            )
+            .map_err(report_error)?;
+            let res = generate_report_files(|| generate_files_from_json(&json, options.clone()));
+            audit_report_files(audit_log, &options)?;
+            res?;
+        }
+    }
+    let failed = [
+        VerificationStatus::FinishedWithFailures,
+        VerificationStatus::FinishedWithErrors,
+        VerificationStatus::FinishedWithFailuresAndErrors,
+    ]
+    .iter()
+    .any(|s| !ri.verifications_with_status(*s).is_empty());
+    match failed {
+        true => Ok(ExitCode::from(EXIT_FAILED)),
+        false => Ok(ExitCode::SUCCESS),
+    }
+}
+
+/// User of the system, used if the person excluding the verifications is not given
+fn system_user() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or("unknown".to_string())
+}
+
+/// Generate the report files, collecting the errors in one message
+fn generate_report_files<E: std::error::Error>(
+    generate: impl FnOnce() -> Vec<E>,
+) -> Result<(), String> {
+    let errors = generate();
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors
+            .iter()
+            .map(|e| Report::new(e).to_string())
+            .collect::<Vec<_>>()
+            .join("\n")),
+    }
+}
+
+fn report(
+    config: &VerifierConfig,
+    args: &ReportArgs,
+    audit_log: &Arc<Mutex<AuditLog>>,
+) -> Result<ExitCode, String> {
+    let output_types = match args.types.is_empty() {
+        true => ReportOutputFileType::iter().collect::<Vec<_>>(),
+        false => args.types.clone(),
+    };
+    let filename = args
+        .input
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or("report".to_string());
+    let mut builder = ReportOutputFileOptionsBuilder::default()
+        .directory(args.output_dir.as_path())
+        .filename_without_extension(filename.as_str());
+    for output_type in output_types.iter() {
+        builder = builder.add_output_type(*output_type);
+    }
+    if output_types.contains(&ReportOutputFileType::Pdf) {
+        let mut pdf_builder = PDFReportOptionsBuilder::default();
+        match &args.browser {
+            Some(browser) => {
+                pdf_builder
+                    .path_to_browser(browser.as_path())
+                    .sandbox(config.report_sandbox().map_err(report_error)?);
+            }
+            None => {
+                pdf_builder.backend(PDFReportBackend::Native);
+            }
+        }
+        builder = builder.pdf_options(pdf_builder.build().map_err(report_error)?);
+    }
+    for member in config.report_electoral_board_members() {
+        builder = builder.add_explicit_electoral_board_member(&member);
+    }
+    let options = builder.build().map_err(report_error)?;
+    let res = generate_report_files(|| generate_files_from_json_file(&args.input, options.clone()));
+    audit_report_files(audit_log, &options)?;
+    res?;
+    println!("Report files generated in {}", args.output_dir.display());
+    Ok(ExitCode::SUCCESS)
+}
+
+fn fingerprints(config: &VerifierConfig) -> Result<ExitCode, String> {
+    let keystore = config.keystore().map_err(report_error)?;
+    let mut fingerprints = keystore
+        .fingerprints()
+        .map_err(report_error)?
+        .into_iter()
+        .map(|(ca, fingerprint)| {
+            (
+                ca.as_ref().to_string(),
+                fingerprint.base16_encode().unwrap_or_default(),
+            )
+        })
+        .collect::<Vec<_>>();
+    fingerprints.sort();
+    for (ca, fingerprint) in fingerprints {
+        println!("{ca}: {fingerprint}");
+    }
+    for (certificate, validity) in keystore.additional_fingerprints() {
+        match validity {
+            Some(v) => println!("{certificate} valid {v}"),
+            None => println!("{certificate}"),
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn list(config: &VerifierConfig, args: &ListArgs) -> Result<ExitCode, String> {
+    let period = args.period.map(VerificationPeriod::from);
+    let metadata =
+        VerificationMetaDataList::load(config.get_verification_list_str()).map_err(report_error)?;
+    for md in metadata
+        .iter()
+        .filter(|md| period.is_none_or(|p| md.period() == &p))
+    {
+        println!(
+            "{}\t{}\t{}\t{}",
+            md.id(),
+            md.period().as_ref(),
+            md.category().as_ref(),
+            md.name()
+        );
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn verify(audit_log_path: &Path) -> Result<ExitCode, String> {
+    if !audit_log_path.is_file() {
+        return Err(format!(
+            "Audit log {} does not exist",
+            audit_log_path.display()
+        ));
+    }
+    match verify_audit_log(audit_log_path) {
+        Ok(records) => {
+            println!(
+                "Audit log {} verified: {} records, last hash {}",
+                audit_log_path.display(),
+                records.len(),
+                records
+                    .last()
+                    .map(|r| r.hash().as_str())
+                    .unwrap_or(GENESIS_HASH)
+            );
+            Ok(ExitCode::SUCCESS)
+        }
+        Err(e) => {
+            println!("Audit log broken: {}", report_error(e));
+            Ok(ExitCode::from(EXIT_FAILED))
+        }
+    }
+}
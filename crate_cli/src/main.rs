@@ -0,0 +1,431 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Command-line application of the verifier
+//!
+//! The subcommands are:
+//! - `extract`: Extract the datasets of the zip files
+//! - `check`: Check the configuration and the direct trust, and optionally an extracted directory
+//! - `run`: Extract the datasets and run the verifications of the setup or the tally
+//! - `report`: Generate the report files from a report archived as json (see `run --json`)
+//! - `fingerprints`: Print the fingerprints of the certificates of the direct trust
+//! - `list`: List the verifications
+//! - `verify-audit-log`: Verify the chain of the audit log
+//!
+//! The subcommands `extract`, `run` and `report` append their actions to the audit log
+//! (see [rust_ev_verifier_application_lib::AuditLog]).
+//!
+//! The password of the zip files is not given as argument, since the arguments are visible to
+//! the other users of the system. It is read from stdin (`--password-stdin`) or from the
+//! environment variable `VERIFIER_DATASET_PASSWORD` (or the file `.env`).
+//!
+//! The exit codes are:
+//! - `0`: Success
+//! - `1`: At least one verification has errors or failures, a check failed or the audit log is broken
+//! - `2`: Invalid arguments or error during the execution
+//!
+//! The log is written to stderr. The level can be changed with the environment variable `RUST_LOG`.
+
+mod commands;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use rust_ev_verifier_application_lib::{RunStrategyKind, report::ReportOutputFileType};
+use rust_ev_verifier_lib::{VerifierConfig, verification::VerificationPeriod};
+use std::{path::PathBuf, process::ExitCode, str::FromStr, sync::Arc};
+use tracing_subscriber::EnvFilter;
+
+/// Exit code if at least one verification has errors or failures, or if a check failed
+const EXIT_FAILED: u8 = 1;
+/// Exit code in case of error
+const EXIT_ERROR: u8 = 2;
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Verifier for the E-Voting system of Swiss Post")]
+struct Cli {
+    /// Root directory of the verifier (containing the direct trust, the data and the reports)
+    #[arg(long, global = true, default_value = ".")]
+    root_dir: PathBuf,
+    /// Configuration file (json) overriding the default configuration
+    #[arg(long, global = true)]
+    config_file: Option<PathBuf>,
+    /// Audit log. If not given, the audit log in the log directory of the root directory is used
+    #[arg(long, global = true)]
+    audit_log: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Extract the datasets of the zip files
+    Extract(DatasetArgs),
+    /// Check the configuration and the direct trust, and optionally an extracted directory
+    Check(CheckArgs),
+    /// Extract the datasets and run the verifications of the setup or the tally
+    Run(RunArgs),
+    /// Generate the report files from a report archived as json (see `run --json`)
+    Report(ReportArgs),
+    /// Print the fingerprints of the certificates of the direct trust
+    Fingerprints,
+    /// List the verifications
+    List(ListArgs),
+    /// Verify the chain of the audit log
+    VerifyAuditLog,
+}
+
+#[derive(Args, Debug)]
+struct DatasetArgs {
+    /// Period of the verifications
+    #[arg(long, value_enum)]
+    period: PeriodArg,
+    /// Zip file of the context
+    #[arg(long)]
+    context: PathBuf,
+    /// Zip file of the tally (mandatory for the tally)
+    #[arg(long, required_if_eq("period", "tally"))]
+    tally: Option<PathBuf>,
+    /// Read the password of the zip files from the first line of stdin.
+    /// If not given, the password is read from the environment variable `VERIFIER_DATASET_PASSWORD`
+    #[arg(long)]
+    password_stdin: bool,
+}
+
+#[derive(Args, Debug)]
+struct CheckArgs {
+    /// Period of the extracted directory to check
+    #[arg(long, value_enum, requires = "dir")]
+    period: Option<PeriodArg>,
+    /// Extracted directory to check
+    #[arg(long, requires = "period")]
+    dir: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+struct RunArgs {
+    #[command(flatten)]
+    dataset: DatasetArgs,
+    /// Ids of the verifications to exclude (comma separated or repeated)
+    #[arg(long = "exclude", value_delimiter = ',', requires = "exclusion_reason")]
+    exclusion: Vec<String>,
+    /// Reason of the exclusion of the verifications, recorded in the audit log
+    #[arg(long)]
+    exclusion_reason: Option<String>,
+    /// Person excluding the verifications, recorded in the audit log. If not given, the user of the system is used
+    #[arg(long)]
+    excluded_by: Option<String>,
+    /// Strategy to run the verifications
+    #[arg(long, value_enum, default_value_t = StrategyArg::Parallel)]
+    strategy: StrategyArg,
+    /// Archive the report as json in the file
+    #[arg(long)]
+    json: Option<PathBuf>,
+    /// Generate the report files of the configuration in the directory
+    #[arg(long)]
+    report_dir: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+struct ReportArgs {
+    /// Report archived as json
+    input: PathBuf,
+    /// Directory where the report files are generated
+    #[arg(long)]
+    output_dir: PathBuf,
+    /// Types of the report files (comma separated or repeated). If not given, all the types are generated
+    #[arg(long = "type", value_delimiter = ',', value_parser = parse_report_type)]
+    types: Vec<ReportOutputFileType>,
+    /// Path to the browser to generate the pdf. If not given, the pdf is rendered natively
+    #[arg(long)]
+    browser: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+struct ListArgs {
+    /// List only the verifications of the period
+    #[arg(long, value_enum)]
+    period: Option<PeriodArg>,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum PeriodArg {
+    Setup,
+    Tally,
+}
+
+impl From<PeriodArg> for VerificationPeriod {
+    fn from(value: PeriodArg) -> Self {
+        match value {
+            PeriodArg::Setup => VerificationPeriod::Setup,
+            PeriodArg::Tally => VerificationPeriod::Tally,
+        }
+    }
+}
+
+/// Strategies of [rust_ev_verifier_application_lib::RunStrategy]
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum StrategyArg {
+    Sequential,
+    Parallel,
+    LongestFirst,
+    FailFast,
+    CategoryGates,
+    Pool,
+}
+
+impl From<StrategyArg> for RunStrategyKind {
+    fn from(value: StrategyArg) -> Self {
+        match value {
+            StrategyArg::Sequential => RunStrategyKind::Sequential,
+            StrategyArg::Parallel => RunStrategyKind::Parallel,
+            StrategyArg::LongestFirst => RunStrategyKind::LongestFirst,
+            StrategyArg::FailFast => RunStrategyKind::FailFast,
+            StrategyArg::CategoryGates => RunStrategyKind::CategoryGates,
+            StrategyArg::Pool => RunStrategyKind::Pool,
+        }
+    }
+}
+
+fn parse_report_type(s: &str) -> Result<ReportOutputFileType, String> {
+    ReportOutputFileType::from_str(s).map_err(|_| format!("Unknown report type {s}"))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+    run(cli)
+}
+
+/// Execute the command line and return the exit code
+fn run(cli: Cli) -> ExitCode {
+    let config = match &cli.config_file {
+        Some(config_file) => VerifierConfig::new_with_config_file(&cli.root_dir, config_file),
+        None => VerifierConfig::new(&cli.root_dir),
+    };
+    let config = Arc::new(config);
+    let audit_log_path = cli
+        .audit_log
+        .unwrap_or_else(|| config.audit_log_file_path());
+    match commands::execute(cli.command, config, &audit_log_path) {
+        Ok(code) => code,
+        Err(msg) => {
+            eprintln!("Error: {msg}");
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::CommandFactory;
+    use rust_ev_verifier_application_lib::{AuditEvent, AuditLog};
+    use std::path::Path;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "rust_ev_verifier_cli_{}_{name}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn run_args(root_dir: &Path, args: &[&str]) -> ExitCode {
+        let root_dir = root_dir.to_str().unwrap();
+        run(Cli::try_parse_from(
+            ["rust_ev_verifier", "--root-dir", root_dir]
+                .iter()
+                .chain(args.iter()),
+        )
+        .unwrap())
+    }
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_run() {
+        let cli = Cli::try_parse_from([
+            "rust_ev_verifier",
+            "--root-dir",
+            "/verifier",
+            "run",
+            "--period",
+            "tally",
+            "--context",
+            "context.zip",
+            "--tally",
+            "tally.zip",
+            "--exclude",
+            "05.01,05.02",
+            "--exclude",
+            "10.01",
+            "--exclusion-reason",
+            "Known issue",
+            "--strategy",
+            "longest-first",
+        ])
+        .unwrap();
+        assert_eq!(cli.root_dir, PathBuf::from("/verifier"));
+        match cli.command {
+            Command::Run(args) => {
+                assert_eq!(args.dataset.period, PeriodArg::Tally);
+                assert_eq!(args.exclusion, vec!["05.01", "05.02", "10.01"]);
+                assert_eq!(args.exclusion_reason.as_deref(), Some("Known issue"));
+                assert_eq!(args.strategy, StrategyArg::LongestFirst);
+                assert!(args.json.is_none());
+            }
+            c => panic!("Unexpected command {c:?}"),
+        }
+        assert!(
+            Cli::try_parse_from([
+                "rust_ev_verifier",
+                "run",
+                "--period",
+                "tally",
+                "--context",
+                "context.zip"
+            ])
+            .is_err()
+        );
+        assert!(
+            Cli::try_parse_from([
+                "rust_ev_verifier",
+                "run",
+                "--period",
+                "setup",
+                "--context",
+                "context.zip",
+                "--exclude",
+                "05.01"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_report() {
+        let cli = Cli::try_parse_from([
+            "rust_ev_verifier",
+            "report",
+            "report.json",
+            "--output-dir",
+            "out",
+            "--type",
+            "txt,junit.xml",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Report(args) => assert_eq!(
+                args.types,
+                vec![ReportOutputFileType::Txt, ReportOutputFileType::JUnit]
+            ),
+            c => panic!("Unexpected command {c:?}"),
+        }
+        assert!(
+            Cli::try_parse_from([
+                "rust_ev_verifier",
+                "report",
+                "report.json",
+                "--output-dir",
+                "out",
+                "--type",
+                "doc",
+            ])
+            .is_err()
+        );
+        assert!(Cli::try_parse_from(["rust_ev_verifier", "check", "--period", "setup"]).is_err());
+    }
+
+    #[test]
+    fn test_exit_codes() {
+        let dir = test_dir("exit_codes");
+        let audit_log_path = dir.join("audit.log");
+        let audit_log = audit_log_path.to_str().unwrap();
+        assert_eq!(run_args(&dir, &["list"]), ExitCode::SUCCESS);
+        // The extracted directory is empty
+        let extracted = dir.join("extracted");
+        std::fs::create_dir_all(&extracted).unwrap();
+        assert_eq!(
+            run_args(
+                &dir,
+                &[
+                    "check",
+                    "--period",
+                    "setup",
+                    "--dir",
+                    extracted.to_str().unwrap()
+                ]
+            ),
+            ExitCode::from(EXIT_FAILED)
+        );
+        assert_eq!(
+            run_args(&dir, &["--audit-log", audit_log, "verify-audit-log"]),
+            ExitCode::from(EXIT_ERROR)
+        );
+        let mut log = AuditLog::open(&audit_log_path).unwrap();
+        log.append(AuditEvent::VerificationsExcluded {
+            period: "setup".to_string(),
+            ids: vec!["05.01".to_string()],
+            by: "tester".to_string(),
+            reason: "Known issue".to_string(),
+        })
+        .unwrap();
+        drop(log);
+        assert_eq!(
+            run_args(&dir, &["--audit-log", audit_log, "verify-audit-log"]),
+            ExitCode::SUCCESS
+        );
+        let content = std::fs::read_to_string(&audit_log_path).unwrap();
+        std::fs::write(&audit_log_path, content.replace("Known issue", "Other")).unwrap();
+        assert_eq!(
+            run_args(&dir, &["--audit-log", audit_log, "verify-audit-log"]),
+            ExitCode::from(EXIT_FAILED)
+        );
+        assert_eq!(
+            run_args(
+                &dir,
+                &[
+                    "extract",
+                    "--period",
+                    "setup",
+                    "--context",
+                    dir.join("missing.zip").to_str().unwrap()
+                ]
+            ),
+            ExitCode::from(EXIT_ERROR)
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_read_password() {
+        assert_eq!(
+            commands::read_password("secret\r\nnext".as_bytes()).unwrap(),
+            "secret"
+        );
+        assert!(commands::read_password("\n".as_bytes()).is_err());
+        assert!(commands::read_password("".as_bytes()).is_err());
+    }
+}
@@ -18,12 +18,19 @@
 
 use crate::direct_trust::DirectTrustError;
 
+use super::config_file::{
+    CONFIG_OPTIONS, ConfigFile, ConfigValueSource, EffectiveConfigValue, config_option_for_env,
+};
 use super::consts;
 use super::direct_trust::Keystore;
 use super::resources::VERIFICATION_LIST;
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::OnceLock,
+};
 use thiserror::Error;
-use tracing::info;
+use tracing::{info, warn};
 
 // Directory structure
 const CONTEXT_DIR_NAME: &str = "context";
@@ -35,16 +42,21 @@ const BB_DIR_NAME: &str = "ballotBoxes";
 // Program structure
 const LOG_DIR_NAME: &str = "log";
 const LOG_FILE_NAME: &str = "log.txt";
+const AUDIT_LOG_FILE_NAME: &str = "audit_log.jsonl";
 const DIRECT_TRUST_DIR_NAME: &str = "direct-trust";
 const DATA_DIR_NAME: &str = "data";
 const ZIP_TEMP_DIR_NAME: &str = "decrypted_zip";
 const REPORT_DIR_NAME: &str = "report";
+const CONFIG_FILE_NAME: &str = "verifier_config.json";
 
 // Other Options
 const DEFAULT_TXT_REPORT_TAB_SIZE: u8 = 2;
 const DEFAULT_REPORT_FORMAT_DATE_TIME: &str = "%d.%m.%Y %H:%M:%S.%3f";
 const DEFAULT_REPORT_TYPE_EXPORT: bool = false;
 const DEFAULT_REPORT_BROWSER_SANDBOX: bool = false;
+const DEFAULT_REPORT_SIGNING_AUTHORITY: &str = "verifier";
+const DEFAULT_SIGNATURE_CACHE: bool = false;
+const DEFAULT_RUN_LOW_PRIORITY: bool = false;
 
 #[derive(Error, Debug)]
 #[error(transparent)]
@@ -59,20 +71,42 @@ enum VerifierConfigErrorImpl {
     DirectTrust(#[from] DirectTrustError),
     #[error("Error with file {msg}: {value}")]
     FileError { msg: String, value: String },
+    #[error("Invalid configuration: {}", .0.join(" / "))]
+    InvalidValues(Vec<String>),
+    #[error("Invalid value {value} for {name}: {msg}")]
+    InvalidValue {
+        name: String,
+        value: String,
+        msg: String,
+    },
 }
 
 /// Structuring getting all the configuration information relevant for the
 /// verifier
 ///
 /// The structure get only the root directory of the running application. The structure
-/// can be defined as static using lazy_static crate:
+/// is owned and can be shared between the verifications, the runners and the threads with an [Arc].
+/// Many configurations (e.g. one per tenant with its own root directory) can live in the same process:
 /// ```ignore
-/// use lazy_static::lazy_static;
-/// lazy_static! {
-///     static ref CONFIG: Config = Config::new("..");
-///  }
+/// let config = Arc::new(VerifierConfig::new("/data/tenant_a"));
+/// let suite = VerificationSuite::new(&period, &metadata_list, &[])?;
+/// let context = RunContext::new(&config);
 /// ```
-pub struct VerifierConfig(&'static str);
+///
+/// The structure contains no state of a run. The keystore and the cache of the signature verifications
+/// are owned by the runs (see [crate::verification::RunContext]).
+///
+/// The value of each option is taken, in this order, from the environment variable, from the
+/// configuration file (see [VerifierConfig::config_file_path] and the schema in [crate::config_file])
+/// or from the default value. The environment variables are not used if the environment is ignored
+/// (see [VerifierConfig::ignore_env]). The default value is used only if the option is not set: the getters
+/// return an error for an invalid value. All the invalid values are reported by [VerifierConfig::validate].
+pub struct VerifierConfig {
+    root_dir: PathBuf,
+    config_file_path: Option<PathBuf>,
+    config_file: OnceLock<Option<Result<ConfigFile, String>>>,
+    ignore_env: bool,
+}
 
 /// New config with root_dir equal "."
 impl Default for VerifierConfig {
@@ -83,13 +117,171 @@ impl Default for VerifierConfig {
 
 impl VerifierConfig {
     /// New Config
-    pub fn new(root_dir: &'static str) -> Self {
-        VerifierConfig(root_dir)
+    pub fn new(root_dir: impl AsRef<Path>) -> Self {
+        VerifierConfig {
+            root_dir: root_dir.as_ref().to_path_buf(),
+            config_file_path: None,
+            config_file: OnceLock::new(),
+            ignore_env: false,
+        }
+    }
+
+    /// New Config reading the options from the given configuration file
+    pub fn new_with_config_file(root_dir: impl AsRef<Path>, config_file_path: &Path) -> Self {
+        VerifierConfig {
+            config_file_path: Some(config_file_path.to_path_buf()),
+            ..Self::new(root_dir)
+        }
+    }
+
+    /// Ignore the environment variables: the options are taken only from the configuration file
+    /// or from the default values
+    ///
+    /// The configuration does not depend on the environment of the process (e.g. a `.env` file),
+    /// for example for many configurations in the same process or for the tests.
+    /// The password of the datasets is still read from the environment (see [Self::decrypt_password])
+    pub fn ignore_env(mut self) -> Self {
+        self.ignore_env = true;
+        self
+    }
+
+    /// Path of the configuration file
+    ///
+    /// The path is, in this order, the path given in [Self::new_with_config_file], the path in the env variable
+    /// `VERIFIER_CONFIG_FILE` or the file `verifier_config.json` in the root directory, if it exists.
+    /// `None` if no configuration file is used
+    pub fn config_file_path(&self) -> Option<PathBuf> {
+        if let Some(p) = &self.config_file_path {
+            return Some(p.clone());
+        }
+        if !self.ignore_env
+            && let Ok(v) = dotenvy::var(consts::ENV_CONFIG_FILE)
+        {
+            return Some(PathBuf::from(v));
+        }
+        Some(self.root_dir_path().join(CONFIG_FILE_NAME)).filter(|p| p.is_file())
+    }
+
+    /// The configuration file, loaded at the first call
+    fn config_file(&self) -> Option<&Result<ConfigFile, String>> {
+        self.config_file
+            .get_or_init(|| {
+                self.config_file_path().map(|p| {
+                    let res = ConfigFile::load(&p);
+                    if let Err(e) = &res {
+                        warn!("The configuration file is not used: {e}");
+                    }
+                    res
+                })
+            })
+            .as_ref()
+    }
+
+    /// Value of the option with its source, from the env variable or from the configuration file
+    fn value_with_source(&self, env: &str) -> Option<(String, ConfigValueSource)> {
+        if !self.ignore_env
+            && let Ok(v) = dotenvy::var(env)
+        {
+            return Some((v, ConfigValueSource::Env));
+        }
+        match self.config_file() {
+            Some(Ok(file)) => file
+                .value(config_option_for_env(env))
+                .map(|v| (v.to_string(), ConfigValueSource::File)),
+            _ => None,
+        }
+    }
+
+    /// Value of the option, from the env variable or from the configuration file
+    fn value(&self, env: &str) -> Option<String> {
+        self.value_with_source(env).map(|(v, _)| v)
+    }
+
+    /// Value of the option parsed
+    ///
+    /// `None` if the option is not set. Return an error if the value is not valid for the option
+    /// (see [VerifierConfig::validate]), instead of using the default value
+    fn parsed_value<T: FromStr>(&self, env: &str) -> Result<Option<T>, VerifierConfigError> {
+        let Some((v, source)) = self.value_with_source(env) else {
+            return Ok(None);
+        };
+        let option = config_option_for_env(env);
+        let invalid = |msg: String| {
+            VerifierConfigError::from(VerifierConfigErrorImpl::InvalidValue {
+                name: format!("{} ({source})", option.name_for_source(source)),
+                value: v.clone(),
+                msg,
+            })
+        };
+        option.check(&v).map_err(invalid)?;
+        v.parse::<T>()
+            .map(Some)
+            .map_err(|_| invalid("cannot be parsed".to_string()))
+    }
+
+    /// Validate the configuration file and the values of all the options
+    ///
+    /// Return an error with all the invalid values, instead of using the default values.
+    /// The path of the browser is checked only if the PDF report is printed with the browser
+    pub fn validate(&self) -> Result<(), VerifierConfigError> {
+        let mut problems = vec![];
+        match self.config_file() {
+            Some(Ok(file)) => problems.extend(
+                file.problems()
+                    .iter()
+                    .map(|p| format!("Configuration file: {p}")),
+            ),
+            Some(Err(e)) => problems.push(e.clone()),
+            None => {}
+        }
+        for option in CONFIG_OPTIONS {
+            // The browser is used only to print the PDF report. A wrong path does not block the verifications
+            if option.env == consts::ENV_REPORT_BROWSER_PATH && !self.report_pdf_with_browser() {
+                continue;
+            }
+            if let Some((v, source)) = self.value_with_source(option.env)
+                && let Err(e) = option.check(&v)
+            {
+                problems.push(format!(
+                    "{} ({source}): {e} ({v})",
+                    option.name_for_source(source)
+                ));
+            }
+        }
+        match problems.is_empty() {
+            true => Ok(()),
+            false => Err(VerifierConfigError::from(
+                VerifierConfigErrorImpl::InvalidValues(problems),
+            )),
+        }
+    }
+
+    /// The effective configuration, with the value and the source of each option
+    ///
+    /// The password of the datasets is not part of the effective configuration
+    pub fn effective_configuration(&self) -> Vec<EffectiveConfigValue> {
+        CONFIG_OPTIONS
+            .iter()
+            .map(|option| match self.value_with_source(option.env) {
+                Some((v, source)) => EffectiveConfigValue {
+                    key: option.key.to_string(),
+                    env: option.env.to_string(),
+                    value: Some(v),
+                    source,
+                },
+                None => EffectiveConfigValue {
+                    key: option.key.to_string(),
+                    env: option.env.to_string(),
+                    value: option.default.map(|v| v.to_string()),
+                    source: ConfigValueSource::Default,
+                },
+            })
+            .collect()
     }
 
     /// Path of the root directory of the programm
     pub fn root_dir_path(&self) -> PathBuf {
-        Path::new(self.0).to_path_buf()
+        self.root_dir.clone()
     }
 
     /// Maximum number of voting options according to the specification
@@ -160,6 +352,13 @@ impl VerifierConfig {
         self.root_dir_path().join(LOG_DIR_NAME).join(LOG_FILE_NAME)
     }
 
+    /// The path to the audit log of the runs (one json record per line, hash-chained)
+    pub fn audit_log_file_path(&self) -> PathBuf {
+        self.root_dir_path()
+            .join(LOG_DIR_NAME)
+            .join(AUDIT_LOG_FILE_NAME)
+    }
+
     /// The path to the dir name
     /// Create the directory if not exist
     pub fn data_dir_path(&self) -> PathBuf {
@@ -207,11 +406,26 @@ impl VerifierConfig {
 
     /// The path to the directory where direct trust keystore is stored
     ///
-    /// If the env variable is not used, then use the `./DIRECT_TRUST_DIR_NAME`
+    /// If the option is not set, then use the `./DIRECT_TRUST_DIR_NAME`
     pub fn direct_trust_dir_path(&self) -> PathBuf {
-        match dotenvy::var(consts::ENV_DIRECT_TRUST_DIR_PATH) {
-            Ok(v) => PathBuf::from(v),
-            Err(_) => self.root_dir_path().join(DIRECT_TRUST_DIR_NAME),
+        match self.value(consts::ENV_DIRECT_TRUST_DIR_PATH) {
+            Some(v) => PathBuf::from(v),
+            None => self.root_dir_path().join(DIRECT_TRUST_DIR_NAME),
+        }
+    }
+
+    /// The paths to the directories of the additional trusted keystores (e.g. during a rollover of the certificates)
+    ///
+    /// The paths are given separated with a comma (as list in the configuration file). Empty if the option is not set
+    pub fn direct_trust_additional_dir_paths(&self) -> Vec<PathBuf> {
+        match self.value(consts::ENV_DIRECT_TRUST_ADDITIONAL_DIR_PATHS) {
+            Some(v) => v
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .map(PathBuf::from)
+                .collect(),
+            None => vec![],
         }
     }
 
@@ -221,31 +435,93 @@ impl VerifierConfig {
     }
 
     /// Get the keystore
+    ///
+    /// The keystore contains the keystore of [Self::direct_trust_dir_path] as primary keystore
+    /// and the keystores of [Self::direct_trust_additional_dir_paths]
     pub fn keystore(&self) -> Result<Keystore, VerifierConfigError> {
-        Keystore::try_from(self.direct_trust_dir_path().as_path())
-            .map_err(VerifierConfigErrorImpl::from)
-            .map_err(VerifierConfigError::from)
+        let mut res = Keystore::try_from(self.direct_trust_dir_path().as_path())
+            .map_err(VerifierConfigErrorImpl::from)?;
+        for path in self.direct_trust_additional_dir_paths() {
+            res.add_trusted_keystores(
+                Keystore::try_from(path.as_path()).map_err(VerifierConfigErrorImpl::from)?,
+            );
+        }
+        Ok(res)
     }
 
-    /// Get tab size for text reports
+    /// Get the keystore used to sign the report files
     ///
-    /// If the env variable not found, use the default value
-    pub fn txt_report_tab_size(&self) -> u8 {
-        match dotenvy::var(consts::ENV_TXT_TAB_SIZE) {
-            Ok(v) => match v.parse::<u8>() {
-                Ok(v) => v,
-                Err(_) => DEFAULT_TXT_REPORT_TAB_SIZE,
-            },
-            Err(_) => DEFAULT_TXT_REPORT_TAB_SIZE,
+    /// The directory must contain the keystore of the verifier with the secret key (see [Keystore::try_from]).
+    /// `None` if the option is not set (the report files are not signed)
+    pub fn report_signing_keystore(&self) -> Result<Option<Keystore>, VerifierConfigError> {
+        match self.value(consts::ENV_REPORT_SIGNING_KEYSTORE_DIR_PATH) {
+            Some(v) => Ok(Some(
+                Keystore::try_from(PathBuf::from(v).as_path())
+                    .map_err(VerifierConfigErrorImpl::from)?,
+            )),
+            None => Ok(None),
         }
     }
 
+    /// Get the authority of the certificate of the verifier signing the report files
+    ///
+    /// Only the signatures with this authority are accepted by the verification of the report files.
+    /// If the option is not set, use the default value (`verifier`)
+    pub fn report_signing_authority(&self) -> String {
+        self.value(consts::ENV_REPORT_SIGNING_AUTHORITY)
+            .unwrap_or_else(|| DEFAULT_REPORT_SIGNING_AUTHORITY.to_string())
+    }
+
+    /// Is the cache of the signature verifications used
+    ///
+    /// If the option is not set, use the default value (`false`)
+    pub fn signature_cache_enabled(&self) -> Result<bool, VerifierConfigError> {
+        Ok(self
+            .parsed_value(consts::ENV_SIGNATURE_CACHE)?
+            .unwrap_or(DEFAULT_SIGNATURE_CACHE))
+    }
+
+    /// Number of threads of the pool running the verifications in parallel
+    ///
+    /// `None` if the option is not set. In this case, the number of threads is chosen by rayon
+    /// (number of logical CPUs). Error if the value is not a positive number
+    pub fn run_threads(&self) -> Result<Option<usize>, VerifierConfigError> {
+        self.parsed_value(consts::ENV_RUN_THREADS)
+    }
+
+    /// Number of threads of the pool for the parallelism inside the verifications
+    /// (e.g. over the ballot boxes)
+    ///
+    /// `None` if the option is not set. In this case, the verifications use the same pool for both
+    /// levels of parallelism. Error if the value is not a positive number
+    pub fn run_inner_threads(&self) -> Result<Option<usize>, VerifierConfigError> {
+        self.parsed_value(consts::ENV_RUN_INNER_THREADS)
+    }
+
+    /// Are the threads running the verifications started with a low priority
+    ///
+    /// If the option is not set, use the default value (`false`)
+    pub fn run_low_priority(&self) -> Result<bool, VerifierConfigError> {
+        Ok(self
+            .parsed_value(consts::ENV_RUN_LOW_PRIORITY)?
+            .unwrap_or(DEFAULT_RUN_LOW_PRIORITY))
+    }
+
+    /// Get tab size for text reports
+    ///
+    /// If the option is not set, use the default value
+    pub fn txt_report_tab_size(&self) -> Result<u8, VerifierConfigError> {
+        Ok(self
+            .parsed_value(consts::ENV_TXT_TAB_SIZE)?
+            .unwrap_or(DEFAULT_TXT_REPORT_TAB_SIZE))
+    }
+
     /// Get the path to the browser executable for PDF report generation
     ///
-    /// If the env variable not found, return error
+    /// If the option is not set, return error
     pub fn pdf_report_browser_path(&self) -> Result<Option<PathBuf>, VerifierConfigError> {
-        match dotenvy::var(consts::ENV_REPORT_BROWSER_PATH) {
-            Ok(v) => {
+        match self.value(consts::ENV_REPORT_BROWSER_PATH) {
+            Some(v) => {
                 let path = PathBuf::from(v);
                 if !path.is_file() {
                     return Err(VerifierConfigErrorImpl::FileError {
@@ -256,16 +532,16 @@ impl VerifierConfig {
                 }
                 Ok(Some(path))
             }
-            Err(_) => Ok(None),
+            None => Ok(None),
         }
     }
 
     /// Get the path to the browser executable for PDF report generation
     ///
-    /// If the env variable not found, return error
+    /// If the option is not set, return error
     pub fn report_logo_path(&self) -> Result<Option<PathBuf>, VerifierConfigError> {
-        match dotenvy::var(consts::ENV_REPORT_LOGO) {
-            Ok(v) => {
+        match self.value(consts::ENV_REPORT_LOGO) {
+            Some(v) => {
                 let path = PathBuf::from(v);
                 if !path.is_file() {
                     return Err(VerifierConfigErrorImpl::FileError {
@@ -276,69 +552,112 @@ impl VerifierConfig {
                 }
                 Ok(Some(path))
             }
-            Err(_) => Ok(None),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the path to the template of the HTML report (used also for the PDF generated with the browser)
+    ///
+    /// `None` if the option is not set (the default layout is used). Error if the file does not exist
+    pub fn report_html_template_path(&self) -> Result<Option<PathBuf>, VerifierConfigError> {
+        match self.value(consts::ENV_REPORT_HTML_TEMPLATE) {
+            Some(v) => {
+                let path = PathBuf::from(v);
+                if !path.is_file() {
+                    return Err(VerifierConfigErrorImpl::FileError {
+                        msg: "Report HTML template file not found".to_string(),
+                        value: path.to_string_lossy().to_string(),
+                    }
+                    .into());
+                }
+                Ok(Some(path))
+            }
+            None => Ok(None),
         }
     }
 
     /// Get the electoral board members to be displayed in the report   
     pub fn report_electoral_board_members(&self) -> Vec<String> {
-        match dotenvy::var(consts::ENV_REPORT_ELECTORAL_BOARD_MEMBERS) {
-            Ok(v) => v.split(',').map(|s| s.trim().to_string()).collect(),
-            Err(_) => Vec::new(),
+        match self.value(consts::ENV_REPORT_ELECTORAL_BOARD_MEMBERS) {
+            Some(v) => v.split(',').map(|s| s.trim().to_string()).collect(),
+            None => Vec::new(),
         }
     }
 
     /// Has the report to be exported as PDF
-    pub fn report_export_pdf(&self) -> bool {
-        match dotenvy::var(consts::ENV_REPORT_EXPORT_PDF) {
-            Ok(v) => match v.parse::<bool>() {
-                Ok(v) => v,
-                Err(_) => DEFAULT_REPORT_TYPE_EXPORT,
-            },
-            Err(_) => DEFAULT_REPORT_TYPE_EXPORT,
-        }
+    pub fn report_export_pdf(&self) -> Result<bool, VerifierConfigError> {
+        Ok(self
+            .parsed_value(consts::ENV_REPORT_EXPORT_PDF)?
+            .unwrap_or(DEFAULT_REPORT_TYPE_EXPORT))
     }
 
     /// Has the report to be exported as HTML
-    pub fn report_export_html(&self) -> bool {
-        match dotenvy::var(consts::ENV_REPORT_EXPORT_HTML) {
-            Ok(v) => match v.parse::<bool>() {
-                Ok(v) => v,
-                Err(_) => DEFAULT_REPORT_TYPE_EXPORT,
-            },
-            Err(_) => DEFAULT_REPORT_TYPE_EXPORT,
-        }
+    pub fn report_export_html(&self) -> Result<bool, VerifierConfigError> {
+        Ok(self
+            .parsed_value(consts::ENV_REPORT_EXPORT_HTML)?
+            .unwrap_or(DEFAULT_REPORT_TYPE_EXPORT))
+    }
+
+    /// Has the PDF report to be rendered natively (without browser)
+    ///
+    /// If `false`, the PDF is printed with the browser given by the path of the browser
+    pub fn report_pdf_native(&self) -> Result<bool, VerifierConfigError> {
+        Ok(self
+            .parsed_value(consts::ENV_REPORT_PDF_NATIVE)?
+            .unwrap_or(DEFAULT_REPORT_TYPE_EXPORT))
+    }
+
+    /// The PDF report is printed with the browser (see [Self::pdf_report_browser_path])
+    fn report_pdf_with_browser(&self) -> bool {
+        self.report_export_pdf()
+            .unwrap_or(DEFAULT_REPORT_TYPE_EXPORT)
+            && !self
+                .report_pdf_native()
+                .unwrap_or(DEFAULT_REPORT_TYPE_EXPORT)
     }
 
     /// Has the report to be exported as HTML
-    pub fn report_sandbox(&self) -> bool {
-        match dotenvy::var(consts::ENV_REPORT_BROWSER_SANDBOX) {
-            Ok(v) => match v.parse::<bool>() {
-                Ok(v) => v,
-                Err(_) => DEFAULT_REPORT_BROWSER_SANDBOX,
-            },
-            Err(_) => DEFAULT_REPORT_BROWSER_SANDBOX,
-        }
+    pub fn report_sandbox(&self) -> Result<bool, VerifierConfigError> {
+        Ok(self
+            .parsed_value(consts::ENV_REPORT_BROWSER_SANDBOX)?
+            .unwrap_or(DEFAULT_REPORT_BROWSER_SANDBOX))
     }
 
     /// Has the report to be exported as TXT
-    pub fn report_export_txt(&self) -> bool {
-        match dotenvy::var(consts::ENV_REPORT_EXPORT_TXT) {
-            Ok(v) => match v.parse::<bool>() {
-                Ok(v) => v,
-                Err(_) => DEFAULT_REPORT_TYPE_EXPORT,
-            },
-            Err(_) => DEFAULT_REPORT_TYPE_EXPORT,
-        }
+    pub fn report_export_txt(&self) -> Result<bool, VerifierConfigError> {
+        Ok(self
+            .parsed_value(consts::ENV_REPORT_EXPORT_TXT)?
+            .unwrap_or(DEFAULT_REPORT_TYPE_EXPORT))
+    }
+
+    /// Has the report to be exported as JUnit XML (e.g. for CI pipelines)
+    pub fn report_export_junit(&self) -> Result<bool, VerifierConfigError> {
+        Ok(self
+            .parsed_value(consts::ENV_REPORT_EXPORT_JUNIT)?
+            .unwrap_or(DEFAULT_REPORT_TYPE_EXPORT))
+    }
+
+    /// Have the errors and failures to be exported as CSV (one line per event)
+    pub fn report_export_csv(&self) -> Result<bool, VerifierConfigError> {
+        Ok(self
+            .parsed_value(consts::ENV_REPORT_EXPORT_CSV)?
+            .unwrap_or(DEFAULT_REPORT_TYPE_EXPORT))
+    }
+
+    /// Have the errors and failures to be exported as JSON Lines (one line per event)
+    pub fn report_export_jsonl(&self) -> Result<bool, VerifierConfigError> {
+        Ok(self
+            .parsed_value(consts::ENV_REPORT_EXPORT_JSONL)?
+            .unwrap_or(DEFAULT_REPORT_TYPE_EXPORT))
     }
 
     /// Get tab size for text reports
     ///
-    /// If the env variable not found, use the default value
+    /// If the option is not set, use the default value
     pub fn report_format_date(&self) -> String {
-        match dotenvy::var(consts::ENV_REPORT_FORMAT_DATE) {
-            Ok(v) => v,
-            Err(_) => DEFAULT_REPORT_FORMAT_DATE_TIME.to_string(),
+        match self.value(consts::ENV_REPORT_FORMAT_DATE) {
+            Some(v) => v,
+            None => DEFAULT_REPORT_FORMAT_DATE_TIME.to_string(),
         }
     }
 
@@ -360,10 +679,11 @@ pub(crate) mod test {
         Report,
         direct_trust::CertificateAuthority,
         file_structure::{VerificationDirectory, mock::MockVerificationDirectory},
-        verification::VerificationPeriod,
+        verification::{RunContext, VerificationPeriod},
     };
     use lazy_static::lazy_static;
     use rust_ev_system_library::rust_ev_crypto_primitives::prelude::direct_trust::Keystore as BasisKeystore;
+    use std::sync::Arc;
 
     const CANTON_KEYSTORE_FILE_NAME: &str = "local_direct_trust_keystore_canton.p12";
     const CANTON_KEYSTORE_PASSWORD_FILE_NAME: &str = "local_direct_trust_pw_canton.txt";
@@ -391,7 +711,8 @@ pub(crate) mod test {
     const TEST_DECRYPT_ZIP_PASSWORD: &str = "LongPassword_Encryption1";
 
     lazy_static! {
-        pub(crate) static ref CONFIG_TEST: VerifierConfig = VerifierConfig::new(".");
+        pub(crate) static ref CONFIG_TEST: Arc<VerifierConfig> = Arc::new(VerifierConfig::new("."));
+        pub(crate) static ref CONTEXT_TEST: RunContext = RunContext::new(&CONFIG_TEST);
     }
 
     pub(crate) fn test_datasets_path() -> PathBuf {
@@ -549,7 +870,111 @@ pub(crate) mod test {
         let c = VerifierConfig::default();
         assert_eq!(c.root_dir_path(), Path::new("."));
         assert_eq!(c.log_file_path(), Path::new("./log/log.txt"));
+        assert_eq!(c.audit_log_file_path(), Path::new("./log/audit_log.jsonl"));
         assert_eq!(c.direct_trust_dir_path(), Path::new("./direct-trust"));
+        assert!(c.direct_trust_additional_dir_paths().is_empty());
         assert!(!c.get_verification_list_str().is_empty());
     }
+
+    #[test]
+    fn test_config_owned_root_dir() {
+        let c1 = Arc::new(VerifierConfig::new(String::from("tenant_a")));
+        let c2 = Arc::new(VerifierConfig::new(PathBuf::from("tenant_b")));
+        assert_eq!(c1.log_file_path(), Path::new("tenant_a/log/log.txt"));
+        assert_eq!(c2.log_file_path(), Path::new("tenant_b/log/log.txt"));
+        let shared = c1.clone();
+        assert!(Arc::ptr_eq(&shared, &c1));
+    }
+
+    #[test]
+    fn test_config_file() {
+        let path = std::env::temp_dir().join(format!(
+            "test_verifier_config_{}_{}.json",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        std::fs::write(
+            &path,
+            r#"{"report_export_jsonl": true, "run_inner_threads": 0, "report_logo": "./toto.png", "report_signing_authority": "verifier_ne"}"#,
+        )
+        .unwrap();
+        let c = VerifierConfig::new_with_config_file(".", &path);
+        assert_eq!(c.config_file_path(), Some(path.clone()));
+        assert!(c.report_export_jsonl().unwrap());
+        let e = c.run_inner_threads().unwrap_err().to_string();
+        assert!(e.contains("run_inner_threads (file)"));
+        assert!(!c.report_export_pdf().unwrap());
+        assert_eq!(c.report_signing_authority(), "verifier_ne");
+        let e = c.validate().unwrap_err().to_string();
+        assert!(e.contains("run_inner_threads (file)"));
+        assert!(e.contains("report_logo (file)"));
+        let effective = c.effective_configuration();
+        let jsonl = effective
+            .iter()
+            .find(|v| v.key == "report_export_jsonl")
+            .unwrap();
+        assert_eq!(jsonl.value.as_deref(), Some("true"));
+        assert_eq!(jsonl.source, ConfigValueSource::File);
+        let tab_size = effective
+            .iter()
+            .find(|v| v.key == "txt_report_tab_size")
+            .unwrap();
+        assert_eq!(tab_size.value.as_deref(), Some("2"));
+        assert_eq!(tab_size.source, ConfigValueSource::Default);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_ignore_env() {
+        let path = std::env::temp_dir().join(format!(
+            "test_verifier_config_ignore_env_{}_{}.json",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        std::fs::write(&path, r#"{"direct_trust_dir_path": "./keystore"}"#).unwrap();
+        let c = VerifierConfig::new_with_config_file(".", &path).ignore_env();
+        assert_eq!(c.direct_trust_dir_path(), Path::new("./keystore"));
+        // Even if some variables of the verifier are set in the environment of the process
+        assert!(
+            c.effective_configuration()
+                .iter()
+                .all(|v| v.source != ConfigValueSource::Env)
+        );
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_validate_browser_path() {
+        let path = std::env::temp_dir().join(format!(
+            "test_verifier_config_browser_{}_{}.json",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let validate = |json: &str| {
+            std::fs::write(&path, json).unwrap();
+            VerifierConfig::new_with_config_file(".", &path).validate()
+        };
+        // The path of the browser is not used without PDF or with the native PDF
+        assert!(validate(r#"{"report_browser_path": "./toto/chrome"}"#).is_ok());
+        assert!(
+            validate(
+                r#"{"report_browser_path": "./toto/chrome", "report_export_pdf": true, "report_pdf_native": true}"#
+            )
+            .is_ok()
+        );
+        let e = validate(r#"{"report_browser_path": "./toto/chrome", "report_export_pdf": true}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(e.contains("report_browser_path (file)"));
+        let _ = std::fs::remove_file(&path);
+    }
 }
@@ -0,0 +1,487 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Module implementing the configuration file of the verifier
+//!
+//! The configuration file is a JSON object. The keys are the following (all optional):
+//!
+//! | Key                                 | Type              | Environment variable                | Default |
+//! | ----------------------------------- | ----------------- | ----------------------------------- | ------- |
+//! | `txt_report_tab_size`               | integer (0-255)   | `TXT_TAB_SIZE`                      | `2` |
+//! | `report_format_date`                | string            | `REPORT_FORMAT_DATE`                | `%d.%m.%Y %H:%M:%S.%3f` |
+//! | `direct_trust_dir_path`             | directory         | `DIRECT_TRUST_DIR_PATH`             | `direct-trust` in the root directory |
+//! | `direct_trust_additional_dir_paths` | list of directories | `DIRECT_TRUST_ADDITIONAL_DIR_PATHS` | none |
+//! | `signature_cache`                   | boolean           | `SIGNATURE_CACHE`                   | `false` |
+//! | `run_threads`                       | positive integer  | `RUN_THREADS`                       | number of logical CPUs |
+//! | `run_inner_threads`                 | positive integer  | `RUN_INNER_THREADS`                 | none |
+//! | `run_low_priority`                  | boolean           | `RUN_LOW_PRIORITY`                  | `false` |
+//! | `report_export_txt`                 | boolean           | `REPORT_EXPORT_TXT`                 | `false` |
+//! | `report_export_html`                | boolean           | `REPORT_EXPORT_HTML`                | `false` |
+//! | `report_export_pdf`                 | boolean           | `REPORT_EXPORT_PDF`                 | `false` |
+//! | `report_export_junit`               | boolean           | `REPORT_EXPORT_JUNIT`               | `false` |
+//! | `report_export_csv`                 | boolean           | `REPORT_EXPORT_CSV`                 | `false` |
+//! | `report_export_jsonl`               | boolean           | `REPORT_EXPORT_JSONL`               | `false` |
+//! | `report_pdf_native`                 | boolean           | `REPORT_PDF_NATIVE`                 | `false` |
+//! | `report_browser_path`               | file              | `REPORT_BROWSER_PATH`               | none |
+//! | `report_browser_sandbox`            | boolean           | `REPORT_BROWSER_SANDBOX`            | `false` |
+//! | `report_electoral_board_members`    | list of strings   | `REPORT_ELECTORAL_BOARD_MEMBERS`    | none |
+//! | `report_logo`                       | file              | `REPORT_LOGO`                       | none |
+//! | `report_html_template`              | file              | `REPORT_HTML_TEMPLATE`              | none |
+//! | `report_signing_keystore_dir_path`  | directory         | `REPORT_SIGNING_KEYSTORE_DIR_PATH`  | none |
+//! | `report_signing_authority`          | string            | `REPORT_SIGNING_AUTHORITY`          | `verifier` |
+//!
+//! The lists are JSON arrays in the file and values separated with a comma in the environment
+//! variables. The password of the datasets (`VERIFIER_DATASET_PASSWORD`) cannot be set in the file.
+//!
+//! Example:
+//! ```json
+//! {
+//!     "report_export_pdf": true,
+//!     "txt_report_tab_size": 4,
+//!     "report_electoral_board_members": ["Member 1", "Member 2"]
+//! }
+//! ```
+//!
+//! The value of an option is taken, in this order, from the environment variable, from the
+//! configuration file or from the default value (see [crate::VerifierConfig]).
+
+use crate::consts;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{collections::HashMap, path::Path};
+
+/// Kind of the value of an option, used to validate the value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ConfigValueKind {
+    Bool,
+    U8,
+    PositiveUsize,
+    Text,
+    TextList,
+    File,
+    Dir,
+    DirList,
+}
+
+impl ConfigValueKind {
+    /// Check the value (as string, like in the environment variables)
+    fn check(&self, value: &str) -> Result<(), String> {
+        match self {
+            Self::Bool => value
+                .parse::<bool>()
+                .map(|_| ())
+                .map_err(|_| "expected `true` or `false`".to_string()),
+            Self::U8 => value
+                .parse::<u8>()
+                .map(|_| ())
+                .map_err(|_| "expected an integer between 0 and 255".to_string()),
+            Self::PositiveUsize => match value.parse::<usize>() {
+                Ok(v) if v > 0 => Ok(()),
+                _ => Err("expected a positive integer".to_string()),
+            },
+            Self::Text | Self::TextList => Ok(()),
+            Self::File => match Path::new(value).is_file() {
+                true => Ok(()),
+                false => Err("file not found".to_string()),
+            },
+            Self::Dir => match Path::new(value).is_dir() {
+                true => Ok(()),
+                false => Err("directory not found".to_string()),
+            },
+            Self::DirList => match value
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .find(|s| !Path::new(s).is_dir())
+            {
+                Some(s) => Err(format!("directory {s} not found")),
+                None => Ok(()),
+            },
+        }
+    }
+
+    fn is_list(&self) -> bool {
+        matches!(self, Self::TextList | Self::DirList)
+    }
+}
+
+/// Option of the configuration, which can be set in the configuration file or in an environment variable
+#[derive(Debug)]
+pub(crate) struct ConfigOption {
+    /// Key in the configuration file
+    pub key: &'static str,
+    /// Name of the environment variable
+    pub env: &'static str,
+    pub kind: ConfigValueKind,
+    /// Default value, as displayed in the effective configuration
+    pub default: Option<&'static str>,
+}
+
+const fn option(
+    key: &'static str,
+    env: &'static str,
+    kind: ConfigValueKind,
+    default: Option<&'static str>,
+) -> ConfigOption {
+    ConfigOption {
+        key,
+        env,
+        kind,
+        default,
+    }
+}
+
+/// All the options of the configuration
+pub(crate) const CONFIG_OPTIONS: &[ConfigOption] = &[
+    option(
+        "txt_report_tab_size",
+        consts::ENV_TXT_TAB_SIZE,
+        ConfigValueKind::U8,
+        Some("2"),
+    ),
+    option(
+        "report_format_date",
+        consts::ENV_REPORT_FORMAT_DATE,
+        ConfigValueKind::Text,
+        Some("%d.%m.%Y %H:%M:%S.%3f"),
+    ),
+    option(
+        "direct_trust_dir_path",
+        consts::ENV_DIRECT_TRUST_DIR_PATH,
+        ConfigValueKind::Dir,
+        Some("direct-trust"),
+    ),
+    option(
+        "direct_trust_additional_dir_paths",
+        consts::ENV_DIRECT_TRUST_ADDITIONAL_DIR_PATHS,
+        ConfigValueKind::DirList,
+        None,
+    ),
+    option(
+        "signature_cache",
+        consts::ENV_SIGNATURE_CACHE,
+        ConfigValueKind::Bool,
+        Some("false"),
+    ),
+    option(
+        "run_threads",
+        consts::ENV_RUN_THREADS,
+        ConfigValueKind::PositiveUsize,
+        None,
+    ),
+    option(
+        "run_inner_threads",
+        consts::ENV_RUN_INNER_THREADS,
+        ConfigValueKind::PositiveUsize,
+        None,
+    ),
+    option(
+        "run_low_priority",
+        consts::ENV_RUN_LOW_PRIORITY,
+        ConfigValueKind::Bool,
+        Some("false"),
+    ),
+    option(
+        "report_export_txt",
+        consts::ENV_REPORT_EXPORT_TXT,
+        ConfigValueKind::Bool,
+        Some("false"),
+    ),
+    option(
+        "report_export_html",
+        consts::ENV_REPORT_EXPORT_HTML,
+        ConfigValueKind::Bool,
+        Some("false"),
+    ),
+    option(
+        "report_export_pdf",
+        consts::ENV_REPORT_EXPORT_PDF,
+        ConfigValueKind::Bool,
+        Some("false"),
+    ),
+    option(
+        "report_export_junit",
+        consts::ENV_REPORT_EXPORT_JUNIT,
+        ConfigValueKind::Bool,
+        Some("false"),
+    ),
+    option(
+        "report_export_csv",
+        consts::ENV_REPORT_EXPORT_CSV,
+        ConfigValueKind::Bool,
+        Some("false"),
+    ),
+    option(
+        "report_export_jsonl",
+        consts::ENV_REPORT_EXPORT_JSONL,
+        ConfigValueKind::Bool,
+        Some("false"),
+    ),
+    option(
+        "report_pdf_native",
+        consts::ENV_REPORT_PDF_NATIVE,
+        ConfigValueKind::Bool,
+        Some("false"),
+    ),
+    option(
+        "report_browser_path",
+        consts::ENV_REPORT_BROWSER_PATH,
+        ConfigValueKind::File,
+        None,
+    ),
+    option(
+        "report_browser_sandbox",
+        consts::ENV_REPORT_BROWSER_SANDBOX,
+        ConfigValueKind::Bool,
+        Some("false"),
+    ),
+    option(
+        "report_electoral_board_members",
+        consts::ENV_REPORT_ELECTORAL_BOARD_MEMBERS,
+        ConfigValueKind::TextList,
+        None,
+    ),
+    option(
+        "report_logo",
+        consts::ENV_REPORT_LOGO,
+        ConfigValueKind::File,
+        None,
+    ),
+    option(
+        "report_html_template",
+        consts::ENV_REPORT_HTML_TEMPLATE,
+        ConfigValueKind::File,
+        None,
+    ),
+    option(
+        "report_signing_keystore_dir_path",
+        consts::ENV_REPORT_SIGNING_KEYSTORE_DIR_PATH,
+        ConfigValueKind::Dir,
+        None,
+    ),
+    option(
+        "report_signing_authority",
+        consts::ENV_REPORT_SIGNING_AUTHORITY,
+        ConfigValueKind::Text,
+        Some("verifier"),
+    ),
+];
+
+/// Get the option for the environment variable
+pub(crate) fn config_option_for_env(env: &str) -> &'static ConfigOption {
+    CONFIG_OPTIONS
+        .iter()
+        .find(|o| o.env == env)
+        .unwrap_or_else(|| panic!("No option for the environment variable {env}"))
+}
+
+impl ConfigOption {
+    /// Check the value of the option
+    ///
+    /// Return the message describing the problem
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        self.kind.check(value)
+    }
+
+    /// Name of the option for the given source: the key in the configuration file or
+    /// the name of the environment variable
+    pub fn name_for_source(&self, source: ConfigValueSource) -> &'static str {
+        match source {
+            ConfigValueSource::File => self.key,
+            _ => self.env,
+        }
+    }
+}
+
+/// Source of the value of an option of the configuration
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, strum::AsRefStr, strum::Display, Serialize, Deserialize,
+)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigValueSource {
+    /// Environment variable (or `.env` file)
+    Env,
+    /// Configuration file
+    File,
+    /// Default value
+    Default,
+}
+
+/// Value of an option of the effective configuration (see [crate::VerifierConfig::effective_configuration])
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectiveConfigValue {
+    /// Key of the option in the configuration file
+    pub key: String,
+    /// Name of the environment variable of the option
+    pub env: String,
+    /// Value of the option. `None` if not set and without default value
+    pub value: Option<String>,
+    /// Source of the value
+    pub source: ConfigValueSource,
+}
+
+/// Values read from the configuration file
+///
+/// The values are stored as strings, in the same format as in the environment variables, so that
+/// they are parsed the same way for both sources.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ConfigFile {
+    values: HashMap<&'static str, String>,
+    /// Problems found by loading the file (unknown keys, wrong types)
+    problems: Vec<String>,
+}
+
+impl ConfigFile {
+    /// Load the configuration file
+    ///
+    /// The keys not known and the values with the wrong type are not loaded and collected
+    /// as problems (see [Self::problems]). Return an error if the file cannot be read or if
+    /// it is not a JSON object
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let s = std::fs::read_to_string(path).map_err(|e| format!("Cannot read {path:?}: {e}"))?;
+        Self::from_json_str(&s).map_err(|e| format!("Error in {path:?}: {e}"))
+    }
+
+    /// Load the configuration from a json string
+    pub fn from_json_str(s: &str) -> Result<Self, String> {
+        let object = match serde_json::from_str::<Value>(s).map_err(|e| e.to_string())? {
+            Value::Object(o) => o,
+            _ => return Err("The configuration must be a JSON object".to_string()),
+        };
+        let mut res = Self::default();
+        for (key, value) in object {
+            let option = match CONFIG_OPTIONS.iter().find(|o| o.key == key) {
+                Some(o) => o,
+                None => {
+                    res.problems.push(format!("Unknown key {key}"));
+                    continue;
+                }
+            };
+            match json_value_to_string(&value, option.kind.is_list()) {
+                Some(v) => {
+                    res.values.insert(option.key, v);
+                }
+                None => res.problems.push(format!("Wrong type for the key {key}")),
+            }
+        }
+        Ok(res)
+    }
+
+    /// Value of the option, as string
+    pub fn value(&self, option: &ConfigOption) -> Option<&str> {
+        self.values.get(option.key).map(|s| s.as_str())
+    }
+
+    /// Problems found by loading the file
+    pub fn problems(&self) -> &[String] {
+        &self.problems
+    }
+}
+
+/// Transform the JSON value to the string format of the environment variables
+///
+/// `None` if the type of the value is not allowed
+fn json_value_to_string(value: &Value, is_list: bool) -> Option<String> {
+    match value {
+        Value::Bool(b) if !is_list => Some(b.to_string()),
+        Value::Number(n) if !is_list => Some(n.to_string()),
+        Value::String(s) if !is_list => Some(s.clone()),
+        Value::Array(a) if is_list => a
+            .iter()
+            .map(|v| v.as_str().map(|s| s.to_string()))
+            .collect::<Option<Vec<_>>>()
+            .map(|l| l.join(",")),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_json_str() {
+        let file = ConfigFile::from_json_str(
+            r#"{
+                "report_export_pdf": true,
+                "txt_report_tab_size": 4,
+                "report_format_date": "%d.%m.%Y",
+                "report_electoral_board_members": ["Member 1", "Member 2"],
+                "run_threads": "four",
+                "report_export_txt": [true],
+                "toto": 1
+            }"#,
+        )
+        .unwrap();
+        let value = |env| file.value(config_option_for_env(env));
+        assert_eq!(value(consts::ENV_REPORT_EXPORT_PDF), Some("true"));
+        assert_eq!(value(consts::ENV_TXT_TAB_SIZE), Some("4"));
+        assert_eq!(value(consts::ENV_REPORT_FORMAT_DATE), Some("%d.%m.%Y"));
+        assert_eq!(
+            value(consts::ENV_REPORT_ELECTORAL_BOARD_MEMBERS),
+            Some("Member 1,Member 2")
+        );
+        assert_eq!(value(consts::ENV_REPORT_EXPORT_TXT), None);
+        assert_eq!(value(consts::ENV_RUN_THREADS), Some("four"));
+        assert_eq!(file.problems().len(), 2);
+        assert!(ConfigFile::from_json_str("[1]").is_err());
+        assert!(ConfigFile::from_json_str("{").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let check = |env, value| config_option_for_env(env).check(value);
+        assert!(check(consts::ENV_REPORT_EXPORT_PDF, "true").is_ok());
+        assert!(check(consts::ENV_REPORT_EXPORT_PDF, "yes").is_err());
+        assert!(check(consts::ENV_TXT_TAB_SIZE, "300").is_err());
+        assert!(check(consts::ENV_RUN_THREADS, "0").is_err());
+        assert!(check(consts::ENV_RUN_THREADS, "8").is_ok());
+        assert!(check(consts::ENV_REPORT_LOGO, "./toto.png").is_err());
+        assert!(check(consts::ENV_DIRECT_TRUST_ADDITIONAL_DIR_PATHS, ".,./toto").is_err());
+        assert!(check(consts::ENV_DIRECT_TRUST_ADDITIONAL_DIR_PATHS, ".,").is_ok());
+    }
+
+    #[test]
+    fn test_all_env_have_option() {
+        for env in [
+            consts::ENV_TXT_TAB_SIZE,
+            consts::ENV_REPORT_FORMAT_DATE,
+            consts::ENV_DIRECT_TRUST_DIR_PATH,
+            consts::ENV_DIRECT_TRUST_ADDITIONAL_DIR_PATHS,
+            consts::ENV_REPORT_BROWSER_PATH,
+            consts::ENV_REPORT_EXPORT_PDF,
+            consts::ENV_REPORT_EXPORT_HTML,
+            consts::ENV_REPORT_EXPORT_TXT,
+            consts::ENV_REPORT_EXPORT_JUNIT,
+            consts::ENV_REPORT_EXPORT_CSV,
+            consts::ENV_REPORT_EXPORT_JSONL,
+            consts::ENV_REPORT_PDF_NATIVE,
+            consts::ENV_REPORT_ELECTORAL_BOARD_MEMBERS,
+            consts::ENV_REPORT_LOGO,
+            consts::ENV_REPORT_HTML_TEMPLATE,
+            consts::ENV_REPORT_SIGNING_KEYSTORE_DIR_PATH,
+            consts::ENV_REPORT_SIGNING_AUTHORITY,
+            consts::ENV_REPORT_BROWSER_SANDBOX,
+            consts::ENV_SIGNATURE_CACHE,
+            consts::ENV_RUN_THREADS,
+            consts::ENV_RUN_INNER_THREADS,
+            consts::ENV_RUN_LOW_PRIORITY,
+        ] {
+            assert_eq!(config_option_for_env(env).env, env);
+        }
+    }
+}
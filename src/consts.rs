@@ -29,13 +29,26 @@ pub const ENV_VERIFIER_DATASET_PASSWORD: &str = "VERIFIER_DATASET_PASSWORD";
 pub const ENV_TXT_TAB_SIZE: &str = "TXT_TAB_SIZE";
 pub const ENV_REPORT_FORMAT_DATE: &str = "REPORT_FORMAT_DATE";
 pub const ENV_DIRECT_TRUST_DIR_PATH: &str = "DIRECT_TRUST_DIR_PATH";
+pub const ENV_DIRECT_TRUST_ADDITIONAL_DIR_PATHS: &str = "DIRECT_TRUST_ADDITIONAL_DIR_PATHS";
 pub const ENV_REPORT_BROWSER_PATH: &str = "REPORT_BROWSER_PATH";
 pub const ENV_REPORT_EXPORT_PDF: &str = "REPORT_EXPORT_PDF";
 pub const ENV_REPORT_EXPORT_HTML: &str = "REPORT_EXPORT_HTML";
 pub const ENV_REPORT_EXPORT_TXT: &str = "REPORT_EXPORT_TXT";
+pub const ENV_REPORT_EXPORT_JUNIT: &str = "REPORT_EXPORT_JUNIT";
+pub const ENV_REPORT_EXPORT_CSV: &str = "REPORT_EXPORT_CSV";
+pub const ENV_REPORT_EXPORT_JSONL: &str = "REPORT_EXPORT_JSONL";
+pub const ENV_REPORT_PDF_NATIVE: &str = "REPORT_PDF_NATIVE";
 pub const ENV_REPORT_ELECTORAL_BOARD_MEMBERS: &str = "REPORT_ELECTORAL_BOARD_MEMBERS";
 pub const ENV_REPORT_LOGO: &str = "REPORT_LOGO";
+pub const ENV_REPORT_HTML_TEMPLATE: &str = "REPORT_HTML_TEMPLATE";
+pub const ENV_REPORT_SIGNING_KEYSTORE_DIR_PATH: &str = "REPORT_SIGNING_KEYSTORE_DIR_PATH";
+pub const ENV_REPORT_SIGNING_AUTHORITY: &str = "REPORT_SIGNING_AUTHORITY";
 pub const ENV_REPORT_BROWSER_SANDBOX: &str = "REPORT_BROWSER_SANDBOX";
+pub const ENV_SIGNATURE_CACHE: &str = "SIGNATURE_CACHE";
+pub const ENV_RUN_THREADS: &str = "RUN_THREADS";
+pub const ENV_RUN_INNER_THREADS: &str = "RUN_INNER_THREADS";
+pub const ENV_RUN_LOW_PRIORITY: &str = "RUN_LOW_PRIORITY";
+pub const ENV_CONFIG_FILE: &str = "VERIFIER_CONFIG_FILE";
 
 #[cfg(test)]
 #[allow(dead_code)]
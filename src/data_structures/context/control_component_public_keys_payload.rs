@@ -83,11 +83,14 @@ impl<'a> VerifiyJSONSignatureTrait<'a> for ControlComponentPublicKeysPayload {
 }
 
 impl<'a> VerifiySignatureTrait<'a> for ControlComponentPublicKeysPayload {
-    fn verifiy_signature(
+    fn verifiy_signature_with_certificate(
         &'a self,
         keystore: &crate::direct_trust::Keystore,
-    ) -> Result<bool, crate::direct_trust::VerifySignatureError> {
-        self.verifiy_json_signature(keystore)
+    ) -> Result<
+        Option<crate::direct_trust::TrustedCertificate>,
+        crate::direct_trust::VerifySignatureError,
+    > {
+        self.verifiy_json_signature_with_certificate(keystore)
     }
 }
 
@@ -386,11 +386,14 @@ impl VerifiyXMLSignatureTrait<'_> for ElectionEventConfiguration {
 }
 
 impl VerifiySignatureTrait<'_> for ElectionEventConfiguration {
-    fn verifiy_signature(
+    fn verifiy_signature_with_certificate(
         &'_ self,
         keystore: &crate::direct_trust::Keystore,
-    ) -> Result<bool, crate::direct_trust::VerifySignatureError> {
-        self.verifiy_xml_signature(keystore)
+    ) -> Result<
+        Option<crate::direct_trust::TrustedCertificate>,
+        crate::direct_trust::VerifySignatureError,
+    > {
+        self.verifiy_xml_signature_with_certificate(keystore)
     }
 }
 
@@ -85,11 +85,14 @@ impl<'a> VerifiyJSONSignatureTrait<'a> for SetupComponentPublicKeysPayload {
 }
 
 impl<'a> VerifiySignatureTrait<'a> for SetupComponentPublicKeysPayload {
-    fn verifiy_signature(
+    fn verifiy_signature_with_certificate(
         &'a self,
         keystore: &crate::direct_trust::Keystore,
-    ) -> Result<bool, crate::direct_trust::VerifySignatureError> {
-        self.verifiy_json_signature(keystore)
+    ) -> Result<
+        Option<crate::direct_trust::TrustedCertificate>,
+        crate::direct_trust::VerifySignatureError,
+    > {
+        self.verifiy_json_signature_with_certificate(keystore)
     }
 }
 
@@ -167,7 +170,7 @@ mod test {
     fn test_sign() {
         let mut payload = get_data_res().unwrap();
         let new_signature = payload
-            .sign(&Keystore(
+            .sign(&Keystore::from(
                 signing_keystore(payload.get_certificate_authority().unwrap()).unwrap(),
             ))
             .unwrap();
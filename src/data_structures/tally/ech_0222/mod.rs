@@ -20,6 +20,7 @@
 
 mod ech_0222_data;
 mod election;
+mod results;
 mod votations;
 
 use super::{
@@ -34,6 +35,10 @@ use crate::{
     file_structure::FileStructureError,
 };
 pub use ech_0222_data::ECH0222Data;
+pub use results::{
+    CountResult, CountingCircleResults, ElectionResults, ElectionResultsSummary, QuestionResults,
+    VoteResults,
+};
 use roxmltree::Document;
 use std::{fmt::Display, sync::Arc};
 use thiserror::Error;
@@ -72,6 +77,10 @@ pub enum ECH0222CalculatedErrorImpl {
         decoded_vote: String,
         msg: &'static str,
     },
+    #[error("Error reading the context for the results: {source}")]
+    ContextMissing { source: Box<FileStructureError> },
+    #[error("Error decoding the election event configuration: {source}")]
+    ConfigurationData { source: Box<DataStructureError> },
 }
 
 /// The difference between two [ECH0222Data]
@@ -160,11 +169,14 @@ impl<'a> VerifiyXMLSignatureTrait<'a> for ECH0222 {
 }
 
 impl<'a> VerifiySignatureTrait<'a> for ECH0222 {
-    fn verifiy_signature(
+    fn verifiy_signature_with_certificate(
         &'a self,
         keystore: &crate::direct_trust::Keystore,
-    ) -> Result<bool, crate::direct_trust::VerifySignatureError> {
-        self.verifiy_xml_signature(keystore)
+    ) -> Result<
+        Option<crate::direct_trust::TrustedCertificate>,
+        crate::direct_trust::VerifySignatureError,
+    > {
+        self.verifiy_xml_signature_with_certificate(keystore)
     }
 }
 
@@ -0,0 +1,382 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Summary of the results contained in the eCH-0222
+//!
+//! The summary counts the answers of the votes and the list and candidate votes of the elections,
+//! per counting circle. The texts are taken from the [ElectionEventConfigurationData].
+
+use super::{
+    ECH0222CalculatedError, ECH0222CalculatedErrorImpl, ECH0222Data,
+    ech_0222_data::CountingCircleRawData,
+    election::{Candidate, CandidateOrIsEmpty, ElectionRawData},
+};
+use crate::{
+    data_structures::context::election_event_configuration::{
+        ElectionEventConfigurationData, ElectionInformation, StandardQuestion, Vote,
+    },
+    file_structure::{ContextDirectoryTrait, TallyDirectoryTrait, VerificationDirectoryTrait},
+};
+
+/// Number of votes for an answer, a list or a candidate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountResult {
+    pub identification: String,
+    pub text: String,
+    pub count: usize,
+}
+
+/// Results of a question
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuestionResults {
+    pub question_identification: String,
+    pub question: String,
+    pub answers: Vec<CountResult>,
+}
+
+/// Results of a vote
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoteResults {
+    pub vote_identification: String,
+    pub questions: Vec<QuestionResults>,
+}
+
+/// Results of an election
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElectionResults {
+    pub election_identification: String,
+    pub number_of_ballots: usize,
+    /// Number of ballots per list. Empty if the election has no list
+    pub lists: Vec<CountResult>,
+    pub ballots_without_list: usize,
+    pub candidates: Vec<CountResult>,
+    pub write_ins: usize,
+    pub empty_positions: usize,
+}
+
+/// Results of a counting circle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountingCircleResults {
+    pub counting_circle_id: String,
+    pub valid_voting_cards: usize,
+    pub invalid_voting_cards: usize,
+    pub votes: Vec<VoteResults>,
+    pub elections: Vec<ElectionResults>,
+}
+
+/// Summary of the results of the contest, per counting circle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElectionResultsSummary {
+    pub contest_identification: String,
+    /// Counting circles, sorted by id
+    pub counting_circles: Vec<CountingCircleResults>,
+}
+
+/// Select the text in the given language. Take the first text if the language is not found
+fn text_in_language<'a>(
+    texts: impl Iterator<Item = (&'a str, &'a str)> + Clone,
+    language: &str,
+) -> String {
+    texts
+        .clone()
+        .find(|(l, _)| l.eq_ignore_ascii_case(language))
+        .or_else(|| texts.clone().next())
+        .map(|(_, t)| t.to_string())
+        .unwrap_or_default()
+}
+
+impl QuestionResults {
+    fn new(question: &StandardQuestion, cc: &CountingCircleRawData, language: &str) -> Self {
+        let casted_positions = cc
+            .vote_raw_data
+            .values()
+            .flat_map(|v| v.ballot_raw_data.iter())
+            .flat_map(|b| b.ballot_casted.question_raw_data.iter())
+            .filter(|q| q.question_identification == question.question_identification)
+            .filter_map(|q| q.casted.as_ref().map(|c| c.casted_vote))
+            .collect::<Vec<_>>();
+        Self {
+            question_identification: question.question_identification.clone(),
+            question: text_in_language(
+                question
+                    .ballot_question
+                    .ballot_question_info
+                    .iter()
+                    .map(|i| (i.language.as_str(), i.ballot_question.as_str())),
+                language,
+            ),
+            answers: question
+                .answers
+                .iter()
+                .map(|a| CountResult {
+                    identification: a.answer_identification.clone(),
+                    text: text_in_language(
+                        a.answer_info
+                            .iter()
+                            .map(|i| (i.language.as_str(), i.answer.as_str())),
+                        language,
+                    ),
+                    count: casted_positions
+                        .iter()
+                        .filter(|p| **p == a.answer_position)
+                        .count(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl VoteResults {
+    fn new(vote: &Vote, cc: &CountingCircleRawData, language: &str) -> Self {
+        Self {
+            vote_identification: vote.vote_identification.clone(),
+            questions: vote
+                .ballots
+                .iter()
+                .flat_map(|b| b.questions())
+                .map(|q| QuestionResults::new(q, cc, language))
+                .collect(),
+        }
+    }
+}
+
+impl ElectionResults {
+    fn new(
+        information: &ElectionInformation,
+        ballots: &[&ElectionRawData],
+        language: &str,
+    ) -> Self {
+        let positions = ballots
+            .iter()
+            .flat_map(|b| b.ballot_positions.iter())
+            .map(|p| &p.0)
+            .collect::<Vec<_>>();
+        // List of each ballot (`None` for the empty list)
+        let ballot_lists = ballots
+            .iter()
+            .map(|b| {
+                b.list_raw_data
+                    .as_ref()
+                    .map(|l| l.list_identification.as_str())
+                    .filter(|id| *id != information.empty_list.list_identification)
+            })
+            .collect::<Vec<_>>();
+        Self {
+            election_identification: information.election.election_identification.clone(),
+            number_of_ballots: ballots.len(),
+            lists: information
+                .lists
+                .iter()
+                .map(|l| CountResult {
+                    identification: l.list_identification.clone(),
+                    text: text_in_language(
+                        l.list_description
+                            .list_description_info
+                            .iter()
+                            .map(|i| (i.language.as_str(), i.list_description.as_str())),
+                        language,
+                    ),
+                    count: ballot_lists
+                        .iter()
+                        .filter(|id| **id == Some(l.list_identification.as_str()))
+                        .count(),
+                })
+                .collect(),
+            ballots_without_list: ballot_lists.iter().filter(|id| id.is_none()).count(),
+            candidates: information
+                .candidates
+                .iter()
+                .map(|c| CountResult {
+                    identification: c.candidate_identification.clone(),
+                    text: format!("{} {}", c.family_name, c.call_name),
+                    count: positions
+                        .iter()
+                        .filter(|p| {
+                            matches!(
+                                p,
+                                CandidateOrIsEmpty::Candidate(Candidate::Candidate {
+                                    candidate_identification,
+                                    ..
+                                }) if *candidate_identification == c.candidate_identification
+                            )
+                        })
+                        .count(),
+                })
+                .collect(),
+            write_ins: positions
+                .iter()
+                .filter(|p| matches!(p, CandidateOrIsEmpty::Candidate(Candidate::WriteIn(_))))
+                .count(),
+            empty_positions: positions
+                .iter()
+                .filter(|p| matches!(p, CandidateOrIsEmpty::IsEmpty(true)))
+                .count(),
+        }
+    }
+}
+
+impl CountingCircleResults {
+    fn new(
+        cc: &CountingCircleRawData,
+        configuration: &ElectionEventConfigurationData,
+        language: &str,
+    ) -> Self {
+        let mut votes = configuration
+            .contest
+            .votes
+            .iter()
+            .map(|v| &v.vote)
+            .filter(|v| cc.vote_raw_data.contains_key(&v.vote_identification))
+            .collect::<Vec<_>>();
+        votes.sort_by_key(|v| v.vote_position);
+        let mut election_groups = configuration
+            .contest
+            .election_groups
+            .iter()
+            .collect::<Vec<_>>();
+        election_groups.sort_by_key(|eg| eg.election_group_position);
+        let elections = election_groups
+            .iter()
+            .flat_map(|eg| eg.election_informations.iter())
+            .filter_map(|information| {
+                let ballots = cc
+                    .election_group_ballot_raw_data
+                    .iter()
+                    .flat_map(|eg| eg.election_raw_data.iter())
+                    .filter(|e| {
+                        e.election_identification == information.election.election_identification
+                    })
+                    .collect::<Vec<_>>();
+                match ballots.is_empty() {
+                    true => None,
+                    false => Some(ElectionResults::new(information, &ballots, language)),
+                }
+            })
+            .collect();
+        Self {
+            counting_circle_id: cc.counting_circle_id.clone(),
+            valid_voting_cards: cc
+                .voting_cards_information
+                .count_of_received_valid_voting_cards_total,
+            invalid_voting_cards: cc
+                .voting_cards_information
+                .count_of_received_invalid_voting_cards_total,
+            votes: votes
+                .into_iter()
+                .map(|v| VoteResults::new(v, cc, language))
+                .collect(),
+            elections,
+        }
+    }
+}
+
+impl ElectionResultsSummary {
+    /// Create the summary of the results
+    ///
+    /// Inputs:
+    /// - `ech_0222`: The eCH-0222 data, e.g. calculated with [ECH0222Data::create_ech0222_data] from the decoded votes
+    /// - `configuration`: The election event configuration, to get the texts
+    /// - `language`: The language of the texts (e.g. `de`). If a text is not available in the language, the first
+    ///   language is taken
+    pub fn new(
+        ech_0222: &ECH0222Data,
+        configuration: &ElectionEventConfigurationData,
+        language: &str,
+    ) -> Self {
+        let mut counting_circles = ech_0222
+            .raw_data
+            .counting_circle_raw_data
+            .values()
+            .map(|cc| CountingCircleResults::new(cc, configuration, language))
+            .collect::<Vec<_>>();
+        counting_circles.sort_by(|cc1, cc2| cc1.counting_circle_id.cmp(&cc2.counting_circle_id));
+        Self {
+            contest_identification: ech_0222.raw_data.contest_identification.clone(),
+            counting_circles,
+        }
+    }
+
+    /// Create the summary of the results from the tally verification directory
+    ///
+    /// The eCH-0222 is calculated from the decoded votes of the ballot boxes
+    /// (see [ECH0222Data::create_ech0222_data])
+    pub fn from_verification_directory<D: VerificationDirectoryTrait>(
+        directory: &D,
+        language: &str,
+    ) -> Result<Self, ECH0222CalculatedError> {
+        let context_dir = directory.context();
+        let configuration = context_dir
+            .election_event_configuration()
+            .map_err(|e| ECH0222CalculatedErrorImpl::ContextMissing {
+                source: Box::new(e),
+            })?
+            .get_data()
+            .map_err(|e| ECH0222CalculatedErrorImpl::ConfigurationData {
+                source: Box::new(e),
+            })?;
+        let context = context_dir.election_event_context_payload().map_err(|e| {
+            ECH0222CalculatedErrorImpl::ContextMissing {
+                source: Box::new(e),
+            }
+        })?;
+        let ech_0222 = ECH0222Data::create_ech0222_data(
+            &context.election_event_context,
+            configuration.as_ref(),
+            directory.unwrap_tally().bb_directories(),
+        )?;
+        Ok(Self::new(&ech_0222, configuration.as_ref(), language))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::test::get_test_verifier_tally_dir;
+
+    #[test]
+    fn test_summary() {
+        let summary = ElectionResultsSummary::from_verification_directory(
+            &get_test_verifier_tally_dir(),
+            "de",
+        )
+        .unwrap();
+        assert!(!summary.counting_circles.is_empty());
+        for cc in summary.counting_circles.iter() {
+            for v in cc.votes.iter() {
+                for q in v.questions.iter() {
+                    assert!(!q.question.is_empty());
+                    assert!(
+                        q.answers.iter().map(|a| a.count).sum::<usize>() <= cc.valid_voting_cards
+                    );
+                }
+            }
+            for e in cc.elections.iter() {
+                assert!(
+                    e.ballots_without_list + e.lists.iter().map(|l| l.count).sum::<usize>()
+                        == e.number_of_ballots
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_text_in_language() {
+        let texts = [("de", "Ja"), ("fr", "Oui")];
+        assert_eq!(text_in_language(texts.iter().copied(), "fr"), "Oui");
+        assert_eq!(text_in_language(texts.iter().copied(), "en"), "Ja");
+        assert_eq!(text_in_language([].iter().copied(), "en"), "");
+    }
+}
@@ -135,11 +135,14 @@ impl<'a> VerifiyJSONSignatureTrait<'a> for TallyComponentShufflePayload {
 }
 
 impl<'a> VerifiySignatureTrait<'a> for TallyComponentShufflePayload {
-    fn verifiy_signature(
+    fn verifiy_signature_with_certificate(
         &'a self,
         keystore: &crate::direct_trust::Keystore,
-    ) -> Result<bool, crate::direct_trust::VerifySignatureError> {
-        self.verifiy_json_signature(keystore)
+    ) -> Result<
+        Option<crate::direct_trust::TrustedCertificate>,
+        crate::direct_trust::VerifySignatureError,
+    > {
+        self.verifiy_json_signature_with_certificate(keystore)
     }
 }
 
@@ -14,7 +14,8 @@
 // a copy of the GNU General Public License along with this program. If not, see
 // <https://www.gnu.org/licenses/>.
 
-use crate::data_structures::DataStructureError;
+use crate::{data_structures::DataStructureError, ErrorChain, Report};
+use chrono::{Local, NaiveDate};
 use rust_ev_system_library::{
     chanel_security::xml::{verify_xml_signature, XMLSignatureError},
     rust_ev_crypto_primitives::prelude::{
@@ -24,18 +25,88 @@ use rust_ev_system_library::{
             Keystore as BasisKeystore,
         },
         signature::{sign, verify_signature, SignatureError},
-        ByteArray, HashableMessage,
+        ByteArray, EncodeTrait, HashableMessage,
     },
 };
+use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
+    fmt::Display,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{Arc, OnceLock, RwLock},
 };
 use strum::{AsRefStr, EnumIter, EnumString, IntoEnumIterator};
 use thiserror::Error;
 
-pub struct Keystore(pub(crate) BasisKeystore);
+/// Name of the optional file in a keystore directory containing the validity window
+const VALIDITY_FILE_NAME: &str = "validity.json";
+/// Format of the dates in the validity file
+const VALIDITY_DATE_FORMAT: &str = "%Y-%m-%d";
+/// Name of the keystore, when it is not created from a directory
+const PRIMARY_KEYSTORE_NAME: &str = "primary";
+
+/// Keystore containing the trusted certificates
+///
+/// The keystore contains at least one keystore, the primary keystore (the keystore of the direct trust directory).
+/// During a rollover of the certificates, additional keystores can be added with [Keystore::add_trusted_keystores].
+/// A signature is accepted if it can be verified with the certificate of one of the trusted keystores
+/// that are valid at the reference date (see [Keystore::set_reference_date]).
+///
+/// The clones share the trusted keystores, so that a keystore read once can be used with
+/// many reference dates (e.g. for the setup and the tally).
+#[derive(Clone)]
+pub struct Keystore {
+    trusted_keystores: Vec<Arc<TrustedKeystore>>,
+    identifier: OnceLock<String>,
+    reference_date: Option<NaiveDate>,
+}
+
+/// A keystore with its name and its optional validity window
+struct TrustedKeystore {
+    name: String,
+    keystore: BasisKeystore,
+    validity: Option<ValidityWindow>,
+}
+
+/// Window of dates, in which a keystore is trusted
+///
+/// The bounds are inclusive. A missing bound means that the window is open on this side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ValidityWindow {
+    not_before: Option<NaiveDate>,
+    not_after: Option<NaiveDate>,
+}
+
+/// Content of the validity file
+#[derive(Deserialize, Debug)]
+struct ValidityFile {
+    not_before: Option<String>,
+    not_after: Option<String>,
+}
+
+/// Cache of the results of the signature verifications
+///
+/// The results are stored with the fingerprint of the file containing the signed object, the identifier
+/// of the keystore used (see [Keystore::identifier]) and the reference date of the keystore (see [Keystore::reference_date]).
+/// Then the results can be reused by other verifications or by a next run, as long as the file, the trusted
+/// certificates and the reference date are unchanged.
+///
+/// Only the results without error are stored.
+#[derive(Default)]
+pub struct SignatureVerificationCache {
+    entries: RwLock<HashMap<SignatureCacheKey, Vec<Option<TrustedCertificate>>>>,
+}
+
+/// Key of the cache: identifier of the keystore, reference date and fingerprint of the file
+type SignatureCacheKey = (String, NaiveDate, String);
+
+/// Trusted certificate, with which a signature has been successfully verified
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustedCertificate {
+    authority: CertificateAuthority,
+    keystore_name: String,
+    fingerprint: ByteArray,
+}
 
 #[derive(Error, Debug)]
 #[error(transparent)]
@@ -69,6 +140,24 @@ enum DirectTrustErrorImpl {
         ca: String,
         source: BasisCryptoError,
     },
+    #[error("Error reading the validity file {path}")]
+    ReadValidity {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Error parsing the validity file {path}")]
+    ParseValidity {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("Error getting the secret key certificate of the primary keystore")]
+    SecretKeyCertificate { source: Box<BasisDirectTrustError> },
+    #[error("Error parsing the date {value} in the validity file {path}")]
+    ParseValidityDate {
+        path: PathBuf,
+        value: String,
+        source: chrono::ParseError,
+    },
 }
 
 #[derive(Error, Debug)]
@@ -117,6 +206,90 @@ enum VerifySignatureErrorImpl {
         ca: String,
         source: Box<DirectTrustError>,
     },
+    #[error("No trusted certificate valid on {date} found for {ca}")]
+    NoTrustedCertificate { ca: String, date: NaiveDate },
+}
+
+/// Cause of a failed verification of a signature
+///
+/// The cause allows to distinguish between a tampered object (e.g. [SignatureFailureCause::CryptographicMismatch])
+/// and a misconfiguration of the keystore (e.g. [SignatureFailureCause::UnknownCertificate])
+#[derive(Debug, Clone, PartialEq, Eq, strum::Display, Serialize, Deserialize)]
+pub enum SignatureFailureCause {
+    /// The object contains no signature
+    #[strum(to_string = "Missing signature")]
+    MissingSignature,
+    /// The certificate authority is unknown or the certificate is not in the keystore
+    #[strum(to_string = "Unknown certificate authority or certificate not in keystore")]
+    UnknownCertificate,
+    /// The hashable message cannot be constructed. Contains the report of the [DataStructureError]
+    #[strum(to_string = "Construction of the hashable message failed: {0}")]
+    HashableMessage(String),
+    /// The signature does not match the object
+    #[strum(to_string = "Cryptographic mismatch of the signature")]
+    CryptographicMismatch,
+    /// The xml cannot be processed (parsing or canonicalization)
+    #[strum(to_string = "Error canonicalizing the xml")]
+    XMLCanonicalization,
+}
+
+impl SignatureFailureCause {
+    /// `true` if the cause points to a problem of the keystore and not to the object
+    pub fn is_keystore_issue(&self) -> bool {
+        matches!(self, SignatureFailureCause::UnknownCertificate)
+    }
+}
+
+impl VerifySignatureError {
+    /// Classify the error according to [SignatureFailureCause]
+    pub fn cause(&self) -> SignatureFailureCause {
+        match &self.0 {
+            VerifySignatureErrorImpl::NoCA
+            | VerifySignatureErrorImpl::DirectTrust { .. }
+            | VerifySignatureErrorImpl::NoTrustedCertificate { .. } => {
+                SignatureFailureCause::UnknownCertificate
+            }
+            VerifySignatureErrorImpl::SignatureNotFound => SignatureFailureCause::MissingSignature,
+            VerifySignatureErrorImpl::XMLAlreadyParsed => {
+                SignatureFailureCause::XMLCanonicalization
+            }
+            VerifySignatureErrorImpl::SignatureError { .. } => {
+                SignatureFailureCause::CryptographicMismatch
+            }
+            VerifySignatureErrorImpl::XMLSignatureError { source, .. } => {
+                xml_signature_error_cause(source)
+            }
+            VerifySignatureErrorImpl::GetHashable { source, .. } => {
+                SignatureFailureCause::HashableMessage(Report::new(source.as_ref()).to_string())
+            }
+        }
+    }
+}
+
+/// Message of the xml signature error when the signature is missing (rust_ev_system_library 0.3.2)
+const XML_ERROR_NO_SIGNATURE: &str = "No Signature found";
+/// Messages of the xml signature error when the cryptographic verification fails (rust_ev_system_library 0.3.2)
+const XML_ERROR_CRYPTO: [&str; 2] = ["Error verfiying the signature", "Error calculating digest"];
+
+/// Classify the [XMLSignatureError]
+///
+/// The variants of the error are not exposed by rust_ev_system_library. The cause is found using the messages
+/// of the error chain, which are pinned by the tests to the version 0.3.2. If the messages change with an
+/// upgrade, the tests fail
+fn xml_signature_error_cause(error: &XMLSignatureError) -> SignatureFailureCause {
+    let messages = ErrorChain::new(error)
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>();
+    if messages.iter().any(|m| m == XML_ERROR_NO_SIGNATURE) {
+        return SignatureFailureCause::MissingSignature;
+    }
+    if messages
+        .iter()
+        .any(|m| XML_ERROR_CRYPTO.contains(&m.as_str()))
+    {
+        return SignatureFailureCause::CryptographicMismatch;
+    }
+    SignatureFailureCause::XMLCanonicalization
 }
 
 /// List of valide Certificate authorities
@@ -198,10 +371,113 @@ fn find_unique_file_with_extension(
     }
 }
 
-impl TryFrom<&Path> for Keystore {
-    type Error = DirectTrustError;
+impl ValidityWindow {
+    /// New validity window
+    pub fn new(not_before: Option<NaiveDate>, not_after: Option<NaiveDate>) -> Self {
+        Self {
+            not_before,
+            not_after,
+        }
+    }
 
-    fn try_from(value: &Path) -> Result<Self, Self::Error> {
+    /// First day of validity
+    pub fn not_before(&self) -> Option<&NaiveDate> {
+        self.not_before.as_ref()
+    }
+
+    /// Last day of validity
+    pub fn not_after(&self) -> Option<&NaiveDate> {
+        self.not_after.as_ref()
+    }
+
+    /// `true` if the date is in the window
+    pub fn contains(&self, date: &NaiveDate) -> bool {
+        self.not_before.is_none_or(|d| &d <= date) && self.not_after.is_none_or(|d| date <= &d)
+    }
+
+    /// Read the validity window from the file [VALIDITY_FILE_NAME] in the directory
+    ///
+    /// Return `None` if the file does not exist
+    fn from_dir(dir: &Path) -> Result<Option<Self>, DirectTrustErrorImpl> {
+        let path = dir.join(VALIDITY_FILE_NAME);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let s = std::fs::read_to_string(&path).map_err(|e| DirectTrustErrorImpl::ReadValidity {
+            path: path.clone(),
+            source: e,
+        })?;
+        let file: ValidityFile =
+            serde_json::from_str(&s).map_err(|e| DirectTrustErrorImpl::ParseValidity {
+                path: path.clone(),
+                source: e,
+            })?;
+        let parse_date = |value: Option<String>| {
+            value
+                .map(|v| {
+                    NaiveDate::parse_from_str(&v, VALIDITY_DATE_FORMAT).map_err(|e| {
+                        DirectTrustErrorImpl::ParseValidityDate {
+                            path: path.clone(),
+                            value: v.clone(),
+                            source: e,
+                        }
+                    })
+                })
+                .transpose()
+        };
+        Ok(Some(Self::new(
+            parse_date(file.not_before)?,
+            parse_date(file.not_after)?,
+        )))
+    }
+}
+
+impl Display for ValidityWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fmt_date = |d: Option<&NaiveDate>| {
+            d.map(|d| d.format(VALIDITY_DATE_FORMAT).to_string())
+                .unwrap_or("-".to_string())
+        };
+        write!(
+            f,
+            "{} - {}",
+            fmt_date(self.not_before()),
+            fmt_date(self.not_after())
+        )
+    }
+}
+
+impl TrustedCertificate {
+    /// Certificate authority of the certificate
+    pub fn authority(&self) -> CertificateAuthority {
+        self.authority
+    }
+
+    /// Name of the keystore containing the certificate
+    pub fn keystore_name(&self) -> &str {
+        &self.keystore_name
+    }
+
+    /// Fingerprint of the certificate
+    pub fn fingerprint(&self) -> &ByteArray {
+        &self.fingerprint
+    }
+}
+
+impl Display for TrustedCertificate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of keystore {} (fingerprint: {})",
+            self.authority.as_ref(),
+            self.keystore_name,
+            self.fingerprint.base16_encode().unwrap_or_default()
+        )
+    }
+}
+
+impl TrustedKeystore {
+    fn try_from_dir(value: &Path) -> Result<Self, DirectTrustErrorImpl> {
         let keystore_path = find_unique_file_with_extension(value, "p12").map_err(|e| {
             DirectTrustErrorImpl::FindUniqueFile {
                 extension: "p12",
@@ -214,36 +490,42 @@ impl TryFrom<&Path> for Keystore {
                 source: e,
             }
         })?;
-        Ok(Keystore(
-            BasisKeystore::from_pkcs12(&keystore_path, &password_path).map_err(|e| {
-                DirectTrustErrorImpl::Keystore {
-                    path: keystore_path.clone(),
-                    source: Box::new(e),
-                }
-            })?,
-        ))
+        let keystore = BasisKeystore::from_pkcs12(&keystore_path, &password_path).map_err(|e| {
+            DirectTrustErrorImpl::Keystore {
+                path: keystore_path.clone(),
+                source: Box::new(e),
+            }
+        })?;
+        Ok(Self {
+            name: value
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or(PRIMARY_KEYSTORE_NAME.to_string()),
+            keystore,
+            validity: ValidityWindow::from_dir(value)?,
+        })
     }
-}
 
-impl Keystore {
-    pub fn fingerprints(
-        &self,
-    ) -> Result<HashMap<CertificateAuthority, ByteArray>, DirectTrustError> {
-        let mut res = HashMap::new();
-        for ca in CertificateAuthority::iter() {
-            res.insert(ca, self.fingerprint(ca)?);
-        }
-        Ok(res)
+    /// `true` if the keystore is valid at the given date
+    fn is_valid_at(&self, date: &NaiveDate) -> bool {
+        self.validity.is_none_or(|w| w.contains(date))
     }
 
-    pub fn fingerprint(&self, ca: CertificateAuthority) -> Result<ByteArray, DirectTrustError> {
-        self.0
+    fn public_certificate(
+        &self,
+        ca: CertificateAuthority,
+    ) -> Result<DirectTrustCertificate, DirectTrustError> {
+        self.keystore
             .public_certificate(ca.as_ref())
             .map_err(|e| DirectTrustErrorImpl::PublicCertificate {
                 ca: ca.as_ref().to_string(),
                 source: Box::new(e),
             })
-            .map_err(DirectTrustError::from)?
+            .map_err(DirectTrustError)
+    }
+
+    fn fingerprint(&self, ca: CertificateAuthority) -> Result<ByteArray, DirectTrustError> {
+        self.public_certificate(ca)?
             .signing_certificate()
             .digest()
             .map_err(|e| DirectTrustErrorImpl::FingerPrint {
@@ -253,28 +535,400 @@ impl Keystore {
             .map_err(DirectTrustError::from)
     }
 
-    pub fn public_certificate(
+    fn public_key(&self, ca: CertificateAuthority) -> Result<PublicKey, DirectTrustError> {
+        self.public_certificate(ca)?
+            .signing_certificate()
+            .public_key()
+            .map_err(|e| DirectTrustErrorImpl::PublicKey {
+                ca: ca.as_ref().to_string(),
+                source: e,
+            })
+            .map_err(DirectTrustError)
+    }
+
+    fn trusted_certificate(
         &self,
         ca: CertificateAuthority,
-    ) -> Result<DirectTrustCertificate, DirectTrustError> {
-        self.0
-            .public_certificate(ca.as_ref())
-            .map_err(|e| DirectTrustErrorImpl::PublicCertificate {
-                ca: ca.as_ref().to_string(),
+    ) -> Result<TrustedCertificate, DirectTrustError> {
+        Ok(TrustedCertificate {
+            authority: ca,
+            keystore_name: self.name.clone(),
+            fingerprint: self.fingerprint(ca)?,
+        })
+    }
+}
+
+impl TryFrom<&Path> for Keystore {
+    type Error = DirectTrustError;
+
+    /// Read the keystore from the directory
+    ///
+    /// The directory must contain a unique `.p12` file and a unique `.txt` file containing the password.
+    /// It can contain the file `validity.json` with the validity window of the keystore in the form
+    /// `{"not_before": "YYYY-MM-DD", "not_after": "YYYY-MM-DD"}` (both fields are optional).
+    /// The name of the keystore is the name of the directory.
+    fn try_from(value: &Path) -> Result<Self, Self::Error> {
+        Ok(Self {
+            trusted_keystores: vec![Arc::new(TrustedKeystore::try_from_dir(value)?)],
+            identifier: OnceLock::new(),
+            reference_date: None,
+        })
+    }
+}
+
+impl std::fmt::Debug for Keystore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Keystore")
+            .field("keystore_names", &self.keystore_names())
+            .finish()
+    }
+}
+
+impl From<BasisKeystore> for Keystore {
+    fn from(value: BasisKeystore) -> Self {
+        Self {
+            trusted_keystores: vec![Arc::new(TrustedKeystore {
+                name: PRIMARY_KEYSTORE_NAME.to_string(),
+                keystore: value,
+                validity: None,
+            })],
+            identifier: OnceLock::new(),
+            reference_date: None,
+        }
+    }
+}
+
+impl Keystore {
+    /// Add the trusted keystores of `other` after the keystores of `self`
+    pub fn add_trusted_keystores(&mut self, other: Keystore) {
+        self.trusted_keystores.extend(other.trusted_keystores);
+        self.identifier = OnceLock::new();
+    }
+
+    /// Set the date at which the validity windows of the trusted keystores are checked
+    ///
+    /// The date should be the date of the signed data (e.g. a date of the election event),
+    /// so that the result of a verification does not depend on the day the verifier runs
+    pub fn set_reference_date(&mut self, date: NaiveDate) {
+        self.reference_date = Some(date);
+    }
+
+    /// Date at which the validity windows of the trusted keystores are checked
+    ///
+    /// If no reference date is set, the current date is used
+    pub fn reference_date(&self) -> NaiveDate {
+        self.reference_date
+            .unwrap_or_else(|| Local::now().date_naive())
+    }
+
+    /// Identifier of the keystore
+    ///
+    /// The identifier is built from the names, the validity windows and the fingerprints of the certificates
+    /// of all the trusted keystores. Two keystores with the same identifier give the same results verifying
+    /// the signatures.
+    pub fn identifier(&self) -> &str {
+        self.identifier.get_or_init(|| {
+            self.trusted_keystores
+                .iter()
+                .map(|ks| {
+                    let fingerprints = CertificateAuthority::iter()
+                        .map(|ca| {
+                            format!(
+                                "{}={}",
+                                ca.as_ref(),
+                                ks.fingerprint(ca)
+                                    .ok()
+                                    .and_then(|f| f.base16_encode().ok())
+                                    .unwrap_or_default()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",");
+                    format!(
+                        "{}[{}]({})",
+                        ks.name,
+                        ks.validity.map(|v| v.to_string()).unwrap_or_default(),
+                        fingerprints
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(";")
+        })
+    }
+
+    /// Names of the trusted keystores. The first one is the primary keystore
+    pub fn keystore_names(&self) -> Vec<&str> {
+        self.trusted_keystores
+            .iter()
+            .map(|ks| ks.name.as_str())
+            .collect()
+    }
+
+    /// Authority of the certificate of the secret key of the primary keystore
+    ///
+    /// Return an error if the primary keystore contains no secret key
+    pub fn signing_authority(&self) -> Result<String, DirectTrustError> {
+        self.primary()
+            .keystore
+            .secret_key_certificate()
+            .map(|c| c.authority().to_string())
+            .map_err(|e| DirectTrustErrorImpl::SecretKeyCertificate {
                 source: Box::new(e),
             })
-            .map_err(DirectTrustError)
+            .map_err(DirectTrustError::from)
+    }
+
+    /// Sign the message with the secret key of the primary keystore
+    ///
+    /// The signature is calculated according to the specifications of Swiss Post, with the given context
+    /// (e.g. to sign a file created by the verifier)
+    pub fn sign_message(
+        &self,
+        message: &HashableMessage,
+        context: &HashableMessage,
+    ) -> Result<ByteArray, VerifySignatureError> {
+        sign(&self.primary().keystore, message, context)
+            .map_err(|e| VerifySignatureErrorImpl::SignatureError {
+                msg: "Error signing".to_string(),
+                source: Box::new(e),
+            })
+            .map_err(VerifySignatureError::from)
+    }
+
+    /// Verify the signature of the message with the certificate of the given authority
+    ///
+    /// The authority is not limited to [CertificateAuthority] (e.g. the certificate of the verifier).
+    /// The authority must be fixed by the caller and not read from the signed data, since the signature
+    /// of any authority of the keystore would be accepted.
+    /// The signature is verified with each trusted keystore valid at the reference date containing the authority.
+    /// Return `true` if one of them matches
+    pub fn verify_message_signature(
+        &self,
+        authority: &str,
+        message: &HashableMessage,
+        context: &HashableMessage,
+        signature: &ByteArray,
+    ) -> Result<bool, VerifySignatureError> {
+        let reference_date = self.reference_date();
+        let candidates = self
+            .trusted_keystores
+            .iter()
+            .filter(|ks| {
+                ks.is_valid_at(&reference_date) && ks.keystore.public_certificate(authority).is_ok()
+            })
+            .map(|ks| ks.as_ref())
+            .collect::<Vec<_>>();
+        if candidates.is_empty() {
+            return Err(VerifySignatureError::from(
+                VerifySignatureErrorImpl::NoTrustedCertificate {
+                    ca: authority.to_string(),
+                    date: reference_date,
+                },
+            ));
+        }
+        first_matching_candidate(candidates, |ks| {
+            verify_signature(&ks.keystore, authority, message, context, signature).map_err(|e| {
+                VerifySignatureErrorImpl::SignatureError {
+                    msg: "Error verifying the signature".to_string(),
+                    source: Box::new(e),
+                }
+            })
+        })
+        .map(|ks| ks.is_some())
+    }
+
+    /// The primary keystore
+    fn primary(&self) -> &TrustedKeystore {
+        // The vector contains always at least one keystore
+        &self.trusted_keystores[0]
+    }
+
+    /// The keystores that are valid at the reference date and contain a certificate for the certificate authority
+    fn candidates(&self, ca: CertificateAuthority) -> Vec<&TrustedKeystore> {
+        let reference_date = self.reference_date();
+        self.trusted_keystores
+            .iter()
+            .filter(|ks| ks.is_valid_at(&reference_date) && ks.public_certificate(ca).is_ok())
+            .map(|ks| ks.as_ref())
+            .collect()
+    }
+
+    /// Fingerprints of the certificates of the primary keystore
+    pub fn fingerprints(
+        &self,
+    ) -> Result<HashMap<CertificateAuthority, ByteArray>, DirectTrustError> {
+        let mut res = HashMap::new();
+        for ca in CertificateAuthority::iter() {
+            res.insert(ca, self.fingerprint(ca)?);
+        }
+        Ok(res)
+    }
+
+    /// Fingerprints of the certificates of the additional keystores
+    ///
+    /// Only the certificates found in the additional keystores are returned, with the
+    /// validity window of the keystore
+    pub fn additional_fingerprints(&self) -> Vec<(TrustedCertificate, Option<ValidityWindow>)> {
+        self.trusted_keystores
+            .iter()
+            .skip(1)
+            .flat_map(|ks| {
+                CertificateAuthority::iter()
+                    .filter_map(|ca| ks.trusted_certificate(ca).ok())
+                    .map(|c| (c, ks.validity))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Fingerprint of the certificate of the primary keystore
+    pub fn fingerprint(&self, ca: CertificateAuthority) -> Result<ByteArray, DirectTrustError> {
+        self.primary().fingerprint(ca)
     }
 
+    /// Public certificate of the primary keystore
+    pub fn public_certificate(
+        &self,
+        ca: CertificateAuthority,
+    ) -> Result<DirectTrustCertificate, DirectTrustError> {
+        self.primary().public_certificate(ca)
+    }
+
+    /// Public key of the certificate of the primary keystore
     pub fn public_key(&self, ca: CertificateAuthority) -> Result<PublicKey, DirectTrustError> {
-        self.public_certificate(ca)?
-            .signing_certificate()
-            .public_key()
-            .map_err(|e| DirectTrustErrorImpl::PublicKey {
+        self.primary().public_key(ca)
+    }
+}
+
+/// Trusted certificate of the matching keystore for the certificate authority
+fn matching_certificate(
+    matching: Option<&TrustedKeystore>,
+    ca: CertificateAuthority,
+) -> Result<Option<TrustedCertificate>, VerifySignatureError> {
+    matching
+        .map(|ks| ks.trusted_certificate(ca))
+        .transpose()
+        .map_err(|e| {
+            VerifySignatureError::from(VerifySignatureErrorImpl::DirectTrust {
                 ca: ca.as_ref().to_string(),
-                source: e,
+                source: Box::new(e),
             })
-            .map_err(DirectTrustError)
+        })
+}
+
+/// Verify the signature with each candidate, until one matches
+///
+/// Return the first matching keystore. Return `None` if no candidate matches and at least one
+/// candidate gives a mismatch. Return the last error if all the candidates give an error
+fn first_matching_candidate(
+    candidates: Vec<&TrustedKeystore>,
+    verify: impl Fn(&TrustedKeystore) -> Result<bool, VerifySignatureErrorImpl>,
+) -> Result<Option<&TrustedKeystore>, VerifySignatureError> {
+    let mut has_mismatch = false;
+    let mut last_error = None;
+    for ks in candidates {
+        match verify(ks) {
+            Ok(true) => return Ok(Some(ks)),
+            Ok(false) => has_mismatch = true,
+            Err(e) => last_error = Some(e),
+        }
+    }
+    match (has_mismatch, last_error) {
+        (false, Some(e)) => Err(VerifySignatureError::from(e)),
+        _ => Ok(None),
+    }
+}
+
+impl SignatureVerificationCache {
+    /// New empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the cached results for the file with the given fingerprint
+    ///
+    /// Return `None` if the results are not in the cache
+    pub fn get(
+        &self,
+        keystore: &Keystore,
+        file_fingerprint: &str,
+    ) -> Option<Vec<Option<TrustedCertificate>>> {
+        self.entries
+            .read()
+            .unwrap()
+            .get(&Self::key(keystore, file_fingerprint))
+            .cloned()
+    }
+
+    /// Is the file with the given fingerprint authentic (all the signatures verified)
+    ///
+    /// Return `None` if the results are not in the cache
+    pub fn is_authentic(&self, keystore: &Keystore, file_fingerprint: &str) -> Option<bool> {
+        self.get(keystore, file_fingerprint)
+            .map(|res| res.iter().all(|c| c.is_some()))
+    }
+
+    /// Store the results for the file with the given fingerprint
+    pub fn insert(
+        &self,
+        keystore: &Keystore,
+        file_fingerprint: &str,
+        results: Vec<Option<TrustedCertificate>>,
+    ) {
+        self.entries
+            .write()
+            .unwrap()
+            .insert(Self::key(keystore, file_fingerprint), results);
+    }
+
+    fn key(keystore: &Keystore, file_fingerprint: &str) -> SignatureCacheKey {
+        (
+            keystore.identifier().to_string(),
+            keystore.reference_date(),
+            file_fingerprint.to_string(),
+        )
+    }
+
+    /// Number of the cached files
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap().len()
+    }
+
+    /// Is the cache empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all the entries of the cache
+    pub fn clear(&self) {
+        self.entries.write().unwrap().clear()
+    }
+
+    /// Verify the signatures of the object, using the cache
+    ///
+    /// If the results for the file are in the cache, they are returned without verification. Else the signatures are verified
+    /// and the results are stored, if no error occured.
+    pub fn verify_signatures_with_certificate<'a, T>(
+        &self,
+        obj: &'a T,
+        keystore: &Keystore,
+        file_fingerprint: &str,
+    ) -> Vec<Result<Option<TrustedCertificate>, VerifySignatureError>>
+    where
+        T: VerifiySignatureTrait<'a>,
+    {
+        if let Some(res) = self.get(keystore, file_fingerprint) {
+            return res.into_iter().map(Ok).collect();
+        }
+        let res = obj.verify_signatures_with_certificate(keystore);
+        if res.iter().all(|r| r.is_ok()) {
+            self.insert(
+                keystore,
+                file_fingerprint,
+                res.iter().map(|r| r.as_ref().unwrap().clone()).collect(),
+            );
+        }
+        res
     }
 }
 
@@ -284,14 +938,38 @@ where
     Self: 'a,
 {
     /// Verfiy the signature according to the specifications of Verifier
-    fn verifiy_signature(&'a self, keystore: &Keystore) -> Result<bool, VerifySignatureError>;
+    ///
+    /// Return the trusted certificate that matched the signature, or `None` if the signature is wrong
+    fn verifiy_signature_with_certificate(
+        &'a self,
+        keystore: &Keystore,
+    ) -> Result<Option<TrustedCertificate>, VerifySignatureError>;
 
-    /// Verify signatures of an array element
+    /// Verfiy the signature according to the specifications of Verifier
+    fn verifiy_signature(&'a self, keystore: &Keystore) -> Result<bool, VerifySignatureError> {
+        self.verifiy_signature_with_certificate(keystore)
+            .map(|c| c.is_some())
+    }
+
+    /// Verify signatures of an array element, returning the matched certificates
     ///
     /// Per default return an array of one element containing the result of the element verified
     /// The method must be rewritten for a array of elements
+    fn verify_signatures_with_certificate(
+        &'a self,
+        keystore: &Keystore,
+    ) -> Vec<Result<Option<TrustedCertificate>, VerifySignatureError>> {
+        vec![self.verifiy_signature_with_certificate(keystore)]
+    }
+
+    /// Verify signatures of an array element
+    ///
+    /// See [VerifiySignatureTrait::verify_signatures_with_certificate]
     fn verify_signatures(&'a self, keystore: &Keystore) -> Vec<Result<bool, VerifySignatureError>> {
-        vec![self.verifiy_signature(keystore)]
+        self.verify_signatures_with_certificate(keystore)
+            .into_iter()
+            .map(|r| r.map(|c| c.is_some()))
+            .collect()
     }
 }
 
@@ -328,36 +1006,62 @@ where
 
     /// Verfiy the signature according to the specifications of Verifier
     fn verifiy_json_signature(&'a self, keystore: &Keystore) -> Result<bool, VerifySignatureError> {
+        self.verifiy_json_signature_with_certificate(keystore)
+            .map(|c| c.is_some())
+    }
+
+    /// Verfiy the signature according to the specifications of Verifier
+    ///
+    /// The signature is verified with each trusted keystore valid at the reference date and containing
+    /// the certificate authority. Return the first certificate that matches, or `None` if no certificate matches.
+    /// An error is returned only if the verification fails with an error for all the keystores.
+    fn verifiy_json_signature_with_certificate(
+        &'a self,
+        keystore: &Keystore,
+    ) -> Result<Option<TrustedCertificate>, VerifySignatureError> {
         let ca = match self.get_certificate_authority() {
             Some(ca) => ca,
             None => return Err(VerifySignatureError::from(VerifySignatureErrorImpl::NoCA)),
         };
+        // Check the certificates before, to distinguish a missing certificate from a wrong signature
+        let candidates = keystore.candidates(ca);
+        if candidates.is_empty() {
+            return Err(VerifySignatureError::from(
+                VerifySignatureErrorImpl::NoTrustedCertificate {
+                    ca: ca.as_ref().to_string(),
+                    date: keystore.reference_date(),
+                },
+            ));
+        }
         let hashable_message =
             self.get_hashable()
                 .map_err(|e| VerifySignatureErrorImpl::GetHashable {
                     function: "verify_signature",
                     source: Box::new(e),
                 })?;
-        verify_signature(
-            &keystore.0,
-            ca.as_ref(),
-            //HashableMessage::from(&d),
-            &hashable_message,
-            &self.get_context_hashable(),
-            &self.get_signature().ok_or(VerifySignatureError::from(
-                VerifySignatureErrorImpl::SignatureNotFound,
-            ))?,
-        )
-        .map_err(|e| VerifySignatureErrorImpl::SignatureError {
-            msg: "Error verifying the signature".to_string(),
-            source: Box::new(e),
-        })
-        .map_err(VerifySignatureError::from)
+        let signature = self.get_signature().ok_or(VerifySignatureError::from(
+            VerifySignatureErrorImpl::SignatureNotFound,
+        ))?;
+        let context = self.get_context_hashable();
+        let matching = first_matching_candidate(candidates, |ks| {
+            verify_signature(
+                &ks.keystore,
+                ca.as_ref(),
+                &hashable_message,
+                &context,
+                &signature,
+            )
+            .map_err(|e| VerifySignatureErrorImpl::SignatureError {
+                msg: "Error verifying the signature".to_string(),
+                source: Box::new(e),
+            })
+        })?;
+        matching_certificate(matching, ca)
     }
 
     /// Sign according to the specifications of Verifier
     ///
-    /// Can be usefull to resign the payload after mocking it
+    /// Can be usefull to resign the payload after mocking it. The primary keystore is used.
     fn sign(&'a self, keystore: &Keystore) -> Result<ByteArray, VerifySignatureError> {
         let hashable_message =
             self.get_hashable()
@@ -365,12 +1069,16 @@ where
                     function: "sign",
                     source: Box::new(e),
                 })?;
-        sign(&keystore.0, &hashable_message, &self.get_context_hashable())
-            .map_err(|e| VerifySignatureErrorImpl::SignatureError {
-                msg: "Error signing".to_string(),
-                source: Box::new(e),
-            })
-            .map_err(VerifySignatureError::from)
+        sign(
+            &keystore.primary().keystore,
+            &hashable_message,
+            &self.get_context_hashable(),
+        )
+        .map_err(|e| VerifySignatureErrorImpl::SignatureError {
+            msg: "Error signing".to_string(),
+            source: Box::new(e),
+        })
+        .map_err(VerifySignatureError::from)
     }
 }
 
@@ -391,28 +1099,50 @@ where
 
     /// Verfiy the signature according to the specifications of Verifier
     fn verifiy_xml_signature(&'a self, keystore: &Keystore) -> Result<bool, VerifySignatureError> {
+        self.verifiy_xml_signature_with_certificate(keystore)
+            .map(|c| c.is_some())
+    }
+
+    /// Verfiy the signature according to the specifications of Verifier
+    ///
+    /// The signature is verified with each trusted keystore valid at the reference date and containing
+    /// the certificate authority. Return the first certificate that matches, or `None` if no certificate matches.
+    /// An error is returned only if the verification fails with an error for all the keystores.
+    fn verifiy_xml_signature_with_certificate(
+        &'a self,
+        keystore: &Keystore,
+    ) -> Result<Option<TrustedCertificate>, VerifySignatureError> {
         let ca = match self.get_certificate_authority() {
             Some(ca) => ca,
             None => return Err(VerifySignatureError::from(VerifySignatureErrorImpl::NoCA)),
         };
-        let public_key =
-            keystore
-                .public_key(ca)
-                .map_err(|e| VerifySignatureErrorImpl::DirectTrust {
+        let candidates = keystore.candidates(ca);
+        if candidates.is_empty() {
+            return Err(VerifySignatureError::from(
+                VerifySignatureErrorImpl::NoTrustedCertificate {
                     ca: ca.as_ref().to_string(),
+                    date: keystore.reference_date(),
+                },
+            ));
+        }
+        let data = self
+            .get_data_str()
+            .ok_or(VerifySignatureErrorImpl::XMLAlreadyParsed)?;
+        let matching = first_matching_candidate(candidates, |ks| {
+            let public_key =
+                ks.public_key(ca)
+                    .map_err(|e| VerifySignatureErrorImpl::DirectTrust {
+                        ca: ca.as_ref().to_string(),
+                        source: Box::new(e),
+                    })?;
+            verify_xml_signature(data.as_str(), &public_key)
+                .map(|r| r.is_ok())
+                .map_err(|e| VerifySignatureErrorImpl::XMLSignatureError {
+                    msg: "Error verifying the signature".to_string(),
                     source: Box::new(e),
-                })?;
-        Ok(verify_xml_signature(
-            self.get_data_str()
-                .ok_or(VerifySignatureErrorImpl::XMLAlreadyParsed)?
-                .as_str(),
-            &public_key,
-        )
-        .map_err(|e| VerifySignatureErrorImpl::XMLSignatureError {
-            msg: "Error verifying the signature".to_string(),
-            source: Box::new(e),
-        })?
-        .is_ok())
+                })
+        })?;
+        matching_certificate(matching, ca)
     }
 }
 
@@ -421,7 +1151,7 @@ mod test {
     use std::str::FromStr;
 
     use super::*;
-    use crate::config::test::CONFIG_TEST;
+    use crate::config::test::{get_keystore, signing_keystore, CONFIG_TEST};
 
     #[test]
     fn test_as_ref() {
@@ -479,36 +1209,201 @@ mod test {
         assert!(CertificateAuthority::from_str("toto").is_err(),);
     }
 
+    #[test]
+    fn test_cause() {
+        assert_eq!(
+            VerifySignatureError::from(VerifySignatureErrorImpl::NoCA).cause(),
+            SignatureFailureCause::UnknownCertificate
+        );
+        assert_eq!(
+            VerifySignatureError::from(VerifySignatureErrorImpl::SignatureNotFound).cause(),
+            SignatureFailureCause::MissingSignature
+        );
+        assert_eq!(
+            VerifySignatureError::from(VerifySignatureErrorImpl::XMLAlreadyParsed).cause(),
+            SignatureFailureCause::XMLCanonicalization
+        );
+        assert!(SignatureFailureCause::UnknownCertificate.is_keystore_issue());
+        assert!(!SignatureFailureCause::CryptographicMismatch.is_keystore_issue());
+    }
+
+    #[test]
+    fn test_xml_signature_error_cause() {
+        let public_key = get_keystore()
+            .public_key(CertificateAuthority::Canton)
+            .unwrap();
+        let e = verify_xml_signature("<root><a>1</a></root>", &public_key).unwrap_err();
+        assert_eq!(
+            ErrorChain::new(&e)
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>(),
+            vec!["Error verifying the xml", XML_ERROR_NO_SIGNATURE]
+        );
+        assert_eq!(
+            xml_signature_error_cause(&e),
+            SignatureFailureCause::MissingSignature
+        );
+        let e = verify_xml_signature("<root><a>1</a>", &public_key).unwrap_err();
+        assert_eq!(
+            ErrorChain::new(&e).next().unwrap().to_string(),
+            "Error verifying the xml"
+        );
+        assert_eq!(
+            xml_signature_error_cause(&e),
+            SignatureFailureCause::XMLCanonicalization
+        );
+    }
+
     #[test]
     fn test_create() {
         let dt = CONFIG_TEST.keystore().unwrap();
+        let dt = &dt.primary().keystore;
         assert!(dt
-            .0
             .public_certificate(CertificateAuthority::Canton.as_ref())
             .is_ok());
         assert!(dt
-            .0
             .public_certificate(CertificateAuthority::SdmConfig.as_ref())
             .is_ok());
         assert!(dt
-            .0
             .public_certificate(CertificateAuthority::SdmTally.as_ref())
             .is_ok());
         assert!(dt
-            .0
             .public_certificate(CertificateAuthority::ControlComponent1.as_ref())
             .is_ok());
         assert!(dt
-            .0
             .public_certificate(CertificateAuthority::ControlComponent2.as_ref())
             .is_ok());
         assert!(dt
-            .0
             .public_certificate(CertificateAuthority::ControlComponent3.as_ref())
             .is_ok());
         assert!(dt
-            .0
             .public_certificate(CertificateAuthority::ControlComponent4.as_ref())
             .is_ok());
     }
+
+    #[test]
+    fn test_validity_window() {
+        let d = |s: &str| NaiveDate::parse_from_str(s, VALIDITY_DATE_FORMAT).unwrap();
+        let w = ValidityWindow::new(Some(d("2025-01-01")), Some(d("2025-06-30")));
+        assert!(w.contains(&d("2025-01-01")));
+        assert!(w.contains(&d("2025-03-15")));
+        assert!(w.contains(&d("2025-06-30")));
+        assert!(!w.contains(&d("2024-12-31")));
+        assert!(!w.contains(&d("2025-07-01")));
+        let w = ValidityWindow::new(None, Some(d("2025-06-30")));
+        assert!(w.contains(&d("2000-01-01")));
+        assert!(!w.contains(&d("2025-07-01")));
+        assert!(ValidityWindow::default().contains(&d("2025-07-01")));
+        assert_eq!(
+            ValidityWindow::new(Some(d("2025-01-01")), None).to_string(),
+            "2025-01-01 - -"
+        );
+    }
+
+    #[test]
+    fn test_add_trusted_keystores() {
+        let mut ks = get_keystore();
+        assert_eq!(ks.keystore_names(), vec!["direct-trust"]);
+        assert!(ks.additional_fingerprints().is_empty());
+        ks.add_trusted_keystores(Keystore::from(
+            Arc::into_inner(get_keystore().trusted_keystores.remove(0))
+                .unwrap()
+                .keystore,
+        ));
+        assert_eq!(ks.keystore_names(), vec!["direct-trust", "primary"]);
+        let additional = ks.additional_fingerprints();
+        assert_eq!(additional.len(), CertificateAuthority::iter().count());
+        assert!(additional
+            .iter()
+            .all(|(c, v)| c.keystore_name() == "primary" && v.is_none()));
+        assert_eq!(
+            additional
+                .iter()
+                .find(|(c, _)| c.authority() == CertificateAuthority::Canton)
+                .unwrap()
+                .0
+                .fingerprint(),
+            &ks.fingerprint(CertificateAuthority::Canton).unwrap()
+        );
+        assert_eq!(ks.candidates(CertificateAuthority::Canton).len(), 2);
+    }
+
+    #[test]
+    fn test_reference_date() {
+        let d = |s: &str| NaiveDate::parse_from_str(s, VALIDITY_DATE_FORMAT).unwrap();
+        let mut ks = get_keystore();
+        Arc::get_mut(&mut ks.trusted_keystores[0]).unwrap().validity =
+            Some(ValidityWindow::new(Some(d("2025-01-01")), Some(d("2025-06-30"))));
+        assert_eq!(ks.reference_date(), Local::now().date_naive());
+        assert!(ks.candidates(CertificateAuthority::Canton).is_empty());
+        ks.set_reference_date(d("2025-03-15"));
+        assert_eq!(ks.reference_date(), d("2025-03-15"));
+        assert_eq!(ks.candidates(CertificateAuthority::Canton).len(), 1);
+        ks.set_reference_date(d("2025-07-01"));
+        assert!(ks.candidates(CertificateAuthority::Canton).is_empty());
+        let e = ks
+            .verify_message_signature(
+                CertificateAuthority::Canton.as_ref(),
+                &HashableMessage::from("message"),
+                &HashableMessage::from("context"),
+                &ByteArray::default(),
+            )
+            .unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "No trusted certificate valid on 2025-07-01 found for canton"
+        );
+    }
+
+    #[test]
+    fn test_sign_message() {
+        let signing_ks = Keystore::from(signing_keystore(CertificateAuthority::Canton).unwrap());
+        let authority = signing_ks.signing_authority().unwrap();
+        let message = HashableMessage::from("message");
+        let context = HashableMessage::from("context");
+        let signature = signing_ks.sign_message(&message, &context).unwrap();
+        let ks = get_keystore();
+        assert!(ks
+            .verify_message_signature(&authority, &message, &context, &signature)
+            .unwrap());
+        assert!(!ks
+            .verify_message_signature(
+                &authority,
+                &HashableMessage::from("other message"),
+                &context,
+                &signature
+            )
+            .unwrap());
+        assert!(ks
+            .verify_message_signature("toto", &message, &context, &signature)
+            .is_err());
+    }
+
+    #[test]
+    fn test_signature_cache() {
+        let ks = get_keystore();
+        let cache = SignatureVerificationCache::new();
+        assert!(cache.is_empty());
+        assert!(cache.get(&ks, "1234").is_none());
+        assert!(cache.is_authentic(&ks, "1234").is_none());
+        let certificate = ks
+            .primary()
+            .trusted_certificate(CertificateAuthority::Canton)
+            .unwrap();
+        cache.insert(&ks, "1234", vec![Some(certificate.clone())]);
+        cache.insert(&ks, "5678", vec![Some(certificate.clone()), None]);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&ks, "1234").unwrap(), vec![Some(certificate)]);
+        assert_eq!(cache.is_authentic(&ks, "1234"), Some(true));
+        assert_eq!(cache.is_authentic(&ks, "5678"), Some(false));
+        let mut other_ks = get_keystore();
+        other_ks.add_trusted_keystores(get_keystore());
+        assert_ne!(ks.identifier(), other_ks.identifier());
+        assert!(cache.get(&other_ks, "1234").is_none());
+        let mut ks_other_date = get_keystore();
+        ks_other_date.set_reference_date(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap());
+        assert!(cache.get(&ks_other_date, "1234").is_none());
+        cache.clear();
+        assert!(cache.is_empty());
+    }
 }
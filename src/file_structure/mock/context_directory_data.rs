@@ -184,7 +184,7 @@ impl ContextDirectoryTrait for MockContextDirectory {
 
 impl ContextVCSDirectoryTrait for MockContextVCSDirectory {
     fn setup_component_tally_data_payload_file(&self) -> &File<SetupComponentTallyDataPayload> {
-        todo!()
+        self.dir.setup_component_tally_data_payload_file()
     }
 
     impl_trait_get_method_for_mocked_data!(
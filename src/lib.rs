@@ -58,13 +58,23 @@
 //! | TXT_REPORT_TAB_SIZE       | The tab size for the text reports                      |          | 2 |
 //! | REPORT_FORMAT_DATE        | The format of the date in the report                   |          | `%d.%m.%Y %H:%M:%S.%3f` |
 //! | DIRECT_TRUST_DIR_PATH     | The path to the direct trust keystore for the verifier |          | The path `./direct-trust` where `.` is the installation directory |
+//! | DIRECT_TRUST_ADDITIONAL_DIR_PATHS | Paths to additional trusted keystores (e.g. during a rollover of the certificates), separated with a comma. Each directory can contain a file `validity.json` with the validity window |          | none |
+//! | SIGNATURE_CACHE           | Cache the results of the signature verifications, using the fingerprint of the files (`true` or `false`) |          | `false` |
 //!
 //! The environment variables are retrieved using the static instance of [`VerifierConfig`]
 //!
+//! ### Configuration file
+//! The options (except the password) can also be set in a JSON configuration file (see [`config_file`] for the schema).
+//! The file is given with [`VerifierConfig::new_with_config_file`], with the environment variable `VERIFIER_CONFIG_FILE`
+//! or is the file `verifier_config.json` in the root directory. The environment variables have precedence over the
+//! configuration file. Use [`VerifierConfig::validate`] to check the values and [`VerifierConfig::effective_configuration`]
+//! to get the effective values with their source
+//!
 //!
 
 //pub mod application_runner;
 mod config;
+pub mod config_file;
 mod consts;
 mod data_structures;
 pub mod dataset;
@@ -76,5 +86,13 @@ pub mod startup_checks;
 pub mod verification;
 
 pub use config::{VerifierConfig, VerifierConfigError};
-pub use data_structures::{DatasetType, dataset::DatasetTypeKind};
+pub use config_file::{ConfigValueSource, EffectiveConfigValue};
+pub use data_structures::{
+    DatasetType,
+    dataset::DatasetTypeKind,
+    tally::ech_0222::{
+        CountResult, CountingCircleResults, ECH0222CalculatedError, ElectionResults,
+        ElectionResultsSummary, QuestionResults, VoteResults,
+    },
+};
 pub use error_utils::{ErrorChain, Report};
@@ -16,7 +16,7 @@
 
 use crate::{
     file_structure::{CompletnessTestTrait, VerificationDirectory, VerificationDirectoryTrait},
-    verification::{VerificationMetaDataList, VerificationPeriod},
+    verification::{SharedRunState, VerificationMetaDataList, VerificationPeriod},
     VerifierConfig,
 };
 use std::path::Path;
@@ -24,7 +24,17 @@ use std::path::Path;
 /// Check some elements before starting the verifications.
 ///
 /// Must be called by the application at the beginning. If error, then cannot continue
-pub fn start_check(config: &'static VerifierConfig) -> Result<(), String> {
+pub fn start_check(config: &VerifierConfig) -> Result<(), String> {
+    start_check_with_shared_state(config, &SharedRunState::new(config))
+}
+
+/// Check some elements before starting the verifications, with the keystore of the shared state
+///
+/// Like [start_check], without reading the keystore again (see [SharedRunState])
+pub fn start_check_with_shared_state(
+    config: &VerifierConfig,
+    state: &SharedRunState,
+) -> Result<(), String> {
     let md_list_check = VerificationMetaDataList::load(config.get_verification_list_str());
     if md_list_check.is_err() {
         return Err(format!(
@@ -32,7 +42,8 @@ pub fn start_check(config: &'static VerifierConfig) -> Result<(), String> {
             md_list_check.unwrap_err()
         ));
     }
-    config
+    config.validate().map_err(|e| e.to_string())?;
+    state
         .keystore()
         .map_err(|e| format!("Cannot read keystore: {e}"))?;
     Ok(())
@@ -162,26 +162,42 @@ impl<D: VerificationDirectoryTrait> ManualVerificationsForAllPeriod<D> {
     /// Inputs
     /// - `directory`: The Verification directory
     /// - `config`: The configuration of the verifier
-    pub fn try_new(
-        directory: Arc<D>,
-        config: &'static VerifierConfig,
-    ) -> Result<Self, VerificationError> {
+    pub fn try_new(directory: Arc<D>, config: &VerifierConfig) -> Result<Self, VerificationError> {
         Self::try_new_impl(directory, config).map_err(VerificationError::from)
     }
 
     fn try_new_impl(
         directory: Arc<D>,
-        config: &'static VerifierConfig,
+        config: &VerifierConfig,
     ) -> Result<Self, VerificationErrorImpl> {
         let keystore = config
             .keystore()
             .map_err(|e| VerificationErrorImpl::KeystoreNewAll { source: e })?;
-        let fingerprints = keystore
+        let mut fingerprints = keystore
             .fingerprints()
             .map_err(|e| VerificationErrorImpl::FingerprintsNewAll { source: e })?
             .iter()
             .map(|(k, v)| (k.as_ref().to_string(), v.base16_encode().unwrap()))
             .collect::<HashMap<_, _>>();
+        // Fingerprints of the additional trusted keystores (e.g. during a rollover)
+        fingerprints.extend(keystore.additional_fingerprints().iter().map(
+            |(certificate, validity)| {
+                let key = match validity {
+                    Some(v) => format!(
+                        "{} ({}, valid {})",
+                        certificate.authority().as_ref(),
+                        certificate.keystore_name(),
+                        v
+                    ),
+                    None => format!(
+                        "{} ({})",
+                        certificate.authority().as_ref(),
+                        certificate.keystore_name()
+                    ),
+                };
+                (key, certificate.fingerprint().base16_encode().unwrap())
+            },
+        ));
         let config_dir = directory.context();
         let ee_config = config_dir.election_event_configuration().map_err(|e| {
             VerificationErrorImpl::EEContextNewAll {
@@ -422,7 +438,7 @@ impl<D: VerificationDirectoryTrait> ManualVerificationsSetup<D> {
     /// not be delivered
     pub fn try_new(
         directory: Arc<D>,
-        config: &'static VerifierConfig,
+        config: &VerifierConfig,
         metadata: &VerificationMetaDataList,
         verifications_status: &HashMap<String, VerificationStatus>,
         verifications_with_errors_and_failures: &VerficationsWithErrorAndFailures,
@@ -494,7 +510,7 @@ impl<D: VerificationDirectoryTrait> ManualVerificationsTally<D> {
     /// not be delivered
     fn try_new(
         directory: Arc<D>,
-        config: &'static VerifierConfig,
+        config: &VerifierConfig,
         metadata: &VerificationMetaDataList,
         verifications_status: &HashMap<String, VerificationStatus>,
         verifications_with_errors_and_failures: &VerficationsWithErrorAndFailures,
@@ -618,7 +634,7 @@ impl<D: VerificationDirectoryTrait> ManualVerifications<D> {
     pub fn try_new(
         period: VerificationPeriod,
         directory: Arc<D>,
-        config: &'static VerifierConfig,
+        config: &VerifierConfig,
         verifications_status: &HashMap<String, VerificationStatus>,
         verifications_with_errors_and_failures: &VerficationsWithErrorAndFailures,
         excluded_verifications: &[String],
@@ -21,15 +21,17 @@
 use super::{VerificationCategory, VerificationError, VerificationErrorImpl, VerificationPeriod};
 use serde::{
     de::{Deserialize as Deserialize2, Deserializer, Error},
-    Deserialize,
+    Deserialize, Serialize, Serializer,
 };
 
 /// List of Verification Metadata
-#[derive(Deserialize, Debug, Clone)]
+///
+/// The list can be serialized, e.g. to archive the metadata used for a run
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct VerificationMetaDataList(Vec<VerificationMetaData>);
 
 /// Metadata of a verification
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct VerificationMetaData {
     /// id of the verification
     id: String,
@@ -44,12 +46,25 @@ pub struct VerificationMetaData {
     description: String,
 
     /// Period (Set or Tally) of the verification
-    #[serde(deserialize_with = "deserialize_string_to_period")]
+    #[serde(
+        deserialize_with = "deserialize_string_to_period",
+        serialize_with = "serialize_as_str"
+    )]
     period: VerificationPeriod,
 
     /// Category of the verification
-    #[serde(deserialize_with = "deserialize_string_to_category")]
+    #[serde(
+        deserialize_with = "deserialize_string_to_category",
+        serialize_with = "serialize_as_str"
+    )]
     category: VerificationCategory,
+
+    /// Rough hint of the expected duration of the verification in seconds for a large dataset
+    ///
+    /// Only used to schedule the longest verifications first, if no duration of an earlier run is known.
+    /// `None` for the verifications running quickly
+    #[serde(default)]
+    expected_duration_hint: Option<u64>,
 }
 
 impl VerificationMetaDataList {
@@ -135,6 +150,10 @@ impl VerificationMetaData {
     pub fn category(&self) -> &VerificationCategory {
         &self.category
     }
+
+    pub fn expected_duration_hint(&self) -> Option<u64> {
+        self.expected_duration_hint
+    }
 }
 
 fn deserialize_string_to_period<'de, D>(deserializer: D) -> Result<VerificationPeriod, D::Error>
@@ -155,6 +174,14 @@ where
     VerificationCategory::try_from(buf.as_str()).map_err(|e| Error::custom(e.to_string()))
 }
 
+fn serialize_as_str<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<str>,
+    S: Serializer,
+{
+    serializer.serialize_str(value.as_ref())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -168,4 +195,39 @@ mod test {
         assert!(!metadata.is_empty());
         assert!(metadata.meta_data_from_id("01.01").is_some())
     }
+
+    #[test]
+    fn test_expected_duration_hint() {
+        let metadata =
+            VerificationMetaDataList::load(CONFIG_TEST.get_verification_list_str()).unwrap();
+        assert!(metadata
+            .meta_data_from_id("01.01")
+            .unwrap()
+            .expected_duration_hint()
+            .is_none());
+        assert!(metadata
+            .meta_data_from_id("10.01")
+            .unwrap()
+            .expected_duration_hint()
+            .is_some());
+    }
+
+    #[test]
+    fn test_serialize() {
+        let metadata =
+            VerificationMetaDataList::load(CONFIG_TEST.get_verification_list_str()).unwrap();
+        let reloaded =
+            VerificationMetaDataList::load(&serde_json::to_string(&metadata).unwrap()).unwrap();
+        assert_eq!(reloaded.len(), metadata.len());
+        let m = reloaded.meta_data_from_id("10.01").unwrap();
+        assert_eq!(m.period(), &VerificationPeriod::Tally);
+        assert_eq!(m.category(), &VerificationCategory::Evidence);
+        assert_eq!(
+            m.expected_duration_hint(),
+            metadata
+                .meta_data_from_id("10.01")
+                .unwrap()
+                .expected_duration_hint()
+        );
+    }
 }
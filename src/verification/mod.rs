@@ -19,6 +19,7 @@
 mod manual;
 mod meta_data;
 mod result;
+mod run_context;
 mod setup;
 mod suite;
 mod tally;
@@ -29,18 +30,23 @@ use std::fmt::Display;
 pub use self::{
     manual::*,
     meta_data::*,
-    result::{VerficationsWithErrorAndFailures, VerificationEvent, VerificationResult},
+    result::{
+        VerficationsWithErrorAndFailures, VerificationEvent, VerificationEventKind,
+        VerificationResult,
+    },
+    run_context::{RunContext, SharedRunState},
     setup::get_verifications as get_verifications_setup,
     suite::VerificationSuite,
     tally::get_verifications as get_verifications_tally,
 };
 use crate::{
-    config::{VerifierConfig, VerifierConfigError},
-    data_structures::DataStructureError,
-    direct_trust::{DirectTrustError, VerifiySignatureTrait},
-    file_structure::{FileStructureError, VerificationDirectoryTrait},
+    config::VerifierConfigError,
+    data_structures::{DataStructureError, VerifierDataDecode, VerifierDataToTypeTrait},
+    direct_trust::{DirectTrustError, SignatureFailureCause, VerifiySignatureTrait},
+    file_structure::{FileStructureError, VerificationDirectoryTrait, file::File},
 };
 use thiserror::Error;
+use tracing::warn;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, strum::EnumString, strum::AsRefStr)]
 #[strum(serialize_all = "lowercase")]
@@ -52,7 +58,17 @@ pub enum VerificationCategory {
     Evidence,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, strum::EnumString, strum::AsRefStr)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    strum::EnumString,
+    strum::AsRefStr,
+    serde::Serialize,
+    serde::Deserialize,
+)]
 #[strum(serialize_all = "lowercase")]
 ///  Status of a verification
 pub enum VerificationStatus {
@@ -93,8 +109,20 @@ impl VerificationStatus {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, strum::EnumString, strum::AsRefStr)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    strum::EnumString,
+    strum::AsRefStr,
+    serde::Serialize,
+    serde::Deserialize,
+)]
 #[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
 pub enum VerificationPeriod {
     Setup,
     Tally,
@@ -194,6 +222,10 @@ enum VerificationErrorImpl {
         period: VerificationPeriod,
         source: Box<VerificationError>,
     },
+    #[error("Verification {id} is not in the suite (unknown or excluded)")]
+    NotInSuite { id: String },
+    #[error("Verification {id} is running and cannot be reset")]
+    ResetRunning { id: String },
     #[error("Error calculating the fingerprint of ech-0222 file")]
     ECH0222 { source: Box<FileStructureError> },
 }
@@ -213,7 +245,7 @@ impl VerificationPeriod {
 #[allow(dead_code)]
 pub(super) fn verification_unimplemented<D: VerificationDirectoryTrait>(
     _dir: &D,
-    _config: &'static VerifierConfig,
+    _context: &RunContext,
     result: &mut VerificationResult,
 ) {
     result.push(VerificationEvent::new_error(
@@ -222,34 +254,75 @@ pub(super) fn verification_unimplemented<D: VerificationDirectoryTrait>(
 }
 
 /// Verify the signatue for a given object implementing [VerifiySignatureSkeletonTrait]
-fn verify_signature_for_object<'a, T>(
+///
+/// Each failure contains the [SignatureFailureCause], in order to distinguish a tampered
+/// object from a misconfiguration of the keystore. The valid signatures are counted per matched
+/// trusted certificate (see [VerificationResult::add_verified_signature])
+///
+/// The keystore of the run is used. If the cache of the signatures is enabled,
+/// the results are looked up in the cache of the run with the fingerprint of `file` (the file containing the object)
+///
+/// The verified signatures are counted as items checked (see [VerificationResult::items_checked])
+fn verify_signature_for_object<'a, T, D>(
     obj: &'a T,
-    config: &'static VerifierConfig,
+    file: &File<D>,
+    context: &RunContext,
 ) -> VerificationResult
 where
     T: VerifiySignatureTrait<'a>,
+    D: VerifierDataDecode + VerifierDataToTypeTrait,
 {
     let mut result = VerificationResult::new();
-    let ks = match config.keystore() {
+    let ks = match context.keystore() {
         Ok(ks) => ks,
         Err(e) => {
             result.push(
-                VerificationEvent::new_error_from_error(&e).add_context("Cannot read keystore"),
+                VerificationEvent::new_error_from_error(e).add_context("Cannot read keystore"),
             );
             return result;
         }
     };
-    let res = obj.verify_signatures(&ks);
+    let cache_enabled = match context.config().signature_cache_enabled() {
+        Ok(enabled) => enabled,
+        Err(e) => {
+            result.push(
+                VerificationEvent::new_error_from_error(&e)
+                    .add_context("Cannot read the option of the signature cache"),
+            );
+            return result;
+        }
+    };
+    let res = match cache_enabled {
+        true => match file.fingerprint() {
+            Ok(fingerprint) => context
+                .signature_cache()
+                .verify_signatures_with_certificate(obj, ks, &fingerprint),
+            Err(e) => {
+                warn!(
+                    "Cannot calculate the fingerprint of {}. The cache is not used: {}",
+                    file.path_to_str(),
+                    e
+                );
+                obj.verify_signatures_with_certificate(ks)
+            }
+        },
+        false => obj.verify_signatures_with_certificate(ks),
+    };
+    result.add_items_checked(res.len());
     for (i, r) in res.iter().enumerate() {
         match r {
-            Ok(t) => {
-                if !t {
-                    result.push(VerificationEvent::new_failure("Wrong signature"))
-                }
-            }
+            Ok(Some(certificate)) => result.add_verified_signature(certificate),
+            Ok(None) => result.push(
+                VerificationEvent::new_failure("Wrong signature")
+                    .with_signature_failure_cause(SignatureFailureCause::CryptographicMismatch)
+                    .with_location(file.path_to_str()),
+            ),
             Err(e) => {
                 result.push(
-                    VerificationEvent::new_failure(e).add_context(format!("at position {i}")),
+                    VerificationEvent::new_failure(e)
+                        .with_signature_failure_cause(e.cause())
+                        .with_location(file.path_to_str())
+                        .add_context(format!("at position {i}")),
                 );
             }
         }
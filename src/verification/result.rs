@@ -16,23 +16,32 @@
 
 //! Module implementing the errors of the verifications
 //!
-use std::{collections::HashMap, fmt::Display};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+};
 use strum::AsRefStr;
 
-use crate::ErrorChain;
+use crate::{ErrorChain, direct_trust::SignatureFailureCause};
 
 /// Kind of the event during a verification
-#[derive(Debug, Clone, AsRefStr)]
+#[derive(Debug, Clone, AsRefStr, Serialize, Deserialize)]
 pub enum VerificationEventKind {
     Error,
     Failure,
+    /// Information about the verification, which is neither an error nor a failure
+    /// (e.g. the certificate used to verify a signature)
+    Information,
 }
 
-/// Enum representing one event (an error or a failure) during the tests
-#[derive(Debug, Clone)]
+/// Enum representing one event (an error, a failure or an information) during the tests
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VerificationEvent {
     kind: VerificationEventKind,
     results: Vec<String>,
+    signature_failure_cause: Option<SignatureFailureCause>,
+    location: Option<String>,
 }
 
 /// Struct representing a result of the verification
@@ -40,22 +49,25 @@ pub struct VerificationEvent {
 #[derive(Clone, Debug)]
 pub struct VerificationResult {
     results: Vec<VerificationEvent>,
+    items_checked: Option<usize>,
+    verified_signatures: BTreeMap<String, usize>,
 }
 
 /// Type representing verifications with errors and failures
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct VerficationsWithErrorAndFailures(HashMap<String, (Vec<String>, Vec<String>)>);
 
 impl VerificationEventKind {
     pub fn is_error(&self) -> bool {
-        match self {
-            VerificationEventKind::Error => true,
-            VerificationEventKind::Failure => false,
-        }
+        matches!(self, VerificationEventKind::Error)
     }
 
     pub fn is_failure(&self) -> bool {
-        !self.is_error()
+        matches!(self, VerificationEventKind::Failure)
+    }
+
+    pub fn is_information(&self) -> bool {
+        matches!(self, VerificationEventKind::Information)
     }
 }
 
@@ -64,6 +76,8 @@ impl VerificationEvent {
         Self {
             kind,
             results: vec![format!("{}", value)],
+            signature_failure_cause: None,
+            location: None,
         }
     }
 
@@ -77,6 +91,8 @@ impl VerificationEvent {
         Self {
             kind,
             results: values,
+            signature_failure_cause: None,
+            location: None,
         }
     }
 
@@ -92,6 +108,10 @@ impl VerificationEvent {
         Self::new(VerificationEventKind::Failure, value)
     }
 
+    pub fn new_information<T: Display + ?Sized>(value: &T) -> Self {
+        Self::new(VerificationEventKind::Information, value)
+    }
+
     /// Add a context to the Verification Event
     pub fn add_context<C>(mut self, context: C) -> Self
     where
@@ -101,6 +121,37 @@ impl VerificationEvent {
         self
     }
 
+    /// Set the cause of a failed signature verification
+    pub fn with_signature_failure_cause(mut self, cause: SignatureFailureCause) -> Self {
+        self.signature_failure_cause = Some(cause);
+        self
+    }
+
+    /// Cause of the failed signature verification
+    ///
+    /// `None` if the event is not related to the verification of a signature
+    pub fn signature_failure_cause(&self) -> Option<&SignatureFailureCause> {
+        self.signature_failure_cause.as_ref()
+    }
+
+    /// Set the location of the event in the dataset (e.g. the path of the file)
+    pub fn with_location<L: Display>(mut self, location: L) -> Self {
+        self.location = Some(location.to_string());
+        self
+    }
+
+    /// Location of the event in the dataset (e.g. the path of the file)
+    ///
+    /// `None` if the location is not known
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    /// Kind of the event
+    pub fn kind(&self) -> &VerificationEventKind {
+        &self.kind
+    }
+
     /// Is the event an error)
     pub fn is_error(&self) -> bool {
         self.kind.is_error()
@@ -111,6 +162,11 @@ impl VerificationEvent {
         self.kind.is_failure()
     }
 
+    /// Is the event an information
+    pub fn is_information(&self) -> bool {
+        self.kind.is_information()
+    }
+
     /// Source of the event
     pub fn source(&self) -> &str {
         match self.results.first() {
@@ -167,11 +223,38 @@ impl VerficationsWithErrorAndFailures {
     pub fn insert<S: Into<String>>(&mut self, id: S, errors: Vec<String>, failures: Vec<String>) {
         self.0.insert(id.into(), (errors, failures));
     }
+
+    /// Remove the errors and failures of the verification with the id
+    ///
+    /// Return the removed tuple (errors, failures) if the verification is contained
+    pub fn remove(&mut self, id: &str) -> Option<(Vec<String>, Vec<String>)> {
+        self.0.remove(id)
+    }
 }
 
 impl Display for VerificationEvent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // An information has no backtrace. The contexts are given in one line
+        if self.is_information() {
+            let contexts = self.contexts();
+            return match contexts.is_empty() {
+                true => write!(f, "{}: {}", self.kind.as_ref(), self.source()),
+                false => write!(
+                    f,
+                    "{}: {} [{}]",
+                    self.kind.as_ref(),
+                    self.source(),
+                    contexts.join(" / ")
+                ),
+            };
+        }
         let mut res = vec![format!("{}: {}", self.kind.as_ref(), self.last())];
+        if let Some(cause) = &self.signature_failure_cause {
+            res.push(format!("cause: {cause}"));
+        }
+        if let Some(location) = &self.location {
+            res.push(format!("location: {location}"));
+        }
         if self.results.len() > 1 {
             res.push("backtrace:".to_string());
             res.append(
@@ -221,6 +304,11 @@ impl VerificationResult {
         self.results.iter().filter(|e| e.is_failure()).collect()
     }
 
+    /// Get the informations
+    pub fn informations(&self) -> Vec<&VerificationEvent> {
+        self.results.iter().filter(|e| e.is_information()).collect()
+    }
+
     /// Get the errors as string
     pub fn errors_to_string(&self) -> Vec<String> {
         self.errors().iter().map(|e| e.to_string()).collect()
@@ -231,14 +319,82 @@ impl VerificationResult {
         self.failures().iter().map(|e| e.to_string()).collect()
     }
 
-    /// Get the errors and the failures (all events)
+    /// Get the informations as string
+    pub fn informations_to_string(&self) -> Vec<String> {
+        self.informations().iter().map(|e| e.to_string()).collect()
+    }
+
+    /// Get the errors and the failures
     pub fn errors_and_failures(&self) -> Vec<&VerificationEvent> {
+        self.results
+            .iter()
+            .filter(|e| !e.is_information())
+            .collect()
+    }
+
+    /// Get all the events (errors, failures and informations)
+    pub fn events(&self) -> Vec<&VerificationEvent> {
         self.results.iter().collect()
     }
 
+    /// Number of items (e.g. signatures, ballot boxes) checked by the verification
+    ///
+    /// `None` if the verification does not count the items checked
+    pub fn items_checked(&self) -> Option<usize> {
+        self.items_checked
+    }
+
+    /// Add the given number to the number of items checked
+    pub fn add_items_checked(&mut self, nb: usize) {
+        self.items_checked = Some(self.items_checked.unwrap_or_default() + nb);
+    }
+
+    fn add_items_checked_of(&mut self, other: &Self) {
+        if let Some(nb) = other.items_checked {
+            self.add_items_checked(nb);
+        }
+        for (certificate, nb) in other.verified_signatures.iter() {
+            *self
+                .verified_signatures
+                .entry(certificate.clone())
+                .or_default() += nb;
+        }
+    }
+
+    /// Count a signature verified with the given certificate
+    ///
+    /// The signatures are counted per certificate and summarized once for the verification
+    /// (see [Self::summarize_verified_signatures]), instead of one information per signature
+    pub fn add_verified_signature<C: Display + ?Sized>(&mut self, certificate: &C) {
+        *self
+            .verified_signatures
+            .entry(certificate.to_string())
+            .or_default() += 1;
+    }
+
+    /// Number of signatures verified per certificate, not summarized yet
+    pub fn verified_signatures(&self) -> &BTreeMap<String, usize> {
+        &self.verified_signatures
+    }
+
+    /// Add one information per certificate with the number of signatures verified with it
+    ///
+    /// The counters are reset
+    pub fn summarize_verified_signatures(&mut self) {
+        for (certificate, nb) in std::mem::take(&mut self.verified_signatures) {
+            self.push(VerificationEvent::new_information(&format!(
+                "Certificate {certificate}: {nb} signatures verified"
+            )));
+        }
+    }
+
     /// New VerificationResult
     pub fn new() -> Self {
-        Self { results: vec![] }
+        Self {
+            results: vec![],
+            items_checked: None,
+            verified_signatures: BTreeMap::new(),
+        }
     }
 
     /// Add the context to the contexts of self
@@ -277,6 +433,9 @@ impl VerificationResult {
     /// Append the results of ohter to self, emptying the vectors of other
     pub fn append(&mut self, other: &mut Self) {
         self.results.append(&mut other.results);
+        self.add_items_checked_of(other);
+        other.items_checked = None;
+        other.verified_signatures.clear();
     }
 
     /// Append the results of ohter to self with context
@@ -284,9 +443,10 @@ impl VerificationResult {
     where
         C: Clone + Display + Send + Sync + 'static,
     {
-        for e in other.errors_and_failures() {
+        for e in other.events() {
             self.push_with_context(e.clone(), context.clone());
         }
+        self.add_items_checked_of(other);
     }
 
     /// Append the results of ohter to self, emptying the vectors of other
@@ -349,6 +509,7 @@ impl VerificationResult {
             for e in d.results.iter() {
                 res.push(e.clone());
             }
+            res.add_items_checked_of(d);
         }
         res
     }
@@ -364,6 +525,8 @@ impl From<&[VerificationEvent]> for VerificationResult {
     fn from(value: &[VerificationEvent]) -> Self {
         Self {
             results: value.to_vec(),
+            items_checked: None,
+            verified_signatures: BTreeMap::new(),
         }
     }
 }
@@ -409,6 +572,47 @@ mod test {
         assert!(!res.has_failures());
     }
 
+    #[test]
+    fn test_items_checked() {
+        let mut res = VerificationResult::new();
+        assert!(res.items_checked().is_none());
+        let mut other = VerificationResult::new();
+        other.add_items_checked(2);
+        res.append_with_context(&other, "context");
+        assert_eq!(res.items_checked(), Some(2));
+        res.append(&mut other);
+        assert_eq!(res.items_checked(), Some(4));
+        assert!(other.items_checked().is_none());
+        assert_eq!(
+            VerificationResult::join(&[res.clone(), VerificationResult::new(), res])
+                .items_checked(),
+            Some(8)
+        );
+    }
+
+    #[test]
+    fn test_verified_signatures() {
+        let mut res = VerificationResult::new();
+        let mut other = VerificationResult::new();
+        other.add_verified_signature("canton");
+        other.add_verified_signature("canton");
+        other.add_verified_signature("sdm_config");
+        res.append_with_context(&other, "context 1");
+        res.append_with_context(&other, "context 2");
+        assert_eq!(res.verified_signatures().get("canton"), Some(&4));
+        assert!(res.informations().is_empty());
+        res.summarize_verified_signatures();
+        assert!(res.verified_signatures().is_empty());
+        assert_eq!(
+            res.informations_to_string(),
+            vec![
+                "Information: Certificate canton: 4 signatures verified",
+                "Information: Certificate sdm_config: 2 signatures verified"
+            ]
+        );
+        assert!(res.is_ok());
+    }
+
     #[test]
     fn test_verif_event() {
         let event = VerificationEvent::new_error("toto")
@@ -420,6 +624,43 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_verif_event_with_cause() {
+        let event = VerificationEvent::new_failure("Wrong signature")
+            .with_signature_failure_cause(SignatureFailureCause::CryptographicMismatch);
+        assert_eq!(
+            event.signature_failure_cause(),
+            Some(&SignatureFailureCause::CryptographicMismatch)
+        );
+        assert_eq!(
+            event.to_string(),
+            "Failure: Wrong signature\ncause: Cryptographic mismatch of the signature".to_string()
+        );
+        assert!(
+            VerificationEvent::new_failure("toto")
+                .signature_failure_cause()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_verif_event_with_location() {
+        let event = VerificationEvent::new_failure("Wrong signature")
+            .with_location("context/election_event_configuration.xml")
+            .add_context("context");
+        assert_eq!(
+            event.location(),
+            Some("context/election_event_configuration.xml")
+        );
+        assert!(event.kind().is_failure());
+        assert_eq!(
+            event.to_string(),
+            "Failure: context\nlocation: context/election_event_configuration.xml\nbacktrace:\n0: Wrong signature\n1: context"
+                .to_string()
+        );
+        assert!(VerificationEvent::new_failure("toto").location().is_none());
+    }
+
     #[test]
     fn test_from_error() {
         let e = Outer::Inner {
@@ -489,4 +730,25 @@ mod test {
         assert_eq!(verifs.has_failures("test3"), Some(true));
         assert_eq!(verifs.has_failures("test4"), None);
     }
+
+    #[test]
+    fn test_informations() {
+        let mut res = VerificationResult::new();
+        res.push(VerificationEvent::new_information("info"));
+        assert!(res.is_ok());
+        assert!(res.errors_and_failures().is_empty());
+        assert_eq!(res.informations().len(), 1);
+        assert_eq!(res.informations_to_string(), vec!["Information: info"]);
+        res.push(VerificationEvent::new_failure("failure"));
+        assert!(!res.is_ok());
+        assert_eq!(res.errors_and_failures().len(), 1);
+        assert_eq!(res.events().len(), 2);
+        assert_eq!(
+            VerificationEvent::new_information("info")
+                .add_context("context 1")
+                .add_context("context 2")
+                .to_string(),
+            "Information: info [context 1 / context 2]"
+        );
+    }
 }
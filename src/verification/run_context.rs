@@ -0,0 +1,189 @@
+// Copyright © 2025 Denis Morel
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more
+// details.
+//
+// You should have received a copy of the GNU General Public License and
+// a copy of the GNU General Public License along with this program. If not, see
+// <https://www.gnu.org/licenses/>.
+
+//! Module implementing the context of a run of verifications
+
+use crate::{
+    config::{VerifierConfig, VerifierConfigError},
+    direct_trust::{Keystore, SignatureVerificationCache},
+};
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+/// Context of a run, given to each verification of the run
+///
+/// The context contains the configuration and the state of the run:
+/// - The keystore, read once at the creation of the context, with the reference date of the run
+///   (see [Keystore::set_reference_date])
+/// - The cache of the signature verifications (see [SignatureVerificationCache])
+///
+/// The context is created by the owner of the run (e.g. the runner) at the start of each run.
+/// The cache can be given to the next contexts, in order to reuse the results in the next runs.
+/// The keystore and the cache can be shared by many runs (see [SharedRunState]).
+pub struct RunContext {
+    config: Arc<VerifierConfig>,
+    keystore: Result<Keystore, Arc<VerifierConfigError>>,
+    signature_cache: Arc<SignatureVerificationCache>,
+}
+
+/// State shared by many runs, e.g. the runs of the setup and of the tally on the same datasets
+///
+/// The keystore is read once at the creation of the state. Each context created with the state
+/// (see [RunContext::new_with_shared_state]) uses the same trusted keystores with its own reference date,
+/// and the same cache of the signature verifications.
+#[derive(Clone)]
+pub struct SharedRunState {
+    keystore: Result<Arc<Keystore>, Arc<VerifierConfigError>>,
+    signature_cache: Arc<SignatureVerificationCache>,
+}
+
+impl SharedRunState {
+    /// New state, reading the keystore from the configuration, with an empty cache
+    ///
+    /// An error reading the keystore is kept and reported by the verifications using the keystore
+    pub fn new(config: &VerifierConfig) -> Self {
+        Self {
+            keystore: config.keystore().map(Arc::new).map_err(Arc::new),
+            signature_cache: Arc::new(SignatureVerificationCache::new()),
+        }
+    }
+
+    /// Keystore read at the creation of the state
+    pub fn keystore(&self) -> Result<&Keystore, &VerifierConfigError> {
+        self.keystore.as_deref().map_err(|e| e.as_ref())
+    }
+
+    /// Cache of the signature verifications shared by the runs
+    pub fn signature_cache(&self) -> &Arc<SignatureVerificationCache> {
+        &self.signature_cache
+    }
+}
+
+impl RunContext {
+    /// New context with an empty cache
+    ///
+    /// The keystore is read from the configuration. An error reading the keystore is kept
+    /// and reported by the verifications using the keystore
+    pub fn new(config: &Arc<VerifierConfig>) -> Self {
+        Self::new_with_shared_state(config, &SharedRunState::new(config))
+    }
+
+    /// New context using the given cache of the signature verifications
+    ///
+    /// The keystore is read from the configuration
+    pub fn new_with_signature_cache(
+        config: &Arc<VerifierConfig>,
+        signature_cache: &Arc<SignatureVerificationCache>,
+    ) -> Self {
+        Self {
+            config: config.clone(),
+            keystore: config.keystore().map_err(Arc::new),
+            signature_cache: signature_cache.clone(),
+        }
+    }
+
+    /// New context using the keystore and the cache of the shared state, without reading the keystore again
+    pub fn new_with_shared_state(config: &Arc<VerifierConfig>, state: &SharedRunState) -> Self {
+        Self {
+            config: config.clone(),
+            keystore: state
+                .keystore
+                .as_ref()
+                .map(|ks| ks.as_ref().clone())
+                .map_err(Arc::clone),
+            signature_cache: state.signature_cache.clone(),
+        }
+    }
+
+    /// Set the date at which the validity of the trusted keystores is checked (see [Keystore::set_reference_date])
+    ///
+    /// Without reference date, the current date is used
+    pub fn with_reference_date(mut self, date: NaiveDate) -> Self {
+        if let Ok(ks) = self.keystore.as_mut() {
+            ks.set_reference_date(date);
+        }
+        self
+    }
+
+    /// Configuration of the run
+    pub fn config(&self) -> &VerifierConfig {
+        &self.config
+    }
+
+    /// Keystore of the run
+    pub fn keystore(&self) -> Result<&Keystore, &VerifierConfigError> {
+        self.keystore.as_ref().map_err(|e| e.as_ref())
+    }
+
+    /// Cache of the signature verifications
+    pub fn signature_cache(&self) -> &SignatureVerificationCache {
+        &self.signature_cache
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::test::CONFIG_TEST;
+
+    #[test]
+    fn test_new() {
+        let context = RunContext::new(&CONFIG_TEST);
+        assert!(context.keystore().is_ok());
+        assert!(context.signature_cache().is_empty());
+        let cache = Arc::new(SignatureVerificationCache::new());
+        let context_2 = RunContext::new_with_signature_cache(&CONFIG_TEST, &cache);
+        assert!(std::ptr::eq(context_2.signature_cache(), cache.as_ref()));
+        assert_eq!(
+            context.keystore().unwrap().identifier(),
+            context_2.keystore().unwrap().identifier()
+        );
+    }
+
+    #[test]
+    fn test_new_with_shared_state() {
+        let state = SharedRunState::new(&CONFIG_TEST);
+        let setup_date = NaiveDate::from_ymd_opt(2025, 7, 1).unwrap();
+        let tally_date = NaiveDate::from_ymd_opt(2025, 8, 2).unwrap();
+        let setup =
+            RunContext::new_with_shared_state(&CONFIG_TEST, &state).with_reference_date(setup_date);
+        let tally =
+            RunContext::new_with_shared_state(&CONFIG_TEST, &state).with_reference_date(tally_date);
+        // Each context has its own reference date, the keystore of the state is not changed
+        assert_eq!(setup.keystore().unwrap().reference_date(), setup_date);
+        assert_eq!(tally.keystore().unwrap().reference_date(), tally_date);
+        assert_ne!(state.keystore().unwrap().reference_date(), setup_date);
+        assert_eq!(
+            setup.keystore().unwrap().identifier(),
+            state.keystore().unwrap().identifier()
+        );
+        assert!(std::ptr::eq(
+            setup.signature_cache(),
+            tally.signature_cache()
+        ));
+        assert!(std::ptr::eq(
+            setup.signature_cache(),
+            state.signature_cache().as_ref()
+        ));
+    }
+
+    #[test]
+    fn test_with_reference_date() {
+        let date = NaiveDate::from_ymd_opt(2025, 8, 2).unwrap();
+        let context = RunContext::new(&CONFIG_TEST).with_reference_date(date);
+        assert_eq!(context.keystore().unwrap().reference_date(), date);
+    }
+}
@@ -23,17 +23,17 @@ use super::super::{
     verify_signature_for_object,
 };
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         VerificationDirectoryTrait,
         context_directory::{ContextDirectoryTrait, ContextVCSDirectoryTrait},
     },
-    verification::{VerificationError, VerificationErrorImpl, meta_data::VerificationMetaDataList},
+    verification::{
+        RunContext, VerificationError, VerificationErrorImpl, meta_data::VerificationMetaDataList,
+    },
 };
 
 pub fn get_verifications<'a>(
     metadata_list: &'a VerificationMetaDataList,
-    config: &'static VerifierConfig,
 ) -> Result<VerificationList<'a>, VerificationError> {
     Ok(VerificationList(vec![
         Verification::new(
@@ -41,7 +41,6 @@ pub fn get_verifications<'a>(
             "VerifySignatureCantonConfig",
             fn_0201_verify_signature_canton_config,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifySignatureCantonConfig",
@@ -52,7 +51,6 @@ pub fn get_verifications<'a>(
             "VerifySignatureSetupComponentPublicKeys",
             fn_0202_verify_signature_setup_component_public_keys,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifySignatureSetupComponentPublicKeys",
@@ -63,7 +61,6 @@ pub fn get_verifications<'a>(
             "VerifySignatureControlComponentPublicKeys",
             fn_0203_verify_signature_control_component_public_keys,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifySignatureControlComponentPublicKeys",
@@ -74,7 +71,6 @@ pub fn get_verifications<'a>(
             "VerifySignatureSetupComponentTallyData",
             fn_0204_verify_signature_setup_component_tally_data,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifySignatureSetupComponentTallyData",
@@ -85,7 +81,6 @@ pub fn get_verifications<'a>(
             "VerifySignatureElectionEventContext",
             fn_0205_verify_signature_election_event_context,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifySignatureElectionEventContext",
@@ -96,7 +91,7 @@ pub fn get_verifications<'a>(
 
 fn fn_0201_verify_signature_canton_config<D: VerificationDirectoryTrait>(
     dir: &D,
-    config: &'static VerifierConfig,
+    run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -111,14 +106,18 @@ fn fn_0201_verify_signature_canton_config<D: VerificationDirectoryTrait>(
         }
     };
     result.append_with_context(
-        &verify_signature_for_object(ee_config.as_ref(), config),
+        &verify_signature_for_object(
+            ee_config.as_ref(),
+            context_dir.election_event_configuration_file(),
+            run_context,
+        ),
         "election_event_configuration",
     )
 }
 
 fn fn_0202_verify_signature_setup_component_public_keys<D: VerificationDirectoryTrait>(
     dir: &D,
-    config: &'static VerifierConfig,
+    run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -135,14 +134,18 @@ fn fn_0202_verify_signature_setup_component_public_keys<D: VerificationDirectory
         }
     };
     result.append_with_context(
-        &verify_signature_for_object(payload.as_ref(), config),
+        &verify_signature_for_object(
+            payload.as_ref(),
+            context_dir.setup_component_public_keys_payload_file(),
+            run_context,
+        ),
         "setup_component_public_keys_payload",
     )
 }
 
 fn fn_0203_verify_signature_control_component_public_keys<D: VerificationDirectoryTrait>(
     dir: &D,
-    config: &'static VerifierConfig,
+    run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -150,7 +153,13 @@ fn fn_0203_verify_signature_control_component_public_keys<D: VerificationDirecto
         trace!("Verification 2.03 for cc {}", i);
         match cc {
             Ok(cc) => result.append_with_context(
-                &verify_signature_for_object(cc.as_ref(), config),
+                &verify_signature_for_object(
+                    cc.as_ref(),
+                    &context_dir
+                        .control_component_public_keys_payload_group()
+                        .get_file_with_number(i),
+                    run_context,
+                ),
                 format!("control_component_public_keys_payload_{i}"),
             ),
             Err(e) => result.push(VerificationEvent::new_error_from_error(&e).add_context(
@@ -162,7 +171,7 @@ fn fn_0203_verify_signature_control_component_public_keys<D: VerificationDirecto
 
 fn fn_0204_verify_signature_setup_component_tally_data<D: VerificationDirectoryTrait>(
     dir: &D,
-    config: &'static VerifierConfig,
+    run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -170,7 +179,11 @@ fn fn_0204_verify_signature_setup_component_tally_data<D: VerificationDirectoryT
         trace!("Verification 2.04 for vcs_dir {}", d.name());
         match d.setup_component_tally_data_payload() {
             Ok(p) => result.append_with_context(
-                &verify_signature_for_object(p.as_ref(), config),
+                &verify_signature_for_object(
+                    p.as_ref(),
+                    d.setup_component_tally_data_payload_file(),
+                    run_context,
+                ),
                 format!("{}/setup_component_tally_data_payload.json", d.name(),),
             ),
             Err(e) => result.push(VerificationEvent::new_error_from_error(&e).add_context(
@@ -182,7 +195,7 @@ fn fn_0204_verify_signature_setup_component_tally_data<D: VerificationDirectoryT
 
 fn fn_0205_verify_signature_election_event_context<D: VerificationDirectoryTrait>(
     dir: &D,
-    config: &'static VerifierConfig,
+    run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -199,7 +212,11 @@ fn fn_0205_verify_signature_election_event_context<D: VerificationDirectoryTrait
         }
     };
     result.append_with_context(
-        &verify_signature_for_object(rp.as_ref(), config),
+        &verify_signature_for_object(
+            rp.as_ref(),
+            context_dir.election_event_context_payload_file(),
+            run_context,
+        ),
         "election_event_context_payload",
     )
 }
@@ -211,17 +228,18 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_setup_dir,
+            CONTEXT_TEST, get_test_verifier_mock_setup_dir,
             get_test_verifier_setup_dir as get_verifier_dir,
         },
         consts::NUMBER_CONTROL_COMPONENTS,
+        direct_trust::SignatureFailureCause,
     };
 
     #[test]
     fn test_0201() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0201_verify_signature_canton_config(&dir, &CONFIG_TEST, &mut result);
+        fn_0201_verify_signature_canton_config(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for e in result.errors() {
                 println!("{e:?}");
@@ -237,7 +255,7 @@ mod test {
     fn test_0202() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0202_verify_signature_setup_component_public_keys(&dir, &CONFIG_TEST, &mut result);
+        fn_0202_verify_signature_setup_component_public_keys(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -245,15 +263,23 @@ mod test {
     fn test_0203() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0203_verify_signature_control_component_public_keys(&dir, &CONFIG_TEST, &mut result);
+        fn_0203_verify_signature_control_component_public_keys(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
+        // One information per certificate, not per signature
+        assert!(result.informations().is_empty());
+        assert_eq!(
+            Some(result.verified_signatures().values().sum::<usize>()),
+            result.items_checked()
+        );
+        result.summarize_verified_signatures();
+        assert_eq!(result.informations().len(), 4);
     }
 
     #[test]
     fn test_0204() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0204_verify_signature_setup_component_tally_data(&dir, &CONFIG_TEST, &mut result);
+        fn_0204_verify_signature_setup_component_tally_data(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -261,7 +287,7 @@ mod test {
     fn test_0205() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0205_verify_signature_election_event_context(&dir, &CONFIG_TEST, &mut result);
+        fn_0205_verify_signature_election_event_context(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -284,10 +310,14 @@ mod test {
             .context_mut()
             .mock_election_event_configuration_string(new_input);
         let mut result = VerificationResult::new();
-        fn_0201_verify_signature_canton_config(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_0201_verify_signature_canton_config(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.is_ok());
         assert!(!result.has_errors());
         assert_eq!(result.failures().len(), 1);
+        assert_eq!(
+            result.failures()[0].signature_failure_cause(),
+            Some(&SignatureFailureCause::CryptographicMismatch)
+        );
     }
 
     #[test]
@@ -299,10 +329,14 @@ mod test {
                 d.encryption_group.set_p(&Integer::from(1234usize));
             });
         let mut result = VerificationResult::new();
-        fn_0202_verify_signature_setup_component_public_keys(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_0202_verify_signature_setup_component_public_keys(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.is_ok());
         assert!(!result.has_errors());
         assert_eq!(result.failures().len(), 1);
+        assert_eq!(
+            result.failures()[0].signature_failure_cause(),
+            Some(&SignatureFailureCause::CryptographicMismatch)
+        );
     }
 
     #[test]
@@ -317,7 +351,7 @@ mod test {
             let mut result = VerificationResult::new();
             fn_0203_verify_signature_control_component_public_keys(
                 &mock_dir,
-                &CONFIG_TEST,
+                &CONTEXT_TEST,
                 &mut result,
             );
             assert!(!result.is_ok());
@@ -345,7 +379,7 @@ mod test {
             let mut result = VerificationResult::new();
             fn_0204_verify_signature_setup_component_tally_data(
                 &mock_dir,
-                &CONFIG_TEST,
+                &CONTEXT_TEST,
                 &mut result,
             );
             assert!(!result.is_ok());
@@ -363,7 +397,7 @@ mod test {
                 d.encryption_group.set_p(&Integer::from(1234usize));
             });
         let mut result = VerificationResult::new();
-        fn_0205_verify_signature_election_event_context(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_0205_verify_signature_election_event_context(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.is_ok());
         assert!(!result.has_errors());
         assert_eq!(result.failures().len(), 1);
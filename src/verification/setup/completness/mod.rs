@@ -21,21 +21,18 @@ use super::super::{
     verifications::Verification,
 };
 use crate::{
-    config::VerifierConfig,
     file_structure::{CompletnessTestTrait, VerificationDirectoryTrait},
-    verification::{VerificationError, VerificationErrorImpl},
+    verification::{RunContext, VerificationError, VerificationErrorImpl},
 };
 
 pub fn get_verifications<'a>(
     metadata_list: &'a VerificationMetaDataList,
-    config: &'static VerifierConfig,
 ) -> Result<VerificationList<'a>, VerificationError> {
     Ok(VerificationList(vec![Verification::new(
         "01.01",
         "VerifySetupCompleteness",
         fn_0101_verify_setup_completeness,
         metadata_list,
-        config,
     )
     .map_err(|e| VerificationErrorImpl::GetVerification {
         name: "VerifySetupCompleteness",
@@ -45,7 +42,7 @@ pub fn get_verifications<'a>(
 
 fn fn_0101_verify_setup_completeness<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir: &<D as VerificationDirectoryTrait>::ContextDirType = dir.context();
@@ -58,13 +55,13 @@ fn fn_0101_verify_setup_completeness<D: VerificationDirectoryTrait>(
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::config::test::{get_test_verifier_setup_dir as get_verifier_dir, CONFIG_TEST};
+    use crate::config::test::{get_test_verifier_setup_dir as get_verifier_dir, CONTEXT_TEST};
 
     #[test]
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0101_verify_setup_completeness(&dir, &CONFIG_TEST, &mut result);
+        fn_0101_verify_setup_completeness(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 }
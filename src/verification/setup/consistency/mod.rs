@@ -32,14 +32,10 @@ mod v0314_total_voters_consistency;
 use super::super::{
     meta_data::VerificationMetaDataList, suite::VerificationList, verifications::Verification,
 };
-use crate::{
-    config::VerifierConfig,
-    verification::{VerificationError, VerificationErrorImpl},
-};
+use crate::verification::{VerificationError, VerificationErrorImpl};
 
 pub fn get_verifications<'a>(
     metadata_list: &'a VerificationMetaDataList,
-    config: &'static VerifierConfig,
 ) -> Result<VerificationList<'a>, VerificationError> {
     Ok(VerificationList(vec![
         Verification::new(
@@ -47,7 +43,6 @@ pub fn get_verifications<'a>(
             "VerifyEncryptionGroupConsistency",
             v0301_encryption_group_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyEncryptionGroupConsistency",
@@ -58,7 +53,6 @@ pub fn get_verifications<'a>(
             "VerifyNodeIdsConsistency",
             v0302_verify_node_ids_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyNodeIdsConsistency",
@@ -69,7 +63,6 @@ pub fn get_verifications<'a>(
             "VerifyFileNameNodeIdsConsistency",
             v0303_file_name_node_ids_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyFileNameNodeIdsConsistency",
@@ -80,7 +73,6 @@ pub fn get_verifications<'a>(
             "VerifyElectionEventIdConsistency",
             v0304_election_event_id_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyElectionEventIdConsistency",
@@ -91,7 +83,6 @@ pub fn get_verifications<'a>(
             "VerifyVerificationCardSetIdsConsistency",
             v0305_verification_card_set_ids_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyVerificationCardSetIdsConsistency",
@@ -102,7 +93,6 @@ pub fn get_verifications<'a>(
             "VerifyFileNameVerificationCardSetIdsConsistency",
             v0306_file_name_verification_card_set_ids_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyFileNameVerificationCardSetIdsConsistency",
@@ -113,7 +103,6 @@ pub fn get_verifications<'a>(
             "VerifyVerificationCardIdsConsistency",
             v0307_verification_card_ids_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyVerificationCardIdsConsistency",
@@ -124,7 +113,6 @@ pub fn get_verifications<'a>(
             "VerifyCCRChoiceReturnCodesPublicKeyConsistency",
             v0308_ccr_choice_return_codes_pk_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyCCRChoiceReturnCodesPublicKeyConsistency",
@@ -135,7 +123,6 @@ pub fn get_verifications<'a>(
             "VerifyCCMElectionPublicKeyConsistency",
             v0309_ccm_election_pk_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyCCMElectionPublicKeyConsistency",
@@ -146,7 +133,6 @@ pub fn get_verifications<'a>(
             "VerifyCCMAndCCRSchnorrProofsConsistency",
             v0310_ccm_and_ccr_schnorr_proofs_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyCCMAndCCRSchnorrProofsConsistency",
@@ -157,7 +143,6 @@ pub fn get_verifications<'a>(
             "VerifyChoiceReturnCodesPublicKeyConsistency",
             v0311_choice_return_codes_public_key_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyChoiceReturnCodesPublicKeyConsistency",
@@ -168,7 +153,6 @@ pub fn get_verifications<'a>(
             "VerifyElectionPublicKeyConsistency",
             v0312_election_pk_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyElectionPublicKeyConsistency",
@@ -179,7 +163,6 @@ pub fn get_verifications<'a>(
             "VerifyPrimesMappingTableConsistency",
             v0313_primes_mapping_table_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyPrimesMappingTableConsistency",
@@ -190,7 +173,6 @@ pub fn get_verifications<'a>(
             "VerifyTotalVotersConsistency",
             v0314_total_voters_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyTotalVotersConsistency",
@@ -16,11 +16,11 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         VerificationDirectoryTrait,
         context_directory::{ContextDirectoryTrait, ContextVCSDirectoryTrait},
     },
+    verification::RunContext,
 };
 use rust_ev_system_library::rust_ev_crypto_primitives::prelude::elgamal::EncryptionParameters;
 
@@ -62,7 +62,7 @@ fn verify_encryption_group_for_context_vcs_dir<V: ContextVCSDirectoryTrait>(
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let config_dir = dir.context();
@@ -112,15 +112,19 @@ mod test {
     use rust_ev_system_library::rust_ev_crypto_primitives::prelude::Integer;
 
     use super::*;
-    use crate::{config::test::{
-        get_test_verifier_mock_setup_dir as get_mock_verifier_dir, get_test_verifier_setup_dir as get_verifier_dir, CONFIG_TEST
-    }, consts::NUMBER_CONTROL_COMPONENTS};
+    use crate::{
+        config::test::{
+            CONTEXT_TEST, get_test_verifier_mock_setup_dir as get_mock_verifier_dir,
+            get_test_verifier_setup_dir as get_verifier_dir,
+        },
+        consts::NUMBER_CONTROL_COMPONENTS,
+    };
 
     #[test]
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -164,38 +168,38 @@ mod test {
         // p
         let mut result = VerificationResult::new();
         let mut mock_dir = get_mock_verifier_dir();
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
         mock_dir
             .context_mut()
             .mock_control_component_public_keys_payload(2, |d| {
                 d.encryption_group.set_p(&Integer::from(1234usize));
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
         // q
         let mut result = VerificationResult::new();
         let mut mock_dir = get_mock_verifier_dir();
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
         mock_dir
             .context_mut()
             .mock_control_component_public_keys_payload(2, |d| {
                 d.encryption_group.set_q(&Integer::from(1234usize));
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
         // g
         let mut result = VerificationResult::new();
         let mut mock_dir = get_mock_verifier_dir();
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
         mock_dir
             .context_mut()
             .mock_control_component_public_keys_payload(2, |d| {
                 d.encryption_group.set_g(&Integer::from(1234usize));
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
     }
 
@@ -209,7 +213,7 @@ mod test {
             .mock_setup_component_public_keys_payload(|d| {
                 d.encryption_group.set_p(&Integer::from(1234usize));
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
         // q
         let mut result = VerificationResult::new();
@@ -219,7 +223,7 @@ mod test {
             .mock_setup_component_public_keys_payload(|d| {
                 d.encryption_group.set_q(&Integer::from(1234usize));
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
         // g
         let mut result = VerificationResult::new();
@@ -229,7 +233,7 @@ mod test {
             .mock_setup_component_public_keys_payload(|d| {
                 d.encryption_group.set_g(&Integer::from(1234usize));
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
     }
 
@@ -244,7 +248,7 @@ mod test {
                 .mock_control_component_public_keys_payload(j, |d| {
                     d.encryption_group.set_p(&Integer::from(1234usize));
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(result.has_failures());
             // q
             let mut result = VerificationResult::new();
@@ -254,7 +258,7 @@ mod test {
                 .mock_control_component_public_keys_payload(j, |d| {
                     d.encryption_group.set_q(&Integer::from(1234usize));
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(result.has_failures());
             // g
             let mut result = VerificationResult::new();
@@ -264,7 +268,7 @@ mod test {
                 .mock_control_component_public_keys_payload(j, |d| {
                     d.encryption_group.set_g(&Integer::from(1234usize));
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(result.has_failures());
         }
     }
@@ -280,7 +284,7 @@ mod test {
                 .mock_setup_component_tally_data_payload(|d| {
                     d.encryption_group.set_p(&Integer::from(1234usize));
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(result.has_failures());
             // q
             let mut result = VerificationResult::new();
@@ -289,7 +293,7 @@ mod test {
                 .mock_setup_component_tally_data_payload(|d| {
                     d.encryption_group.set_q(&Integer::from(1234usize));
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(result.has_failures());
             // g
             let mut result = VerificationResult::new();
@@ -298,7 +302,7 @@ mod test {
                 .mock_setup_component_tally_data_payload(|d| {
                     d.encryption_group.set_g(&Integer::from(1234usize));
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(result.has_failures());
         }
     }
@@ -16,15 +16,15 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     consts::CONTROL_COMPONENT_ID_LIST,
     file_structure::{ContextDirectoryTrait, VerificationDirectoryTrait},
+    verification::RunContext,
 };
 use std::collections::HashSet;
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -63,7 +63,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_setup_dir,
+            CONTEXT_TEST, get_test_verifier_mock_setup_dir,
             get_test_verifier_setup_dir as get_verifier_dir, test_data_path,
         },
         consts::{NUMBER_CONTROL_COMPONENTS, test::MIXED_CONTROL_COMPONENT_ID_LIST},
@@ -75,7 +75,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -90,7 +90,7 @@ mod test {
                     d.control_component_public_keys.node_id =
                         MIXED_CONTROL_COMPONENT_ID_LIST[j - 1];
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "j={}", j);
             assert!(result.has_failures(), "j={}", j);
         }
@@ -104,7 +104,7 @@ mod test {
             mock_dir
                 .context_mut()
                 .mock_control_component_public_keys_payload_as_deleted(j);
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "j={}", j);
             assert!(result.has_failures(), "j={}", j);
         }
@@ -121,7 +121,7 @@ mod test {
         {
             let dir = VerificationDirectory::new(&VerificationPeriod::Setup, &p);
             let mut result = VerificationResult::new();
-            fn_verification(&dir, &CONFIG_TEST, &mut result);
+            fn_verification(&dir, &CONTEXT_TEST, &mut result);
             assert!(
                 !result.has_errors(),
                 "path={}",
@@ -16,13 +16,13 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{VerificationDirectoryTrait, context_directory::ContextDirectoryTrait},
+    verification::RunContext,
 };
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -58,7 +58,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_setup_dir as get_verifier_dir, test_data_path,
+            CONTEXT_TEST, get_test_verifier_setup_dir as get_verifier_dir, test_data_path,
         },
         file_structure::VerificationDirectory,
         verification::VerificationPeriod,
@@ -68,7 +68,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for e in result.errors() {
                 println!("{}", e);
@@ -91,7 +91,7 @@ mod test {
         {
             let dir = VerificationDirectory::new(&VerificationPeriod::Setup, &p);
             let mut result = VerificationResult::new();
-            fn_verification(&dir, &CONFIG_TEST, &mut result);
+            fn_verification(&dir, &CONTEXT_TEST, &mut result);
             assert!(
                 !result.has_errors(),
                 "path={}",
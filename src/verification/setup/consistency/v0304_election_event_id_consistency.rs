@@ -16,11 +16,11 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         VerificationDirectoryTrait,
         context_directory::{ContextDirectoryTrait, ContextVCSDirectoryTrait},
     },
+    verification::RunContext,
 };
 
 fn test_election_event_id(ee_id: &String, expected: &String) -> VerificationResult {
@@ -55,7 +55,7 @@ fn test_ee_id_for_context_vcs_dir<V: ContextVCSDirectoryTrait>(
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -102,15 +102,19 @@ pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{config::test::{
-        get_test_verifier_mock_setup_dir, get_test_verifier_setup_dir as get_verifier_dir, CONFIG_TEST
-    }, consts::NUMBER_CONTROL_COMPONENTS};
+    use crate::{
+        config::test::{
+            CONTEXT_TEST, get_test_verifier_mock_setup_dir,
+            get_test_verifier_setup_dir as get_verifier_dir,
+        },
+        consts::NUMBER_CONTROL_COMPONENTS,
+    };
 
     #[test]
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -124,7 +128,7 @@ mod test {
                 d.election_event_context.election_event_id =
                     "modified-election-event-id".to_string();
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.has_errors());
         assert!(result.has_failures());
     }
@@ -138,7 +142,7 @@ mod test {
             .mock_setup_component_public_keys_payload(|d| {
                 d.election_event_id = "modified-election-event-id".to_string();
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.has_errors());
         assert!(result.has_failures());
     }
@@ -153,7 +157,7 @@ mod test {
                 .mock_control_component_public_keys_payload(j, |d| {
                     d.election_event_id = "modified-election-event-id".to_string();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at CC {}", j);
             assert!(result.has_failures(), "Failed at CC {}", j);
         }
@@ -172,7 +176,7 @@ mod test {
                 .mock_setup_component_tally_data_payload(|d| {
                     d.election_event_id = "modified-election-event-id".to_string();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at vcs {}", vcs.name());
             assert!(result.has_failures(), "Failed at VCS {}", vcs.name());
         }
@@ -16,11 +16,11 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         VerificationDirectoryTrait,
         context_directory::{ContextDirectoryTrait, ContextVCSDirectoryTrait},
     },
+    verification::RunContext,
 };
 
 fn verrify_card_set_ids_context_vcs<V: ContextVCSDirectoryTrait>(
@@ -51,7 +51,7 @@ fn verrify_card_set_ids_context_vcs<V: ContextVCSDirectoryTrait>(
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -68,7 +68,7 @@ pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
 mod test {
     use super::*;
     use crate::config::test::{
-        CONFIG_TEST, get_test_verifier_mock_setup_dir,
+        CONTEXT_TEST, get_test_verifier_mock_setup_dir,
         get_test_verifier_setup_dir as get_verifier_dir,
     };
 
@@ -76,7 +76,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -93,7 +93,7 @@ mod test {
                 .mock_setup_component_tally_data_payload(|d| {
                     d.verification_card_set_id = "modified-vcs_id".to_string();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at vcs {}", vcs.name());
             assert!(result.has_failures(), "Failed at VCS {}", vcs.name());
         }
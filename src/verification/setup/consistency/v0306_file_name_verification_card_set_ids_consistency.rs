@@ -16,8 +16,8 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{VerificationDirectoryTrait, context_directory::ContextDirectoryTrait},
+    verification::RunContext,
 };
 
 fn verify_file_name_correct(vcs_ids: &[&str], dir_names: &[String]) -> VerificationResult {
@@ -38,7 +38,7 @@ fn verify_file_name_correct(vcs_ids: &[&str], dir_names: &[String]) -> Verificat
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -65,7 +65,7 @@ pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
 mod test {
     use super::*;
     use crate::config::test::{
-        CONFIG_TEST, get_test_verifier_mock_setup_dir as get_mock_verifier_dir,
+        CONTEXT_TEST, get_test_verifier_mock_setup_dir as get_mock_verifier_dir,
         get_test_verifier_setup_dir as get_verifier_dir,
     };
 
@@ -73,7 +73,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for e in result.errors() {
                 println!("{}", e);
@@ -96,7 +96,7 @@ mod test {
                 .push(context);
         });
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
         assert!(!result.has_errors());
     }
@@ -114,7 +114,7 @@ mod test {
                     d.election_event_context.verification_card_set_contexts[i]
                         .verification_card_set_id = "modified-vcs_id".to_string();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at vcs {}", i);
             assert!(result.has_failures(), "Failed at VCS {}", i);
         }
@@ -129,7 +129,7 @@ mod test {
                 .pop();
         });
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
         assert!(!result.has_errors());
     }
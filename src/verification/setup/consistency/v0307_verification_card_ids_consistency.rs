@@ -16,7 +16,6 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     data_structures::context::{
         election_event_context_payload::ElectionEventContext,
         setup_component_tally_data_payload::SetupComponentTallyDataPayload,
@@ -25,6 +24,7 @@ use crate::{
         VerificationDirectoryTrait,
         context_directory::{ContextDirectoryTrait, ContextVCSDirectoryTrait},
     },
+    verification::RunContext,
 };
 use std::collections::HashSet;
 
@@ -75,7 +75,7 @@ fn verrify_card_ids_context_vcs(
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -121,7 +121,7 @@ pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
 mod test {
     use super::*;
     use crate::config::test::{
-        CONFIG_TEST, get_test_verifier_mock_setup_dir,
+        CONTEXT_TEST, get_test_verifier_mock_setup_dir,
         get_test_verifier_setup_dir as get_verifier_dir,
     };
 
@@ -129,7 +129,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for e in result.errors() {
                 println!("{}", e);
@@ -154,7 +154,7 @@ mod test {
                 .mock_setup_component_tally_data_payload(|d| {
                     d.verification_card_ids.push("new-vc-id".to_string());
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at vcs {}", vcs.name());
             assert!(result.has_failures(), "Failed at VCS {}", vcs.name());
         }
@@ -173,7 +173,7 @@ mod test {
                 .mock_setup_component_tally_data_payload(|d| {
                     d.verification_card_ids.pop();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at vcs {}", vcs.name());
             assert!(result.has_failures(), "Failed at VCS {}", vcs.name());
         }
@@ -198,7 +198,7 @@ mod test {
                             .push(d.verification_card_ids[0].clone());
                     }
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at vcs {}", vcs.name());
             assert!(result.has_failures(), "Failed at VCS {}", vcs.name());
         }
@@ -243,7 +243,7 @@ mod test {
                     .mock_setup_component_tally_data_payload(|d| {
                         d.verification_card_ids[i] = first_vc_id.clone();
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors(), "Failed at vcs {}", vcs.name());
                 assert!(result.has_failures(), "Failed at VCS {}", vcs.name());
             }
@@ -268,7 +268,7 @@ mod test {
                     d.election_event_context.verification_card_set_contexts[i]
                         .number_of_eligible_voters += 1
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
         }
@@ -16,13 +16,13 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{VerificationDirectoryTrait, context_directory::ContextDirectoryTrait},
+    verification::RunContext,
 };
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -92,15 +92,19 @@ mod test {
     use rust_ev_system_library::rust_ev_crypto_primitives::prelude::Integer;
 
     use super::*;
-    use crate::{config::test::{
-        get_test_verifier_mock_setup_dir, get_test_verifier_setup_dir as get_verifier_dir, CONFIG_TEST
-    }, consts::NUMBER_CONTROL_COMPONENTS};
+    use crate::{
+        config::test::{
+            CONTEXT_TEST, get_test_verifier_mock_setup_dir,
+            get_test_verifier_setup_dir as get_verifier_dir,
+        },
+        consts::NUMBER_CONTROL_COMPONENTS,
+    };
 
     #[test]
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -132,7 +136,7 @@ mod test {
                             .unwrap();
                         cc_pk.ccmj_election_public_key[i] = Integer::from(111usize);
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors(), "Failed at CC {} at pos {}", j, i);
                 assert!(result.has_failures(), "Failed at CC {} at pos {}", j, i);
             }
@@ -155,7 +159,7 @@ mod test {
                         .unwrap();
                     cc_pk.ccmj_election_public_key.push(Integer::from(111usize));
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at CC {}", j);
             assert!(result.has_failures(), "Failed at CC {}", j);
         }
@@ -177,7 +181,7 @@ mod test {
                         .unwrap();
                     cc_pk.ccmj_election_public_key.pop();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at CC {}", j);
             assert!(result.has_failures(), "Failed at CC {}", j);
         }
@@ -204,7 +208,7 @@ mod test {
                         d.control_component_public_keys.ccmj_election_public_key[i] =
                             Integer::from(111usize);
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors(), "Failed at CC {} at pos {}", j, i);
                 assert!(result.has_failures(), "Failed at CC {} at pos {}", j, i);
             }
@@ -223,7 +227,7 @@ mod test {
                         .ccmj_election_public_key
                         .push(Integer::from(111usize));
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at CC {}", j);
             assert!(result.has_failures(), "Failed at CC {}", j);
         }
@@ -241,7 +245,7 @@ mod test {
                         .ccmj_election_public_key
                         .pop();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at CC {}", j);
             assert!(result.has_failures(), "Failed at CC {}", j);
         }
@@ -16,12 +16,12 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     data_structures::{
         common_types::SchnorrProof,
         context::control_component_public_keys_payload::ControlComponentPublicKeys,
     },
     file_structure::{VerificationDirectoryTrait, context_directory::ContextDirectoryTrait},
+    verification::RunContext,
 };
 use std::iter::zip;
 
@@ -69,7 +69,7 @@ fn validate_ccm_and_ccr_schorr_proofs(
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -133,7 +133,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_setup_dir,
+            CONTEXT_TEST, get_test_verifier_mock_setup_dir,
             get_test_verifier_setup_dir as get_verifier_dir,
         },
         consts::NUMBER_CONTROL_COMPONENTS,
@@ -143,7 +143,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -176,7 +176,7 @@ mod test {
                             .unwrap();
                         cc_pk.ccmj_schnorr_proofs[i].e = Integer::from(111usize);
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors(), "Failed at CC {} at pos {}", j, i);
                 assert!(result.has_failures(), "Failed at CC {} at pos {}", j, i);
                 // z
@@ -193,7 +193,7 @@ mod test {
                             .unwrap();
                         cc_pk.ccmj_schnorr_proofs[i].z = Integer::from(111usize);
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors(), "Failed at CC {} at pos {}", j, i);
                 assert!(result.has_failures(), "Failed at CC {} at pos {}", j, i);
             }
@@ -229,7 +229,7 @@ mod test {
                             .unwrap();
                         cc_pk.ccrj_schnorr_proofs[i].e = Integer::from(111usize);
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors(), "Failed at CC {} at pos {}", j, i);
                 assert!(result.has_failures(), "Failed at CC {} at pos {}", j, i);
                 // z
@@ -246,7 +246,7 @@ mod test {
                             .unwrap();
                         cc_pk.ccrj_schnorr_proofs[i].z = Integer::from(111usize);
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors(), "Failed at CC {} at pos {}", j, i);
                 assert!(result.has_failures(), "Failed at CC {} at pos {}", j, i);
             }
@@ -272,7 +272,7 @@ mod test {
                         z: Integer::from(222usize),
                     });
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at CC {}", j);
             assert!(result.has_failures(), "Failed at CC {}", j);
         }
@@ -297,7 +297,7 @@ mod test {
                         z: Integer::from(222usize),
                     });
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at CC {}", j);
             assert!(result.has_failures(), "Failed at CC {}", j);
         }
@@ -319,7 +319,7 @@ mod test {
                         .unwrap();
                     cc_pk.ccmj_schnorr_proofs.pop();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at CC {}", j);
             assert!(result.has_failures(), "Failed at CC {}", j);
         }
@@ -341,7 +341,7 @@ mod test {
                         .unwrap();
                     cc_pk.ccrj_schnorr_proofs.pop();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at CC {}", j);
             assert!(result.has_failures(), "Failed at CC {}", j);
         }
@@ -369,7 +369,7 @@ mod test {
                         d.control_component_public_keys.ccmj_schnorr_proofs[i].e =
                             Integer::from(111usize);
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors(), "Failed at CC {} at pos {}", j, i);
                 assert!(result.has_failures(), "Failed at CC {} at pos {}", j, i);
                 // z
@@ -381,7 +381,7 @@ mod test {
                         d.control_component_public_keys.ccmj_schnorr_proofs[i].z =
                             Integer::from(111usize);
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors(), "Failed at CC {} at pos {}", j, i);
                 assert!(result.has_failures(), "Failed at CC {} at pos {}", j, i);
             }
@@ -410,7 +410,7 @@ mod test {
                         d.control_component_public_keys.ccrj_schnorr_proofs[i].e =
                             Integer::from(111usize);
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors(), "Failed at CC {} at pos {}", j, i);
                 assert!(result.has_failures(), "Failed at CC {} at pos {}", j, i);
                 // z
@@ -422,7 +422,7 @@ mod test {
                         d.control_component_public_keys.ccrj_schnorr_proofs[i].z =
                             Integer::from(111usize);
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors(), "Failed at CC {} at pos {}", j, i);
                 assert!(result.has_failures(), "Failed at CC {} at pos {}", j, i);
             }
@@ -444,7 +444,7 @@ mod test {
                             z: Integer::from(222usize),
                         });
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at CC {}", j);
             assert!(result.has_failures(), "Failed at CC {}", j);
         }
@@ -465,7 +465,7 @@ mod test {
                             z: Integer::from(222usize),
                         });
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at CC {}", j);
             assert!(result.has_failures(), "Failed at CC {}", j);
         }
@@ -481,7 +481,7 @@ mod test {
                 .mock_control_component_public_keys_payload(j, |d| {
                     d.control_component_public_keys.ccmj_schnorr_proofs.pop();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at CC {}", j);
             assert!(result.has_failures(), "Failed at CC {}", j);
         }
@@ -497,7 +497,7 @@ mod test {
                 .mock_control_component_public_keys_payload(j, |d| {
                     d.control_component_public_keys.ccrj_schnorr_proofs.pop();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at CC {}", j);
             assert!(result.has_failures(), "Failed at CC {}", j);
         }
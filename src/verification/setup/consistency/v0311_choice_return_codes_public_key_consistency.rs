@@ -16,15 +16,15 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{VerificationDirectoryTrait, context_directory::ContextDirectoryTrait},
+    verification::RunContext,
 };
 use rust_ev_system_library::rust_ev_crypto_primitives::prelude::Integer;
 use rust_ev_system_library::rust_ev_crypto_primitives::prelude::{ConstantsTrait, OperationsTrait};
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -121,7 +121,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_setup_dir,
+            CONTEXT_TEST, get_test_verifier_mock_setup_dir,
             get_test_verifier_setup_dir as get_verifier_dir,
         },
         consts::NUMBER_CONTROL_COMPONENTS,
@@ -141,7 +141,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -156,7 +156,7 @@ mod test {
                     d.setup_component_public_keys
                         .choice_return_codes_encryption_public_key[i] = Integer::from(111u32)
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
         }
@@ -173,7 +173,7 @@ mod test {
                     .choice_return_codes_encryption_public_key
                     .push(Integer::from(111u32));
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.has_errors());
         assert!(result.has_failures());
     }
@@ -189,7 +189,7 @@ mod test {
                     .choice_return_codes_encryption_public_key
                     .pop();
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.has_errors());
         assert!(result.has_failures());
     }
@@ -207,7 +207,7 @@ mod test {
                             .ccrj_choice_return_codes_encryption_public_key[i] =
                             Integer::from(111u32)
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
             }
@@ -226,7 +226,7 @@ mod test {
                         .ccrj_choice_return_codes_encryption_public_key
                         .push(Integer::from(111u32));
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
         }
@@ -244,7 +244,7 @@ mod test {
                         .ccrj_choice_return_codes_encryption_public_key
                         .pop();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
         }
@@ -16,15 +16,15 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{VerificationDirectoryTrait, context_directory::ContextDirectoryTrait},
+    verification::RunContext,
 };
 use rust_ev_system_library::rust_ev_crypto_primitives::prelude::Integer;
 use rust_ev_system_library::rust_ev_crypto_primitives::prelude::{ConstantsTrait, OperationsTrait};
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -137,7 +137,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_setup_dir, get_test_verifier_setup_dir,
+            CONTEXT_TEST, get_test_verifier_mock_setup_dir, get_test_verifier_setup_dir,
         },
         consts::NUMBER_CONTROL_COMPONENTS,
     };
@@ -146,7 +146,7 @@ mod test {
     fn test_ok() {
         let dir = get_test_verifier_setup_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(
             result.is_ok(),
             "errors: {:?} \n failures: {:?}",
@@ -166,7 +166,7 @@ mod test {
                     d.setup_component_public_keys.electoral_board_public_key[i] =
                         Integer::from(111u32)
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
         }
@@ -182,7 +182,7 @@ mod test {
                 .mock_setup_component_public_keys_payload(|d| {
                     d.setup_component_public_keys.election_public_key[i] = Integer::from(111u32)
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
         }
@@ -200,7 +200,7 @@ mod test {
                         d.control_component_public_keys.ccmj_election_public_key[i] =
                             Integer::from(111u32);
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
             }
@@ -218,7 +218,7 @@ mod test {
                     .electoral_board_public_key
                     .pop();
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.has_errors());
         assert!(result.has_failures());
     }
@@ -232,7 +232,7 @@ mod test {
             .mock_setup_component_public_keys_payload(|d| {
                 d.setup_component_public_keys.election_public_key.pop();
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.has_errors());
         assert!(result.has_failures());
     }
@@ -249,7 +249,7 @@ mod test {
                         .ccmj_election_public_key
                         .pop();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
         }
@@ -266,7 +266,7 @@ mod test {
                     .electoral_board_public_key
                     .push(Integer::from(111u32));
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.has_errors());
         assert!(result.has_failures());
     }
@@ -282,7 +282,7 @@ mod test {
                     .election_public_key
                     .push(Integer::from(111u32));
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.has_errors());
         assert!(result.has_failures());
     }
@@ -299,7 +299,7 @@ mod test {
                         .ccmj_election_public_key
                         .push(Integer::from(111u32));
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
         }
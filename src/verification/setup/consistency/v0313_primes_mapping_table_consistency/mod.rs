@@ -18,9 +18,9 @@ mod consistent_xml;
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     data_structures::context::election_event_context_payload::ElectionEventContext,
     file_structure::{VerificationDirectoryTrait, context_directory::ContextDirectoryTrait},
+    verification::RunContext,
 };
 use consistent_xml::verification_2_3_same_than_xml;
 use rust_ev_system_library::preliminaries::PTableElement;
@@ -28,7 +28,7 @@ use std::collections::HashMap;
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -112,7 +112,7 @@ fn verify_1_same_actual_voting_options(ee_context: &ElectionEventContext) -> Ver
 mod test {
     use super::*;
     use crate::config::test::{
-        CONFIG_TEST, get_test_verifier_mock_setup_dir,
+        CONTEXT_TEST, get_test_verifier_mock_setup_dir,
         get_test_verifier_setup_dir as get_verifier_dir,
     };
 
@@ -120,7 +120,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for r in result.errors_to_string() {
                 println!("{r:?}")
@@ -157,7 +157,7 @@ mod test {
                                 .p_table[j]
                                 .encoded_voting_option = 1usize
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(
                         !result.has_errors(),
                         "Failed at vcs {} in position {j} of pTable for encoded_voting_option change",
@@ -181,7 +181,7 @@ mod test {
                                 .p_table[j]
                                 .actual_voting_option = "Changed".to_string()
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(
                         !result.has_errors(),
                         "Failed at vcs {} in position {j} of pTable for actual_voting_option change",
@@ -205,7 +205,7 @@ mod test {
                                 .p_table[j]
                                 .semantic_information = "Changed".to_string()
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(
                         !result.has_errors(),
                         "Failed at vcs {} in position {j} of pTable for semantic_information change",
@@ -229,7 +229,7 @@ mod test {
                                 .p_table[j]
                                 .correctness_information = "Changed".to_string()
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(
                         !result.has_errors(),
                         "Failed at vcs {} in position {j} of pTable for correctness_information change",
@@ -267,7 +267,7 @@ mod test {
                         .p_table
                         .pop();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(
                 !result.has_errors(),
                 "Failed at vcs {}",
@@ -308,7 +308,7 @@ mod test {
                             correctness_information: "New".to_string(),
                         })
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(
                 !result.has_errors(),
                 "Failed at vcs {}",
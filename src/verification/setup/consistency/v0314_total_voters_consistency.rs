@@ -16,13 +16,13 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{VerificationDirectoryTrait, context_directory::ContextDirectoryTrait},
+    verification::RunContext,
 };
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -78,7 +78,7 @@ pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
 mod test {
     use super::*;
     use crate::config::test::{
-        CONFIG_TEST, get_test_verifier_mock_setup_dir,
+        CONTEXT_TEST, get_test_verifier_mock_setup_dir,
         get_test_verifier_setup_dir as get_verifier_dir,
     };
     use crate::data_structures::context::election_event_configuration::Voter;
@@ -88,7 +88,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -110,7 +110,7 @@ mod test {
                     d.election_event_context.verification_card_set_contexts[i]
                         .number_of_eligible_voters += 1
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
         }
@@ -130,7 +130,7 @@ mod test {
                     })
                 });
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.has_errors());
         assert!(result.has_failures());
     }
@@ -146,7 +146,7 @@ mod test {
                     d.register.pop();
                 });
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.has_errors());
         assert!(result.has_failures());
     }
@@ -21,13 +21,11 @@ mod v0504_key_generation_schnorr_proofs;
 
 use super::super::{suite::VerificationList, verifications::Verification};
 use crate::{
-    config::VerifierConfig,
     verification::{meta_data::VerificationMetaDataList, VerificationError, VerificationErrorImpl},
 };
 
 pub fn get_verifications<'a>(
     metadata_list: &'a VerificationMetaDataList,
-    config: &'static VerifierConfig,
 ) -> Result<VerificationList<'a>, VerificationError> {
     Ok(VerificationList(vec![
         Verification::new(
@@ -35,7 +33,6 @@ pub fn get_verifications<'a>(
             "VerifyEncryptionParameters",
             v0501_encryption_parameters::fn_0501_verify_encryption_parameters,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyEncryptionParameters",
@@ -46,7 +43,6 @@ pub fn get_verifications<'a>(
             "VerifySmallPrimeGroupMembers",
             v0502_verify_small_prime_group_members::fn_0502_verify_small_prime_group_members,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifySmallPrimeGroupMembers",
@@ -57,7 +53,6 @@ pub fn get_verifications<'a>(
             "VerifyVotingOptions",
             v0503_voting_options::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyVotingOptions",
@@ -68,7 +63,6 @@ pub fn get_verifications<'a>(
             "VerifySchnorrProofs",
             v0504_key_generation_schnorr_proofs::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifySchnorrProofs",
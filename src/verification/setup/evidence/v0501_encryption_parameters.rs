@@ -16,15 +16,15 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{VerificationDirectoryTrait, context_directory::ContextDirectoryTrait},
+    verification::RunContext,
 };
 
 use rust_ev_system_library::rust_ev_crypto_primitives::prelude::elgamal::EncryptionParameters;
 
 pub(super) fn fn_0501_verify_encryption_parameters<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -79,7 +79,7 @@ mod test {
 
     use super::*;
     use crate::config::test::{
-        CONFIG_TEST, get_test_verifier_mock_setup_dir,
+        CONTEXT_TEST, get_test_verifier_mock_setup_dir,
         get_test_verifier_setup_dir as get_verifier_dir,
     };
 
@@ -87,7 +87,7 @@ mod test {
     fn test_0501_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0501_verify_encryption_parameters(&dir, &CONFIG_TEST, &mut result);
+        fn_0501_verify_encryption_parameters(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -100,7 +100,7 @@ mod test {
             .mock_election_event_context_payload(|d| {
                 d.seed = "wrongseed".to_string();
             });
-        fn_0501_verify_encryption_parameters(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_0501_verify_encryption_parameters(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
     }
 
@@ -113,7 +113,7 @@ mod test {
             .mock_election_event_context_payload(|d| {
                 d.encryption_group.set_p(&Integer::from(13u32));
             });
-        fn_0501_verify_encryption_parameters(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_0501_verify_encryption_parameters(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
     }
 
@@ -126,7 +126,7 @@ mod test {
             .mock_election_event_context_payload(|d| {
                 d.encryption_group.set_q(&Integer::from(13u32));
             });
-        fn_0501_verify_encryption_parameters(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_0501_verify_encryption_parameters(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
     }
 
@@ -139,7 +139,7 @@ mod test {
             .mock_election_event_context_payload(|d| {
                 d.encryption_group.set_g(&Integer::from(13u32));
             });
-        fn_0501_verify_encryption_parameters(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_0501_verify_encryption_parameters(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
     }
 }
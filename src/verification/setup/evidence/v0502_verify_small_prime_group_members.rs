@@ -18,11 +18,12 @@ use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
     config::VerifierConfig,
     file_structure::{VerificationDirectoryTrait, context_directory::ContextDirectoryTrait},
+    verification::RunContext,
 };
 
 pub(super) fn fn_0502_verify_small_prime_group_members<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -80,7 +81,7 @@ mod test {
 
     use super::*;
     use crate::config::test::{
-        CONFIG_TEST, get_test_verifier_mock_setup_dir,
+        CONTEXT_TEST, get_test_verifier_mock_setup_dir,
         get_test_verifier_setup_dir as get_verifier_dir,
     };
 
@@ -88,7 +89,7 @@ mod test {
     fn test_0502_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0502_verify_small_prime_group_members(&dir, &CONFIG_TEST, &mut result);
+        fn_0502_verify_small_prime_group_members(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for e in result.errors() {
                 println!("{e:?}");
@@ -110,7 +111,7 @@ mod test {
                 d.encryption_group
                     .set_p(&(d.encryption_group.p() + Integer::from(2u32)));
             });
-        fn_0502_verify_small_prime_group_members(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_0502_verify_small_prime_group_members(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
     }
 
@@ -123,7 +124,7 @@ mod test {
             .mock_election_event_context_payload(|d| {
                 d.small_primes[1] = 17usize;
             });
-        fn_0502_verify_small_prime_group_members(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_0502_verify_small_prime_group_members(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
     }
 
@@ -136,7 +137,7 @@ mod test {
             .mock_election_event_context_payload(|d| {
                 d.small_primes = d.small_primes[10..d.small_primes.len() - 1].to_vec();
             });
-        fn_0502_verify_small_prime_group_members(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_0502_verify_small_prime_group_members(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
     }
 }
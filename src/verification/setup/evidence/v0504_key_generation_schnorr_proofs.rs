@@ -16,8 +16,8 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{VerificationDirectoryTrait, context_directory::ContextDirectoryTrait},
+    verification::RunContext,
 };
 use rust_ev_system_library::preliminaries::{
     GetHashElectionEventContextContext, VerifyKeyGenerationSchnorrProofsInput,
@@ -26,7 +26,7 @@ use rust_ev_system_library::preliminaries::{
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -152,7 +152,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_setup_dir,
+            CONTEXT_TEST, get_test_verifier_mock_setup_dir,
             get_test_verifier_setup_dir as get_verifier_dir,
         },
         consts::NUMBER_CONTROL_COMPONENTS,
@@ -162,7 +162,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(
             result.is_ok(),
             "errors: {:?} \n failures: {:?}",
@@ -188,7 +188,7 @@ mod test {
                             Integer::from(111u32)
                     });
 
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
             }
@@ -210,7 +210,7 @@ mod test {
                             .ccrj_schnorr_proofs[i]
                             .e = Integer::from(111u32)
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
                 // z
@@ -224,7 +224,7 @@ mod test {
                             .ccrj_schnorr_proofs[i]
                             .z = Integer::from(111u32)
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
             }
@@ -247,7 +247,7 @@ mod test {
                             .ccmj_election_public_key[i] = Integer::from(111u32)
                     });
 
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
             }
@@ -269,7 +269,7 @@ mod test {
                             .ccmj_schnorr_proofs[i]
                             .e = Integer::from(111u32)
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
                 // z
@@ -283,7 +283,7 @@ mod test {
                             .ccmj_schnorr_proofs[i]
                             .z = Integer::from(111u32)
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
             }
@@ -302,7 +302,7 @@ mod test {
                         Integer::from(111u32)
                 });
 
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
         }
@@ -320,7 +320,7 @@ mod test {
                     d.setup_component_public_keys.electoral_board_schnorr_proofs[i].e =
                         Integer::from(111u32)
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
             // z
@@ -332,7 +332,7 @@ mod test {
                     d.setup_component_public_keys.electoral_board_schnorr_proofs[i].z =
                         Integer::from(111u32)
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
         }
@@ -20,18 +20,18 @@ use super::super::{
     verifications::Verification,
 };
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         VerificationDirectoryTrait,
         context_directory::{ContextDirectoryTrait, ContextVCSDirectoryTrait},
     },
-    verification::{VerificationError, VerificationErrorImpl, meta_data::VerificationMetaDataList},
+    verification::{
+        RunContext, VerificationError, VerificationErrorImpl, meta_data::VerificationMetaDataList,
+    },
 };
 use rust_ev_system_library::rust_ev_crypto_primitives::prelude::{EmptyContext, VerifyDomainTrait};
 
 pub fn get_verifications<'a>(
     metadata_list: &'a VerificationMetaDataList,
-    config: &'static VerifierConfig,
 ) -> Result<VerificationList<'a>, VerificationError> {
     Ok(VerificationList(vec![
         Verification::new(
@@ -39,7 +39,6 @@ pub fn get_verifications<'a>(
             "VerifySetupIntegrity",
             fn_0401_verify_setup_integrity,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifySetupIntegrity",
@@ -130,7 +129,7 @@ fn validate_context_dir<C: ContextDirectoryTrait>(dir: &C, result: &mut Verifica
 
 fn fn_0401_verify_setup_integrity<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -140,13 +139,13 @@ fn fn_0401_verify_setup_integrity<D: VerificationDirectoryTrait>(
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::config::test::{CONFIG_TEST, get_test_verifier_setup_dir as get_verifier_dir};
+    use crate::config::test::{CONTEXT_TEST, get_test_verifier_setup_dir as get_verifier_dir};
 
     #[test]
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0401_verify_setup_integrity(&dir, &CONFIG_TEST, &mut result);
+        fn_0401_verify_setup_integrity(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 }
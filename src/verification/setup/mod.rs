@@ -25,16 +25,14 @@ use super::{
     meta_data::VerificationMetaDataList, suite::VerificationList, VerificationError,
     VerificationErrorImpl,
 };
-use crate::config::VerifierConfig;
 
 /// Collect the verifications of the submodules
 pub fn get_verifications<'a>(
     metadata_list: &'a VerificationMetaDataList,
-    config: &'static VerifierConfig,
 ) -> Result<VerificationList<'a>, VerificationError> {
     let mut res = VerificationList(vec![]);
     res.0.append(
-        &mut authenticity::get_verifications(metadata_list, config)
+        &mut authenticity::get_verifications(metadata_list)
             .map_err(|e| VerificationErrorImpl::GetCategory {
                 category: "Authenticity",
                 source: Box::new(e),
@@ -42,7 +40,7 @@ pub fn get_verifications<'a>(
             .0,
     );
     res.0.append(
-        &mut completness::get_verifications(metadata_list, config)
+        &mut completness::get_verifications(metadata_list)
             .map_err(|e| VerificationErrorImpl::GetCategory {
                 category: "Completness",
                 source: Box::new(e),
@@ -50,7 +48,7 @@ pub fn get_verifications<'a>(
             .0,
     );
     res.0.append(
-        &mut consistency::get_verifications(metadata_list, config)
+        &mut consistency::get_verifications(metadata_list)
             .map_err(|e| VerificationErrorImpl::GetCategory {
                 category: "Consistency",
                 source: Box::new(e),
@@ -58,7 +56,7 @@ pub fn get_verifications<'a>(
             .0,
     );
     res.0.append(
-        &mut evidence::get_verifications(metadata_list, config)
+        &mut evidence::get_verifications(metadata_list)
             .map_err(|e| VerificationErrorImpl::GetCategory {
                 category: "Evidence",
                 source: Box::new(e),
@@ -66,7 +64,7 @@ pub fn get_verifications<'a>(
             .0,
     );
     res.0.append(
-        &mut integrity::get_verifications(metadata_list, config)
+        &mut integrity::get_verifications(metadata_list)
             .map_err(|e| VerificationErrorImpl::GetCategory {
                 category: "Integrity",
                 source: Box::new(e),
@@ -20,8 +20,9 @@ use super::{
     meta_data::VerificationMetaDataList, setup::get_verifications as get_verifications_setup,
     tally::get_verifications as get_verifications_tally, verifications::Verification,
     VerificationCategory, VerificationError, VerificationErrorImpl, VerificationPeriod,
+    VerificationStatus,
 };
-use crate::{config::VerifierConfig, file_structure::VerificationDirectory};
+use crate::file_structure::VerificationDirectory;
 
 /// Enum for the suite of verifications
 pub struct VerificationSuite<'a> {
@@ -42,22 +43,22 @@ impl<'a> VerificationSuite<'a> {
         period: &VerificationPeriod,
         metadata_list: &'a VerificationMetaDataList,
         exclusion: &[String],
-        config: &'static VerifierConfig,
     ) -> Result<VerificationSuite<'a>, VerificationError> {
-        let all_verifs =
-            match period {
-                VerificationPeriod::Setup => get_verifications_setup(metadata_list, config)
-                    .map_err(|e| VerificationErrorImpl::GetPeriod {
-                        period: VerificationPeriod::Setup,
-                        source: Box::new(e),
-                    })?,
-
-                VerificationPeriod::Tally => get_verifications_tally(metadata_list, config)
-                    .map_err(|e| VerificationErrorImpl::GetPeriod {
-                        period: VerificationPeriod::Tally,
-                        source: Box::new(e),
-                    })?,
-            };
+        let all_verifs = match period {
+            VerificationPeriod::Setup => get_verifications_setup(metadata_list).map_err(|e| {
+                VerificationErrorImpl::GetPeriod {
+                    period: VerificationPeriod::Setup,
+                    source: Box::new(e),
+                }
+            })?,
+
+            VerificationPeriod::Tally => get_verifications_tally(metadata_list).map_err(|e| {
+                VerificationErrorImpl::GetPeriod {
+                    period: VerificationPeriod::Tally,
+                    source: Box::new(e),
+                }
+            })?,
+        };
         let all_ids: Vec<String> = all_verifs.0.iter().map(|v| v.id().to_string()).collect();
         let verifs = all_verifs
             .0
@@ -81,14 +82,14 @@ impl<'a> VerificationSuite<'a> {
     /// All verifications
     ///
     /// The excluded verifications are not collected
-    pub fn verifications(&'a self) -> &'a VerificationList<'a> {
+    pub fn verifications(&self) -> &VerificationList<'a> {
         &self.list
     }
 
     /// All verifications mutable
     ///
     /// The excluded verifications are not collected
-    pub fn verifications_mut(&'a mut self) -> &'a mut VerificationList<'a> {
+    pub fn verifications_mut(&mut self) -> &mut VerificationList<'a> {
         &mut self.list
     }
 
@@ -142,6 +143,54 @@ impl<'a> VerificationSuite<'a> {
     pub fn find_by_id(&self, id: &str) -> Option<&Verification<'a, VerificationDirectory>> {
         self.list.0.iter().find(|&v| v.meta_data().id() == id)
     }
+
+    /// List of ids of the verifications in one of the given status
+    ///
+    /// The excluded verifications are not collected
+    pub fn collect_id_with_status(&self, status: &[VerificationStatus]) -> Vec<&str> {
+        let mut list: Vec<&str> = self
+            .list
+            .0
+            .iter()
+            .filter(|v| status.contains(&v.status()))
+            .map(|v| v.id())
+            .collect();
+        list.sort();
+        list
+    }
+
+    /// Reset the verifications with the given ids, in order to run them again
+    ///
+    /// The previous results are discarded (see [Verification::reset]).
+    ///
+    /// Return an error if an id is not in the suite (e.g. excluded) or if a verification is running.
+    /// In this case, no verification is reset
+    pub fn reset_verifications(&mut self, ids: &[String]) -> Result<(), VerificationError> {
+        for id in ids {
+            match self.find_by_id(id) {
+                None => {
+                    return Err(VerificationError::from(VerificationErrorImpl::NotInSuite {
+                        id: id.clone(),
+                    }));
+                }
+                Some(v) if v.status() == VerificationStatus::Running => {
+                    return Err(VerificationError::from(
+                        VerificationErrorImpl::ResetRunning { id: id.clone() },
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+        for v in self
+            .list
+            .0
+            .iter_mut()
+            .filter(|v| ids.iter().any(|id| id == v.id()))
+        {
+            v.reset();
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -153,12 +202,7 @@ mod test {
     fn test_setup_verifications() {
         let metadata_list =
             VerificationMetaDataList::load(CONFIG_TEST.get_verification_list_str()).unwrap();
-        let r_verifs = VerificationSuite::new(
-            &VerificationPeriod::Setup,
-            &metadata_list,
-            &[],
-            &CONFIG_TEST,
-        );
+        let r_verifs = VerificationSuite::new(&VerificationPeriod::Setup, &metadata_list, &[]);
         if r_verifs.is_err() {
             let err = r_verifs.as_ref().err().unwrap();
             println!("{err:?}")
@@ -180,12 +224,7 @@ mod test {
     fn test_tally_verifications() {
         let metadata_list =
             VerificationMetaDataList::load(CONFIG_TEST.get_verification_list_str()).unwrap();
-        let r_verifs = VerificationSuite::new(
-            &VerificationPeriod::Tally,
-            &metadata_list,
-            &[],
-            &CONFIG_TEST,
-        );
+        let r_verifs = VerificationSuite::new(&VerificationPeriod::Tally, &metadata_list, &[]);
         if r_verifs.is_err() {
             let err = r_verifs.as_ref().err().unwrap();
             println!("{err:?}")
@@ -203,6 +242,34 @@ mod test {
         assert_eq!(verif_ids, metadata_ids)
     }
 
+    #[test]
+    fn test_reset_verifications() {
+        let metadata_list =
+            VerificationMetaDataList::load(CONFIG_TEST.get_verification_list_str()).unwrap();
+        let mut verifs = VerificationSuite::new(
+            &VerificationPeriod::Setup,
+            &metadata_list,
+            &["02.01".to_string()],
+        )
+        .unwrap();
+        assert_eq!(
+            verifs
+                .collect_id_with_status(&[VerificationStatus::NotStarted])
+                .len(),
+            verifs.len()
+        );
+        assert!(verifs
+            .collect_id_with_status(&[VerificationStatus::FinishedWithErrors])
+            .is_empty());
+        assert!(verifs.reset_verifications(&["01.01".to_string()]).is_ok());
+        assert_eq!(
+            verifs.find_by_id("01.01").unwrap().status(),
+            VerificationStatus::NotStarted
+        );
+        assert!(verifs.reset_verifications(&["02.01".to_string()]).is_err());
+        assert!(verifs.reset_verifications(&["toto".to_string()]).is_err());
+    }
+
     #[test]
     fn test_with_exclusion() {
         /*
@@ -212,7 +279,6 @@ mod test {
             &VerificationPeriod::Setup,
             &metadata_list,
             &["02.01".to_string(), "05.01".to_string()],
-            &CONFIG_TEST,
         );
         assert_eq!(verifs.len(), EXPECTED_IMPL_SETUP_VERIF - 2);
         assert_eq!(verifs.len_excluded(), 2);
@@ -224,7 +290,6 @@ mod test {
             &VerificationPeriod::Setup,
             &metadata_list,
             &["toto".to_string()],
-            &CONFIG_TEST,
         );
         assert_eq!(verifs.len(), EXPECTED_IMPL_SETUP_VERIF);
         assert_eq!(verifs.len_excluded(), 0);
@@ -233,7 +298,6 @@ mod test {
             &VerificationPeriod::Setup,
             &metadata_list,
             &["02.01".to_string(), "05.01".to_string(), "toto".to_string()],
-            &CONFIG_TEST,
         );
         assert_eq!(verifs.len(), EXPECTED_IMPL_SETUP_VERIF - 2);
         assert_eq!(verifs.len_excluded(), 2);
@@ -18,20 +18,18 @@ use super::super::{
     result::VerificationEvent, suite::VerificationList, verifications::Verification,
 };
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         VerificationDirectoryTrait,
         tally_directory::{BBDirectoryTrait, TallyDirectoryTrait},
     },
     verification::{
-        VerificationError, VerificationErrorImpl, meta_data::VerificationMetaDataList,
+        RunContext, VerificationError, VerificationErrorImpl, meta_data::VerificationMetaDataList,
         result::VerificationResult, verify_signature_for_object,
     },
 };
 
 pub fn get_verifications<'a>(
     metadata_list: &'a VerificationMetaDataList,
-    config: &'static VerifierConfig,
 ) -> Result<VerificationList<'a>, VerificationError> {
     Ok(VerificationList(vec![
         Verification::new(
@@ -39,7 +37,6 @@ pub fn get_verifications<'a>(
             "VerifySignatureControlComponentBallotBox",
             fn_0701_verify_signature_control_component_ballot_box,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifySignatureControlComponentBallotBox",
@@ -50,7 +47,6 @@ pub fn get_verifications<'a>(
             "VerifySignatureControlComponentShuffle",
             fn_0702_verify_verify_signature_control_component_shuffle,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifySignatureControlComponentShuffle",
@@ -61,7 +57,6 @@ pub fn get_verifications<'a>(
             "VerifySignatureTallyComponentShuffle",
             fn_0703_verify_signature_tally_component_shuffle,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifySignatureTallyComponentShuffle",
@@ -72,7 +67,6 @@ pub fn get_verifications<'a>(
             "VerifySignatureTallyComponentVotes",
             fn_0704_verify_signature_tally_component_votes,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifySignatureTallyComponentVotes",
@@ -83,7 +77,6 @@ pub fn get_verifications<'a>(
             "VerifySignatureTallyComponentEch0222",
             fn_0705_verify_signature_ech0222,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifySignatureTallyComponentEch0222",
@@ -94,7 +87,7 @@ pub fn get_verifications<'a>(
 
 fn fn_0701_verify_signature_control_component_ballot_box<D: VerificationDirectoryTrait>(
     dir: &D,
-    config: &'static VerifierConfig,
+    run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let tally_dir = dir.unwrap_tally();
@@ -102,7 +95,13 @@ fn fn_0701_verify_signature_control_component_ballot_box<D: VerificationDirector
         for (i, f) in bb_d.control_component_ballot_box_payload_iter() {
             match f {
                 Ok(d) => result.append_with_context(
-                    &verify_signature_for_object(d.as_ref(), config),
+                    &verify_signature_for_object(
+                        d.as_ref(),
+                        &bb_d
+                            .control_component_ballot_box_payload_group()
+                            .get_file_with_number(i),
+                        run_context,
+                    ),
                     format!(
                         "{}/control_component_ballot_box_payload_{}.json",
                         bb_d.name(),
@@ -123,7 +122,7 @@ fn fn_0701_verify_signature_control_component_ballot_box<D: VerificationDirector
 
 fn fn_0702_verify_verify_signature_control_component_shuffle<D: VerificationDirectoryTrait>(
     dir: &D,
-    config: &'static VerifierConfig,
+    run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let tally_dir = dir.unwrap_tally();
@@ -131,7 +130,13 @@ fn fn_0702_verify_verify_signature_control_component_shuffle<D: VerificationDire
         for (i, f) in bb_d.control_component_shuffle_payload_iter() {
             match f {
                 Ok(d) => result.append_with_context(
-                    &verify_signature_for_object(d.as_ref(), config),
+                    &verify_signature_for_object(
+                        d.as_ref(),
+                        &bb_d
+                            .control_component_shuffle_payload_group()
+                            .get_file_with_number(i),
+                        run_context,
+                    ),
                     format!(
                         "{}/control_component_shuffle_payload_{}.json",
                         bb_d.name(),
@@ -152,14 +157,18 @@ fn fn_0702_verify_verify_signature_control_component_shuffle<D: VerificationDire
 
 fn fn_0703_verify_signature_tally_component_shuffle<D: VerificationDirectoryTrait>(
     dir: &D,
-    config: &'static VerifierConfig,
+    run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let tally_dir = dir.unwrap_tally();
     for bb_d in tally_dir.bb_directories().iter() {
         match bb_d.tally_component_shuffle_payload() {
             Ok(d) => result.append_with_context(
-                &verify_signature_for_object(d.as_ref(), config),
+                &verify_signature_for_object(
+                    d.as_ref(),
+                    bb_d.tally_component_shuffle_payload_file(),
+                    run_context,
+                ),
                 format!("{}/tally_component_shuffle_payload.json", bb_d.name(),),
             ),
             Err(e) => result.push(VerificationEvent::new_error_from_error(&e).add_context(
@@ -171,14 +180,18 @@ fn fn_0703_verify_signature_tally_component_shuffle<D: VerificationDirectoryTrai
 
 fn fn_0704_verify_signature_tally_component_votes<D: VerificationDirectoryTrait>(
     dir: &D,
-    config: &'static VerifierConfig,
+    run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let tally_dir = dir.unwrap_tally();
     for bb_d in tally_dir.bb_directories().iter() {
         match bb_d.tally_component_votes_payload() {
             Ok(d) => result.append_with_context(
-                &verify_signature_for_object(d.as_ref(), config),
+                &verify_signature_for_object(
+                    d.as_ref(),
+                    bb_d.tally_component_votes_payload_file(),
+                    run_context,
+                ),
                 format!("{}/tally_component_votes_payload.json", bb_d.name(),),
             ),
             Err(e) => result.push(VerificationEvent::new_error_from_error(&e).add_context(
@@ -190,13 +203,13 @@ fn fn_0704_verify_signature_tally_component_votes<D: VerificationDirectoryTrait>
 
 fn fn_0705_verify_signature_ech0222<D: VerificationDirectoryTrait>(
     dir: &D,
-    config: &'static VerifierConfig,
+    run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let tally_dir = dir.unwrap_tally();
     match tally_dir.ech_0222() {
         Ok(d) => result.append_with_context(
-            &verify_signature_for_object(d.as_ref(), config),
+            &verify_signature_for_object(d.as_ref(), tally_dir.ech_0222_file(), run_context),
             "ech_0222.xml",
         ),
         Err(e) => {
@@ -210,9 +223,13 @@ mod test {
     use rust_ev_system_library::rust_ev_crypto_primitives::prelude::Integer;
 
     use super::*;
-    use crate::{config::test::{
-        get_test_verifier_mock_tally_dir, get_test_verifier_tally_dir as get_verifier_dir, CONFIG_TEST
-    }, consts::NUMBER_CONTROL_COMPONENTS};
+    use crate::{
+        config::test::{
+            CONTEXT_TEST, get_test_verifier_mock_tally_dir,
+            get_test_verifier_tally_dir as get_verifier_dir,
+        },
+        consts::NUMBER_CONTROL_COMPONENTS,
+    };
 
     fn nb_bb() -> usize {
         get_test_verifier_mock_tally_dir()
@@ -225,7 +242,7 @@ mod test {
     fn test_0701() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0701_verify_signature_control_component_ballot_box(&dir, &CONFIG_TEST, &mut result);
+        fn_0701_verify_signature_control_component_ballot_box(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for e in result.errors() {
                 println!("{e:?}");
@@ -241,7 +258,7 @@ mod test {
     fn test_0702() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0702_verify_verify_signature_control_component_shuffle(&dir, &CONFIG_TEST, &mut result);
+        fn_0702_verify_verify_signature_control_component_shuffle(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for e in result.errors() {
                 println!("{e:?}");
@@ -257,7 +274,7 @@ mod test {
     fn test_0703() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0703_verify_signature_tally_component_shuffle(&dir, &CONFIG_TEST, &mut result);
+        fn_0703_verify_signature_tally_component_shuffle(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for e in result.errors() {
                 println!("{e:?}");
@@ -273,7 +290,7 @@ mod test {
     fn test_0704() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0704_verify_signature_tally_component_votes(&dir, &CONFIG_TEST, &mut result);
+        fn_0704_verify_signature_tally_component_votes(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for e in result.errors() {
                 println!("{e:?}");
@@ -289,7 +306,7 @@ mod test {
     fn test_0705() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0705_verify_signature_ech0222(&dir, &CONFIG_TEST, &mut result);
+        fn_0705_verify_signature_ech0222(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for e in result.errors() {
                 println!("{e:?}");
@@ -317,7 +334,7 @@ mod test {
                 let mut result = VerificationResult::new();
                 fn_0701_verify_signature_control_component_ballot_box(
                     &mock_dir,
-                    &CONFIG_TEST,
+                    &CONTEXT_TEST,
                     &mut result,
                 );
                 dbg!(&result);
@@ -344,7 +361,7 @@ mod test {
                 let mut result = VerificationResult::new();
                 fn_0702_verify_verify_signature_control_component_shuffle(
                     &mock_dir,
-                    &CONFIG_TEST,
+                    &CONTEXT_TEST,
                     &mut result,
                 );
                 dbg!(&result);
@@ -368,7 +385,7 @@ mod test {
                     d.encryption_group.set_p(&Integer::from(1234usize));
                 });
             let mut result = VerificationResult::new();
-            fn_0703_verify_signature_tally_component_shuffle(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_0703_verify_signature_tally_component_shuffle(&mock_dir, &CONTEXT_TEST, &mut result);
             dbg!(&result);
             assert!(!result.is_ok());
             assert!(!result.has_errors());
@@ -389,7 +406,7 @@ mod test {
                     d.encryption_group.set_p(&Integer::from(1234usize));
                 });
             let mut result = VerificationResult::new();
-            fn_0704_verify_signature_tally_component_votes(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_0704_verify_signature_tally_component_votes(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.is_ok());
             assert!(!result.has_errors());
             assert_eq!(result.failures().len(), 1);
@@ -415,7 +432,7 @@ mod test {
             .unwrap_tally_mut()
             .mock_mock_ech_0222_raw(new_input);
         let mut result = VerificationResult::new();
-        fn_0705_verify_signature_ech0222(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_0705_verify_signature_ech0222(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.is_ok());
         assert!(!result.has_errors());
         assert_eq!(result.failures().len(), 1);
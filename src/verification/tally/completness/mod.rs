@@ -21,21 +21,18 @@ use super::super::{
     verifications::Verification,
 };
 use crate::{
-    config::VerifierConfig,
     file_structure::{CompletnessTestTrait, VerificationDirectoryTrait},
-    verification::{VerificationError, VerificationErrorImpl},
+    verification::{RunContext, VerificationError, VerificationErrorImpl},
 };
 
 pub fn get_verifications<'a>(
     metadata_list: &'a VerificationMetaDataList,
-    config: &'static VerifierConfig,
 ) -> Result<VerificationList<'a>, VerificationError> {
     Ok(VerificationList(vec![Verification::new(
         "06.01",
         "VerifyTallyCompleteness",
         fn_0601_verify_tally_completeness,
         metadata_list,
-        config,
     )
     .map_err(|e| VerificationErrorImpl::GetVerification {
         name: "VerifyTallyCompleteness",
@@ -45,7 +42,7 @@ pub fn get_verifications<'a>(
 
 fn fn_0601_verify_tally_completeness<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -63,13 +60,13 @@ fn fn_0601_verify_tally_completeness<D: VerificationDirectoryTrait>(
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::config::test::{get_test_verifier_tally_dir, CONFIG_TEST};
+    use crate::config::test::{get_test_verifier_tally_dir, CONTEXT_TEST};
 
     #[test]
     fn test_ok() {
         let dir = get_test_verifier_tally_dir();
         let mut result = VerificationResult::new();
-        fn_0601_verify_tally_completeness(&dir, &CONFIG_TEST, &mut result);
+        fn_0601_verify_tally_completeness(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 }
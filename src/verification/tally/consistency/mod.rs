@@ -28,13 +28,11 @@ mod v0811_verify_number_confirmed_encrypted_votes_consistency;
 
 use super::super::{suite::VerificationList, verifications::Verification};
 use crate::{
-    config::VerifierConfig,
     verification::{meta_data::VerificationMetaDataList, VerificationError, VerificationErrorImpl},
 };
 
 pub fn get_verifications<'a>(
     metadata_list: &'a VerificationMetaDataList,
-    config: &'static VerifierConfig,
 ) -> Result<VerificationList<'a>, VerificationError> {
     Ok(VerificationList(vec![
         Verification::new(
@@ -42,7 +40,6 @@ pub fn get_verifications<'a>(
             "VerifyEncryptionGroupConsistency",
             v0801_verify_encryption_group_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyEncryptionGroupConsistency",
@@ -53,7 +50,6 @@ pub fn get_verifications<'a>(
             "VerifyNodeIdsConsistency",
             v0802_verify_node_ids_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyNodeIdsConsistency",
@@ -64,7 +60,6 @@ pub fn get_verifications<'a>(
             "VerifyFileNameNodeIdsConsistency",
             v0803_verify_file_name_node_ids_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyFileNameNodeIdsConsistency",
@@ -75,7 +70,6 @@ pub fn get_verifications<'a>(
             "VerifyElectionEventIdConsistency",
             v0804_verify_election_event_id_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyElectionEventIdConsistency",
@@ -86,7 +80,6 @@ pub fn get_verifications<'a>(
             "VerifyBallotBoxIdsConsistency",
             v0805_verify_ballot_box_ids_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyBallotBoxIdsConsistency",
@@ -97,7 +90,6 @@ pub fn get_verifications<'a>(
             "VerifyFileNameBallotBoxIdsConsistency",
             v0806_verify_file_name_ballot_box_ids_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyFileNameBallotBoxIdsConsistency",
@@ -108,7 +100,6 @@ pub fn get_verifications<'a>(
             "VerifyVerificationCardIdsConsistency",
             v0807_verify_verification_card_ids_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyVerificationCardIdsConsistency",
@@ -119,7 +110,6 @@ pub fn get_verifications<'a>(
             "VerifyConfirmedEncryptedVotesConsistency",
             v0808_verify_confirmed_encrypted_votes_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyConfirmedEncryptedVotesConsistency",
@@ -130,7 +120,6 @@ pub fn get_verifications<'a>(
             "VerifyCiphertextsConsistency",
             v0809_verify_ciphertexts_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyCiphertextsConsistency",
@@ -141,7 +130,6 @@ pub fn get_verifications<'a>(
             "VerifyPlaintextsConsistency",
             v0810_verify_plaintexts_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyPlaintextsConsistency",
@@ -152,7 +140,6 @@ pub fn get_verifications<'a>(
             "VerifyNumberConfirmedEncryptedVotesConsistency",
             v0811_verify_number_confirmed_encrypted_votes_consistency::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyNumberConfirmedEncryptedVotesConsistency",
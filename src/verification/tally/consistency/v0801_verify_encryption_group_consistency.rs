@@ -16,12 +16,12 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         VerificationDirectoryTrait,
         context_directory::ContextDirectoryTrait,
         tally_directory::{BBDirectoryTrait, TallyDirectoryTrait},
     },
+    verification::RunContext,
 };
 use rust_ev_system_library::rust_ev_crypto_primitives::prelude::elgamal::EncryptionParameters;
 
@@ -108,7 +108,7 @@ fn verify_encryption_group_for_tally_bb_dir<B: BBDirectoryTrait>(
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let config_dir = dir.context();
@@ -137,7 +137,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_tally_dir,
+            CONTEXT_TEST, get_test_verifier_mock_tally_dir,
             get_test_verifier_tally_dir as get_verifier_dir,
         },
         consts::NUMBER_CONTROL_COMPONENTS,
@@ -147,7 +147,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -191,38 +191,38 @@ mod test {
         // p
         let mut result = VerificationResult::new();
         let mut mock_dir = get_test_verifier_mock_tally_dir();
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
         mock_dir
             .context_mut()
             .mock_election_event_context_payload(|d| {
                 d.encryption_group.set_p(&Integer::from(1234usize));
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
         // q
         let mut result = VerificationResult::new();
         let mut mock_dir = get_test_verifier_mock_tally_dir();
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
         mock_dir
             .context_mut()
             .mock_election_event_context_payload(|d| {
                 d.encryption_group.set_q(&Integer::from(1234usize));
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
         // g
         let mut result = VerificationResult::new();
         let mut mock_dir = get_test_verifier_mock_tally_dir();
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
         mock_dir
             .context_mut()
             .mock_election_event_context_payload(|d| {
                 d.encryption_group.set_g(&Integer::from(1234usize));
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.has_errors());
         assert!(result.has_failures());
     }
@@ -241,7 +241,7 @@ mod test {
                 .mock_tally_component_shuffle_payload(|d| {
                     d.encryption_group.set_p(&Integer::from(1234usize));
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
             // q
@@ -251,7 +251,7 @@ mod test {
                 .mock_tally_component_shuffle_payload(|d| {
                     d.encryption_group.set_q(&Integer::from(1234usize));
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
             // g
@@ -261,7 +261,7 @@ mod test {
                 .mock_tally_component_shuffle_payload(|d| {
                     d.encryption_group.set_g(&Integer::from(1234usize));
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
         }
@@ -282,7 +282,7 @@ mod test {
                     d.encryption_group.set_p(&Integer::from(1234usize));
                 },
             );
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
             // q
@@ -293,7 +293,7 @@ mod test {
                     d.encryption_group.set_q(&Integer::from(1234usize));
                 },
             );
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
             // g
@@ -304,7 +304,7 @@ mod test {
                     d.encryption_group.set_g(&Integer::from(1234usize));
                 },
             );
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors());
             assert!(result.has_failures());
         }
@@ -325,7 +325,7 @@ mod test {
                     .mock_control_component_ballot_box_payload(j, |d| {
                         d.encryption_group.set_p(&Integer::from(1234usize));
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
                 // q
@@ -335,7 +335,7 @@ mod test {
                     .mock_control_component_ballot_box_payload(j, |d| {
                         d.encryption_group.set_q(&Integer::from(1234usize));
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
                 // g
@@ -345,7 +345,7 @@ mod test {
                     .mock_control_component_ballot_box_payload(j, |d| {
                         d.encryption_group.set_g(&Integer::from(1234usize));
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
             }
@@ -367,7 +367,7 @@ mod test {
                     .mock_control_component_shuffle_payload(j, |d| {
                         d.encryption_group.set_p(&Integer::from(1234usize));
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
                 // q
@@ -377,7 +377,7 @@ mod test {
                     .mock_control_component_shuffle_payload(j, |d| {
                         d.encryption_group.set_q(&Integer::from(1234usize));
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
                 // g
@@ -387,7 +387,7 @@ mod test {
                     .mock_control_component_shuffle_payload(j, |d| {
                         d.encryption_group.set_g(&Integer::from(1234usize));
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
             }
@@ -18,16 +18,16 @@ use std::collections::HashSet;
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     consts::CONTROL_COMPONENT_ID_LIST,
     file_structure::{
         TallyDirectoryTrait, VerificationDirectoryTrait, tally_directory::BBDirectoryTrait,
     },
+    verification::RunContext,
 };
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let tally_dir = dir.unwrap_tally();
@@ -104,7 +104,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_tally_dir,
+            CONTEXT_TEST, get_test_verifier_mock_tally_dir,
             get_test_verifier_tally_dir as get_verifier_dir,
         },
         consts::{NUMBER_CONTROL_COMPONENTS, test::MIXED_CONTROL_COMPONENT_ID_LIST},
@@ -114,7 +114,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for r in result.errors_to_string() {
                 println!("{:?}", r)
@@ -140,7 +140,7 @@ mod test {
                     .mock_control_component_ballot_box_payload(j, |d| {
                         d.node_id = MIXED_CONTROL_COMPONENT_ID_LIST[j - 1];
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors(), "j={}, folder {i}", j);
                 assert!(result.has_failures(), "j={}, folder {i}", j);
             }
@@ -168,7 +168,7 @@ mod test {
                         };
                         d.node_id = new_j;
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors(), "j={}, folder {i}", j);
                 assert!(result.has_failures(), "j={}, folder {i}", j);
             }
@@ -16,11 +16,11 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         VerificationDirectoryTrait,
         tally_directory::{BBDirectoryTrait, TallyDirectoryTrait},
     },
+    verification::RunContext,
 };
 
 fn verify_nod_ir_for_tally_bb_dir<B: BBDirectoryTrait>(dir: &B, result: &mut VerificationResult) {
@@ -67,7 +67,7 @@ fn verify_nod_ir_for_tally_bb_dir<B: BBDirectoryTrait>(dir: &B, result: &mut Ver
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let tally_dir = dir.unwrap_tally();
@@ -82,7 +82,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_tally_dir as get_verifier_dir, test_data_path,
+            CONTEXT_TEST, get_test_verifier_tally_dir as get_verifier_dir, test_data_path,
         },
         file_structure::VerificationDirectory,
         verification::VerificationPeriod,
@@ -92,7 +92,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -107,7 +107,7 @@ mod test {
         {
             let dir = VerificationDirectory::new(&VerificationPeriod::Tally, &p);
             let mut result = VerificationResult::new();
-            fn_verification(&dir, &CONFIG_TEST, &mut result);
+            fn_verification(&dir, &CONTEXT_TEST, &mut result);
             assert!(
                 !result.has_errors(),
                 "Has errors: path={} / errors={:?}",
@@ -16,16 +16,16 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         ContextDirectoryTrait, TallyDirectoryTrait, VerificationDirectoryTrait,
         tally_directory::BBDirectoryTrait,
     },
+    verification::RunContext,
 };
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -131,7 +131,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_tally_dir,
+            CONTEXT_TEST, get_test_verifier_mock_tally_dir,
             get_test_verifier_tally_dir as get_verifier_dir,
         },
         consts::NUMBER_CONTROL_COMPONENTS,
@@ -141,7 +141,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok(), "Result: {:?}", result);
     }
 
@@ -155,7 +155,7 @@ mod test {
                 d.election_event_context.election_event_id =
                     "modified-election-event-id".to_string();
             });
-        fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+        fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
         assert!(!result.has_errors());
         assert!(result.has_failures());
     }
@@ -173,7 +173,7 @@ mod test {
                 .mock_tally_component_votes_payload(|d| {
                     d.election_event_id = "modified-election-event-id".to_string();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at bb {}", bb.name());
             assert!(result.has_failures(), "Failed at bb {}", bb.name());
         }
@@ -192,7 +192,7 @@ mod test {
                 .mock_tally_component_shuffle_payload(|d| {
                     d.election_event_id = "modified-election-event-id".to_string();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at bb {}", bb.name());
             assert!(result.has_failures(), "Failed at bb {}", bb.name());
         }
@@ -212,7 +212,7 @@ mod test {
                     .mock_control_component_ballot_box_payload(j, |d| {
                         d.election_event_id = "modified-election-event-id".to_string();
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(
                     !result.has_errors(),
                     "Failed at bb {} cc_bb {}",
@@ -243,7 +243,7 @@ mod test {
                     .mock_control_component_shuffle_payload(j, |d| {
                         d.election_event_id = "modified-election-event-id".to_string();
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(
                     !result.has_errors(),
                     "Failed at bb {} cc_bb {}",
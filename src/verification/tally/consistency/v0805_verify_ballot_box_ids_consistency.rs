@@ -16,15 +16,15 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         TallyDirectoryTrait, VerificationDirectoryTrait, tally_directory::BBDirectoryTrait,
     },
+    verification::RunContext,
 };
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let tally_dir = dir.unwrap_tally();
@@ -122,7 +122,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_tally_dir,
+            CONTEXT_TEST, get_test_verifier_mock_tally_dir,
             get_test_verifier_tally_dir as get_verifier_dir,
         },
         consts::NUMBER_CONTROL_COMPONENTS,
@@ -132,7 +132,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for r in result.errors_to_string() {
                 println!("{:?}", r)
@@ -157,7 +157,7 @@ mod test {
                 .mock_tally_component_votes_payload(|d| {
                     d.ballot_box_id = "modified-bb-id".to_string();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at bb {}", bb.name());
             assert!(result.has_failures(), "Failed at bb {}", bb.name());
         }
@@ -176,7 +176,7 @@ mod test {
                 .mock_tally_component_shuffle_payload(|d| {
                     d.ballot_box_id = "modified-ballot_box_id".to_string();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at bb {}", bb.name());
             assert!(result.has_failures(), "Failed at bb {}", bb.name());
         }
@@ -196,7 +196,7 @@ mod test {
                     .mock_control_component_ballot_box_payload(j, |d| {
                         d.ballot_box_id = "modified-ballot_box_id".to_string();
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(
                     !result.has_errors(),
                     "Failed at bb {} cc_bb {}",
@@ -227,7 +227,7 @@ mod test {
                     .mock_control_component_shuffle_payload(j, |d| {
                         d.ballot_box_id = "modified-ballot_box_id".to_string();
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(
                     !result.has_errors(),
                     "Failed at bb {} cc_bb {}",
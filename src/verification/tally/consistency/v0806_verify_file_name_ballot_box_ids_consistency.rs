@@ -18,13 +18,13 @@ use std::collections::HashSet;
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{ContextDirectoryTrait, TallyDirectoryTrait, VerificationDirectoryTrait},
+    verification::RunContext,
 };
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -68,7 +68,7 @@ pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
 mod test {
     use super::*;
     use crate::config::test::{
-        CONFIG_TEST, get_test_verifier_mock_tally_dir,
+        CONTEXT_TEST, get_test_verifier_mock_tally_dir,
         get_test_verifier_tally_dir as get_verifier_dir,
     };
 
@@ -76,7 +76,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for r in result.errors_to_string() {
                 println!("{:?}", r)
@@ -99,7 +99,7 @@ mod test {
                 .push(context);
         });
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
         assert!(!result.has_errors());
     }
@@ -117,7 +117,7 @@ mod test {
                     d.election_event_context.verification_card_set_contexts[i].ballot_box_id =
                         "modified-bb_id".to_string();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at vcs {}", i);
             assert!(result.has_failures(), "Failed at VCS {}", i);
         }
@@ -132,7 +132,7 @@ mod test {
                 .pop();
         });
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.has_failures());
         assert!(!result.has_errors());
     }
@@ -18,16 +18,16 @@ use std::collections::HashSet;
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         ContextDirectoryTrait, TallyDirectoryTrait, VerificationDirectoryTrait,
         context_directory::ContextVCSDirectoryTrait, tally_directory::BBDirectoryTrait,
     },
+    verification::RunContext,
 };
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -118,7 +118,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_tally_dir,
+            CONTEXT_TEST, get_test_verifier_mock_tally_dir,
             get_test_verifier_tally_dir as get_verifier_dir,
         },
         consts::NUMBER_CONTROL_COMPONENTS,
@@ -128,7 +128,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for r in result.errors_to_string() {
                 println!("{:?}", r)
@@ -165,7 +165,7 @@ mod test {
                                 .context_ids
                                 .verification_card_id = "modified-vc_id".to_string();
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(
                         !result.has_errors(),
                         "Failed at bb {} cc_bb {}",
@@ -217,7 +217,7 @@ mod test {
                 .mock_setup_component_tally_data_payload(|p| {
                     p.verification_card_ids.clear();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.has_errors(), "Failed at vcs {}", vcs.name(),);
             assert!(result.has_failures(), "Failed at vcs {}", vcs.name(),);
         }
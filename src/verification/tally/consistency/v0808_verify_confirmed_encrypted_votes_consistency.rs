@@ -16,7 +16,6 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     data_structures::{
         ControlComponentBallotBoxPayload,
         tally::control_component_ballot_box_payload::ConfirmedEncryptedVote,
@@ -24,12 +23,13 @@ use crate::{
     file_structure::{
         TallyDirectoryTrait, VerificationDirectoryTrait, tally_directory::BBDirectoryTrait,
     },
+    verification::RunContext,
 };
 use std::fmt::Display;
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let tally_dir = dir.unwrap_tally();
@@ -279,7 +279,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_tally_dir,
+            CONTEXT_TEST, get_test_verifier_mock_tally_dir,
             get_test_verifier_tally_dir as get_verifier_dir,
         },
         consts::NUMBER_CONTROL_COMPONENTS,
@@ -289,7 +289,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -318,7 +318,7 @@ mod test {
                                 .context_ids
                                 .verification_card_id = "modified".to_string();
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(
                         !result.has_errors(),
                         "Failed at bb {} cc_bb {}",
@@ -359,7 +359,7 @@ mod test {
                         .mock_control_component_ballot_box_payload(j, |d| {
                             d.confirmed_encrypted_votes[i].encrypted_vote.gamma = 123.into();
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(
                         !result.has_errors(),
                         "Failed at bb {} cc_bb {}",
@@ -402,7 +402,7 @@ mod test {
                                 .exponentiated_encrypted_vote
                                 .gamma = 123.into();
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(
                         !result.has_errors(),
                         "Failed at bb {} cc_bb {}",
@@ -445,7 +445,7 @@ mod test {
                                 .encrypted_partial_choice_return_codes
                                 .gamma = 123.into();
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(
                         !result.has_errors(),
                         "Failed at bb {} cc_bb {}",
@@ -486,7 +486,7 @@ mod test {
                         .mock_control_component_ballot_box_payload(j, |d| {
                             d.confirmed_encrypted_votes[i].exponentiation_proof.e = 123.into();
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(
                         !result.has_errors(),
                         "Failed at bb {} cc_bb {}",
@@ -527,7 +527,7 @@ mod test {
                         .mock_control_component_ballot_box_payload(j, |d| {
                             d.confirmed_encrypted_votes[i].plaintext_equality_proof.e = 123.into();
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(
                         !result.has_errors(),
                         "Failed at bb {} cc_bb {}",
@@ -570,7 +570,7 @@ mod test {
                     .mock_control_component_ballot_box_payload(j, |d| {
                         d.confirmed_encrypted_votes.pop();
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(
                     !result.has_errors(),
                     "Failed at bb {} cc_bb {}",
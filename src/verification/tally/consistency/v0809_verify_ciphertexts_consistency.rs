@@ -16,7 +16,6 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     data_structures::{
         ControlComponentBallotBoxPayload, ControlComponentShufflePayload,
         TallyComponentShufflePayload,
@@ -25,13 +24,14 @@ use crate::{
         ContextDirectoryTrait, TallyDirectoryTrait, VerificationDirectoryTrait,
         tally_directory::BBDirectoryTrait,
     },
+    verification::RunContext,
 };
 use rust_ev_system_library::preliminaries::PTableTrait;
 use rust_ev_system_library::rust_ev_crypto_primitives::prelude::elgamal::Ciphertext;
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -195,7 +195,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_tally_dir,
+            CONTEXT_TEST, get_test_verifier_mock_tally_dir,
             get_test_verifier_tally_dir as get_verifier_dir,
         },
         consts::NUMBER_CONTROL_COMPONENTS,
@@ -205,7 +205,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 
@@ -227,7 +227,7 @@ mod test {
                     .mock_tally_component_shuffle_payload(|d| {
                         d.verifiable_shuffle.shuffled_ciphertexts[c_i].phis.pop();
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
             }
@@ -254,7 +254,7 @@ mod test {
                             .phis
                             .push(Integer::from(123usize));
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(!result.has_errors());
                 assert!(result.has_failures());
             }
@@ -283,7 +283,7 @@ mod test {
                         .mock_control_component_shuffle_payload(j, |d| {
                             d.verifiable_shuffle.shuffled_ciphertexts[c_i].phis.pop();
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(!result.has_errors());
                     assert!(result.has_failures());
                 }
@@ -315,7 +315,7 @@ mod test {
                                 .phis
                                 .push(Integer::from(123usize));
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(!result.has_errors());
                     assert!(result.has_failures());
                 }
@@ -344,7 +344,7 @@ mod test {
                         .mock_control_component_ballot_box_payload(j, |d| {
                             d.confirmed_encrypted_votes[c_i].encrypted_vote.phis.pop();
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(!result.has_errors());
                     assert!(result.has_failures());
                 }
@@ -376,7 +376,7 @@ mod test {
                                 .phis
                                 .push(Integer::from(123usize));
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(!result.has_errors());
                     assert!(result.has_failures());
                 }
@@ -16,17 +16,17 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         ContextDirectoryTrait, TallyDirectoryTrait, VerificationDirectoryTrait,
         tally_directory::BBDirectoryTrait,
     },
+    verification::RunContext,
 };
 use rust_ev_system_library::preliminaries::PTableTrait;
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -131,7 +131,7 @@ fn verify_pro_ballot_box<B: BBDirectoryTrait>(bb_dir: &B, delta: usize) -> Verif
 mod test {
     use super::*;
     use crate::config::test::{
-        CONFIG_TEST, get_test_verifier_mock_tally_dir,
+        CONTEXT_TEST, get_test_verifier_mock_tally_dir,
         get_test_verifier_tally_dir as get_verifier_dir,
     };
     use rust_ev_system_library::rust_ev_crypto_primitives::prelude::Integer;
@@ -140,7 +140,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for r in result.errors_to_string() {
                 println!("{:?}", r)
@@ -174,7 +174,7 @@ mod test {
                                 .message
                                 .pop();
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(
                         !result.has_errors(),
                         "Failed for decrypted votes at position {c_i}"
@@ -194,7 +194,7 @@ mod test {
                                 .z
                                 .pop();
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(
                         !result.has_errors(),
                         "Failed for decrypted votes at position {c_i}"
@@ -230,7 +230,7 @@ mod test {
                                 .message
                                 .push(Integer::from(123usize));
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(!result.has_errors());
                     assert!(result.has_failures());
                 }
@@ -244,7 +244,7 @@ mod test {
                                 .z
                                 .push(Integer::from(123usize));
                         });
-                    fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                    fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                     assert!(!result.has_errors());
                     assert!(result.has_failures());
                 }
@@ -16,15 +16,15 @@
 
 use super::super::super::result::{VerificationEvent, VerificationResult};
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         TallyDirectoryTrait, VerificationDirectoryTrait, tally_directory::BBDirectoryTrait,
     },
+    verification::RunContext,
 };
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let tally_dir = dir.unwrap_tally();
@@ -133,7 +133,7 @@ mod test {
     use super::*;
     use crate::{
         config::test::{
-            CONFIG_TEST, get_test_verifier_mock_tally_dir,
+            CONTEXT_TEST, get_test_verifier_mock_tally_dir,
             get_test_verifier_tally_dir as get_verifier_dir,
         },
         consts::NUMBER_CONTROL_COMPONENTS,
@@ -143,7 +143,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for r in result.errors_to_string() {
                 println!("{:?}", r)
@@ -164,7 +164,7 @@ mod test {
             let mut mock_dir = get_test_verifier_mock_tally_dir();
             mock_dir.unwrap_tally_mut().bb_directories_mut()[i]
                 .mock_tally_component_votes_payload(|d| d.decrypted_votes.push(vec![1usize; 10]));
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(
                 !result.has_errors(),
                 "Failed for decrypted votes for bb id {i}"
@@ -197,7 +197,7 @@ mod test {
                     d.decrypted_votes.pop();
                 },
             );
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(
                 !result.has_errors(),
                 "Failed for decrypted votes for bb {}",
@@ -236,7 +236,7 @@ mod test {
                     .mock_control_component_ballot_box_payload(j, |d| {
                         d.confirmed_encrypted_votes.pop();
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(
                     !result.has_errors(),
                     "Failed for decrypted votes for bb {} and cc {j}",
@@ -272,7 +272,7 @@ mod test {
                 .mock_tally_component_shuffle_payload(|d| {
                     d.verifiable_shuffle.shuffled_ciphertexts.pop();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(
                 !result.has_errors(),
                 "Failed for decrypted votes for bb {}",
@@ -312,7 +312,7 @@ mod test {
                     .mock_control_component_shuffle_payload(j, |d| {
                         d.verifiable_decryptions.ciphertexts.pop();
                     });
-                fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+                fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
                 assert!(
                     !result.has_errors(),
                     "Failed for decrypted votes for bb {} and cc {j}",
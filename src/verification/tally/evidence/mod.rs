@@ -19,13 +19,11 @@ mod v1002_verify_tally_control_component;
 
 use super::super::{suite::VerificationList, verifications::Verification};
 use crate::{
-    config::VerifierConfig,
     verification::{meta_data::VerificationMetaDataList, VerificationError, VerificationErrorImpl},
 };
 
 pub fn get_verifications<'a>(
     metadata_list: &'a VerificationMetaDataList,
-    config: &'static VerifierConfig,
 ) -> Result<VerificationList<'a>, VerificationError> {
     Ok(VerificationList(vec![
         Verification::new(
@@ -33,7 +31,6 @@ pub fn get_verifications<'a>(
             "VerifyOnlineControlComponents",
             v1001_verify_online_control_components::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyOnlineControlComponents",
@@ -44,7 +41,6 @@ pub fn get_verifications<'a>(
             "VerifyTallyControlComponent",
             v1002_verify_tally_control_component::fn_verification,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyTallyControlComponent",
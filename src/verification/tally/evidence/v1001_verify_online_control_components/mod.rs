@@ -17,7 +17,6 @@
 mod verify_online_control_components_ballot_box;
 
 use crate::{
-    VerifierConfig,
     data_structures::{
         ElectionEventContextPayload,
         context::setup_component_public_keys_payload::SetupComponentPublicKeys,
@@ -26,7 +25,7 @@ use crate::{
         ContextDirectoryTrait, TallyDirectoryTrait, VerificationDirectoryTrait,
         context_directory::ContextVCSDirectoryTrait, tally_directory::BBDirectoryTrait,
     },
-    verification::{VerificationEvent, VerificationResult},
+    verification::{RunContext, VerificationEvent, VerificationResult},
 };
 use rayon::prelude::*;
 use rust_ev_system_library::rust_ev_crypto_primitives::prelude::mix_net::ShuffleArgument as CryptoShuffleArgument;
@@ -36,7 +35,7 @@ use verify_online_control_components_ballot_box::{
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -109,6 +108,7 @@ pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
             res.append_with_context(result, format!("Ballot box {name}"));
             res
         }));
+    result.add_items_checked(tally_dir.bb_directories().len());
 }
 
 fn verify_for_ballotbox<B: BBDirectoryTrait, S: ContextVCSDirectoryTrait>(
@@ -306,7 +306,7 @@ fn verify_for_ballotbox<B: BBDirectoryTrait, S: ContextVCSDirectoryTrait>(
 mod test {
     use super::*;
     use crate::config::test::{
-        CONFIG_TEST, get_test_verifier_mock_tally_dir,
+        CONTEXT_TEST, get_test_verifier_mock_tally_dir,
         get_test_verifier_tally_dir as get_verifier_dir,
     };
 
@@ -314,7 +314,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for r in result.errors_to_string() {
                 println!("{r:?}")
@@ -351,7 +351,7 @@ mod test {
                         .context_ids
                         .verification_card_id = "corrupted_vc_id".to_string();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.is_ok(), "Failed for vote 0 for bb {}", bb.name());
         }
     }
@@ -368,7 +368,7 @@ mod test {
                 .mock_control_component_shuffle_payload(2, |d| {
                     d.verifiable_shuffle.shuffled_ciphertexts[0].gamma = 1.into();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.is_ok(), "Failed for vote 0 for bb {}", bb.name());
         }
     }
@@ -19,13 +19,12 @@ mod verify_process_plaintexts;
 mod verify_tally_control_component_ballot_box;
 
 use crate::{
-    VerifierConfig,
     data_structures::ElectionEventContextPayload,
     file_structure::{
         ContextDirectoryTrait, TallyDirectoryTrait, VerificationDirectoryTrait,
         tally_directory::BBDirectoryTrait,
     },
-    verification::{VerificationEvent, VerificationResult},
+    verification::{RunContext, VerificationEvent, VerificationResult},
 };
 use rayon::prelude::*;
 use rust_ev_system_library::rust_ev_crypto_primitives::prelude::{
@@ -37,7 +36,7 @@ use verify_tally_control_component_ballot_box::{
 
 pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let context_dir = dir.context();
@@ -87,6 +86,7 @@ pub(super) fn fn_verification<D: VerificationDirectoryTrait>(
                 res
             }),
     );
+    result.add_items_checked(tally_dir.bb_directories().len());
 
     {
         let ee_configuration = match context_dir.election_event_configuration() {
@@ -250,7 +250,7 @@ fn verify_for_ballotbox<B: BBDirectoryTrait>(
 mod test {
     use super::*;
     use crate::config::test::{
-        CONFIG_TEST, get_test_verifier_mock_tally_dir,
+        CONTEXT_TEST, get_test_verifier_mock_tally_dir,
         get_test_verifier_tally_dir as get_verifier_dir,
     };
 
@@ -258,7 +258,7 @@ mod test {
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_verification(&dir, &CONFIG_TEST, &mut result);
+        fn_verification(&dir, &CONTEXT_TEST, &mut result);
         if !result.is_ok() {
             for r in result.errors_to_string() {
                 println!("{r:?}")
@@ -282,7 +282,7 @@ mod test {
                 .mock_tally_component_shuffle_payload(|d| {
                     d.verifiable_shuffle.shuffled_ciphertexts[0].gamma = 1.into();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.is_ok(), "Failed for vote 0 for bb {}", bb.name());
         }
     }
@@ -299,7 +299,7 @@ mod test {
                 .mock_control_component_shuffle_payload(4, |d| {
                     d.verifiable_decryptions.ciphertexts[0].gamma = 1.into();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.is_ok(), "Failed for vote 0 for bb {}", bb.name());
         }
     }
@@ -316,7 +316,7 @@ mod test {
                 .mock_tally_component_shuffle_payload(|d| {
                     d.verifiable_shuffle.shuffle_argument.c_a.push(1.into());
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.is_ok(), "Failed for vote 0 for bb {}", bb.name());
         }
     }
@@ -333,7 +333,7 @@ mod test {
                 .mock_tally_component_shuffle_payload(|d| {
                     d.verifiable_plaintext_decryption.decryption_proofs[0].e = 1.into();
                 });
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.is_ok(), "Failed for vote 0 for bb {}", bb.name());
         }
     }
@@ -356,7 +356,7 @@ mod test {
             let mut mock_dir = get_test_verifier_mock_tally_dir();
             mock_dir.unwrap_tally_mut().bb_directories_mut()[i]
                 .mock_tally_component_votes_payload(|d| d.decrypted_votes[0][0] = 1);
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.is_ok(), "Failed for vote 0 for bb {}", bb.name());
         }
     }
@@ -380,7 +380,7 @@ mod test {
             mock_dir.unwrap_tally_mut().bb_directories_mut()[i].mock_tally_component_votes_payload(
                 |d| d.decoded_votes[0][0] = "faked".to_string(),
             );
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.is_ok(), "Failed for vote 0 for bb {}", bb.name());
         }
     }
@@ -415,7 +415,7 @@ mod test {
             mock_dir.unwrap_tally_mut().bb_directories_mut()[i].mock_tally_component_votes_payload(
                 |d| d.decoded_write_ins[id_write_ins.unwrap()][0] = "faked".to_string(),
             );
-            fn_verification(&mock_dir, &CONFIG_TEST, &mut result);
+            fn_verification(&mock_dir, &CONTEXT_TEST, &mut result);
             assert!(!result.is_ok(), "Failed for vote 0 for bb {}", bb.name());
         }
     }
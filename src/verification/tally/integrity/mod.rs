@@ -20,18 +20,18 @@ use super::super::{
     verifications::Verification,
 };
 use crate::{
-    config::VerifierConfig,
     file_structure::{
         VerificationDirectoryTrait,
         tally_directory::{BBDirectoryTrait, TallyDirectoryTrait},
     },
-    verification::{VerificationError, VerificationErrorImpl, meta_data::VerificationMetaDataList},
+    verification::{
+        RunContext, VerificationError, VerificationErrorImpl, meta_data::VerificationMetaDataList,
+    },
 };
 use rust_ev_system_library::rust_ev_crypto_primitives::prelude::{EmptyContext, VerifyDomainTrait};
 
 pub fn get_verifications<'a>(
     metadata_list: &'a VerificationMetaDataList,
-    config: &'static VerifierConfig,
 ) -> Result<VerificationList<'a>, VerificationError> {
     Ok(VerificationList(vec![
         Verification::new(
@@ -39,7 +39,6 @@ pub fn get_verifications<'a>(
             "VerifyTallyIntegrity",
             fn_0901_verify_tally_integrity,
             metadata_list,
-            config,
         )
         .map_err(|e| VerificationErrorImpl::GetVerification {
             name: "VerifyTallyIntegrity",
@@ -123,7 +122,7 @@ fn validate_bb_dir<B: BBDirectoryTrait>(dir: &B, result: &mut VerificationResult
 
 fn fn_0901_verify_tally_integrity<D: VerificationDirectoryTrait>(
     dir: &D,
-    _config: &'static VerifierConfig,
+    _run_context: &RunContext,
     result: &mut VerificationResult,
 ) {
     let setup_dir = dir.unwrap_tally();
@@ -135,13 +134,13 @@ fn fn_0901_verify_tally_integrity<D: VerificationDirectoryTrait>(
 #[cfg(test)]
 mod test {
     use super::{super::super::result::VerificationResult, *};
-    use crate::config::test::{CONFIG_TEST, get_test_verifier_tally_dir as get_verifier_dir};
+    use crate::config::test::{CONTEXT_TEST, get_test_verifier_tally_dir as get_verifier_dir};
 
     #[test]
     fn test_ok() {
         let dir = get_verifier_dir();
         let mut result = VerificationResult::new();
-        fn_0901_verify_tally_integrity(&dir, &CONFIG_TEST, &mut result);
+        fn_0901_verify_tally_integrity(&dir, &CONTEXT_TEST, &mut result);
         assert!(result.is_ok());
     }
 }
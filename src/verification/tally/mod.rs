@@ -20,8 +20,6 @@ mod consistency;
 mod evidence;
 mod integrity;
 
-use crate::config::VerifierConfig;
-
 use super::{
     meta_data::VerificationMetaDataList, suite::VerificationList, VerificationError,
     VerificationErrorImpl,
@@ -29,11 +27,10 @@ use super::{
 
 pub fn get_verifications<'a>(
     metadata_list: &'a VerificationMetaDataList,
-    config: &'static VerifierConfig,
 ) -> Result<VerificationList<'a>, VerificationError> {
     let mut res = VerificationList(vec![]);
     res.0.append(
-        &mut authenticity::get_verifications(metadata_list, config)
+        &mut authenticity::get_verifications(metadata_list)
             .map_err(|e| VerificationErrorImpl::GetCategory {
                 category: "Authenticity",
                 source: Box::new(e),
@@ -41,7 +38,7 @@ pub fn get_verifications<'a>(
             .0,
     );
     res.0.append(
-        &mut completness::get_verifications(metadata_list, config)
+        &mut completness::get_verifications(metadata_list)
             .map_err(|e| VerificationErrorImpl::GetCategory {
                 category: "Completness",
                 source: Box::new(e),
@@ -49,7 +46,7 @@ pub fn get_verifications<'a>(
             .0,
     );
     res.0.append(
-        &mut consistency::get_verifications(metadata_list, config)
+        &mut consistency::get_verifications(metadata_list)
             .map_err(|e| VerificationErrorImpl::GetCategory {
                 category: "Consistency",
                 source: Box::new(e),
@@ -57,7 +54,7 @@ pub fn get_verifications<'a>(
             .0,
     );
     res.0.append(
-        &mut evidence::get_verifications(metadata_list, config)
+        &mut evidence::get_verifications(metadata_list)
             .map_err(|e| VerificationErrorImpl::GetCategory {
                 category: "Evidence",
                 source: Box::new(e),
@@ -65,7 +62,7 @@ pub fn get_verifications<'a>(
             .0,
     );
     res.0.append(
-        &mut integrity::get_verifications(metadata_list, config)
+        &mut integrity::get_verifications(metadata_list)
             .map_err(|e| VerificationErrorImpl::GetCategory {
                 category: "Integrity",
                 source: Box::new(e),
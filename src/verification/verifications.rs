@@ -18,10 +18,10 @@
 use super::{
     meta_data::{VerificationMetaData, VerificationMetaDataList},
     result::VerificationResult,
+    run_context::RunContext,
     VerificationError, VerificationStatus,
 };
 use crate::{
-    config::VerifierConfig,
     file_structure::{VerificationDirectory, VerificationDirectoryTrait},
     verification::VerificationErrorImpl,
 };
@@ -36,11 +36,10 @@ pub struct Verification<'a, D: VerificationDirectoryTrait> {
     /// The meta data is a reference to the metadata list loaded from json
     meta_data: &'a VerificationMetaData,
     status: VerificationStatus,
-    verification_fn:
-        Box<dyn Fn(&D, &'static VerifierConfig, &mut VerificationResult) + Send + Sync>,
+    verification_fn: Box<dyn Fn(&D, &RunContext, &mut VerificationResult) + Send + Sync>,
     duration: Option<Duration>,
+    process_peak_memory: Option<u64>,
     result: Box<VerificationResult>,
-    config: &'static VerifierConfig,
 }
 
 impl<'a> Verification<'a, VerificationDirectory> {
@@ -52,13 +51,15 @@ impl<'a> Verification<'a, VerificationDirectory> {
     /// ```ignore
     /// fn fn_verification<D: VerificationDirectoryTrait>(
     ///    dir: &D,
+    ///    context: &RunContext,
     ///    result: &mut VerificationResult,
     /// ) {
     ///     ...
     /// }
     /// ```
     ///
-    /// The directory contains the directory where the folder setup and tally are located. The result [VerificationResult]
+    /// The directory contains the directory where the folder setup and tally are located. The context [RunContext]
+    /// contains the configuration, the keystore and the cache of the run. The result [VerificationResult]
     /// has to be changed according to the results of the verification (pushing errors and/or failures).
     /// The function is called by the method rust of the Verification.
     ///
@@ -67,12 +68,11 @@ impl<'a> Verification<'a, VerificationDirectory> {
     pub fn new(
         id: &str,
         name: &str,
-        verification_fn: impl Fn(&VerificationDirectory, &'static VerifierConfig, &mut VerificationResult)
+        verification_fn: impl Fn(&VerificationDirectory, &RunContext, &mut VerificationResult)
             + Send
             + Sync
             + 'static,
         metadata_list: &'a VerificationMetaDataList,
-        config: &'static VerifierConfig,
     ) -> Result<Self, VerificationError> {
         let meta_data = match metadata_list.meta_data_from_id(id) {
             Some(m) => m,
@@ -96,8 +96,8 @@ impl<'a> Verification<'a, VerificationDirectory> {
             status: VerificationStatus::NotStarted,
             verification_fn: Box::new(verification_fn),
             duration: None,
+            process_peak_memory: None,
             result: Box::new(VerificationResult::new()),
-            config,
         })
     }
 
@@ -107,7 +107,7 @@ impl<'a> Verification<'a, VerificationDirectory> {
     }
 
     /// Get the meta data of the verification
-    pub fn meta_data(&'a self) -> &'a VerificationMetaData {
+    pub fn meta_data(&self) -> &'a VerificationMetaData {
         self.meta_data
     }
 
@@ -116,6 +116,24 @@ impl<'a> Verification<'a, VerificationDirectory> {
         self.status
     }
 
+    /// Get the duration of the verification
+    ///
+    /// `None` if the verification has not run
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+
+    /// Get the peak memory of the whole process in bytes, read at the end of the verification
+    ///
+    /// The value is the high-water mark of the resident set size since the start of the process (`VmHWM`).
+    /// It never decreases and includes the memory used by the previous verifications and by the verifications
+    /// running concurrently, so it is not the memory used by this verification.
+    ///
+    /// Only available on Linux. `None` on the other platforms or if the verification has not run
+    pub fn process_peak_memory(&self) -> Option<u64> {
+        self.process_peak_memory
+    }
+
     /// Get the result of the verification
     pub fn verification_result(&self) -> &VerificationResult {
         &self.result
@@ -159,8 +177,24 @@ impl<'a> Verification<'a, VerificationDirectory> {
         }
     }
 
-    /// Run the test.
-    pub fn run(&mut self, directory: &VerificationDirectory) {
+    /// Reset the verification in order to run it again
+    ///
+    /// The previous result is discarded. The caller is responsible to keep it if necessary
+    /// (e.g. with the actions of the runner).
+    /// Return `false` if the verification is running, which cannot be reset
+    pub fn reset(&mut self) -> bool {
+        if self.status == VerificationStatus::Running {
+            return false;
+        }
+        *self.result = VerificationResult::new();
+        self.status = VerificationStatus::NotStarted;
+        self.duration = None;
+        self.process_peak_memory = None;
+        true
+    }
+
+    /// Run the test in the given context.
+    pub fn run(&mut self, directory: &VerificationDirectory, context: &RunContext) {
         self.status = VerificationStatus::Running;
         let start_time = SystemTime::now();
         info!(
@@ -168,8 +202,10 @@ impl<'a> Verification<'a, VerificationDirectory> {
             self.meta_data.name(),
             self.meta_data.id()
         );
-        (self.verification_fn)(directory, self.config, self.result.as_mut());
+        (self.verification_fn)(directory, context, self.result.as_mut());
+        self.result.summarize_verified_signatures();
         self.duration = Some(start_time.elapsed().unwrap());
+        self.process_peak_memory = process_peak_memory_usage();
         self.status = VerificationStatus::calculate_finished(
             self.result.has_errors(),
             self.result.has_failures(),
@@ -203,61 +239,65 @@ impl<'a> Verification<'a, VerificationDirectory> {
     }
 }
 
+/// Peak resident set size of the process in bytes (`VmHWM`), read from `/proc/self/status`
+///
+/// `None` if the information is not available
+#[cfg(target_os = "linux")]
+fn process_peak_memory_usage() -> Option<u64> {
+    std::fs::read_to_string("/proc/self/status")
+        .ok()?
+        .lines()
+        .find_map(|l| l.strip_prefix("VmHWM:"))
+        .and_then(|v| v.trim().trim_end_matches("kB").trim().parse::<u64>().ok())
+        .map(|kb| kb * 1024)
+}
+
+/// The peak memory of the process is only measured on Linux
+#[cfg(not(target_os = "linux"))]
+fn process_peak_memory_usage() -> Option<u64> {
+    None
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::{
-        config::test::CONFIG_TEST,
+        config::test::{CONFIG_TEST, CONTEXT_TEST},
         verification::{result::VerificationEvent, VerificationPeriod},
     };
     use std::path::Path;
 
     #[test]
     fn test_creation() {
-        fn ok(_: &VerificationDirectory, _: &'static VerifierConfig, _: &mut VerificationResult) {}
+        fn ok(_: &VerificationDirectory, _: &RunContext, _: &mut VerificationResult) {}
         let md_list =
             VerificationMetaDataList::load(CONFIG_TEST.get_verification_list_str()).unwrap();
-        assert!(Verification::new(
-            "01.01",
-            "VerifySetupCompleteness",
-            ok,
-            &md_list,
-            &CONFIG_TEST,
-        )
-        .is_ok());
-        assert!(Verification::new(
-            "20.01",
-            "VerifySetupCompleteness",
-            ok,
-            &md_list,
-            &CONFIG_TEST,
-        )
-        .is_err());
-        assert!(Verification::new("01.01", "Toto", ok, &md_list, &CONFIG_TEST,).is_err());
+        assert!(Verification::new("01.01", "VerifySetupCompleteness", ok, &md_list).is_ok());
+        assert!(Verification::new("20.01", "VerifySetupCompleteness", ok, &md_list).is_err());
+        assert!(Verification::new("01.01", "Toto", ok, &md_list).is_err());
     }
 
     #[test]
     fn run_ok() {
-        fn ok(_: &VerificationDirectory, _: &'static VerifierConfig, _: &mut VerificationResult) {}
+        fn ok(_: &VerificationDirectory, _: &RunContext, _: &mut VerificationResult) {}
         let md_list =
             VerificationMetaDataList::load(CONFIG_TEST.get_verification_list_str()).unwrap();
-        let mut verif = Verification::new(
-            "01.01",
-            "VerifySetupCompleteness",
-            ok,
-            &md_list,
-            &CONFIG_TEST,
-        )
-        .unwrap();
+        let mut verif =
+            Verification::new("01.01", "VerifySetupCompleteness", ok, &md_list).unwrap();
         assert_eq!(verif.status, VerificationStatus::NotStarted);
         assert!(!verif.is_result_final());
         assert!(verif.is_ok().is_none());
         assert!(verif.has_errors().is_none());
         assert!(verif.has_failures().is_none());
-        verif.run(&VerificationDirectory::new(
-            &VerificationPeriod::Setup,
-            Path::new("."),
-        ));
+        assert!(verif.duration().is_none());
+        assert!(verif.process_peak_memory().is_none());
+        verif.run(
+            &VerificationDirectory::new(&VerificationPeriod::Setup, Path::new(".")),
+            &CONTEXT_TEST,
+        );
+        assert!(verif.duration().is_some());
+        #[cfg(target_os = "linux")]
+        assert!(verif.process_peak_memory().unwrap() > 0);
         assert_eq!(verif.status, VerificationStatus::FinishedSuccessfully);
         assert!(verif.is_result_final());
         assert!(verif.is_ok().unwrap());
@@ -267,34 +307,24 @@ mod test {
 
     #[test]
     fn run_error() {
-        fn error(
-            _: &VerificationDirectory,
-            _: &'static VerifierConfig,
-            result: &mut VerificationResult,
-        ) {
+        fn error(_: &VerificationDirectory, _: &RunContext, result: &mut VerificationResult) {
             result.push(VerificationEvent::new_error("toto"));
             result.push(VerificationEvent::new_error("toto2"));
             result.push(VerificationEvent::new_failure("toto3"));
         }
         let md_list =
             VerificationMetaDataList::load(CONFIG_TEST.get_verification_list_str()).unwrap();
-        let mut verif = Verification::new(
-            "01.01",
-            "VerifySetupCompleteness",
-            error,
-            &md_list,
-            &CONFIG_TEST,
-        )
-        .unwrap();
+        let mut verif =
+            Verification::new("01.01", "VerifySetupCompleteness", error, &md_list).unwrap();
         assert_eq!(verif.status, VerificationStatus::NotStarted);
         assert!(!verif.is_result_final());
         assert!(verif.is_ok().is_none());
         assert!(verif.has_errors().is_none());
         assert!(verif.has_failures().is_none());
-        verif.run(&VerificationDirectory::new(
-            &VerificationPeriod::Setup,
-            Path::new("."),
-        ));
+        verif.run(
+            &VerificationDirectory::new(&VerificationPeriod::Setup, Path::new(".")),
+            &CONTEXT_TEST,
+        );
         assert_eq!(
             verif.status,
             VerificationStatus::FinishedWithFailuresAndErrors
@@ -309,33 +339,23 @@ mod test {
 
     #[test]
     fn run_failure() {
-        fn failure(
-            _: &VerificationDirectory,
-            _: &'static VerifierConfig,
-            result: &mut VerificationResult,
-        ) {
+        fn failure(_: &VerificationDirectory, _: &RunContext, result: &mut VerificationResult) {
             result.push(VerificationEvent::new_failure("toto"));
             result.push(VerificationEvent::new_failure("toto2"));
         }
         let md_list =
             VerificationMetaDataList::load(CONFIG_TEST.get_verification_list_str()).unwrap();
-        let mut verif = Verification::new(
-            "01.01",
-            "VerifySetupCompleteness",
-            failure,
-            &md_list,
-            &CONFIG_TEST,
-        )
-        .unwrap();
+        let mut verif =
+            Verification::new("01.01", "VerifySetupCompleteness", failure, &md_list).unwrap();
         assert_eq!(verif.status, VerificationStatus::NotStarted);
         assert!(!verif.is_result_final());
         assert!(verif.is_ok().is_none());
         assert!(verif.has_errors().is_none());
         assert!(verif.has_failures().is_none());
-        verif.run(&VerificationDirectory::new(
-            &VerificationPeriod::Setup,
-            Path::new("."),
-        ));
+        verif.run(
+            &VerificationDirectory::new(&VerificationPeriod::Setup, Path::new(".")),
+            &CONTEXT_TEST,
+        );
         assert_eq!(verif.status, VerificationStatus::FinishedWithFailures);
         assert!(verif.is_result_final());
         assert!(!verif.is_ok().unwrap());
@@ -344,4 +364,25 @@ mod test {
         assert_eq!(verif.verification_result().errors().len(), 0);
         assert_eq!(verif.verification_result().failures().len(), 2);
     }
+
+    #[test]
+    fn reset_and_rerun() {
+        fn failure(_: &VerificationDirectory, _: &RunContext, result: &mut VerificationResult) {
+            result.push(VerificationEvent::new_failure("toto"));
+        }
+        let md_list =
+            VerificationMetaDataList::load(CONFIG_TEST.get_verification_list_str()).unwrap();
+        let mut verif =
+            Verification::new("01.01", "VerifySetupCompleteness", failure, &md_list).unwrap();
+        let dir = VerificationDirectory::new(&VerificationPeriod::Setup, Path::new("."));
+        assert!(verif.reset());
+        verif.run(&dir, &CONTEXT_TEST);
+        assert!(verif.reset());
+        assert_eq!(verif.status(), VerificationStatus::NotStarted);
+        assert!(verif.duration().is_none());
+        assert!(verif.verification_result().is_ok());
+        verif.run(&dir, &CONTEXT_TEST);
+        assert_eq!(verif.status(), VerificationStatus::FinishedWithFailures);
+        assert_eq!(verif.verification_result().failures().len(), 1);
+    }
 }